@@ -0,0 +1,157 @@
+//! Collision and pathfinding benchmarks.
+//!
+//! Run with `cargo bench --features bench`.
+//!
+//! The game is a binary crate, so the collision sources are compiled straight
+//! into this bench. Maps are random obstacle fields at roughly the density the
+//! WFC generator produces, seeded so runs are comparable.
+//!
+//! This file stands in for both `crate` and `crate::collision`, so the
+//! modules below and the re-exports after them give the included sources the
+//! paths they expect.
+
+#[allow(dead_code)]
+#[path = "../src/config.rs"]
+mod config;
+
+#[allow(dead_code)]
+#[path = "../src/collision/tile_type.rs"]
+mod tile_type;
+
+#[allow(dead_code)]
+#[path = "../src/collision/edges.rs"]
+mod edges;
+
+#[allow(dead_code)]
+#[path = "../src/collision/layer.rs"]
+mod layer;
+
+#[allow(dead_code)]
+#[path = "../src/collision/shape.rs"]
+mod shape;
+
+#[allow(dead_code)]
+#[path = "../src/collision/map.rs"]
+mod map;
+
+use bevy::math::Vec2;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use edges::Edges;
+use layer::Layer;
+use map::CollisionMap;
+use shape::ColliderShape;
+use tile_type::TileType;
+
+const TILE_SIZE: f32 = 32.0;
+const RADIUS: f32 = 10.0;
+/// Fraction of tiles that block movement.
+const OBSTACLE_DENSITY: f64 = 0.2;
+const MAP_SIZES: [i32; 3] = [64, 128, 256];
+
+fn generate_map(size: i32, seed: u64) -> CollisionMap {
+    let origin = -TILE_SIZE * size as f32 / 2.0;
+    let mut map = CollisionMap::new(size, size, TILE_SIZE, origin, origin);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for y in 0..size {
+        for x in 0..size {
+            if rng.gen_bool(OBSTACLE_DENSITY) {
+                let blocker = match rng.gen_range(0..3) {
+                    0 => TileType::Water,
+                    1 => TileType::Tree,
+                    _ => TileType::Rock,
+                };
+                map.set_tile(x, y, blocker);
+            }
+        }
+    }
+
+    map
+}
+
+/// Random walkable tile centers to use as query points.
+fn walkable_points(map: &CollisionMap, size: i32, count: usize, seed: u64) -> Vec<Vec2> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut points = Vec::with_capacity(count);
+    while points.len() < count {
+        let (x, y) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        if map.is_walkable(x, y) {
+            points.push(map.grid_to_world(x, y));
+        }
+    }
+    points
+}
+
+fn bench_is_circle_clear(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_circle_clear");
+    for size in MAP_SIZES {
+        let map = generate_map(size, 1);
+        let points = walkable_points(&map, size, 256, 2);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &points, |b, points| {
+            b.iter(|| {
+                for point in points {
+                    black_box(map.is_circle_clear(black_box(*point), RADIUS));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_sweep_circle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sweep_circle");
+    let shape = ColliderShape::Circle { radius: RADIUS };
+    for size in MAP_SIZES {
+        let map = generate_map(size, 1);
+        let starts = walkable_points(&map, size, 256, 3);
+        // One frame of fast movement plus a longer dash, in varying directions
+        let moves: Vec<(Vec2, Vec2)> = starts
+            .iter()
+            .enumerate()
+            .map(|(i, start)| {
+                let angle = i as f32 * 0.7;
+                let distance = if i % 2 == 0 { 8.0 } else { TILE_SIZE * 4.0 };
+                (*start, *start + Vec2::from_angle(angle) * distance)
+            })
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(size), &moves, |b, moves| {
+            b.iter(|| {
+                for (start, end) in moves {
+                    black_box(map.sweep_shape(black_box(*start), black_box(*end), &shape));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_find_path(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_path");
+    group.sample_size(20);
+    for size in MAP_SIZES {
+        let map = generate_map(size, 1);
+        let points = walkable_points(&map, size, 16, 4);
+        let pairs: Vec<(Vec2, Vec2)> = points.chunks(2).map(|p| (p[0], p[1])).collect();
+        // A point-sized agent, then one with a collider that has to fit the gaps
+        for clearance in [0.0, RADIUS] {
+            let id = BenchmarkId::new(format!("clearance_{}", clearance), size);
+            group.bench_with_input(id, &pairs, |b, pairs| {
+                b.iter(|| {
+                    for (start, goal) in pairs {
+                        black_box(map.find_path(
+                            black_box(*start),
+                            black_box(*goal),
+                            clearance,
+                        ));
+                    }
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_is_circle_clear, bench_sweep_circle, bench_find_path);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+// src/accessibility/mod.rs
+pub mod settings;
+pub mod ui;
+
+use bevy::prelude::*;
+
+use crate::state::GameState;
+
+pub use settings::AccessibilitySettings;
+pub use ui::AccessibilityUIState;
+
+/// Reduced-motion multipliers (screen shake, hit-pause, particle density,
+/// camera smoothing), the colour mode, hold-or-toggle run and block, and UI
+/// text size and font, changed from a page reachable from the main and
+/// pause menus.
+pub struct AccessibilityPlugin;
+
+/// States with an accessibility page: the main menu and pause.
+fn accessibility_ui_open(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::MainMenu | GameState::Paused)
+}
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AccessibilitySettings>()
+            .init_resource::<AccessibilityUIState>()
+            .add_systems(PreStartup, settings::load_accessibility_settings)
+            .add_systems(
+                Update,
+                settings::apply_accessibility_settings
+                    .run_if(resource_changed::<AccessibilitySettings>),
+            )
+            .add_systems(Update, ui::handle_accessibility_ui.run_if(accessibility_ui_open))
+            .add_systems(
+                Update,
+                ui::handle_accessibility_buttons
+                    .run_if(|ui_state: Res<AccessibilityUIState>| ui_state.active)
+                    .run_if(accessibility_ui_open),
+            )
+            .add_systems(OnExit(GameState::MainMenu), ui::close_accessibility_ui)
+            .add_systems(OnExit(GameState::Paused), ui::close_accessibility_ui);
+    }
+}
@@ -0,0 +1,117 @@
+// src/accessibility/settings.rs
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::combat::HitPause;
+use crate::config::accessibility::{MOTION_STEPS, TEXT_SCALES};
+use crate::logging::targets;
+use crate::save::data::saves_directory;
+
+/// Reduced-motion options, hold-to-toggle input and UI text. The motion
+/// options are multipliers on the game's own amount, where 0 turns the
+/// effect off. Persisted next to the save slots as JSON, where a field
+/// missing from the file takes its default.
+#[derive(Resource, Serialize, Deserialize, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    pub screen_shake: f32,
+    pub hit_pause: f32,
+    /// Scales how many particles every emitter puts out
+    pub particle_density: f32,
+    /// 0 locks the camera onto the player instead of easing after them
+    pub camera_smoothing: f32,
+    /// Press run (Shift) once to start running and again to stop
+    pub toggle_run: bool,
+    /// Press block (Alt) once to raise the shield and again to lower it
+    pub toggle_block: bool,
+    /// Multiplier on every UI text size, one of `TEXT_SCALES`
+    pub text_scale: f32,
+    /// Use the dyslexia-friendly font for UI text
+    pub dyslexic_font: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            screen_shake: 1.0,
+            hit_pause: 1.0,
+            particle_density: 1.0,
+            camera_smoothing: 1.0,
+            toggle_run: false,
+            toggle_block: false,
+            text_scale: TEXT_SCALES[0],
+            dyslexic_font: false,
+        }
+    }
+}
+
+pub fn accessibility_settings_file_path() -> PathBuf {
+    saves_directory().join("accessibility.json")
+}
+
+impl AccessibilitySettings {
+    /// Load the settings from disk, falling back to defaults if missing or unreadable.
+    pub fn load() -> Self {
+        let Ok(bytes) = fs::read(accessibility_settings_file_path()) else {
+            return Self::default();
+        };
+        serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            warn!(
+                target: targets::SAVE,
+                "Unreadable accessibility settings, using defaults: {}",
+                e
+            );
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let bytes =
+            serde_json::to_vec_pretty(self).map_err(|e| format!("Serialize error: {}", e))?;
+        fs::create_dir_all(saves_directory()).map_err(|e| format!("Create dir error: {}", e))?;
+        fs::write(accessibility_settings_file_path(), bytes)
+            .map_err(|e| format!("Write error: {}", e))
+    }
+}
+
+/// The step after `value`, wrapping from full back to off.
+pub fn next_step(value: f32) -> f32 {
+    MOTION_STEPS
+        .iter()
+        .copied()
+        .find(|&step| step > value + f32::EPSILON)
+        .unwrap_or(MOTION_STEPS[0])
+}
+
+/// The text size after `scale`, wrapping back to the smallest.
+pub fn next_text_scale(scale: f32) -> f32 {
+    TEXT_SCALES
+        .iter()
+        .copied()
+        .find(|&step| step > scale + f32::EPSILON)
+        .unwrap_or(TEXT_SCALES[0])
+}
+
+pub fn load_accessibility_settings(mut commands: Commands) {
+    commands.insert_resource(AccessibilitySettings::load());
+}
+
+/// Hit-pause lives in combat; hand it the new length whenever it changes.
+pub fn apply_accessibility_settings(
+    settings: Res<AccessibilitySettings>,
+    mut hit_pause: ResMut<HitPause>,
+) {
+    hit_pause.set_duration_scale(settings.hit_pause);
+    debug!(
+        target: targets::STATE,
+        "Accessibility: shake {}, hit pause {}, particles {}, camera smoothing {}",
+        settings.screen_shake,
+        settings.hit_pause,
+        settings.particle_density,
+        settings.camera_smoothing
+    );
+}
@@ -0,0 +1,223 @@
+// src/accessibility/ui.rs
+use bevy::prelude::*;
+
+use super::settings::{next_step, next_text_scale, AccessibilitySettings};
+use crate::config::accessibility::DYSLEXIC_FONT_PATH;
+use crate::logging::targets;
+use crate::optional_assets;
+use crate::rendering::ColorMode;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+
+#[derive(Resource, Default)]
+pub struct AccessibilityUIState {
+    pub active: bool,
+}
+
+#[derive(Component)]
+pub struct AccessibilityUI;
+
+#[derive(Component, Clone, Copy)]
+pub enum AccessibilityButton {
+    ScreenShake,
+    HitPause,
+    ParticleDensity,
+    CameraSmoothing,
+    Colors,
+    ToggleRun,
+    ToggleBlock,
+    TextSize,
+    Font,
+    Back,
+}
+
+fn hold_label(name: &str, toggle: bool) -> String {
+    format!("{}: {}", name, if toggle { "Toggle" } else { "Hold" })
+}
+
+fn percent_label(name: &str, value: f32) -> String {
+    if value <= 0.0 {
+        format!("{}: Off", name)
+    } else {
+        format!("{}: {}%", name, (value * 100.0).round() as u32)
+    }
+}
+
+/// Rebuilds the page whenever it opens, closes or a setting changes, so the
+/// labels always show what's applied.
+pub fn handle_accessibility_ui(
+    mut commands: Commands,
+    ui_state: Res<AccessibilityUIState>,
+    settings: Res<AccessibilitySettings>,
+    color_mode: Res<ColorMode>,
+    existing_ui: Query<Entity, With<AccessibilityUI>>,
+    theme: Res<UiTheme>,
+    mut font_installed: Local<Option<bool>>,
+) {
+    if !ui_state.is_changed() && !settings.is_changed() && !color_mode.is_changed() {
+        return;
+    }
+
+    for entity in existing_ui.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if !ui_state.active {
+        return;
+    }
+
+    let mut buttons = vec![
+        (
+            AccessibilityButton::ScreenShake,
+            percent_label("Screen Shake", settings.screen_shake),
+        ),
+        (AccessibilityButton::HitPause, percent_label("Hit Pause", settings.hit_pause)),
+        (
+            AccessibilityButton::ParticleDensity,
+            percent_label("Particles", settings.particle_density),
+        ),
+        (
+            AccessibilityButton::CameraSmoothing,
+            percent_label("Camera Smoothing", settings.camera_smoothing),
+        ),
+        (AccessibilityButton::Colors, format!("Colors: {}", color_mode.display_name())),
+        (AccessibilityButton::ToggleRun, hold_label("Run", settings.toggle_run)),
+        (AccessibilityButton::ToggleBlock, hold_label("Block", settings.toggle_block)),
+        (AccessibilityButton::TextSize, percent_label("Text Size", settings.text_scale)),
+    ];
+    // The dyslexia-friendly font isn't shipped, so its option is only offered
+    // once it's installed. Looked up once rather than on every rebuild.
+    let font_installed =
+        *font_installed.get_or_insert_with(|| optional_assets::installed(DYSLEXIC_FONT_PATH));
+    if font_installed {
+        buttons.push((
+            AccessibilityButton::Font,
+            format!(
+                "Font: {}",
+                if settings.dyslexic_font { "Dyslexia-Friendly" } else { "Standard" }
+            ),
+        ));
+    }
+
+    commands
+        .spawn((
+            AccessibilityUI,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 1.0)),
+            GlobalZIndex(100),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("ACCESSIBILITY"),
+                theme.text(TextRole::Heading),
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            let game_button = GameButton::new(ButtonColors::MENU);
+            for (button, label) in buttons {
+                parent
+                    .spawn((
+                        button,
+                        game_button.bundle(Node {
+                            width: Val::Px(350.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::vertical(Val::Px(5.0)),
+                            ..default()
+                        }),
+                    ))
+                    .with_child(game_button.label(label, theme.text(TextRole::Body)));
+            }
+
+            let back = GameButton::new(ButtonColors::DANGER);
+            parent
+                .spawn((
+                    AccessibilityButton::Back,
+                    back.bundle(Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(45.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    }),
+                ))
+                .with_child(back.label("Back", theme.text(TextRole::Button)));
+        });
+}
+
+pub fn handle_accessibility_buttons(
+    mut ui_state: ResMut<AccessibilityUIState>,
+    mut settings: ResMut<AccessibilitySettings>,
+    mut color_mode: ResMut<ColorMode>,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&AccessibilityButton>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    // Close on ESC
+    if input.just_pressed(KeyCode::Escape) {
+        ui_state.active = false;
+        return;
+    }
+
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
+            continue;
+        };
+
+        match button {
+            AccessibilityButton::ScreenShake => {
+                settings.screen_shake = next_step(settings.screen_shake);
+            }
+            AccessibilityButton::HitPause => settings.hit_pause = next_step(settings.hit_pause),
+            AccessibilityButton::ParticleDensity => {
+                settings.particle_density = next_step(settings.particle_density);
+            }
+            AccessibilityButton::CameraSmoothing => {
+                settings.camera_smoothing = next_step(settings.camera_smoothing);
+            }
+            AccessibilityButton::ToggleRun => settings.toggle_run = !settings.toggle_run,
+            AccessibilityButton::ToggleBlock => settings.toggle_block = !settings.toggle_block,
+            AccessibilityButton::TextSize => {
+                settings.text_scale = next_text_scale(settings.text_scale);
+            }
+            AccessibilityButton::Font => settings.dyslexic_font = !settings.dyslexic_font,
+            // Colour mode isn't persisted, so there's nothing to save
+            AccessibilityButton::Colors => {
+                *color_mode = color_mode.next();
+                continue;
+            }
+            AccessibilityButton::Back => {
+                ui_state.active = false;
+                continue;
+            }
+        }
+
+        if let Err(e) = settings.save() {
+            error!(target: targets::SAVE, "Failed to save accessibility settings: {}", e);
+        }
+    }
+}
+
+/// The page only rebuilds in menu states, so leaving one tears it down here.
+pub fn close_accessibility_ui(
+    mut commands: Commands,
+    mut ui_state: ResMut<AccessibilityUIState>,
+    existing_ui: Query<Entity, With<AccessibilityUI>>,
+) {
+    ui_state.active = false;
+    for entity in existing_ui.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,133 @@
+// src/ambience/director.rs
+use bevy::audio::{AudioSinkPlayback, Volume};
+use bevy::prelude::*;
+
+use crate::camera::MainCamera;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::ambience::*;
+use crate::lighting::DayNightCycle;
+use crate::optional_assets;
+
+/// What the ground under the camera sounds like. The map has no biomes of
+/// its own, so it's read off the tile there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Biome {
+    Forest,
+    Waterside,
+    Barren,
+}
+
+impl Biome {
+    pub fn of(tile: TileType) -> Self {
+        match tile {
+            TileType::Water | TileType::Shore | TileType::Bridge => Biome::Waterside,
+            TileType::Rock | TileType::Dirt | TileType::Lava | TileType::Spikes => Biome::Barren,
+            _ => Biome::Forest,
+        }
+    }
+}
+
+/// The looping layers ambience is mixed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmbienceLayer {
+    Birds,
+    Crickets,
+    Water,
+    Wind,
+}
+
+impl AmbienceLayer {
+    pub const ALL: [AmbienceLayer; 4] = [Self::Birds, Self::Crickets, Self::Water, Self::Wind];
+
+    pub fn track(self) -> &'static str {
+        match self {
+            Self::Birds => BIRDS_TRACK,
+            Self::Crickets => CRICKETS_TRACK,
+            Self::Water => WATER_TRACK,
+            Self::Wind => WIND_TRACK,
+        }
+    }
+}
+
+/// Where the camera is and how dark it's got, which decide the ambience.
+/// `biome` is `None` off the map, on the menu and while loading, which
+/// silences every layer.
+#[derive(Resource, Debug, Default)]
+pub struct AmbienceDirector {
+    pub biome: Option<Biome>,
+    /// 0 in full daylight, 1 in the dead of night
+    pub night: f32,
+}
+
+impl AmbienceDirector {
+    /// Volume `layer` fades towards, from 0 to 1.
+    pub fn layer_volume(&self, layer: AmbienceLayer) -> f32 {
+        let Some(biome) = self.biome else {
+            return 0.0;
+        };
+        let day = 1.0 - self.night;
+        match (biome, layer) {
+            (Biome::Forest, AmbienceLayer::Birds) => day,
+            (Biome::Forest, AmbienceLayer::Crickets) => self.night,
+            // Water and wind carry on through the night, under the crickets
+            (_, AmbienceLayer::Crickets) => self.night * NIGHT_UNDERLAY,
+            (Biome::Waterside, AmbienceLayer::Water) => 1.0,
+            (Biome::Barren, AmbienceLayer::Wind) => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// A looping layer of the ambience.
+#[derive(Component, Debug)]
+pub struct AmbienceTrack(pub AmbienceLayer);
+
+/// Starts every installed layer at once, silent. The sounds aren't shipped,
+/// so layers without a file are left out rather than failing to load.
+pub fn spawn_ambience_layers(mut commands: Commands, asset_server: Res<AssetServer>) {
+    for layer in AmbienceLayer::ALL {
+        if !optional_assets::installed(layer.track()) {
+            continue;
+        }
+        commands.spawn((
+            AmbienceTrack(layer),
+            AudioPlayer::new(asset_server.load(layer.track())),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        ));
+    }
+}
+
+pub fn update_ambience_director(
+    mut director: ResMut<AmbienceDirector>,
+    cycle: Res<DayNightCycle>,
+    collision_map: Option<Res<CollisionMap>>,
+    camera: Query<&Transform, With<MainCamera>>,
+) {
+    let (Some(map), Ok(camera)) = (collision_map, camera.single()) else {
+        return;
+    };
+    let cell = map.world_to_grid(camera.translation.truncate());
+    director.biome = map.get_tile(cell.x, cell.y).map(Biome::of);
+    director.night = cycle.night();
+}
+
+/// Eases each layer towards the volume the director asks for. Runs on real
+/// time so fades finish while the game is paused.
+pub fn crossfade_ambience(
+    time: Res<Time<Real>>,
+    director: Res<AmbienceDirector>,
+    mut layers: Query<(&AmbienceTrack, &mut AudioSink)>,
+) {
+    let step = AMBIENCE_VOLUME * time.delta_secs() / AMBIENCE_CROSSFADE_SECS;
+    for (track, mut sink) in &mut layers {
+        let target = director.layer_volume(track.0) * AMBIENCE_VOLUME;
+        let current = sink.volume().to_linear();
+        sink.set_volume(Volume::Linear(
+            current + (target - current).clamp(-step, step),
+        ));
+    }
+}
+
+pub fn silence_ambience(mut director: ResMut<AmbienceDirector>) {
+    *director = AmbienceDirector::default();
+}
@@ -0,0 +1,31 @@
+// src/ambience/mod.rs
+mod director;
+
+use bevy::prelude::*;
+
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+/// Background sound for where the camera is and the time of day: birds in
+/// the woods by day, crickets at night, lapping water by rivers and wind over
+/// bare rock. The layers loop together and `AmbienceDirector` sets how loud
+/// each one is.
+pub struct AmbiencePlugin;
+
+impl Plugin for AmbiencePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<director::AmbienceDirector>()
+            .add_systems(Startup, director::spawn_ambience_layers)
+            // Quiet on the menu and while a map loads
+            .add_systems(OnEnter(GameState::Loading), director::silence_ambience)
+            .add_systems(OnEnter(GameState::MainMenu), director::silence_ambience)
+            .add_systems(
+                Update,
+                director::update_ambience_director.in_set(GameSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                director::crossfade_ambience.after(GameSet::PostPhysics),
+            );
+    }
+}
@@ -0,0 +1,22 @@
+// src/assets/shaders/hit_flash.wgsl
+// White silhouette of a sprite's current atlas frame, drawn over the sprite
+// as it takes a hit. The sprite itself is left alone, so animation carries on.
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct HitFlash {
+    // Atlas frame in UV space: min corner in xy, size in zw
+    frame: vec4<f32>,
+    // 0 = invisible, 1 = solid white
+    white: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> flash: HitFlash;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var sprite_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(2) var sprite_sampler: sampler;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = flash.frame.xy + mesh.uv * flash.frame.zw;
+    let texel = textureSample(sprite_texture, sprite_sampler, uv);
+    return vec4<f32>(1.0, 1.0, 1.0, texel.a * flash.white);
+}
@@ -0,0 +1,34 @@
+// src/assets/shaders/lighting.wgsl
+// Darkness overlay for the day/night cycle and caves.
+// Each light cuts a soft hole in the darkness; overlapping lights add up.
+// Negative intensities (shadow magic) darken instead.
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+const MAX_LIGHTS: u32 = 64u;
+
+struct Lighting {
+    ambient_darkness: f32,
+    light_count: u32,
+    // xy = world position, z = radius, w = intensity
+    lights: array<vec4<f32>, MAX_LIGHTS>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> lighting: Lighting;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let pos = mesh.world_position.xy;
+
+    var light = 0.0;
+    for (var i = 0u; i < lighting.light_count; i++) {
+        let l = lighting.lights[i];
+        let falloff = 1.0 - smoothstep(0.0, l.z, distance(pos, l.xy));
+        light += falloff * l.w;
+    }
+
+    // Dim zones still darken in daylight, lights can't go brighter than day
+    let darkness = clamp(lighting.ambient_darkness - light, 0.0, 1.0);
+
+    // Slightly blue night rather than flat black
+    return vec4<f32>(0.0, 0.01, 0.04, darkness);
+}
@@ -0,0 +1,26 @@
+// src/assets/shaders/radial_wipe.wgsl
+// Buff icon whose lit wedge shrinks back toward 12 o'clock as the buff runs out.
+// The spent part stays visible, just dimmed.
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+const TAU: f32 = 6.28318530718;
+
+struct RadialWipe {
+    color: vec4<f32>,
+    // 1 = full, 0 = spent
+    fraction: f32,
+}
+
+@group(1) @binding(0) var<uniform> wipe: RadialWipe;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let offset = in.uv - vec2<f32>(0.5, 0.5);
+    // 0 at the top, increasing clockwise (uv y points down)
+    let angle = fract(atan2(offset.x, -offset.y) / TAU + 1.0);
+
+    if angle > wipe.fraction {
+        return vec4<f32>(wipe.color.rgb * 0.35, wipe.color.a);
+    }
+    return wipe.color;
+}
@@ -0,0 +1,47 @@
+// src/assets/shaders/screen_effects.wgsl
+// Full-view overlay drawn over the world: a red vignette that pulses when
+// the player is close to death, a white flash, and a grey wash that drains
+// the colour out of the frozen world while paused. Beneath them all, a faint
+// tint that grades the view by the terrain the player stands on.
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct ScreenEffects {
+    // Terrain tint in rgb, its strength in a
+    grade: vec4<f32>,
+    // 0 = no vignette, 1 = thick red edges
+    vignette: f32,
+    // 0 = none, 1 = solid white
+    flash: f32,
+    // 0 = full colour, 1 = washed out to grey
+    desaturation: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> effects: ScreenEffects;
+
+const VIGNETTE_COLOR: vec3<f32> = vec3<f32>(0.6, 0.0, 0.0);
+const WASH_COLOR: vec3<f32> = vec3<f32>(0.35, 0.35, 0.35);
+
+// Coverage of a layer with alpha `top` drawn over one with alpha `below`
+fn over(below: f32, top: f32) -> f32 {
+    return top + below * (1.0 - top);
+}
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    // 0 at the centre of the view, about 1 in the corners
+    let edge = length((mesh.uv - vec2<f32>(0.5, 0.5)) * 1.4);
+    let vignette = smoothstep(0.45, 1.0, edge) * effects.vignette;
+
+    // Layer them, each drawn over the last: grade, wash, vignette, flash
+    var rgb = effects.grade.rgb;
+    var alpha = effects.grade.a;
+    let wash = effects.desaturation * 0.6;
+    alpha = over(alpha, wash);
+    rgb = mix(rgb, WASH_COLOR, wash / max(alpha, 0.001));
+    alpha = over(alpha, vignette);
+    rgb = mix(rgb, VIGNETTE_COLOR, vignette / max(alpha, 0.001));
+    alpha = over(alpha, effects.flash);
+    rgb = mix(rgb, vec3<f32>(1.0, 1.0, 1.0), effects.flash / max(alpha, 0.001));
+
+    return vec4<f32>(rgb, alpha);
+}
@@ -0,0 +1,120 @@
+// src/behavior/mod.rs
+mod tree;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+use serde::{Deserialize, Serialize};
+
+use crate::characters::input::Player;
+use crate::enemy::ai::enemy_follow_player;
+use crate::enemy::orders::follow_enemy_orders;
+use crate::enemy::{
+    AIBehavior, EnemyCombat, EnemyOrders, Flying, SimulationLod, SpawningIn, SquadManager,
+};
+use crate::schedule::GameSet;
+
+use tree::{Blackboard, Node, TickContext};
+
+/// A behavior tree from a .bt.ron file under src/assets/behaviors.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct BehaviorTree {
+    pub root: Node,
+}
+
+/// Drives an enemy from a behavior tree instead of the built-in AI.
+/// Prefabs set it with `Behavior("behaviors/name.bt.ron")`.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Behavior {
+    /// Asset path of the tree
+    pub path: String,
+}
+
+#[derive(Component)]
+pub struct BehaviorHandle(Handle<BehaviorTree>);
+
+/// Behavior trees for enemy AI: selectors, sequences and decorators over
+/// conditions and orders, with a blackboard per enemy. Trees are assets, so
+/// they hot reload with the `hot_reload` feature.
+pub struct BehaviorPlugin;
+
+impl Plugin for BehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<BehaviorTree>::new(&["bt.ron"]))
+            .register_type::<Behavior>()
+            .add_systems(
+                Update,
+                (load_behavior_trees, tick_behavior_trees)
+                    .chain()
+                    .after(enemy_follow_player)
+                    .before(follow_enemy_orders)
+                    .in_set(GameSet::AI),
+            );
+    }
+}
+
+fn load_behavior_trees(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    added: Query<(Entity, &Behavior), Added<Behavior>>,
+) {
+    for (entity, behavior) in &added {
+        commands.entity(entity).insert((
+            BehaviorHandle(asset_server.load(behavior.path.clone())),
+            Blackboard::default(),
+            EnemyOrders::default(),
+        ));
+    }
+}
+
+/// Ticks each enemy's tree from the root to refresh its orders. Distant
+/// enemies tick less often, like the built-in AI.
+fn tick_behavior_trees(
+    time: Res<Time>,
+    trees: Res<Assets<BehaviorTree>>,
+    squad: Res<SquadManager>,
+    mut enemies: Query<
+        (
+            Entity,
+            &Transform,
+            &BehaviorHandle,
+            &AIBehavior,
+            Option<&EnemyCombat>,
+            Has<Flying>,
+            &mut SimulationLod,
+            &mut Blackboard,
+            &mut EnemyOrders,
+        ),
+        Without<SpawningIn>,
+    >,
+    player: Query<&Transform, With<Player>>,
+) {
+    let player = player.single().ok().map(|t| t.translation.truncate());
+    let now = time.elapsed_secs();
+
+    for (enemy, transform, handle, ai, combat, flying, mut lod, mut blackboard, mut orders) in
+        &mut enemies
+    {
+        if lod.tick(time.delta_secs()).is_none() {
+            continue;
+        }
+        let Some(tree) = trees.get(&handle.0) else {
+            continue;
+        };
+
+        let mut ctx = TickContext {
+            now,
+            position: transform.translation.truncate(),
+            player,
+            slot: squad.slot_for(enemy),
+            attack_range: ai.attack_range,
+            detection_range: ai.detection_range,
+            attack_ready: combat.is_some_and(|c| c.cooldown.is_finished()),
+            direct: lod.reduced || flying,
+            blackboard: &mut blackboard,
+            orders: EnemyOrders::default(),
+        };
+        tree.root.tick(&mut ctx);
+        *orders = ctx.orders;
+    }
+}
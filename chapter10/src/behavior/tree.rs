@@ -0,0 +1,208 @@
+// src/behavior/tree.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::enemy::SQUAD_SLOT_ARRIVE_RADIUS;
+use crate::enemy::{EnemyOrders, MoveGoal};
+
+/// Result of ticking a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Success,
+    Failure,
+    /// Still under way; the tree is ticked again from the root next time
+    Running,
+}
+
+/// A node of a behavior tree, in RON-friendly form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Node {
+    /// Tries children in order until one doesn't fail
+    Selector(Vec<Node>),
+    /// Runs children in order until one doesn't succeed
+    Sequence(Vec<Node>),
+    /// Swaps the child's success and failure
+    Inverter(Box<Node>),
+    /// Succeeds whatever the child does
+    Succeeder(Box<Node>),
+    /// Fails without ticking the child for `secs` after the child last
+    /// succeeded. `key` names the timer on the blackboard.
+    Cooldown { key: String, secs: f32, child: Box<Node> },
+    Check(Condition),
+    Do(Action),
+}
+
+/// Leaf that succeeds or fails on what the enemy sees, without acting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Condition {
+    /// The player is within detection range
+    PlayerDetected,
+    /// At the enemy's squad slot, or in attack range if it holds none,
+    /// allowing `margin` extra distance
+    InPosition { margin: f32 },
+    /// The ranged attack has recharged
+    AttackReady,
+    /// A flag on the blackboard is set
+    Flag(String),
+}
+
+/// Leaf that gives the enemy an order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Action {
+    /// Head straight for the player (running)
+    Chase,
+    /// Head for the squad slot, or the player without one (running)
+    TakeSquadSlot,
+    Stop,
+    /// Fire at the player; fails if out of range or recharging
+    Attack,
+    SetFlag(String, bool),
+}
+
+/// Per-entity memory the tree reads and writes across ticks.
+#[derive(Component, Debug, Default)]
+pub struct Blackboard {
+    flags: HashMap<String, bool>,
+    /// When each named cooldown last started, in seconds of game time
+    cooldowns: HashMap<String, f32>,
+}
+
+impl Blackboard {
+    pub fn flag(&self, key: &str) -> bool {
+        self.flags.get(key).copied().unwrap_or(false)
+    }
+}
+
+/// What a tick can see, and the orders it builds up.
+pub struct TickContext<'a> {
+    pub now: f32,
+    pub position: Vec2,
+    pub player: Option<Vec2>,
+    pub slot: Option<Vec2>,
+    pub attack_range: f32,
+    pub detection_range: f32,
+    pub attack_ready: bool,
+    /// Walk straight instead of pathfinding (flyers, distant enemies)
+    pub direct: bool,
+    pub blackboard: &'a mut Blackboard,
+    pub orders: EnemyOrders,
+}
+
+impl TickContext<'_> {
+    fn distance_to_player(&self) -> f32 {
+        self.player.map_or(f32::INFINITY, |player| self.position.distance(player))
+    }
+
+    fn move_to(&mut self, goal: Vec2) {
+        self.orders.movement = if self.direct {
+            MoveGoal::Toward(goal)
+        } else {
+            MoveGoal::PathTo(goal)
+        };
+    }
+}
+
+impl Node {
+    pub fn tick(&self, ctx: &mut TickContext) -> Status {
+        match self {
+            Node::Selector(children) => {
+                for child in children {
+                    let status = child.tick(ctx);
+                    if status != Status::Failure {
+                        return status;
+                    }
+                }
+                Status::Failure
+            }
+            Node::Sequence(children) => {
+                for child in children {
+                    let status = child.tick(ctx);
+                    if status != Status::Success {
+                        return status;
+                    }
+                }
+                Status::Success
+            }
+            Node::Inverter(child) => match child.tick(ctx) {
+                Status::Success => Status::Failure,
+                Status::Failure => Status::Success,
+                Status::Running => Status::Running,
+            },
+            Node::Succeeder(child) => {
+                child.tick(ctx);
+                Status::Success
+            }
+            Node::Cooldown { key, secs, child } => {
+                let started = ctx.blackboard.cooldowns.get(key).copied();
+                if started.is_some_and(|started| ctx.now - started < *secs) {
+                    return Status::Failure;
+                }
+                let status = child.tick(ctx);
+                if status == Status::Success {
+                    ctx.blackboard.cooldowns.insert(key.clone(), ctx.now);
+                }
+                status
+            }
+            Node::Check(condition) => {
+                if condition.holds(ctx) {
+                    Status::Success
+                } else {
+                    Status::Failure
+                }
+            }
+            Node::Do(action) => action.run(ctx),
+        }
+    }
+}
+
+impl Condition {
+    fn holds(&self, ctx: &TickContext) -> bool {
+        match self {
+            Condition::PlayerDetected => ctx.distance_to_player() <= ctx.detection_range,
+            Condition::InPosition { margin } => match ctx.slot {
+                Some(slot) => ctx.position.distance(slot) <= SQUAD_SLOT_ARRIVE_RADIUS + margin,
+                None => ctx.distance_to_player() <= ctx.attack_range + margin,
+            },
+            Condition::AttackReady => ctx.attack_ready,
+            Condition::Flag(key) => ctx.blackboard.flag(key),
+        }
+    }
+}
+
+impl Action {
+    fn run(&self, ctx: &mut TickContext) -> Status {
+        match self {
+            Action::Chase => {
+                let Some(player) = ctx.player else {
+                    return Status::Failure;
+                };
+                ctx.move_to(player);
+                Status::Running
+            }
+            Action::TakeSquadSlot => {
+                let Some(goal) = ctx.slot.or(ctx.player) else {
+                    return Status::Failure;
+                };
+                ctx.move_to(goal);
+                Status::Running
+            }
+            Action::Stop => {
+                ctx.orders.movement = MoveGoal::Stop;
+                Status::Success
+            }
+            Action::Attack => {
+                if !ctx.attack_ready || ctx.distance_to_player() > ctx.attack_range {
+                    return Status::Failure;
+                }
+                ctx.orders.attack = true;
+                Status::Success
+            }
+            Action::SetFlag(key, value) => {
+                ctx.blackboard.flags.insert(key.clone(), *value);
+                Status::Success
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+// src/buffs/buff.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::characters::input::Player;
+use crate::characters::physics::Velocity;
+use crate::combat::projectile::Projectile;
+use crate::combat::{Heal, Health, Invulnerable, ProjectileHit};
+use crate::console::ConsoleResult;
+use crate::config::buffs::{
+    DAMAGE_BUFF, MAX_BURNING_STACKS, MAX_DAMAGE_STACKS, MIN_SPEED_MULTIPLIER, REGEN_BUFF,
+    SHRINE_BUFF_SECS, SPEED_BUFF,
+};
+use crate::enemy::SimulationLod;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum BuffKind {
+    /// Multiplies outgoing projectile damage by `1 + strength`
+    Damage,
+    /// Multiplies the player's movement speed by `1 + strength`
+    Speed,
+    /// Heals `strength` health per second
+    Regen,
+    /// Deals `strength` damage per second
+    Burning,
+    /// Takes `strength` off the movement speed multiplier
+    Slowed,
+}
+
+/// What happens when a buff is applied to someone who already has it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuffStacking {
+    /// The timer restarts and the stronger magnitude wins
+    Refresh,
+    /// Adds a stack (up to `max`) and restarts the shared timer
+    Stack { max: u32 },
+}
+
+impl BuffKind {
+    /// The kinds shrines hand out.
+    pub const BLESSINGS: [BuffKind; 3] = [BuffKind::Damage, BuffKind::Speed, BuffKind::Regen];
+
+    pub fn stacking(self) -> BuffStacking {
+        match self {
+            BuffKind::Damage => BuffStacking::Stack { max: MAX_DAMAGE_STACKS },
+            BuffKind::Burning => BuffStacking::Stack { max: MAX_BURNING_STACKS },
+            BuffKind::Speed | BuffKind::Regen | BuffKind::Slowed => BuffStacking::Refresh,
+        }
+    }
+
+    /// Harmful effects, shown apart from buffs in the HUD.
+    pub fn is_debuff(self) -> bool {
+        matches!(self, BuffKind::Burning | BuffKind::Slowed)
+    }
+
+    /// Magnitude shrines grant per application.
+    pub fn shrine_magnitude(self) -> f32 {
+        match self {
+            BuffKind::Damage => DAMAGE_BUFF,
+            BuffKind::Speed => SPEED_BUFF,
+            BuffKind::Regen => REGEN_BUFF,
+            BuffKind::Burning | BuffKind::Slowed => 0.0,
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            BuffKind::Damage => "Might",
+            BuffKind::Speed => "Haste",
+            BuffKind::Regen => "Renewal",
+            BuffKind::Burning => "Burning",
+            BuffKind::Slowed => "Slowed",
+        }
+    }
+
+    /// Shrine and HUD icon color.
+    pub fn color(self) -> Color {
+        match self {
+            BuffKind::Damage => Color::srgb(0.9, 0.3, 0.2),
+            BuffKind::Speed => Color::srgb(0.3, 0.8, 0.9),
+            BuffKind::Regen => Color::srgb(0.3, 0.9, 0.4),
+            BuffKind::Burning => Color::srgb(1.0, 0.55, 0.1),
+            BuffKind::Slowed => Color::srgb(0.45, 0.4, 0.8),
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        BuffKind::BLESSINGS
+            .into_iter()
+            .find(|kind| format!("{:?}", kind).eq_ignore_ascii_case(name))
+    }
+}
+
+/// A buff as data, for things that hand buffs out (powers in powers.ron, items).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BuffSpec {
+    pub kind: BuffKind,
+    pub magnitude: f32,
+    pub duration: f32,
+}
+
+/// One timed effect. Items and powers build these and hand them to [`Buffs::apply`].
+#[derive(Reflect, Debug, Clone)]
+pub struct Buff {
+    pub kind: BuffKind,
+    /// Effect per stack; see `BuffKind` for what it means
+    pub magnitude: f32,
+    pub duration: f32,
+    /// `Time::elapsed_secs` it was last (re)applied and when it runs out.
+    /// Stored as timestamps so ticking doesn't touch the component.
+    pub applied_at: f32,
+    pub expires_at: f32,
+    pub stacks: u32,
+}
+
+impl Buff {
+    pub fn new(kind: BuffKind, magnitude: f32, duration: f32) -> Self {
+        Self {
+            kind,
+            magnitude,
+            duration,
+            applied_at: 0.0,
+            expires_at: duration,
+            stacks: 1,
+        }
+    }
+
+    /// Total effect across stacks.
+    pub fn strength(&self) -> f32 {
+        self.magnitude * self.stacks as f32
+    }
+
+    pub fn remaining(&self, now: f32) -> f32 {
+        (self.expires_at - now).max(0.0)
+    }
+
+    /// Fraction of the current run left, 1 when fresh.
+    pub fn remaining_fraction(&self, now: f32) -> f32 {
+        let total = self.expires_at - self.applied_at;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (self.remaining(now) / total).clamp(0.0, 1.0)
+    }
+}
+
+impl From<BuffSpec> for Buff {
+    fn from(spec: BuffSpec) -> Self {
+        Buff::new(spec.kind, spec.magnitude, spec.duration)
+    }
+}
+
+/// Active buffs and debuffs on an entity, at most one entry per kind.
+/// Only changes when something is applied or runs out, so `Changed<Buffs>`
+/// is a reliable signal for the HUD.
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct Buffs(Vec<Buff>);
+
+impl Buffs {
+    /// Adds `buff` starting at `now`, merging it with an existing one of the
+    /// same kind per `BuffKind::stacking`.
+    pub fn apply(&mut self, mut buff: Buff, now: f32) {
+        buff.applied_at = now;
+        buff.expires_at = now + buff.duration;
+
+        let Some(existing) = self.0.iter_mut().find(|b| b.kind == buff.kind) else {
+            self.0.push(buff);
+            return;
+        };
+
+        match buff.kind.stacking() {
+            BuffStacking::Refresh => {
+                existing.magnitude = existing.magnitude.max(buff.magnitude);
+            }
+            BuffStacking::Stack { max } => {
+                existing.stacks = (existing.stacks + buff.stacks).min(max);
+            }
+        }
+        if buff.expires_at > existing.expires_at {
+            existing.duration = buff.duration;
+            existing.applied_at = now;
+            existing.expires_at = buff.expires_at;
+        }
+    }
+
+    pub fn get(&self, kind: BuffKind) -> Option<&Buff> {
+        self.0.iter().find(|b| b.kind == kind)
+    }
+
+    /// Combined strength of `kind`, 0 when it isn't active.
+    pub fn strength(&self, kind: BuffKind) -> f32 {
+        self.get(kind).map_or(0.0, Buff::strength)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Buff> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<Buff> for Buffs {
+    fn from_iter<I: IntoIterator<Item = Buff>>(buffs: I) -> Self {
+        Buffs(buffs.into_iter().collect())
+    }
+}
+
+/// Heals for regen and burns for burning once a second, and drops expired buffs.
+pub fn tick_buffs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Buffs, Option<&mut Health>)>,
+) {
+    let now = time.elapsed_secs();
+    let last_frame = now - time.delta_secs();
+
+    for (entity, mut buffs, mut health) in &mut query {
+        for buff in buffs.0.iter() {
+            // Each time the countdown crosses a whole second
+            let before = last_frame.max(buff.applied_at);
+            if buff.remaining(before).ceil() == buff.remaining(now).ceil() {
+                continue;
+            }
+            match buff.kind {
+                BuffKind::Regen => commands.trigger(Heal {
+                    target: entity,
+                    amount: buff.strength(),
+                }),
+                BuffKind::Burning => {
+                    if let Some(health) = health.as_mut().filter(|h| h.is_alive()) {
+                        health.take_damage(&mut commands, entity, buff.strength());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Only write when something ran out, to keep change detection quiet
+        if buffs.0.iter().any(|b| b.expires_at <= now) {
+            buffs.0.retain(|b| b.expires_at > now);
+        }
+    }
+}
+
+/// Leaves the hitting power's debuff on the target. Invulnerable targets
+/// shrug it off along with the damage.
+pub fn on_hit_inflict(
+    hit: On<ProjectileHit>,
+    time: Res<Time>,
+    mut targets: Query<&mut Buffs, Without<Invulnerable>>,
+) {
+    let Some(spec) = hit.inflicts else {
+        return;
+    };
+    if let Ok(mut buffs) = targets.get_mut(hit.target) {
+        buffs.apply(spec.into(), time.elapsed_secs());
+    }
+}
+
+/// Scales freshly fired projectiles by their shooter's damage buff.
+pub fn apply_damage_buffs(
+    mut projectiles: Query<&mut Projectile, Added<Projectile>>,
+    shooters: Query<&Buffs>,
+) {
+    for mut projectile in &mut projectiles {
+        let bonus = shooters
+            .get(projectile.shooter)
+            .map_or(0.0, |buffs| buffs.strength(BuffKind::Damage));
+        if bonus > 0.0 {
+            projectile.damage *= 1.0 + bonus;
+        }
+    }
+}
+
+/// Scales the velocity input and AI just set by speed boosts and slows.
+/// Enemies on reduced LOD keep last tick's velocity, so they're skipped to
+/// avoid scaling it twice.
+pub fn apply_speed_modifiers(mut query: Query<(&Buffs, &mut Velocity, Option<&SimulationLod>)>) {
+    for (buffs, mut velocity, lod) in &mut query {
+        if lod.is_some_and(|lod| lod.reduced) {
+            continue;
+        }
+        let multiplier = 1.0 + buffs.strength(BuffKind::Speed) - buffs.strength(BuffKind::Slowed);
+        if multiplier != 1.0 {
+            velocity.0 *= multiplier.max(MIN_SPEED_MULTIPLIER);
+        }
+    }
+}
+
+/// Console: `buff <damage|speed|regen>` grants the player a shrine-strength buff.
+pub fn buff_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let name = args
+        .first()
+        .ok_or_else(|| "Usage: buff <damage|speed|regen>".to_string())?;
+    let kind = BuffKind::parse(name).ok_or_else(|| format!("Unknown buff '{}'", name))?;
+
+    let now = world.resource::<Time>().elapsed_secs();
+    let mut players = world.query_filtered::<&mut Buffs, With<Player>>();
+    let mut buffs = players
+        .single_mut(world)
+        .map_err(|_| "No player to buff".to_string())?;
+    buffs.apply(Buff::new(kind, kind.shrine_magnitude(), SHRINE_BUFF_SECS), now);
+    Ok(format!("Granted {}", kind.display_name()))
+}
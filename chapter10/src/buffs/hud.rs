@@ -0,0 +1,141 @@
+// src/buffs/hud.rs
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    ui_render::{MaterialNode, UiMaterial},
+};
+
+use super::buff::{Buff, Buffs};
+use crate::characters::input::Player;
+use crate::ui::{TextRole, UiTheme};
+
+const ICON_SIZE: f32 = 36.0;
+
+#[derive(Component)]
+pub struct BuffBar;
+
+/// Icon for one active buff, holding a copy of it for `update_buff_wipes` to animate.
+#[derive(Component)]
+pub struct BuffIcon(pub Buff);
+
+#[derive(ShaderType, Debug, Clone, Default)]
+pub struct RadialWipe {
+    pub color: LinearRgba,
+    /// 1 = full, 0 = spent
+    pub fraction: f32,
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct RadialWipeMaterial {
+    #[uniform(0)]
+    pub wipe: RadialWipe,
+}
+
+impl UiMaterial for RadialWipeMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/radial_wipe.wgsl".into()
+    }
+}
+
+/// Strip of buff and debuff icons in the top-right corner. Re-entering
+/// Playing (from pause or a load) keeps the existing bar.
+pub fn spawn_buff_bar(mut commands: Commands, existing: Query<(), With<BuffBar>>) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        BuffBar,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            right: Val::Px(16.0),
+            column_gap: Val::Px(6.0),
+            ..default()
+        },
+    ));
+}
+
+/// Rebuilds the icons when the player's buffs change: buffs first, then
+/// debuffs with a red border. Ticking doesn't touch `Buffs`, so this only
+/// runs on application and expiry.
+pub fn sync_buff_icons(
+    mut commands: Commands,
+    players: Query<&Buffs, (With<Player>, Changed<Buffs>)>,
+    bar: Query<Entity, With<BuffBar>>,
+    mut materials: ResMut<Assets<RadialWipeMaterial>>,
+    theme: Res<UiTheme>,
+) {
+    let Ok(buffs) = players.single() else {
+        return;
+    };
+    let Ok(bar) = bar.single() else {
+        return;
+    };
+
+    commands.entity(bar).despawn_children();
+
+    let mut active: Vec<_> = buffs.iter().collect();
+    active.sort_by_key(|buff| buff.kind.is_debuff());
+
+    for buff in active {
+        let border = if buff.kind.is_debuff() {
+            Color::srgb(0.9, 0.1, 0.1)
+        } else {
+            Color::srgba(1.0, 1.0, 1.0, 0.6)
+        };
+        let material = materials.add(RadialWipeMaterial {
+            wipe: RadialWipe {
+                color: buff.kind.color().to_linear(),
+                fraction: 1.0,
+            },
+        });
+
+        let icon = commands
+            .spawn((
+                BuffIcon(buff.clone()),
+                MaterialNode(material),
+                Node {
+                    width: Val::Px(ICON_SIZE),
+                    height: Val::Px(ICON_SIZE),
+                    border: UiRect::all(Val::Px(2.0)),
+                    justify_content: JustifyContent::End,
+                    align_items: AlignItems::End,
+                    ..default()
+                },
+                BorderColor::all(border),
+                ChildOf(bar),
+            ))
+            .id();
+
+        if buff.stacks > 1 {
+            commands.spawn((
+                Text::new(format!("{}", buff.stacks)),
+                theme.text(TextRole::Small),
+                TextColor(Color::WHITE),
+                ChildOf(icon),
+            ));
+        }
+    }
+}
+
+/// Sweeps each icon's radial wipe down as its buff runs out.
+pub fn update_buff_wipes(
+    time: Res<Time>,
+    icons: Query<(&BuffIcon, &MaterialNode<RadialWipeMaterial>)>,
+    mut materials: ResMut<Assets<RadialWipeMaterial>>,
+) {
+    let now = time.elapsed_secs();
+    for (icon, material) in &icons {
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.wipe.fraction = icon.0.remaining_fraction(now);
+        }
+    }
+}
+
+pub fn despawn_buff_bar(mut commands: Commands, query: Query<Entity, With<BuffBar>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,58 @@
+// src/buffs/mod.rs
+mod buff;
+mod hud;
+mod shrine;
+
+use bevy::{prelude::*, ui_render::UiMaterialPlugin};
+
+use crate::combat::projectile::check_projectile_hits;
+use crate::console::ConsoleAppExt;
+use crate::enemy::ai::enemy_follow_player;
+use crate::schedule::{world_rebuilt, GameSet};
+use crate::state::GameState;
+
+pub use buff::{Buff, BuffKind, BuffSpec, Buffs};
+
+/// Timed buffs (damage, speed, regen) and debuffs (burning, slowed), the
+/// shrines that hand buffs out and the HUD strip that shows what's active.
+pub struct BuffsPlugin;
+
+impl Plugin for BuffsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(UiMaterialPlugin::<hud::RadialWipeMaterial>::default())
+            .register_type::<Buffs>()
+            .register_type::<BuffKind>()
+            .add_observer(buff::on_hit_inflict)
+            .add_observer(shrine::on_shrine_interact)
+            .add_systems(
+                Update,
+                shrine::place_shrines.run_if(world_rebuilt),
+            )
+            // After both the player and the AI have picked this frame's velocity
+            .add_systems(
+                Update,
+                buff::apply_speed_modifiers
+                    .after(enemy_follow_player)
+                    .in_set(GameSet::AI),
+            )
+            .add_systems(
+                Update,
+                (
+                    buff::tick_buffs,
+                    shrine::recharge_shrines,
+                    buff::apply_damage_buffs.before(check_projectile_hits),
+                )
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(OnEnter(GameState::Playing), hud::spawn_buff_bar)
+            .add_systems(
+                Update,
+                (hud::sync_buff_icons, hud::update_buff_wipes)
+                    .chain()
+                    .in_set(GameSet::Render),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), hud::despawn_buff_bar)
+            .add_systems(OnEnter(GameState::GameOver), hud::despawn_buff_bar)
+            .add_console_command("buff", "Grant the player a buff: buff <damage|speed|regen>", buff::buff_command);
+    }
+}
@@ -0,0 +1,185 @@
+// src/buffs/shrine.rs
+use bevy::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use super::buff::{Buff, BuffKind, Buffs};
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::buffs::{
+    SHRINE_BUFF_SECS, SHRINE_COOLDOWN_SECS, SHRINE_COUNT, SHRINE_INTERACT_RADIUS,
+    SHRINE_MIN_SPACING, SHRINE_SAFE_RADIUS,
+};
+use crate::interaction::{Interact, Interactable};
+use crate::lighting::LightSource;
+use crate::logging::targets;
+use crate::map::generate::WorldGenSettings;
+use crate::map::level::LevelLayout;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::rendering::ScreenFlash;
+use crate::state::GameplayEntity;
+
+/// Above ground tiles and hazard overlays, level with props.
+const SHRINE_Z: f32 = 3.0;
+const SHRINE_SIZE: Vec2 = Vec2::new(20.0, 28.0);
+
+/// Altar that grants a timed buff when the player interacts with it, then recharges.
+#[derive(Component, Debug)]
+pub struct Shrine {
+    pub buff: BuffKind,
+    /// Seconds until it can be used again; 0 when ready
+    pub recharge: f32,
+}
+
+impl Shrine {
+    pub fn is_ready(&self) -> bool {
+        self.recharge <= 0.0
+    }
+}
+
+/// Point-of-interest pass: once the collision map exists, picks spread-out
+/// open ground away from the player's spawn and puts a shrine on each spot.
+/// Seeded from the world seed, so a reloaded save gets the same shrines.
+/// Hand-made levels choose the spots; the blessings are still rolled.
+pub fn place_shrines(
+    mut commands: Commands,
+    map: Res<CollisionMap>,
+    settings: Res<WorldGenSettings>,
+    level: Option<Res<LevelLayout>>,
+) {
+    let mut rng = StdRng::seed_from_u64(settings.seed ^ 0x5851_F42D);
+
+    if let Some(level) = level {
+        for &position in &level.shrines {
+            let kind = BuffKind::BLESSINGS[rng.gen_range(0..BuffKind::BLESSINGS.len())];
+            spawn_shrine(&mut commands, position.extend(SHRINE_Z), kind);
+        }
+        info!(target: targets::MAP, "Placed {} shrines", level.shrines.len());
+        return;
+    }
+
+    let mut candidates: Vec<Vec2> = (0..map.height())
+        .flat_map(|y| (0..map.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| is_open_ground(&map, x, y))
+        .map(|(x, y)| map.grid_to_world(x, y))
+        .filter(|pos| pos.length() >= SHRINE_SAFE_RADIUS)
+        .collect();
+    candidates.shuffle(&mut rng);
+
+    let mut placed: Vec<Vec2> = Vec::new();
+    for position in candidates {
+        if placed.len() >= SHRINE_COUNT {
+            break;
+        }
+        if placed.iter().any(|p| p.distance(position) < SHRINE_MIN_SPACING) {
+            continue;
+        }
+        let kind = BuffKind::BLESSINGS[rng.gen_range(0..BuffKind::BLESSINGS.len())];
+        spawn_shrine(&mut commands, position.extend(SHRINE_Z), kind);
+        placed.push(position);
+    }
+
+    info!(target: targets::MAP, "Placed {} shrines", placed.len());
+}
+
+/// Grass or dirt with open ground all around, so the altar never blocks a corridor.
+fn is_open_ground(map: &CollisionMap, x: i32, y: i32) -> bool {
+    let ground = |x, y| {
+        matches!(
+            map.get_tile(x, y),
+            Some(TileType::Grass | TileType::YellowGrass | TileType::Dirt)
+        )
+    };
+    (-1..=1).all(|dy| (-1..=1).all(|dx| ground(x + dx, y + dy)))
+}
+
+pub fn spawn_shrine(commands: &mut Commands, position: Vec3, kind: BuffKind) -> Entity {
+    commands
+        .spawn((
+            Shrine {
+                buff: kind,
+                recharge: 0.0,
+            },
+            Sprite::from_color(kind.color(), SHRINE_SIZE),
+            Interactable::new("Pray", SHRINE_INTERACT_RADIUS),
+            LightSource::new(SHRINE_INTERACT_RADIUS * 2.0).with_color(kind.color()),
+            Transform::from_translation(position),
+            GameplayEntity,
+        ))
+        .with_child((
+            ParticleEmitter::new(0.2, 1, shrine_particles(kind)),
+            Transform::IDENTITY,
+            GlobalTransform::from(Transform::from_translation(position)),
+        ))
+        .id()
+}
+
+fn shrine_particles(kind: BuffKind) -> ParticleConfig {
+    ParticleConfig {
+        lifetime: 1.2,
+        lifetime_variance: 0.3,
+        speed: 20.0,
+        speed_variance: 8.0,
+        direction: Vec3::Y,
+        direction_variance: 0.4,
+        scale: 0.6,
+        scale_variance: 0.2,
+        color: glow(kind.color()),
+        emission_shape: EmissionShape::Circle { radius: 10.0 },
+        ..default()
+    }
+}
+
+/// Pushes a color past 1.0 so particles bloom.
+fn glow(color: Color) -> Color {
+    let linear = color.to_linear();
+    Color::linear_rgb(linear.red * 2.0, linear.green * 2.0, linear.blue * 2.0)
+}
+
+/// Using a shrine grants its buff; the shrine then goes dark until it recharges.
+pub fn on_shrine_interact(
+    interact: On<Interact>,
+    time: Res<Time>,
+    mut players: Query<&mut Buffs, With<Player>>,
+    mut shrines: Query<(&mut Shrine, &mut Interactable)>,
+    mut commands: Commands,
+) {
+    let Ok((mut shrine, mut interactable)) = shrines.get_mut(interact.target) else {
+        return;
+    };
+    let Ok(mut buffs) = players.single_mut() else {
+        return;
+    };
+
+    let kind = shrine.buff;
+    buffs.apply(
+        Buff::new(kind, kind.shrine_magnitude(), SHRINE_BUFF_SECS),
+        time.elapsed_secs(),
+    );
+    shrine.recharge = SHRINE_COOLDOWN_SECS;
+    interactable.enabled = false;
+    commands.trigger(ScreenFlash);
+    info!(target: targets::PLAYER, "Shrine granted {}", kind.display_name());
+}
+
+/// Counts recharge down and dims shrines that aren't ready.
+pub fn recharge_shrines(
+    time: Res<Time>,
+    mut shrines: Query<(&mut Shrine, &mut Interactable, &mut Sprite, &mut LightSource)>,
+) {
+    let dt = time.delta_secs();
+    for (mut shrine, mut interactable, mut sprite, mut light) in &mut shrines {
+        if shrine.is_ready() {
+            continue;
+        }
+        shrine.recharge = (shrine.recharge - dt).max(0.0);
+
+        let (color, intensity) = if shrine.is_ready() {
+            (shrine.buff.color(), 1.0)
+        } else {
+            (shrine.buff.color().with_alpha(0.35), 0.2)
+        };
+        sprite.color = color;
+        light.intensity = intensity;
+        interactable.enabled = shrine.is_ready();
+    }
+}
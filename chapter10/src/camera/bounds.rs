@@ -0,0 +1,35 @@
+// src/camera/bounds.rs
+use bevy::prelude::*;
+
+/// The world-space rectangle the camera currently shows.
+#[derive(Debug, Clone, Copy)]
+pub struct ViewBounds {
+    rect: Rect,
+}
+
+impl ViewBounds {
+    /// `None` for non-orthographic projections, which this game doesn't use.
+    pub fn from_camera(transform: &Transform, projection: &Projection) -> Option<Self> {
+        let Projection::Orthographic(ortho) = projection else {
+            return None;
+        };
+        let center = transform.translation.truncate();
+        Some(Self {
+            rect: Rect::from_center_half_size(center, ortho.area.half_size()),
+        })
+    }
+
+    pub fn contains(&self, position: Vec2) -> bool {
+        self.rect.contains(position)
+    }
+
+    /// Where the line from the view's centre to `position` crosses the view
+    /// edge, pulled in by `margin`. Positions inside come back unchanged.
+    pub fn edge_point(&self, position: Vec2, margin: f32) -> Vec2 {
+        let center = self.rect.center();
+        let half_extents = (self.rect.half_size() - Vec2::splat(margin)).max(Vec2::ZERO);
+        let offset = position - center;
+        let fit = (half_extents.x / offset.x.abs()).min(half_extents.y / offset.y.abs());
+        center + offset * fit.min(1.0)
+    }
+}
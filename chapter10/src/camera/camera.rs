@@ -1,8 +1,10 @@
 // src/camera/camera.rs
 use bevy::prelude::*;
 
+use crate::accessibility::AccessibilitySettings;
 use crate::characters::input::Player;
-use crate::config::camera::{CAMERA_LERP_SPEED, CAMERA_Z};
+use crate::combat::LockOn;
+use crate::config::camera::{CAMERA_LERP_SPEED, CAMERA_Z, LOCK_ON_CAMERA_BIAS};
 
 /// Marker component for the main game camera.
 #[derive(Component)]
@@ -13,36 +15,46 @@ pub fn setup_camera(mut commands: Commands) {
     commands.spawn((Camera2d::default(), MainCamera));
 }
 
-/// Smoothly follow the player with the camera.
+/// Smoothly follow the player with the camera, leaning toward a locked-on enemy.
 ///
 /// Uses linear interpolation for smooth movement and snaps to pixel boundaries
-/// to prevent subpixel rendering artifacts (grid shimmer).
+/// to prevent subpixel rendering artifacts (grid shimmer). Lower smoothing
+/// in the accessibility settings makes it snappier, down to locked on.
 pub fn follow_camera(
     time: Res<Time>,
-    player_query: Query<&Transform, (With<Player>, Changed<Transform>)>,
+    accessibility: Res<AccessibilitySettings>,
+    player_query: Query<(&Transform, &LockOn), With<Player>>,
+    lock_targets: Query<&Transform, (Without<Player>, Without<MainCamera>)>,
     mut camera_query: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
 ) {
-    // Only update when player moves (Changed<Transform>)
-    let Some(player_transform) = player_query.iter().next() else {
+    let Some((player_transform, lock)) = player_query.iter().next() else {
         return;
     };
-
     let Ok(mut camera_transform) = camera_query.single_mut() else {
         return;
     };
 
     let player_pos = player_transform.translation.truncate();
+    let focus = match lock.target.and_then(|target| lock_targets.get(target).ok()) {
+        Some(target) => player_pos.lerp(target.translation.truncate(), LOCK_ON_CAMERA_BIAS),
+        None => player_pos,
+    };
     let camera_pos = camera_transform.translation.truncate();
 
     // Early exit if camera is already very close (within 0.5 pixels)
-    let distance = player_pos.distance(camera_pos);
+    let distance = focus.distance(camera_pos);
     if distance < 0.5 {
         return;
     }
 
-    // Smooth interpolation toward player
-    let lerp_factor = (CAMERA_LERP_SPEED * time.delta_secs()).clamp(0.0, 1.0);
-    let new_pos = camera_pos.lerp(player_pos, lerp_factor);
+    // Smooth interpolation toward the focus point
+    let lerp_factor = if accessibility.camera_smoothing > 0.0 {
+        let speed = CAMERA_LERP_SPEED / accessibility.camera_smoothing;
+        (speed * time.delta_secs()).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let new_pos = camera_pos.lerp(focus, lerp_factor);
 
     // Snap to pixel boundaries to prevent grid shimmer
     camera_transform.translation.x = new_pos.x.round();
@@ -1,10 +1,13 @@
 // src/camera/mod.rs
+mod bounds;
 mod camera;
 
 use bevy::prelude::*;
-use crate::state::GameState;
+use crate::schedule::GameSet;
+use crate::state::PlayState;
 
 // Re-export public items
+pub use bounds::ViewBounds;
 pub use camera::MainCamera;
 
 /// Plugin for camera systems.
@@ -18,7 +21,10 @@ impl Plugin for CameraPlugin {
             )
             .add_systems(
                 Update,
-                camera::follow_camera.run_if(in_state(GameState::Playing)),
+                // Cutscenes move the camera themselves
+                camera::follow_camera
+                    .run_if(not(in_state(PlayState::InCutscene)))
+                    .in_set(GameSet::PostPhysics),
             );
     }
 }
\ No newline at end of file
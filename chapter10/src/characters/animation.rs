@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::characters::config::{CharacterEntry, AnimationType};
 use crate::characters::facing::Facing;
 use crate::characters::state::CharacterState; 
+use crate::enemy::SimulationLod;
 
 // Default animation timing (10 FPS = 0.1 seconds per frame)
 pub const DEFAULT_ANIMATION_FRAME_TIME: f32 = 0.1;
@@ -81,7 +82,10 @@ pub fn on_state_change_update_animation(
     for (state, mut controller, mut timer) in query.iter_mut() {
         // Select animation based on new state
         let new_animation = match state {
-            CharacterState::Idle | CharacterState::Walking => AnimationType::Walk,
+            // Blocking holds the first walk frame as a guard pose
+            CharacterState::Idle | CharacterState::Walking | CharacterState::Blocking => {
+                AnimationType::Walk
+            }
             CharacterState::Running => AnimationType::Run,
             CharacterState::Jumping => AnimationType::Jump,
         };
@@ -103,11 +107,17 @@ pub fn animations_playback(
         &mut AnimationTimer,
         &mut Sprite,
         &CharacterEntry,
+        Option<&SimulationLod>,
     )>,
 ) {
-    for (state, facing, controller, mut timer, mut sprite, config) in query.iter_mut() {
+    for (state, facing, controller, mut timer, mut sprite, config, lod) in query.iter_mut() {
+        // Distant enemies hold their current frame
+        if lod.is_some_and(|lod| lod.reduced) {
+            continue;
+        }
+
         // Don't animate when idle
-        if *state == CharacterState::Idle {
+        if matches!(state, CharacterState::Idle | CharacterState::Blocking) {
             // Ensure idle sprite is at frame 0
             if let Some(atlas) = sprite.texture_atlas.as_mut() {
                 if let Some(clip) = controller.get_clip(config, *facing) {
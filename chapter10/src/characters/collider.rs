@@ -1,17 +1,26 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
-use crate::collision::CollisionMap;
+use crate::collision::{ColliderShape, CollisionMap, Layer};
 use crate::characters::physics::Velocity;
 use crate::config::player::{COLLIDER_RADIUS};
+use crate::enemy::{Flying, SpawningIn};
+use crate::props::{Platform, Riding};
 
-/// A circular collider for collision detection.
+/// A collider for collision detection.
 /// 
 /// The collider position is offset from the entity's transform,
 /// typically to represent the character's feet position.
-#[derive(Component, Debug, Clone)]
+///
+/// Most characters are circles; long creatures use a capsule and
+/// rectangular props a box.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+#[require(Layer)]
 pub struct Collider {
-    /// Radius of the circular collider in world units
-    pub radius: f32,
+    /// Footprint in world units
+    pub shape: ColliderShape,
     /// Offset from entity center (e.g., Vec2(0, -25) for feet)
     pub offset: Vec2,
 }
@@ -19,7 +28,7 @@ pub struct Collider {
 impl Default for Collider {
     fn default() -> Self {
         Self {
-            radius: COLLIDER_RADIUS,
+            shape: ColliderShape::Circle { radius: COLLIDER_RADIUS },
             offset: Vec2::ZERO,
         }
     }
@@ -30,16 +39,40 @@ impl Collider {
     pub fn world_position(&self, transform: &Transform) -> Vec2 {
         transform.translation.truncate() + self.offset
     }
+
+    /// Radius of the smallest circle around the whole collider. Pathfinding
+    /// uses it to decide which gaps the owner fits through.
+    pub fn clearance(&self) -> f32 {
+        self.shape.bounding_radius()
+    }
+
+    /// Direction toward `other` and the gap between the two, negative once
+    /// they overlap.
+    pub fn separation(&self, transform: &Transform, other: &Collider, other_transform: &Transform) -> (Vec2, f32) {
+        ColliderShape::separation(
+            &self.shape,
+            self.world_position(transform),
+            &other.shape,
+            other.world_position(other_transform),
+        )
+    }
+
+    /// Gap under which two colliders count as touching: a fraction of their
+    /// combined thickness, so movement stops just short of overlapping.
+    pub fn contact_gap(&self, other: &Collider, margin: f32) -> f32 {
+        (self.shape.inner_radius() + other.shape.inner_radius()) * margin
+    }
 }
 
 pub fn validate_movement(
     map: Option<Res<CollisionMap>>,
     time: Res<Time>,
-    mut query: Query<(&Transform, &mut Velocity, &Collider)>,
+    mut query: Query<(&Transform, &mut Velocity, &Collider, Has<Flying>, Option<&Riding>)>,
+    platforms: Query<&Transform, With<Platform>>,
 ) {
     let Some(map) = map else { return };
 
-    for (transform, mut velocity, collider) in query.iter_mut() {
+    for (transform, mut velocity, collider, flying, riding) in query.iter_mut() {
         // Skip if not moving
         if !velocity.is_moving() {
             continue;
@@ -52,8 +85,23 @@ pub fn validate_movement(
         let delta = velocity.0 * time.delta_secs();
         let desired_pos = current_pos + delta;
 
-        // Use swept collision to find valid position
-        let valid_pos = map.sweep_circle(current_pos, desired_pos, collider.radius);
+        // Use swept collision to find valid position; flyers only mind the map edges.
+        // Decks are solid footing wherever they float, and riders only step
+        // off one onto clear ground.
+        let deck = riding.and_then(|r| platforms.get(r.0).ok());
+        let valid_pos = if flying {
+            map.clamp_to_bounds(desired_pos, collider.clearance())
+        } else if platforms.iter().any(|t| Platform::carries(t, desired_pos)) {
+            desired_pos
+        } else if let Some(deck) = deck {
+            if map.is_shape_clear(desired_pos, &collider.shape) {
+                desired_pos
+            } else {
+                Platform::clamp_to_deck(deck, desired_pos)
+            }
+        } else {
+            map.sweep_shape(current_pos, desired_pos, &collider.shape)
+        };
 
         // Calculate what velocity would get us to valid_pos
         let actual_delta = valid_pos - current_pos;
@@ -69,51 +117,61 @@ pub fn validate_movement(
     }
 }
 
+/// Moves walkers between the ground and bridge decks as they walk on and
+/// off. Flyers stay on the ground layer, above everything anyway.
+pub fn update_layers(
+    map: Option<Res<CollisionMap>>,
+    mut query: Query<(&Transform, &Collider, &mut Layer), Without<Flying>>,
+) {
+    let Some(map) = map else { return };
+
+    for (transform, collider, mut layer) in &mut query {
+        let next = map.layer_at(collider.world_position(transform), *layer);
+        layer.set_if_neq(next);
+    }
+}
+
 /// Resolve collisions between entities (player and enemies)
-/// Prevents entities from moving into each other
+/// Prevents entities from moving into each other, or into props with a
+/// collider that don't move at all. Flyers pass over
+/// walkers and only bump into each other; bridges and the ground below
+/// don't block each other either.
 pub fn resolve_entity_collisions(
-    mut query: Query<(Entity, &Transform, &mut Velocity, &Collider)>,
+    bodies: Query<(Entity, &Transform, &Collider, &Layer, Has<Flying>), Without<SpawningIn>>,
+    mut movers: Query<(Entity, &mut Velocity), Without<SpawningIn>>,
 ) {
-    // Collect all entity positions first to avoid multiple mutable borrows
-    let entities: Vec<_> = query
-        .iter()
-        .map(|(e, t, _, c)| (e, c.world_position(t), c.radius))
-        .collect();
-
-    // Check each entity against all others
-    for (entity, transform, mut velocity, collider) in query.iter_mut() {
+    // Velocities before any blocking, so a body can follow one moving away
+    let velocities: HashMap<Entity, Vec2> = movers.iter().map(|(entity, velocity)| (entity, velocity.0)).collect();
+
+    for (entity, mut velocity) in &mut movers {
         // Skip if not moving
         if !velocity.is_moving() {
             continue;
         }
+        let Ok((_, transform, collider, layer, flying)) = bodies.get(entity) else {
+            continue;
+        };
 
-        let pos = collider.world_position(transform);
-        let radius = collider.radius;
-
-        for &(other_entity, other_pos, other_radius) in &entities {
-            // Skip self
-            if entity == other_entity {
+        // Check against every other body, including props that never move
+        for (other_entity, other_transform, other_collider, other_layer, other_flying) in &bodies {
+            // Skip self, and pairs at different heights
+            if entity == other_entity || flying != other_flying || layer != other_layer {
                 continue;
             }
 
-            let delta = other_pos - pos;
-            let distance = delta.length();
-            let min_distance = radius + other_radius;
+            let (direction, gap) = collider.separation(transform, other_collider, other_transform);
 
             // Check if entities are overlapping or very close
-            if distance < min_distance * 1.1 {
-                // Calculate the direction toward the other entity
-                if distance > 0.01 {
-                    let direction = delta / distance;
-                    
-                    // Project velocity onto the direction toward the other entity
-                    let velocity_toward = velocity.0.dot(direction);
-                    
-                    // If moving toward the other entity, block that movement
-                    if velocity_toward > 0.0 {
-                        // Remove the component of velocity moving toward the other entity
-                        velocity.0 -= direction * velocity_toward;
-                    }
+            if gap < collider.contact_gap(other_collider, 0.1) {
+                // Project velocity onto the direction toward the other entity,
+                // less however fast the other is moving away (a pushed crate)
+                let receding = velocities.get(&other_entity).map_or(0.0, |v| v.dot(direction).max(0.0));
+                let velocity_toward = velocity.0.dot(direction) - receding;
+
+                // If closing in on the other entity, block that movement
+                if velocity_toward > 0.0 {
+                    // Remove the part of the velocity closing the gap
+                    velocity.0 -= direction * velocity_toward;
                 }
             }
         }
@@ -3,6 +3,10 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::collider::Collider;
+use crate::collision::ColliderShape;
+use crate::combat::PowerId;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum AnimationType {
     #[default] 
@@ -21,6 +25,31 @@ pub struct AnimationDefinition {
     pub directional: bool, // true = 4 rows (one per direction), false = 1 row
 }
 
+/// What a character's signature ability does. Used with Q.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Ability {
+    /// Burst of speed in the movement (or facing) direction, invulnerable throughout
+    Dash { speed: f32, duration: f32 },
+    /// Ring around the character healing allies inside it every second
+    HealAura { radius: f32, heal_per_second: f32, duration: f32 },
+    /// Familiars that orbit the character and shoot `power` at nearby enemies
+    Summon { power: PowerId, count: u32, lifetime: f32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureAbility {
+    pub ability: Ability,
+    pub cooldown: f32,
+}
+
+/// Footprint of a character, sized to its sprite.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColliderDefinition {
+    pub shape: ColliderShape,
+    #[serde(default)]
+    pub offset: (f32, f32),
+}
+
 #[derive(Component, Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterEntry {
     pub name: String,
@@ -31,9 +60,30 @@ pub struct CharacterEntry {
     pub tile_size: u32,
     pub atlas_columns: usize,
     pub animations: HashMap<AnimationType, AnimationDefinition>,
+    /// Only used while the character is the player's
+    #[serde(default)]
+    pub ability: Option<SignatureAbility>,
+    /// Falls back to a circle of the shared default radius when left out
+    #[serde(default)]
+    pub collider: Option<ColliderDefinition>,
 }
 
 impl CharacterEntry {
+    pub fn collider(&self) -> Collider {
+        match &self.collider {
+            Some(definition) => Collider {
+                shape: definition.shape,
+                offset: Vec2::from(definition.offset),
+            },
+            None => Collider::default(),
+        }
+    }
+
+    /// On-screen size of one animation frame at the given transform scale.
+    pub fn sprite_size(&self, scale: Vec3) -> Vec2 {
+        Vec2::splat(self.tile_size as f32) * scale.truncate()
+    }
+
     pub fn calculate_max_animation_row(&self) -> usize {
         self.animations
             .values()
@@ -3,7 +3,8 @@ use serde::{Serialize, Deserialize};
 
 /// The direction a character is facing.
 /// Separate from movement - character can face one way while moving another.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[reflect(Component, Default)]
 pub enum Facing {
     Up,
     Left,
@@ -21,6 +22,15 @@ impl Facing {
         }
     }
     
+    pub fn direction(self) -> Vec2 {
+        match self {
+            Facing::Up => Vec2::Y,
+            Facing::Left => Vec2::NEG_X,
+            Facing::Down => Vec2::NEG_Y,
+            Facing::Right => Vec2::X,
+        }
+    }
+
     /// Helper to map direction to row offset (0, 1, 2, 3)
     pub(crate) fn direction_index(self) -> usize {
         match self {
@@ -1,5 +1,10 @@
 use bevy::prelude::*;
+use crate::buffs::Buffs;
+use crate::combat::{AbilityCooldown, Faction, LockOn, Regeneration, Shield, Stamina};
+use crate::input_map::PlayerActions;
+use crate::lighting::LightSource;
 use super::{
+    spawn::SwitchCooldown,
     state::CharacterState,
     physics::Velocity,
     facing::Facing,
@@ -7,32 +12,24 @@ use super::{
     animation::{AnimationController, AnimationTimer},
 };
 
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[require(Faction = Faction::Player, LightSource = LightSource::player(), Shield, Stamina, Regeneration, Buffs, LockOn, AbilityCooldown, SwitchCooldown)]
 pub struct Player;
 
-fn read_movement_input(input: &ButtonInput<KeyCode>) -> Vec2 {
-    const MOVEMENT_KEYS: [(KeyCode, Vec2); 4] = [
-        (KeyCode::ArrowLeft, Vec2::NEG_X),
-        (KeyCode::ArrowRight, Vec2::X),
-        (KeyCode::ArrowUp, Vec2::Y),
-        (KeyCode::ArrowDown, Vec2::NEG_Y),
-    ];
-    
-    MOVEMENT_KEYS.iter()
-        .filter(|(key, _)| input.pressed(*key))
-        .map(|(_, dir)| *dir)
-        .sum()
-}
-
 fn determine_new_state(
     current: CharacterState,
     direction: Vec2,
     is_running: bool,
     wants_jump: bool,
+    is_blocking: bool,
 ) -> CharacterState {
     match current {
         // Can't transition out of jumping until it completes
         CharacterState::Jumping => CharacterState::Jumping,
+
+        // A raised shield roots the player in place
+        _ if is_blocking => CharacterState::Blocking,
         
         // Jump takes priority when grounded
         _ if wants_jump && current.is_grounded() => CharacterState::Jumping,
@@ -48,22 +45,23 @@ fn determine_new_state(
 }
 
 pub fn handle_player_input(
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<PlayerActions>,
     mut query: Query<(
         &mut CharacterState,
         &mut Velocity,
         &mut Facing,
         &CharacterEntry,
+        &Shield,
     ), With<Player>>,
 ) {
-    let Ok((mut state, mut velocity, mut facing, character)) = query.single_mut() else {
+    let Ok((mut state, mut velocity, mut facing, character, shield)) = query.single_mut() else {
         return;
     };
     
-    // Step 1: Read what keys are pressed
-    let direction = read_movement_input(&input);
-    let is_running = input.pressed(KeyCode::ShiftLeft) || input.pressed(KeyCode::ShiftRight);
-    let wants_jump = input.just_pressed(KeyCode::Space);
+    // Step 1: Read what the player is asking for (see `input_map`)
+    let direction = actions.movement;
+    let is_running = actions.running;
+    let wants_jump = actions.jump;
     
     // Step 2: Update facing direction (which way the character looks)
     if direction != Vec2::ZERO {
@@ -75,7 +73,7 @@ pub fn handle_player_input(
     
     // Step 3: Use our state machine to determine the new state
     // This calls the determine_new_state function we wrote earlier
-    let new_state = determine_new_state(*state, direction, is_running, wants_jump);
+    let new_state = determine_new_state(*state, direction, is_running, wants_jump, shield.raised);
     if *state != new_state {
         *state = new_state;  // This triggers Changed<CharacterState>!
     }
@@ -6,7 +6,6 @@ pub mod facing;
 pub mod input; 
 pub mod physics;  
 pub mod collider;
-mod rendering;
 
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
@@ -14,12 +13,21 @@ use config::CharactersList;
 use crate::state::GameState;
 use spawn::PlayerSpawned; // Add this line
 use crate::collision::CollisionMapBuilt; // Add this line
+use crate::schedule::GameSet;
 
 pub struct CharactersPlugin;
 
 impl Plugin for CharactersPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(RonAssetPlugin::<CharactersList>::new(&["characters.ron"]))
+            .register_type::<input::Player>()
+            .register_type::<physics::Velocity>()
+            .register_type::<collider::Collider>()
+            .register_type::<state::CharacterState>()
+            .register_type::<facing::Facing>()
+            .register_type::<spawn::CurrentCharacterIndex>()
+            .register_type::<spawn::SwitchCooldown>()
+            .register_type::<PlayerSpawned>()
             .init_resource::<spawn::CurrentCharacterIndex>()
             .init_resource::<PlayerSpawned>() // Add this line
             // Load character assets at startup (before collision map)
@@ -38,15 +46,32 @@ impl Plugin for CharactersPlugin {
                     input::handle_player_input,
                     spawn::switch_character,
                     input::update_jump_state,
-                    animation::on_state_change_update_animation,
+                )
+                    .chain()
+                    .in_set(GameSet::Input),
+            )
+            .add_systems(
+                Update,
+                (
                     collider::validate_movement,
                     collider::resolve_entity_collisions,
                     physics::apply_velocity,
-                    rendering::update_character_depth,
+                )
+                    .chain()
+                    .in_set(GameSet::Physics),
+            )
+            .add_systems(
+                Update,
+                collider::update_layers.in_set(GameSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                (
+                    animation::on_state_change_update_animation,
                     animation::animations_playback,
                 )
                     .chain()
-                    .run_if(in_state(GameState::Playing)),
+                    .in_set(GameSet::Render),
             );
     }
 }
\ No newline at end of file
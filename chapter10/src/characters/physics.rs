@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 use super::{state::CharacterState, config::CharacterEntry};
+use crate::props::{Platform, Riding};
 
 /// Linear velocity in world units per second.
 /// Systems that want to move an entity modify this.
 /// A physics system reads this to update Transform.
-#[derive(Component, Debug, Clone, Copy, Default, Deref, DerefMut)]
+#[derive(Component, Reflect, Debug, Clone, Copy, Default, Deref, DerefMut)]
+#[reflect(Component, Default)]
 pub struct Velocity(pub Vec2);
 
 impl Velocity {
@@ -23,6 +25,7 @@ pub fn calculate_velocity(
     match state {
         CharacterState::Idle => Velocity::ZERO,
         CharacterState::Jumping => Velocity::ZERO,  // No movement during jump
+        CharacterState::Blocking => Velocity::ZERO,
         CharacterState::Walking => {
             Velocity(direction.normalize_or_zero() * character.base_move_speed)
         }
@@ -33,13 +36,17 @@ pub fn calculate_velocity(
 }
 
 
+/// Moves entities by their velocity, plus that of the platform they ride.
 pub fn apply_velocity(
     time: Res<Time>,
-    mut query: Query<(&Velocity, &mut Transform)>,
+    mut query: Query<(&Velocity, &mut Transform, Option<&Riding>)>,
+    platforms: Query<&Velocity, With<Platform>>,
 ) {
-    for (velocity, mut transform) in query.iter_mut() {
-        if velocity.is_moving() {
-            transform.translation += velocity.0.extend(0.0) * time.delta_secs();
+    for (velocity, mut transform, riding) in query.iter_mut() {
+        let carried = riding.and_then(|r| platforms.get(r.0).ok()).map_or(Vec2::ZERO, |v| v.0);
+        let total = velocity.0 + carried;
+        if total != Vec2::ZERO {
+            transform.translation += total.extend(0.0) * time.delta_secs();
         }
     }
 }
\ No newline at end of file
@@ -1,17 +1,24 @@
 use bevy::prelude::*;
-use crate::characters::animation::*;
 use crate::characters::config::{CharacterEntry, CharactersList};
+use crate::characters::animation::{AnimationController, AnimationTimer, DEFAULT_ANIMATION_FRAME_TIME};
+use crate::characters::facing::Facing;
 use crate::characters::input::Player;  // Changed from movement::Player
-use crate::characters::state::CharacterState;  // Line update alert
-use crate::characters::physics::Velocity;  // Line update alert
-use crate::characters::facing::Facing;  // Line update alert
-use crate::characters::collider::Collider; 
-use crate::config::player::{PLAYER_SCALE, PLAYER_Z_POSITION}; 
-use crate::combat::PlayerCombat;
+use crate::characters::physics::Velocity;
+use crate::characters::state::CharacterState;
+use crate::combat::{Health, Invulnerable, Knockback};
+use crate::config::player::{
+    PLAYER_Z_POSITION, SPAWN_POSITION, SWITCH_COOLDOWN_SECS, SWITCH_INVULNERABLE_SECS,
+};
 use crate::collision::CollisionMap;
-use crate::combat::Health;
+use crate::map::level::LevelLayout;
+use crate::input_map::PlayerActions;
+use crate::prefabs::{PrefabCommandsExt, PrefabRegistry};
+use crate::logging::targets;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
 
-#[derive(Resource, Default)]
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource, Default)]
 pub struct CurrentCharacterIndex {
     pub index: usize,
 }
@@ -22,7 +29,7 @@ pub struct CharactersListResource {
 }
 
 fn create_character_atlas_layout(
-    atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
     character_entry: &CharacterEntry,
 ) -> Handle<TextureAtlasLayout> {
     let max_row = character_entry.calculate_max_animation_row();
@@ -36,72 +43,140 @@ fn create_character_atlas_layout(
     ))
 }
 
+/// Builds a character on `entity` from its entry. Shared by initial spawns,
+/// save loading (both through prefabs) and character switching.
+///
+/// Sprite and entry always follow `character_entry`; every other component is
+/// only added if missing, so whatever the entity already carries (restored
+/// health, facing, a switched character's state) acts as an override.
+pub fn spawn_character(entity: &mut EntityWorldMut, character_entry: CharacterEntry) {
+    let sprite = entity.world_scope(|world| {
+        let texture = world.resource::<AssetServer>().load(&character_entry.texture_path);
+        let layout = create_character_atlas_layout(
+            &mut world.resource_mut::<Assets<TextureAtlasLayout>>(),
+            &character_entry,
+        );
+        Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 })
+    });
+
+    entity.insert_if_new((
+        AnimationController::default(),
+        CharacterState::default(),
+        Velocity::default(),
+        Facing::default(),
+        character_entry.collider(),
+        Health::new(character_entry.max_health),
+        AnimationTimer(Timer::from_seconds(
+            DEFAULT_ANIMATION_FRAME_TIME,
+            TimerMode::Repeating,
+        )),
+    ));
+    entity.insert((sprite, character_entry));
+}
+
+/// Seconds until the player can switch characters again.
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct SwitchCooldown(pub f32);
+
+/// Digit keys swap the player to another character. Swaps have a cooldown,
+/// can't happen mid-jump, while knocked back or where the new character's
+/// collider wouldn't fit, keep the same share of health and come with a
+/// burst and a moment of invulnerability.
+#[allow(clippy::too_many_arguments)]
 pub fn switch_character(
-    input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    actions: Res<PlayerActions>,
+    time: Res<Time>,
     mut character_index: ResMut<CurrentCharacterIndex>,
     characters_lists: Res<Assets<CharactersList>>,
     characters_list_res: Option<Res<CharactersListResource>>,
-    mut query: Query<(
-        &mut CharacterEntry,
-        &mut Sprite,
-    ), With<Player>>,
-    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
-    asset_server: Res<AssetServer>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut query: Query<
+        (Entity, &Transform, &CharacterState, &mut SwitchCooldown, Has<Knockback>),
+        With<Player>,
+    >,
 ) {
-    // Map digit keys to indices
-    const DIGIT_KEYS: [KeyCode; 9] = [
-        KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
-        KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6,
-        KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
-    ];
-    
-    // Find which digit key was pressed
-    let new_index = DIGIT_KEYS.iter()
-        .position(|&key| input.just_pressed(key));
-    
-    let Some(new_index) = new_index else {
+    let Ok((player, transform, state, mut cooldown, knocked_back)) = query.single_mut() else {
         return;
     };
-    
+    cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+
+    let Some(new_index) = actions.select_slot else {
+        return;
+    };
+    if new_index == character_index.index {
+        return;
+    }
+    if cooldown.0 > 0.0 || *state == CharacterState::Jumping || knocked_back {
+        debug!(target: targets::PLAYER, "Character switch blocked");
+        return;
+    }
+
     let Some(characters_list_res) = characters_list_res else {
         return;
     };
-    
+
     let Some(characters_list) = characters_lists.get(&characters_list_res.handle) else {
         return;
     };
-    
-    if new_index >= characters_list.characters.len() {
+
+    let Some(character_entry) = characters_list.characters.get(new_index).cloned() else {
+        return;
+    };
+
+    // A wider character can't be swapped in with its collider inside a wall
+    let collider = character_entry.collider();
+    let position = collider.world_position(transform);
+    if collision_map.is_some_and(|map| !map.is_shape_clear(position, &collider.shape)) {
+        debug!(target: targets::PLAYER, "No room to switch to {}", character_entry.name);
         return;
     }
-    
+
     // Update character index
     character_index.index = new_index;
-    
-    // Update player entity
-    let Ok((mut current_entry, mut sprite)) = query.single_mut() else {
-        return;
+    cooldown.0 = SWITCH_COOLDOWN_SECS;
+
+    info!(target: targets::PLAYER, "Switched to {}", character_entry.name);
+    spawn_switch_burst(&mut commands, transform.translation);
+    commands
+        .entity(player)
+        .insert(Invulnerable::from_seconds(SWITCH_INVULNERABLE_SECS))
+        .queue(move |mut entity: EntityWorldMut| {
+            // spawn_character keeps the existing Health, so carry it over by hand
+            if let Some(mut health) = entity.get_mut::<Health>() {
+                health.rescale(character_entry.max_health);
+            }
+            entity.insert(collider);
+            spawn_character(&mut entity, character_entry);
+        });
+}
+
+fn spawn_switch_burst(commands: &mut Commands, position: Vec3) {
+    let config = ParticleConfig {
+        lifetime: 0.5,
+        lifetime_variance: 0.15,
+        speed: 140.0,
+        speed_variance: 40.0,
+        direction: Vec3::Y,
+        direction_variance: std::f32::consts::PI,
+        scale: 1.0,
+        scale_variance: 0.3,
+        color: Color::srgb(2.0, 1.6, 2.6),
+        emission_shape: EmissionShape::Circle { radius: 16.0 },
+        ..default()
     };
-    
-    let character_entry = &characters_list.characters[new_index];
-    
-    // Update character entry
-    *current_entry = character_entry.clone();
-    
-    // Update sprite with new texture
-    let texture = asset_server.load(&character_entry.texture_path);
-    let layout = create_character_atlas_layout(&mut atlas_layouts, character_entry);
-    
-    *sprite = Sprite::from_atlas_image(
-        texture,
-        TextureAtlas {
-            layout,
-            index: 0,
-        },
-    );
+
+    commands.spawn((
+        ParticleEmitter::new(0.016, 30, config).one_shot(),
+        Transform::from_translation(position),
+        GlobalTransform::from(Transform::from_translation(position)),
+        GameplayEntity,
+    ));
 }
 
-#[derive(Resource, Default, PartialEq, Eq)]
+#[derive(Resource, Reflect, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
 pub struct PlayerSpawned(pub bool);
 
 // Add this helper function after create_character_atlas_layout
@@ -119,6 +194,7 @@ fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) ->
     if let Some(walkable) = collision_map.find_nearest_walkable(grid_pos) {
         let world_pos = collision_map.grid_to_world(walkable.x, walkable.y);
         info!(
+            target: targets::PLAYER,
             "Adjusted player spawn from {:?} to {:?} (was on obstacle)",
             desired_pos, world_pos
         );
@@ -126,7 +202,7 @@ fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) ->
     }
     
     // Fallback to original
-    warn!("Could not find walkable spawn position near {:?}", desired_pos);
+    warn!(target: targets::PLAYER, "Could not find walkable spawn position near {:?}", desired_pos);
     desired_pos
 }
 
@@ -149,19 +225,20 @@ pub fn load_character_assets(
     // Initialize with first character
     character_index.index = 0;
     
-    info!("Character assets loading started");
+    info!(target: targets::PLAYER, "Character assets loading started");
 }
 
 // Add this new function
 /// Spawn player at a valid position AFTER collision map is built
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_player_at_valid_position(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     characters_lists: Res<Assets<CharactersList>>,
     character_index: Res<CurrentCharacterIndex>,
     characters_list_res: Option<Res<CharactersListResource>>,
     collision_map: Option<Res<CollisionMap>>,
+    prefabs: Res<PrefabRegistry>,
+    level: Option<Res<LevelLayout>>,
     mut player_spawned: ResMut<PlayerSpawned>,
 ) {
     // Wait for collision map
@@ -178,44 +255,32 @@ pub fn spawn_player_at_valid_position(
     let Some(characters_list) = characters_lists.get(&characters_list_res.handle) else {
         return;
     };
+
+    if !prefabs.is_loaded() {
+        return;
+    }
     
     if character_index.index >= characters_list.characters.len() {
-        warn!("Invalid character index: {}", character_index.index);
+        warn!(target: targets::PLAYER, "Invalid character index: {}", character_index.index);
         return;
     }
     
     let character_entry = &characters_list.characters[character_index.index];
     
     // Calculate valid spawn position
-    let desired_pos = Vec2::new(0.0, 0.0);
+    let desired_pos = level.and_then(|level| level.player_spawn).unwrap_or(SPAWN_POSITION);
     let valid_pos = get_valid_spawn_position(&collision_map, desired_pos);
     
-    // Create sprite
-    let texture = asset_server.load(&character_entry.texture_path);
-    let layout = create_character_atlas_layout(&mut atlas_layouts, character_entry);
-    let sprite = Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 });
-    
-    // Spawn player with all components at valid position
-    commands.spawn((
-        Player,
-        Transform::from_translation(Vec3::new(valid_pos.x, valid_pos.y, PLAYER_Z_POSITION))
-            .with_scale(Vec3::splat(PLAYER_SCALE)),
-        sprite,
-        AnimationController::default(),
-        CharacterState::default(),
-        Velocity::default(),
-        Facing::default(),
-        Collider::default(),
-        PlayerCombat::default(),
-        Health::new(character_entry.max_health),
-        AnimationTimer(Timer::from_seconds(
-            DEFAULT_ANIMATION_FRAME_TIME,
-            TimerMode::Repeating,
-        )),
-        character_entry.clone(),
-    ));
+    // Spawn player at valid position; the prefab fills in the rest
+    commands.spawn_prefab(
+        "player",
+        (
+            Transform::from_translation(Vec3::new(valid_pos.x, valid_pos.y, PLAYER_Z_POSITION)),
+            character_entry.clone(),
+        ),
+    );
     
     // Mark player as spawned
     player_spawned.0 = true;
-    info!("Player spawned at validated position {:?}", valid_pos);
+    info!(target: targets::PLAYER, "Player spawned at validated position {:?}", valid_pos);
 }
\ No newline at end of file
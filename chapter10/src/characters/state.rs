@@ -2,20 +2,32 @@
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
 
+use crate::rendering::YSorted;
+
 /// Character states. Only one can be active at a time.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[reflect(Component, Default)]
+#[require(YSorted = YSorted::CHARACTER)]
 pub enum CharacterState {
     #[default]
     Idle,
     Walking,
     Running,
     Jumping,
+    /// Standing still behind a raised shield
+    Blocking,
 }
 
 // Append to src/characters/state.rs
 impl CharacterState {
     /// Check if this is a grounded state (can jump from here)
     pub fn is_grounded(&self) -> bool {
-        matches!(self, CharacterState::Idle | CharacterState::Walking | CharacterState::Running)
+        matches!(
+            self,
+            CharacterState::Idle
+                | CharacterState::Walking
+                | CharacterState::Running
+                | CharacterState::Blocking
+        )
     }
 }
\ No newline at end of file
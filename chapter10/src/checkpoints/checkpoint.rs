@@ -0,0 +1,178 @@
+// src/checkpoints/checkpoint.rs
+use bevy::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::characters::config::CharactersList;
+use crate::characters::spawn::{CharactersListResource, CurrentCharacterIndex};
+use crate::collision::CollisionMap;
+use crate::combat::{Health, Invulnerable};
+use crate::config::checkpoints::{
+    CHECKPOINT_COUNT, CHECKPOINT_INTERACT_RADIUS, CHECKPOINT_MIN_SPACING, CHECKPOINT_SAFE_RADIUS,
+    RESPAWN_HEALTH_FRACTION, RESPAWN_INVULNERABLE_SECS,
+};
+use crate::config::player::PLAYER_Z_POSITION;
+use crate::interaction::{Interact, Interactable};
+use crate::lighting::LightSource;
+use crate::logging::targets;
+use crate::map::generate::WorldGenSettings;
+use crate::map::level::LevelLayout;
+use crate::prefabs::PrefabCommandsExt;
+use crate::state::GameplayEntity;
+
+/// Level with shrines and props
+const CHECKPOINT_Z: f32 = 3.0;
+const CHECKPOINT_SIZE: Vec2 = Vec2::new(16.0, 32.0);
+const UNLIT_COLOR: Color = Color::srgb(0.35, 0.35, 0.4);
+const LIT_COLOR: Color = Color::srgb(0.5, 0.8, 1.0);
+/// Room the altar needs, so it never blocks a corridor
+const CHECKPOINT_CLEARANCE: f32 = 48.0;
+
+#[derive(Component, Debug)]
+pub struct Checkpoint;
+
+/// Where the player comes back after dying, `None` until a checkpoint is lit.
+#[derive(Resource, Debug, Default)]
+pub struct ActiveCheckpoint {
+    pub checkpoint: Option<Entity>,
+    pub position: Option<Vec2>,
+}
+
+/// Brings a dead player back at the active checkpoint. Triggered from the
+/// game over screen; the world carries on as the player left it.
+#[derive(Event)]
+pub struct RespawnAtCheckpoint;
+
+/// Spreads checkpoints over open ground away from the player's spawn, seeded
+/// from the world seed like the shrines. Hand-made levels put them where
+/// their designer did. The checkpoint stays lit only if one is placed where
+/// it was, as when a save rebuilds its map; a new game has none lit.
+pub fn place_checkpoints(
+    mut commands: Commands,
+    map: Res<CollisionMap>,
+    settings: Res<WorldGenSettings>,
+    level: Option<Res<LevelLayout>>,
+    mut active: ResMut<ActiveCheckpoint>,
+) {
+    let lit = active.position;
+    *active = ActiveCheckpoint::default();
+    let placed = match level {
+        Some(level) => level.checkpoints.clone(),
+        None => pick_checkpoint_spots(&map, settings.seed),
+    };
+
+    for &position in &placed {
+        let checkpoint = commands.spawn((
+            Checkpoint,
+            Sprite::from_color(UNLIT_COLOR, CHECKPOINT_SIZE),
+            Interactable::new("Light", CHECKPOINT_INTERACT_RADIUS),
+            LightSource {
+                intensity: 0.0,
+                ..LightSource::new(CHECKPOINT_INTERACT_RADIUS * 2.0).with_color(LIT_COLOR)
+            },
+            Transform::from_translation(position.extend(CHECKPOINT_Z)),
+            GameplayEntity,
+        ));
+        if lit.is_some_and(|lit| lit.distance(position) < 1.0) {
+            active.checkpoint = Some(checkpoint.id());
+            active.position = Some(position);
+        }
+    }
+
+    info!(target: targets::MAP, "Placed {} checkpoints", placed.len());
+}
+
+fn pick_checkpoint_spots(map: &CollisionMap, seed: u64) -> Vec<Vec2> {
+    let mut rng = StdRng::seed_from_u64(seed ^ 0x2545_F491);
+
+    let mut candidates: Vec<Vec2> = (0..map.height())
+        .flat_map(|y| (0..map.width()).map(move |x| (x, y)))
+        .map(|(x, y)| map.grid_to_world(x, y))
+        .filter(|pos| pos.length() >= CHECKPOINT_SAFE_RADIUS)
+        .filter(|pos| map.is_circle_clear(*pos, CHECKPOINT_CLEARANCE))
+        .collect();
+    candidates.shuffle(&mut rng);
+
+    let mut placed: Vec<Vec2> = Vec::new();
+    for position in candidates {
+        if placed.len() >= CHECKPOINT_COUNT {
+            break;
+        }
+        if placed.iter().any(|p| p.distance(position) < CHECKPOINT_MIN_SPACING) {
+            continue;
+        }
+        placed.push(position);
+    }
+    placed
+}
+
+/// Lighting a checkpoint makes it the respawn point.
+pub fn on_checkpoint_interact(
+    interact: On<Interact>,
+    checkpoints: Query<&Transform, With<Checkpoint>>,
+    mut active: ResMut<ActiveCheckpoint>,
+) {
+    let Ok(transform) = checkpoints.get(interact.target) else {
+        return;
+    };
+    if active.checkpoint == Some(interact.target) {
+        return;
+    }
+
+    active.checkpoint = Some(interact.target);
+    active.position = Some(transform.translation.truncate());
+    info!(target: targets::PLAYER, "Checkpoint set at {:?}", active.position);
+}
+
+/// Only the active checkpoint glows; the others stay dark and usable.
+pub fn light_active_checkpoint(
+    active: Res<ActiveCheckpoint>,
+    mut checkpoints: Query<(Entity, &mut Sprite, &mut LightSource, &mut Interactable), With<Checkpoint>>,
+) {
+    if !active.is_changed() {
+        return;
+    }
+    for (entity, mut sprite, mut light, mut interactable) in &mut checkpoints {
+        let lit = active.checkpoint == Some(entity);
+        sprite.color = if lit { LIT_COLOR } else { UNLIT_COLOR };
+        light.intensity = if lit { 1.0 } else { 0.0 };
+        interactable.enabled = !lit;
+    }
+}
+
+/// Spawns the current character at the active checkpoint with part of its
+/// health and a short grace period.
+pub fn respawn_at_checkpoint(
+    _respawn: On<RespawnAtCheckpoint>,
+    mut commands: Commands,
+    active: Res<ActiveCheckpoint>,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    character_index: Res<CurrentCharacterIndex>,
+) {
+    let Some(position) = active.position else {
+        warn!(target: targets::PLAYER, "Respawn requested without an active checkpoint");
+        return;
+    };
+    let Some(characters_list) = characters_list_res.and_then(|res| characters_lists.get(&res.handle)) else {
+        return;
+    };
+    let Some(character_entry) = characters_list.characters.get(character_index.index) else {
+        return;
+    };
+
+    let max = character_entry.max_health;
+    commands.spawn_prefab(
+        "player",
+        (
+            Transform::from_translation(position.extend(PLAYER_Z_POSITION)),
+            Health {
+                current: max * RESPAWN_HEALTH_FRACTION,
+                max,
+                bonus: 0.0,
+            },
+            Invulnerable::from_seconds(RESPAWN_INVULNERABLE_SECS),
+            character_entry.clone(),
+        ),
+    );
+    info!(target: targets::PLAYER, "Player respawned at checkpoint {:?}", position);
+}
@@ -0,0 +1,32 @@
+// src/checkpoints/mod.rs
+mod checkpoint;
+
+use bevy::prelude::*;
+
+use crate::schedule::{world_rebuilt, GameSet};
+use crate::state::GameMode;
+
+pub use checkpoint::{ActiveCheckpoint, RespawnAtCheckpoint};
+
+/// Altars the player lights to set where they come back after dying. The
+/// game over screen offers a respawn at the last one lit, keeping the world
+/// as it was.
+pub struct CheckpointsPlugin;
+
+impl Plugin for CheckpointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveCheckpoint>()
+            .add_observer(checkpoint::on_checkpoint_interact)
+            .add_observer(checkpoint::respawn_at_checkpoint)
+            .add_systems(
+                Update,
+                checkpoint::place_checkpoints
+                    .run_if(world_rebuilt)
+                    .run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(
+                Update,
+                checkpoint::light_active_checkpoint.in_set(GameSet::Render),
+            );
+    }
+}
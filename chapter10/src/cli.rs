@@ -0,0 +1,184 @@
+// src/cli.rs
+use bevy::prelude::*;
+
+use crate::characters::config::CharactersList;
+use crate::characters::spawn::CharactersListResource;
+use crate::combat::PowerRegistry;
+use crate::logging::targets;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::WorldGenSettings;
+use crate::prefabs::PrefabRegistry;
+use crate::save::data::MAX_SLOTS;
+use crate::save::ui::PendingSaveLoadAction;
+use crate::save::SaveLoadMode;
+use crate::state::GameState;
+use crate::video::{DisplayMode, VideoSettings};
+
+const USAGE: &str = "\
+Usage: game [options]
+
+  --seed <n>         Generate every new world from this seed
+  --map-size <WxH>   Size of new worlds in chunks, e.g. 4x4
+  --skip-menu        Start a new game straight away
+  --load-slot <n>    Load save slot n (1-5) as soon as assets are ready
+  --windowed         Run in a window, whatever the video settings say
+  --help             Show this message";
+
+/// Startup flags for jumping straight into a scenario while developing or
+/// testing. Parsed before the app runs and applied as a plugin.
+#[derive(Debug, Default, Clone)]
+pub struct CliArgs {
+    pub seed: Option<u64>,
+    /// Chunks across and down
+    pub map_size: Option<(u32, u32)>,
+    pub skip_menu: bool,
+    /// 0-based save slot
+    pub load_slot: Option<usize>,
+    pub windowed: bool,
+}
+
+impl CliArgs {
+    /// Parses the process arguments, printing usage and exiting on `--help`
+    /// or a bad flag.
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(Some(args)) => args,
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// `Ok(None)` when help was asked for.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut parsed = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| args.next().ok_or(format!("{} needs a value", flag));
+            match arg.as_str() {
+                "--seed" => {
+                    let seed = value("--seed")?;
+                    parsed.seed = Some(seed.parse().map_err(|_| format!("Bad seed: {}", seed))?);
+                }
+                "--map-size" => parsed.map_size = Some(parse_map_size(&value("--map-size")?)?),
+                "--skip-menu" => parsed.skip_menu = true,
+                "--load-slot" => {
+                    let slot = value("--load-slot")?;
+                    match slot.parse::<usize>() {
+                        Ok(n) if (1..=MAX_SLOTS).contains(&n) => parsed.load_slot = Some(n - 1),
+                        _ => return Err(format!("Bad save slot: {} (1-{})", slot, MAX_SLOTS)),
+                    }
+                }
+                "--windowed" => parsed.windowed = true,
+                "--help" | "-h" => return Ok(None),
+                other => return Err(format!("Unknown option: {}", other)),
+            }
+        }
+        if parsed.skip_menu && parsed.load_slot.is_some() {
+            return Err("--skip-menu and --load-slot can't be combined".to_string());
+        }
+        Ok(Some(parsed))
+    }
+}
+
+fn parse_map_size(size: &str) -> Result<(u32, u32), String> {
+    let bad = || format!("Bad map size: {} (expected WxH, e.g. 4x4)", size);
+    let (w, h) = size.split_once('x').ok_or_else(bad)?;
+    match (w.parse::<u32>(), h.parse::<u32>()) {
+        (Ok(w), Ok(h)) if w > 0 && h > 0 => Ok((w, h)),
+        _ => Err(bad()),
+    }
+}
+
+/// Seed and size forced onto every world generated this run.
+#[derive(Resource, Debug, Clone, Copy)]
+struct WorldGenOverride {
+    seed: Option<u64>,
+    map_size: Option<(u32, u32)>,
+}
+
+/// Save slot to load once the assets a save needs are in.
+#[derive(Resource, Debug, Clone, Copy)]
+struct PendingCliLoad(usize);
+
+impl Plugin for CliArgs {
+    fn build(&self, app: &mut App) {
+        if self.seed.is_some() || self.map_size.is_some() {
+            app.insert_resource(WorldGenOverride {
+                seed: self.seed,
+                map_size: self.map_size,
+            })
+            .add_systems(OnEnter(GameState::Loading), override_world_gen);
+        }
+        if self.skip_menu {
+            app.add_systems(Startup, skip_menu);
+        }
+        if let Some(slot) = self.load_slot {
+            app.insert_resource(PendingCliLoad(slot)).add_systems(
+                Update,
+                start_cli_load
+                    .run_if(in_state(GameState::MainMenu))
+                    .run_if(resource_exists::<PendingCliLoad>),
+            );
+        }
+        if self.windowed {
+            app.add_systems(Startup, force_windowed);
+        }
+    }
+}
+
+/// Runs before generation starts, after the menu has picked the settings.
+fn override_world_gen(overrides: Res<WorldGenOverride>, mut settings: ResMut<WorldGenSettings>) {
+    if let Some(seed) = overrides.seed {
+        settings.seed = seed;
+    }
+    if let Some((chunks_x, chunks_y)) = overrides.map_size {
+        settings.chunks_x = chunks_x;
+        settings.chunks_y = chunks_y;
+    }
+    info!(
+        target: targets::MAP,
+        "Command line world: seed {}, {}x{} chunks",
+        settings.seed,
+        settings.chunks_x,
+        settings.chunks_y
+    );
+}
+
+fn skip_menu(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Loading);
+}
+
+/// Hands the slot to the load path once everything a staged load reads
+/// has loaded, the same path "Continue" takes from the main menu.
+#[allow(clippy::too_many_arguments)]
+fn start_cli_load(
+    mut commands: Commands,
+    pending_cli: Res<PendingCliLoad>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    characters_lists: Res<Assets<CharactersList>>,
+    tilemap_handles: Option<Res<TilemapHandles>>,
+    prefabs: Res<PrefabRegistry>,
+    powers: Res<PowerRegistry>,
+    mut pending: ResMut<PendingSaveLoadAction>,
+) {
+    let characters_loaded = characters_list_res
+        .is_some_and(|res| characters_lists.get(&res.handle).is_some());
+    let registries_loaded = prefabs.is_loaded() && powers.is_loaded();
+    if !characters_loaded || tilemap_handles.is_none() || !registries_loaded {
+        return;
+    }
+    info!(target: targets::SAVE, "Loading slot {} from the command line", pending_cli.0 + 1);
+    pending.0 = Some((SaveLoadMode::Load, pending_cli.0));
+    commands.remove_resource::<PendingCliLoad>();
+}
+
+/// Only for this run; the saved video settings keep their mode unless the
+/// video page is used.
+fn force_windowed(mut settings: ResMut<VideoSettings>) {
+    settings.mode = DisplayMode::Windowed;
+}
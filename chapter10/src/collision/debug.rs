@@ -1,7 +1,9 @@
 use bevy::prelude::*;
-use super::CollisionMap;
+use super::{ColliderShape, CollisionMap, TriggerZone};
 use crate::characters::input::Player;
 use crate::characters::collider::Collider;
+use crate::logging::targets;
+use crate::rendering::ColorMode;
 
 /// Resource to toggle debug visualization.
 #[derive(Resource, Default)]
@@ -14,9 +16,73 @@ pub fn toggle_debug_collision(
     if keyboard.just_pressed(KeyCode::F3) {
         debug_enabled.0 = !debug_enabled.0;
         if debug_enabled.0 {
-            info!("🔍 Collision debug ENABLED (F3 to toggle)");
+            info!(target: targets::COLLISION, "🔍 Collision debug ENABLED (F3 to toggle)");
         } else {
-            info!("Collision debug disabled");
+            info!(target: targets::COLLISION, "Collision debug disabled");
+        }
+    }
+}
+
+/// Resource to toggle the reachability overlay.
+#[derive(Resource, Default)]
+pub struct DebugReachabilityEnabled(pub bool);
+
+/// Walkable cells the player can reach, cached until they change cell or
+/// the map is rebuilt.
+#[derive(Resource, Default)]
+pub struct Reachability {
+    from: Option<IVec2>,
+    reached: Vec<bool>,
+}
+
+pub fn toggle_debug_reachability(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut debug_enabled: ResMut<DebugReachabilityEnabled>,
+) {
+    if keyboard.just_pressed(KeyCode::F4) {
+        debug_enabled.0 = !debug_enabled.0;
+        if debug_enabled.0 {
+            info!(target: targets::COLLISION, "🔍 Reachability debug ENABLED (F4 to toggle)");
+        } else {
+            info!(target: targets::COLLISION, "Reachability debug disabled");
+        }
+    }
+}
+
+/// Tints walkable tiles the player can't walk to, so sealed-off pockets of
+/// the map stand out.
+pub fn debug_draw_reachability(
+    player_query: Query<(&Transform, &Collider), With<Player>>,
+    map: Option<Res<CollisionMap>>,
+    debug_enabled: Res<DebugReachabilityEnabled>,
+    mut reachability: ResMut<Reachability>,
+    mode: Res<ColorMode>,
+    mut gizmos: Gizmos,
+) {
+    if !debug_enabled.0 {
+        return;
+    }
+
+    let Some(map) = map else { return };
+    let Ok((transform, collider)) = player_query.single() else { return };
+
+    let cell = map.world_to_grid(collider.world_position(transform));
+    if map.is_changed() || reachability.from != Some(cell) {
+        reachability.reached = map.reachable_from(cell, 0.0);
+        reachability.from = Some(cell);
+        let count = reachability.reached.iter().filter(|&&r| r).count();
+        debug!(target: targets::COLLISION, "{} cells reachable from {:?}", count, cell);
+    }
+
+    let color = mode.debug_palette().unreachable;
+    let tile_size = map.tile_size();
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let reached = reachability.reached[(y * map.width() + x) as usize];
+            if reached || !map.is_walkable(x, y) {
+                continue;
+            }
+            gizmos.rect_2d(map.grid_to_world(x, y), Vec2::splat(tile_size * 0.8), color);
         }
     }
 }
@@ -24,6 +90,7 @@ pub fn toggle_debug_collision(
 pub fn debug_draw_collision(
     map: Option<Res<CollisionMap>>,
     debug_enabled: Res<DebugCollisionEnabled>,
+    mode: Res<ColorMode>,
     mut gizmos: Gizmos,
 ) {
     if !debug_enabled.0 {
@@ -32,6 +99,7 @@ pub fn debug_draw_collision(
 
     let Some(map) = map else { return };
 
+    let palette = mode.debug_palette();
     let tile_size = map.tile_size();
     let origin = map.origin();
 
@@ -43,10 +111,12 @@ pub fn debug_draw_collision(
                 origin.y + (y as f32 + 0.5) * tile_size,
             );
 
-            let color = if map.is_walkable(x, y) {
-                Color::srgba(0.0, 1.0, 0.0, 0.25)  // Green, 25% opacity
+            let color = if map.is_bridge(x, y) {
+                palette.bridge
+            } else if map.is_walkable(x, y) {
+                palette.walkable
             } else {
-                Color::srgba(1.0, 0.0, 0.0, 0.4)   // Red, 40% opacity
+                palette.blocked
             };
 
             gizmos.rect_2d(
@@ -80,8 +150,8 @@ pub fn debug_player_position(
     // Draw line from center to collider (shows offset)
     gizmos.line_2d(center, collider_pos, Color::srgba(1.0, 1.0, 0.0, 0.5));
 
-    // Draw actual collider circle
-    gizmos.circle_2d(collider_pos, collider.radius, Color::srgb(0.0, 1.0, 1.0));
+    // Draw actual collider shape
+    draw_shape(&mut gizmos, collider_pos, &collider.shape, Color::srgb(0.0, 1.0, 1.0));
 
     // Draw current grid cell outline
     if map.in_bounds(grid.x, grid.y) {
@@ -107,4 +177,43 @@ pub fn debug_player_position(
             );
         }
     }
-}
\ No newline at end of file
+}
+
+/// Outlines trigger zones: orange when empty, yellow while something is inside.
+pub fn debug_draw_trigger_zones(
+    zones: Query<(&Transform, &TriggerZone)>,
+    debug_enabled: Res<DebugCollisionEnabled>,
+    mut gizmos: Gizmos,
+) {
+    if !debug_enabled.0 {
+        return;
+    }
+
+    for (transform, zone) in &zones {
+        let color = if zone.occupants().is_empty() {
+            Color::srgb(1.0, 0.5, 0.0)
+        } else {
+            Color::srgb(1.0, 1.0, 0.0)
+        };
+        draw_shape(&mut gizmos, transform.translation.truncate(), &zone.shape, color);
+    }
+}
+
+fn draw_shape(gizmos: &mut Gizmos, center: Vec2, shape: &ColliderShape, color: Color) {
+    match *shape {
+        ColliderShape::Circle { radius } => {
+            gizmos.circle_2d(center, radius, color);
+        }
+        ColliderShape::Capsule { radius, half_length } => {
+            let end = Vec2::new(half_length, 0.0);
+            let side = Vec2::new(0.0, radius);
+            gizmos.circle_2d(center - end, radius, color);
+            gizmos.circle_2d(center + end, radius, color);
+            gizmos.line_2d(center - end + side, center + end + side, color);
+            gizmos.line_2d(center - end - side, center + end - side, color);
+        }
+        ColliderShape::Aabb { half_size } => {
+            gizmos.rect_2d(center, half_size * 2.0, color);
+        }
+    }
+}
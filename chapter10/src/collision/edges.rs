@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+/// Sides of a grid cell, as bit flags.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Edges(u8);
+
+impl Edges {
+    pub const NONE: Edges = Edges(0);
+    pub const NORTH: Edges = Edges(1);
+    pub const EAST: Edges = Edges(1 << 1);
+    pub const SOUTH: Edges = Edges(1 << 2);
+    pub const WEST: Edges = Edges(1 << 3);
+
+    pub fn contains(self, other: Edges) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn union(self, other: Edges) -> Edges {
+        Edges(self.0 | other.0)
+    }
+
+    /// The side a cardinal grid step points at: (0, 1) is north.
+    pub fn toward(direction: IVec2) -> Edges {
+        match (direction.x.signum(), direction.y.signum()) {
+            (0, 1) => Edges::NORTH,
+            (1, 0) => Edges::EAST,
+            (0, -1) => Edges::SOUTH,
+            (-1, 0) => Edges::WEST,
+            _ => Edges::NONE,
+        }
+    }
+
+    /// Parses a comma separated list of sides, like "south" or "south,west".
+    pub fn parse(list: &str) -> Option<Edges> {
+        list.split(',').try_fold(Edges::NONE, |edges, side| {
+            let side = match side.trim().to_ascii_lowercase().as_str() {
+                "north" => Edges::NORTH,
+                "east" => Edges::EAST,
+                "south" => Edges::SOUTH,
+                "west" => Edges::WEST,
+                _ => return None,
+            };
+            Some(edges.union(side))
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Height an actor or projectile moves at. Things only touch, hit and
+/// block each other on the same layer, so a fight on a bridge doesn't reach
+/// whatever walks underneath.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component, Default)]
+pub enum Layer {
+    #[default]
+    Ground,
+    /// On a bridge deck, above water or a lower path
+    Bridge,
+}
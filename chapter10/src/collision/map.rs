@@ -1,5 +1,9 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
 use bevy::prelude::*;
-use super::TileType;
+use super::{ColliderShape, Edges, Layer, TileType};
+use crate::config::map::{CARVE_COST_PROP, CARVE_COST_WATER};
 use pathfinding::prelude::astar;
 
 /// Collision map resource that stores walkability information.
@@ -8,6 +12,11 @@ use pathfinding::prelude::astar;
 pub struct CollisionMap {
     /// Flat array of tile types (row-major order)
     tiles: Vec<TileType>,
+    /// Cells with a bridge deck above the ground tile (same order)
+    bridges: Vec<bool>,
+    /// Sides of each cell that can only be crossed on the way out, like the
+    /// foot of a cliff ledge you can drop off but not climb (same order)
+    one_way: Vec<Edges>,
     /// Grid dimensions
     width: i32,
     height: i32,
@@ -16,6 +25,8 @@ pub struct CollisionMap {
     /// World position of grid origin (bottom-left corner)
     origin_x: f32,
     origin_y: f32,
+    /// Seed the hazards were scattered with, if the map has any
+    hazard_seed: Option<u64>,
 }
 
 impl CollisionMap {
@@ -24,11 +35,14 @@ impl CollisionMap {
         let size = (width * height) as usize;
         Self {
             tiles: vec![TileType::Empty; size],
+            bridges: vec![false; size],
+            one_way: vec![Edges::NONE; size],
             width,
             height,
             tile_size,
             origin_x,
             origin_y,
+            hazard_seed: None,
         }
     }
 
@@ -74,17 +88,101 @@ impl CollisionMap {
         }
     }
 
-    /// Check if a grid position is walkable.
+    /// Put a bridge deck over a cell.
+    pub fn set_bridge(&mut self, x: i32, y: i32) {
+        if self.in_bounds(x, y) {
+            let idx = self.xy_to_idx(x, y);
+            self.bridges[idx] = true;
+        }
+    }
+
+    pub fn is_bridge(&self, x: i32, y: i32) -> bool {
+        self.in_bounds(x, y) && self.bridges[self.xy_to_idx(x, y)]
+    }
+
+    /// Makes `edges` of a cell one-way: they can be left through but not
+    /// entered.
+    pub fn set_one_way(&mut self, x: i32, y: i32, edges: Edges) {
+        if self.in_bounds(x, y) {
+            let idx = self.xy_to_idx(x, y);
+            self.one_way[idx] = self.one_way[idx].union(edges);
+        }
+    }
+
+    pub fn one_way_edges(&self, x: i32, y: i32) -> Edges {
+        if self.in_bounds(x, y) {
+            self.one_way[self.xy_to_idx(x, y)]
+        } else {
+            Edges::NONE
+        }
+    }
+
+    /// Whether one-way edges allow stepping from `from` to a neighbouring
+    /// cell. Diagonal steps have to be allowed both ways around the corner,
+    /// so they can't slip past the end of a ledge.
+    pub fn can_step(&self, from: IVec2, to: IVec2) -> bool {
+        let enters = |cell: IVec2, direction: IVec2| {
+            direction == IVec2::ZERO
+                || !self.one_way_edges(cell.x, cell.y).contains(Edges::toward(-direction))
+        };
+        let delta = to - from;
+        if delta.x == 0 || delta.y == 0 {
+            return enters(to, delta);
+        }
+        let via_x = IVec2::new(to.x, from.y);
+        let via_y = IVec2::new(from.x, to.y);
+        enters(via_x, via_x - from)
+            && enters(to, to - via_x)
+            && enters(via_y, via_y - from)
+            && enters(to, to - via_y)
+    }
+
+    /// Check if a grid position is walkable, on the ground or on a bridge.
     pub fn is_walkable(&self, x: i32, y: i32) -> bool {
+        self.is_ground_walkable(x, y) || self.is_bridge(x, y)
+    }
+
+    /// Check if the ground tile itself is walkable, ignoring bridges.
+    pub fn is_ground_walkable(&self, x: i32, y: i32) -> bool {
         self.get_tile(x, y).map_or(false, |t| t.is_walkable())
     }
 
+    /// Walkability as seen from one layer: the ground layer passes under
+    /// bridges, so only the ground tile counts.
+    pub fn is_walkable_on(&self, x: i32, y: i32, layer: Layer) -> bool {
+        match layer {
+            Layer::Ground => self.is_ground_walkable(x, y),
+            Layer::Bridge => self.is_walkable(x, y),
+        }
+    }
+
     /// Check if a world position is walkable.
     pub fn is_world_pos_walkable(&self, world_pos: Vec2) -> bool {
         let grid_pos = self.world_to_grid(world_pos);
         self.is_walkable(grid_pos.x, grid_pos.y)
     }
 
+    /// Check if a world position is walkable on `layer`.
+    pub fn is_world_pos_walkable_on(&self, world_pos: Vec2, layer: Layer) -> bool {
+        let grid_pos = self.world_to_grid(world_pos);
+        self.is_walkable_on(grid_pos.x, grid_pos.y, layer)
+    }
+
+    /// Layer of something standing at `world_pos`: on a deck over
+    /// unwalkable ground it's on the bridge, off any bridge it's on the
+    /// ground. Bridge ends and decks over walkable ground keep `current`,
+    /// so walking under a bridge doesn't climb onto it.
+    pub fn layer_at(&self, world_pos: Vec2, current: Layer) -> Layer {
+        let cell = self.world_to_grid(world_pos);
+        if !self.is_bridge(cell.x, cell.y) {
+            Layer::Ground
+        } else if !self.is_ground_walkable(cell.x, cell.y) {
+            Layer::Bridge
+        } else {
+            current
+        }
+    }
+
     fn circle_intersects_tile(&self, center: Vec2, radius: f32, gx: i32, gy: i32) -> bool {
         // Tile bounding box
         let tile_min = Vec2::new(
@@ -139,7 +237,7 @@ impl CollisionMap {
                 }
 
                 if let Some(tile) = self.get_tile(gx, gy) {
-                    if !tile.is_walkable() {
+                    if !tile.is_walkable() && !self.is_bridge(gx, gy) {
                         // Apply tile-specific collision adjustment
                         let effective_radius = radius + tile.collision_adjustment() * self.tile_size;
                         
@@ -153,7 +251,81 @@ impl CollisionMap {
         true
     }
 
-    pub fn sweep_circle(&self, start: Vec2, end: Vec2, radius: f32) -> Vec2 {
+    /// Pulls a circle back inside the map. Flyers ignore tiles but not the edges.
+    pub fn clamp_to_bounds(&self, center: Vec2, radius: f32) -> Vec2 {
+        let min = Vec2::new(self.origin_x, self.origin_y) + radius;
+        let max = Vec2::new(
+            self.origin_x + self.width as f32 * self.tile_size,
+            self.origin_y + self.height as f32 * self.tile_size,
+        ) - radius;
+        center.clamp(min, max.max(min))
+    }
+
+    /// Checks a horizontal capsule: the circles swept along the spine from
+    /// `center - half_extent` to `center + half_extent`.
+    pub fn is_capsule_clear(&self, center: Vec2, half_extent: Vec2, radius: f32) -> bool {
+        if half_extent == Vec2::ZERO {
+            return self.is_circle_clear(center, radius);
+        }
+        // Circles no more than a radius apart leave no gaps along the spine
+        let steps = (2.0 * half_extent.length() / radius.max(1.0)).ceil() as i32;
+        (0..=steps).all(|i| {
+            let t = i as f32 / steps as f32;
+            self.is_circle_clear(center - half_extent + half_extent * 2.0 * t, radius)
+        })
+    }
+
+    /// Checks an axis-aligned box. Trees and rocks are shrunk the same way
+    /// as for circles, so boxes can cut their corners too.
+    pub fn is_aabb_clear(&self, center: Vec2, half_size: Vec2) -> bool {
+        let min = Vec2::new(self.origin_x, self.origin_y);
+        let max = min + Vec2::new(self.width as f32, self.height as f32) * self.tile_size;
+        if center.cmplt(min + half_size).any() || center.cmpgt(max - half_size).any() {
+            return false;
+        }
+
+        let low = self.world_to_grid(center - half_size);
+        let high = self.world_to_grid(center + half_size);
+        for gy in low.y..=high.y {
+            for gx in low.x..=high.x {
+                let Some(tile) = self.get_tile(gx, gy) else {
+                    return false;
+                };
+                if self.is_walkable(gx, gy) {
+                    continue;
+                }
+                let tile_min = Vec2::new(
+                    self.origin_x + gx as f32 * self.tile_size,
+                    self.origin_y + gy as f32 * self.tile_size,
+                );
+                let inset = -tile.collision_adjustment() * self.tile_size;
+                let shape = ColliderShape::Aabb { half_size };
+                if shape.overlaps_rect(center, tile_min + inset, tile_min + Vec2::splat(self.tile_size) - inset) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Checks any collider shape against the tiles and map edges.
+    pub fn is_shape_clear(&self, center: Vec2, shape: &ColliderShape) -> bool {
+        match *shape {
+            ColliderShape::Circle { radius } => self.is_circle_clear(center, radius),
+            ColliderShape::Capsule { radius, half_length } => {
+                self.is_capsule_clear(center, Vec2::new(half_length, 0.0), radius)
+            }
+            ColliderShape::Aabb { half_size } => self.is_aabb_clear(center, half_size),
+        }
+    }
+
+    /// Moves a shape from `start` toward `end`, sliding along walls and
+    /// stopping at one-way edges crossed the wrong way.
+    pub fn sweep_shape(&self, start: Vec2, end: Vec2, shape: &ColliderShape) -> Vec2 {
+        let can_move = |from: Vec2, to: Vec2| {
+            self.is_shape_clear(to, shape)
+                && self.can_step(self.world_to_grid(from), self.world_to_grid(to))
+        };
         let delta = end - start;
         
         // No movement needed
@@ -170,19 +342,19 @@ impl CollisionMap {
         for _ in 0..steps {
             let candidate = pos + step_vec;
 
-            if self.is_circle_clear(candidate, radius) {
+            if can_move(pos, candidate) {
                 pos = candidate;
             } else {
                 // Try sliding along X axis only
                 let try_x = Vec2::new(candidate.x, pos.y);
-                if self.is_circle_clear(try_x, radius) {
+                if can_move(pos, try_x) {
                     pos = try_x;
                     continue;
                 }
 
                 // Try sliding along Y axis only
                 let try_y = Vec2::new(pos.x, candidate.y);
-                if self.is_circle_clear(try_y, radius) {
+                if can_move(pos, try_y) {
                     pos = try_y;
                     continue;
                 }
@@ -203,10 +375,27 @@ impl CollisionMap {
     #[cfg(debug_assertions)]
     pub fn tile_size(&self) -> f32 { self.tile_size }
     
-    #[cfg(debug_assertions)]
     pub fn origin(&self) -> Vec2 { Vec2::new(self.origin_x, self.origin_y) }
 
-    pub fn get_neighbors(&self, pos: IVec2) -> Vec<IVec2> {
+    pub fn hazard_seed(&self) -> Option<u64> { self.hazard_seed }
+
+    pub fn set_hazard_seed(&mut self, seed: u64) { self.hazard_seed = Some(seed); }
+
+    /// Whether an agent of `clearance` radius can stand at the centre of a
+    /// cell. Anything up to half a tile fits on every walkable cell; bigger
+    /// agents also need the cells around it to be clear.
+    pub fn fits(&self, cell: IVec2, clearance: f32) -> bool {
+        if !self.is_walkable(cell.x, cell.y) {
+            return false;
+        }
+        clearance <= self.tile_size * 0.5
+            || self.is_circle_clear(self.grid_to_world(cell.x, cell.y), clearance)
+    }
+
+    /// Neighbours an agent of `clearance` radius can step to, so gaps
+    /// narrower than the agent count as walls. One-way edges only let it
+    /// through the right way.
+    pub fn get_neighbors(&self, pos: IVec2, clearance: f32) -> Vec<IVec2> {
         let mut neighbors = Vec::new();
         
         // Cardinal directions (always allowed if walkable)
@@ -216,7 +405,7 @@ impl CollisionMap {
         
         for dir in cardinals {
             let neighbor = pos + dir;
-            if self.is_walkable(neighbor.x, neighbor.y) {
+            if self.fits(neighbor, clearance) && self.can_step(pos, neighbor) {
                 neighbors.push(neighbor);
             }
         }
@@ -236,9 +425,10 @@ impl CollisionMap {
             let adj2_pos = pos + adj2;
             
             // Only allow diagonal if destination AND both adjacent cells are walkable
-            if self.is_walkable(diag_pos.x, diag_pos.y)
-                && self.is_walkable(adj1_pos.x, adj1_pos.y)
-                && self.is_walkable(adj2_pos.x, adj2_pos.y)
+            if self.fits(diag_pos, clearance)
+                && self.fits(adj1_pos, clearance)
+                && self.fits(adj2_pos, clearance)
+                && self.can_step(pos, diag_pos)
             {
                 neighbors.push(diag_pos);
             }
@@ -247,28 +437,30 @@ impl CollisionMap {
         neighbors
     }
     
-    /// Find path using A* algorithm
-    pub fn find_path(&self, start: Vec2, goal: Vec2) -> Option<Vec<Vec2>> {
-        use pathfinding::prelude::astar;
-        
+    /// Find path using A* algorithm, for an agent of `clearance` radius: it
+    /// only passes through gaps it fits in, and heads for the nearest cell
+    /// it fits on if the goal is too tight.
+    pub fn find_path(&self, start: Vec2, goal: Vec2, clearance: f32) -> Option<Vec<Vec2>> {
         let start_grid = self.world_to_grid(start);
         let goal_grid = self.world_to_grid(goal);
         
+        // Large agents pressed against a wall may not fit on their own cell;
+        // they can still leave it
         if !self.is_walkable(start_grid.x, start_grid.y) {
             return None;
         }
         
-        let actual_goal = if self.is_walkable(goal_grid.x, goal_grid.y) {
+        let actual_goal = if self.fits(goal_grid, clearance) {
             goal_grid
         } else {
-            self.find_nearest_walkable(goal_grid)?
+            self.find_nearest(goal_grid, |cell| self.fits(cell, clearance))?
         };
         
         let result = astar(
             &start_grid,
             |pos| {
                 let pos = *pos;
-                self.get_neighbors(pos).into_iter().map(move |n| {
+                self.get_neighbors(pos, clearance).into_iter().map(move |n| {
                     let cost = if (n.x - pos.x).abs() + (n.y - pos.y).abs() == 2 {
                         14u32 // Diagonal
                     } else {
@@ -290,16 +482,30 @@ impl CollisionMap {
         })
     }
     
-    /// Find nearest walkable cell
+    /// Find nearest walkable cell (by square rings, starting at `pos` itself).
+    /// Returns `None` only if the map has no walkable cell at all.
     pub fn find_nearest_walkable(&self, pos: IVec2) -> Option<IVec2> {
-        for radius in 1i32..10 {
+        self.find_nearest(pos, |cell| self.is_walkable(cell.x, cell.y))
+    }
+
+    /// Nearest cell to `pos` (by square rings) that passes `accept`.
+    fn find_nearest(&self, pos: IVec2, accept: impl Fn(IVec2) -> bool) -> Option<IVec2> {
+        // Ring radius that reaches the farthest map corner from `pos`
+        let max_radius = pos
+            .x
+            .abs()
+            .max((self.width - 1 - pos.x).abs())
+            .max(pos.y.abs())
+            .max((self.height - 1 - pos.y).abs());
+
+        for radius in 0..=max_radius {
             for dx in -radius..=radius {
-                for dy in -radius..=radius {
-                    if dx.abs() == radius || dy.abs() == radius {
-                        let check = IVec2::new(pos.x + dx, pos.y + dy);
-                        if self.is_walkable(check.x, check.y) {
-                            return Some(check);
-                        }
+                // Interior rows only need the two edge cells of the ring
+                let step = if dx.abs() == radius { 1 } else { (2 * radius).max(1) };
+                for dy in (-radius..=radius).step_by(step as usize) {
+                    let check = IVec2::new(pos.x + dx, pos.y + dy);
+                    if accept(check) {
+                        return Some(check);
                     }
                 }
             }
@@ -307,5 +513,337 @@ impl CollisionMap {
         None
     }
 
+    /// Cells an agent of `clearance` radius can walk to from `start`, by the
+    /// same steps pathfinding takes, as a row-major mask over the grid.
+    /// Nothing is reachable from a cell the agent doesn't fit on.
+    #[cfg(debug_assertions)]
+    pub fn reachable_from(&self, start: IVec2, clearance: f32) -> Vec<bool> {
+        let mut reached = vec![false; (self.width * self.height) as usize];
+        if !self.fits(start, clearance) {
+            return reached;
+        }
 
-}
\ No newline at end of file
+        let index = |cell: IVec2| (cell.y * self.width + cell.x) as usize;
+        reached[index(start)] = true;
+        let mut frontier = vec![start];
+        while let Some(cell) = frontier.pop() {
+            for neighbor in self.get_neighbors(cell, clearance) {
+                if !reached[index(neighbor)] {
+                    reached[index(neighbor)] = true;
+                    frontier.push(neighbor);
+                }
+            }
+        }
+        reached
+    }
+
+    /// Labels each walkable cell with the region it belongs to, as a
+    /// row-major list over the grid, and counts the cells of each region.
+    fn label_regions(&self) -> (Vec<Option<usize>>, Vec<usize>) {
+        let mut labels = vec![None; self.tiles.len()];
+        let mut sizes = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if labels[self.xy_to_idx(x, y)].is_some() || !self.is_walkable(x, y) {
+                    continue;
+                }
+                let label = sizes.len();
+                let mut size = 1;
+                labels[self.xy_to_idx(x, y)] = Some(label);
+                let mut frontier = vec![IVec2::new(x, y)];
+                while let Some(cell) = frontier.pop() {
+                    for neighbor in self.get_neighbors(cell, 0.0) {
+                        let index = self.xy_to_idx(neighbor.x, neighbor.y);
+                        if labels[index].is_none() {
+                            labels[index] = Some(label);
+                            size += 1;
+                            frontier.push(neighbor);
+                        }
+                    }
+                }
+                sizes.push(size);
+            }
+        }
+        (labels, sizes)
+    }
+
+    /// Turns blocking tiles into grass until every walkable cell can be
+    /// walked to from every other. Cuts run from the largest region along
+    /// the cheapest cardinal paths, through props before water. Returns the
+    /// carved cells so the caller can clear their sprites.
+    pub fn connect_regions(&mut self) -> Vec<IVec2> {
+        let (labels, sizes) = self.label_regions();
+        let Some(main) = (0..sizes.len()).max_by_key(|&label| sizes[label]) else {
+            return Vec::new();
+        };
+        if sizes.len() == 1 {
+            return Vec::new();
+        }
+
+        // Cheapest cut from the main region to every cell; walkable cells are
+        // free, so one cut can pass through other regions on its way
+        let mut cost = vec![u32::MAX; self.tiles.len()];
+        let mut parent = vec![None; self.tiles.len()];
+        let mut heap = BinaryHeap::new();
+        for (index, label) in labels.iter().enumerate() {
+            if *label == Some(main) {
+                cost[index] = 0;
+                heap.push(Reverse((0, index)));
+            }
+        }
+        while let Some(Reverse((so_far, index))) = heap.pop() {
+            if so_far > cost[index] {
+                continue;
+            }
+            let cell = IVec2::new(index as i32 % self.width, index as i32 / self.width);
+            for dir in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+                let next = cell + dir;
+                if !self.in_bounds(next.x, next.y) {
+                    continue;
+                }
+                let step = if self.is_walkable(next.x, next.y) {
+                    0
+                } else if self.get_tile(next.x, next.y) == Some(TileType::Water) {
+                    CARVE_COST_WATER
+                } else {
+                    CARVE_COST_PROP
+                };
+                let next_index = self.xy_to_idx(next.x, next.y);
+                if so_far + step < cost[next_index] {
+                    cost[next_index] = so_far + step;
+                    parent[next_index] = Some(index);
+                    heap.push(Reverse((so_far + step, next_index)));
+                }
+            }
+        }
+
+        // Closest cell of each other region, nearest regions first
+        let mut entries: Vec<Option<usize>> = vec![None; sizes.len()];
+        for (index, label) in labels.iter().enumerate() {
+            let Some(label) = *label else { continue };
+            if entries[label].is_none_or(|best| cost[index] < cost[best]) {
+                entries[label] = Some(index);
+            }
+        }
+        let mut entries: Vec<usize> = entries.into_iter().flatten().collect();
+        entries.sort_by_key(|&index| cost[index]);
+
+        let mut joined = vec![false; sizes.len()];
+        joined[main] = true;
+        let mut carved = Vec::new();
+        for entry in entries {
+            if labels[entry].is_some_and(|label| joined[label]) {
+                continue;
+            }
+            let mut next = Some(entry);
+            while let Some(index) = next {
+                let cell = IVec2::new(index as i32 % self.width, index as i32 / self.width);
+                match labels[index] {
+                    Some(label) => joined[label] = true,
+                    None if !self.is_walkable(cell.x, cell.y) => {
+                        self.set_tile(cell.x, cell.y, TileType::Grass);
+                        carved.push(cell);
+                    }
+                    None => {}
+                }
+                next = parent[index];
+            }
+        }
+        carved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const BLOCKERS: [TileType; 3] = [TileType::Water, TileType::Tree, TileType::Rock];
+
+    /// Maps up to 24x24 with an arbitrary mix of walkable and blocking tiles.
+    fn arb_map() -> impl Strategy<Value = CollisionMap> {
+        (1i32..24, 1i32..24, 8.0f32..64.0, -2000.0f32..2000.0, -2000.0f32..2000.0)
+            .prop_flat_map(|(width, height, tile_size, origin_x, origin_y)| {
+                let tiles = prop::collection::vec(0usize..6, (width * height) as usize);
+                (Just((width, height, tile_size, origin_x, origin_y)), tiles)
+            })
+            .prop_map(|((width, height, tile_size, origin_x, origin_y), tiles)| {
+                let mut map = CollisionMap::new(width, height, tile_size, origin_x, origin_y);
+                for (i, kind) in tiles.into_iter().enumerate() {
+                    // Half the values stay walkable
+                    if let Some(&blocker) = BLOCKERS.get(kind) {
+                        map.set_tile(i as i32 % width, i as i32 / width, blocker);
+                    }
+                }
+                map
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn grid_world_round_trip(
+            (width, height) in (1i32..512, 1i32..512),
+            tile_size in 1.0f32..128.0,
+            origin in (-10_000.0f32..10_000.0, -10_000.0f32..10_000.0),
+            cell in (0.0f64..1.0, 0.0f64..1.0),
+        ) {
+            let map = CollisionMap::new(width, height, tile_size, origin.0, origin.1);
+            let x = (cell.0 * width as f64) as i32;
+            let y = (cell.1 * height as f64) as i32;
+
+            let world = map.grid_to_world(x, y);
+            prop_assert_eq!(map.world_to_grid(world), IVec2::new(x, y));
+        }
+
+        #[test]
+        fn sweep_never_ends_blocked(
+            map in arb_map(),
+            start in (0.0f32..1.0, 0.0f32..1.0),
+            delta in (-200.0f32..200.0, -200.0f32..200.0),
+            radius in 0.0f32..16.0,
+        ) {
+            let size = Vec2::new(map.width as f32, map.height as f32) * map.tile_size;
+            let origin = Vec2::new(map.origin_x, map.origin_y);
+            let start = origin + Vec2::new(start.0, start.1) * size;
+            prop_assume!(map.is_circle_clear(start, radius));
+
+            let shape = ColliderShape::Circle { radius };
+            let end = map.sweep_shape(start, start + Vec2::new(delta.0, delta.1), &shape);
+            prop_assert!(map.is_circle_clear(end, radius), "sweep ended blocked at {:?}", end);
+        }
+
+        #[test]
+        fn box_sweep_never_ends_blocked(
+            map in arb_map(),
+            start in (0.0f32..1.0, 0.0f32..1.0),
+            delta in (-200.0f32..200.0, -200.0f32..200.0),
+            half_size in (1.0f32..24.0, 1.0f32..24.0),
+        ) {
+            let size = Vec2::new(map.width as f32, map.height as f32) * map.tile_size;
+            let origin = Vec2::new(map.origin_x, map.origin_y);
+            let start = origin + Vec2::new(start.0, start.1) * size;
+            let shape = ColliderShape::Aabb { half_size: Vec2::new(half_size.0, half_size.1) };
+            prop_assume!(map.is_shape_clear(start, &shape));
+
+            let end = map.sweep_shape(start, start + Vec2::new(delta.0, delta.1), &shape);
+            prop_assert!(map.is_shape_clear(end, &shape), "sweep ended blocked at {:?}", end);
+        }
+
+        #[test]
+        fn paths_only_cross_cells_the_agent_fits(
+            map in arb_map(),
+            start in (0.0f32..1.0, 0.0f32..1.0),
+            goal in (0.0f32..1.0, 0.0f32..1.0),
+            clearance in 0.0f32..96.0,
+        ) {
+            let size = Vec2::new(map.width as f32, map.height as f32) * map.tile_size;
+            let origin = Vec2::new(map.origin_x, map.origin_y);
+            let start = origin + Vec2::new(start.0, start.1) * size;
+            let goal = origin + Vec2::new(goal.0, goal.1) * size;
+
+            // The agent may start squeezed in; every step after that has to fit
+            if let Some(path) = map.find_path(start, goal, clearance) {
+                for waypoint in path.iter().skip(1) {
+                    prop_assert!(map.fits(map.world_to_grid(*waypoint), clearance));
+                }
+            }
+        }
+
+        #[test]
+        fn sweep_never_climbs_a_ledge(
+            map in arb_map(),
+            row in 0.0f32..1.0,
+            start in (0.0f32..1.0, 0.0f32..1.0),
+            delta in (-200.0f32..200.0, -200.0f32..200.0),
+            radius in 0.0f32..16.0,
+        ) {
+            let mut map = map;
+            // A ledge along the whole row: you can drop off it southward, never climb back
+            let ledge = (row * map.height as f32) as i32;
+            for x in 0..map.width {
+                map.set_one_way(x, ledge, Edges::SOUTH);
+            }
+            let size = Vec2::new(map.width as f32, map.height as f32) * map.tile_size;
+            let origin = Vec2::new(map.origin_x, map.origin_y);
+            let start = origin + Vec2::new(start.0, start.1) * size;
+            prop_assume!(map.world_to_grid(start).y < ledge);
+
+            let shape = ColliderShape::Circle { radius };
+            let end = map.sweep_shape(start, start + Vec2::new(delta.0, delta.1), &shape);
+            prop_assert!(map.world_to_grid(end).y < ledge, "climbed the ledge to {:?}", end);
+        }
+
+        #[test]
+        fn paths_respect_one_way_edges(
+            map in arb_map(),
+            ledges in prop::collection::vec((0i32..24, 0i32..24, 0usize..4), 0..32),
+            start in (0.0f32..1.0, 0.0f32..1.0),
+            goal in (0.0f32..1.0, 0.0f32..1.0),
+        ) {
+            let mut map = map;
+            let sides = [Edges::NORTH, Edges::EAST, Edges::SOUTH, Edges::WEST];
+            for (x, y, side) in ledges {
+                map.set_one_way(x, y, sides[side]);
+            }
+            let size = Vec2::new(map.width as f32, map.height as f32) * map.tile_size;
+            let origin = Vec2::new(map.origin_x, map.origin_y);
+            let start = origin + Vec2::new(start.0, start.1) * size;
+            let goal = origin + Vec2::new(goal.0, goal.1) * size;
+
+            if let Some(path) = map.find_path(start, goal, 0.0) {
+                for step in path.windows(2) {
+                    let (from, to) = (map.world_to_grid(step[0]), map.world_to_grid(step[1]));
+                    prop_assert!(map.can_step(from, to), "stepped {:?} -> {:?} the wrong way", from, to);
+                }
+            }
+        }
+
+        #[test]
+        fn nearest_walkable_is_walkable(
+            map in arb_map(),
+            pos in (-10i32..34, -10i32..34),
+        ) {
+            let any_walkable = map.tiles.iter().any(|t| t.is_walkable());
+            let found = map.find_nearest_walkable(IVec2::new(pos.0, pos.1));
+
+            prop_assert_eq!(found.is_some(), any_walkable);
+            if let Some(cell) = found {
+                prop_assert!(map.is_walkable(cell.x, cell.y));
+            }
+        }
+
+        #[test]
+        fn reachability_is_symmetric(
+            map in arb_map(),
+            a in (0.0f64..1.0, 0.0f64..1.0),
+            b in (0.0f64..1.0, 0.0f64..1.0),
+        ) {
+            let cell = |p: (f64, f64)| {
+                IVec2::new((p.0 * map.width as f64) as i32, (p.1 * map.height as f64) as i32)
+            };
+            let (a, b) = (cell(a), cell(b));
+            let index = |c: IVec2| (c.y * map.width + c.x) as usize;
+
+            // Without one-way edges every step can be walked back
+            let from_a = map.reachable_from(a, 0.0);
+            let from_b = map.reachable_from(b, 0.0);
+            prop_assert_eq!(from_a[index(b)], from_b[index(a)]);
+        }
+
+        #[test]
+        fn connected_maps_reach_every_walkable_cell(mut map in arb_map()) {
+            let carved = map.connect_regions();
+            for cell in &carved {
+                prop_assert!(map.is_walkable(cell.x, cell.y));
+            }
+
+            let Some(start) = map.find_nearest_walkable(IVec2::ZERO) else { return Ok(()) };
+            let reached = map.reachable_from(start, 0.0);
+            for y in 0..map.height {
+                for x in 0..map.width {
+                    prop_assert_eq!(reached[map.xy_to_idx(x, y)], map.is_walkable(x, y));
+                }
+            }
+        }
+    }
+}
@@ -1,45 +1,60 @@
 mod tile_type;
+mod edges;
+mod layer;
 mod map;
+mod shape;
 mod systems;
+mod trigger;
 
 #[cfg(debug_assertions)]
 mod debug;
 
 use bevy::prelude::*;
-use crate::state::GameState;
+use crate::schedule::GameSet;
 
 // Re-export commonly used types
 pub use tile_type::{TileType, TileMarker};
+pub use edges::Edges;
+pub use layer::Layer;
 pub use map::CollisionMap;
-pub use systems::CollisionMapBuilt;
+pub use shape::ColliderShape;
+pub use systems::{CollisionMapBuilder, CollisionMapBuilt};
+pub use trigger::{TriggerEntered, TriggerExited, TriggerZone};
 
 #[cfg(debug_assertions)]
-pub use debug::DebugCollisionEnabled;
+pub use debug::{DebugCollisionEnabled, DebugReachabilityEnabled};
 
 pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CollisionMapBuilt>()
-            .add_systems(
-                Update,
-                systems::build_collision_map
-                    .run_if(resource_equals(CollisionMapBuilt(false)))
-                    .run_if(in_state(GameState::Playing)),
-            );
+        app.register_type::<TileType>()
+            .register_type::<TileMarker>()
+            .register_type::<Layer>()
+            .register_type::<TriggerZone>()
+            .register_type::<CollisionMapBuilt>()
+            .init_resource::<CollisionMapBuilt>()
+            .add_observer(trigger::log_trigger_entered)
+            .add_observer(trigger::log_trigger_exited)
+            .add_systems(Update, trigger::update_trigger_zones.in_set(GameSet::PostPhysics));
 
         // Debug systems - only in debug builds
         #[cfg(debug_assertions)]
         {
             app.init_resource::<DebugCollisionEnabled>()
+                .init_resource::<DebugReachabilityEnabled>()
+                .init_resource::<debug::Reachability>()
                 .add_systems(
                     Update,
                     (
                         debug::toggle_debug_collision,
                         debug::debug_draw_collision,
                         debug::debug_player_position,
+                        debug::debug_draw_trigger_zones,
+                        debug::toggle_debug_reachability,
+                        debug::debug_draw_reachability,
                     )
-                        .run_if(in_state(GameState::Playing)),
+                        .in_set(GameSet::Render),
                 );
         }
     }
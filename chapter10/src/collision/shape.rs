@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Footprint of a collider, centred on its position.
+///
+/// Round shapes are a core (a point or a horizontal spine) grown by a
+/// radius; boxes are their own core.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ColliderShape {
+    Circle { radius: f32 },
+    /// Circles of `radius` swept `half_length` to either side, for long
+    /// creatures that would need a huge circle to cover their flanks
+    Capsule { radius: f32, half_length: f32 },
+    /// Axis-aligned box, for rectangular props
+    Aabb { half_size: Vec2 },
+}
+
+impl ColliderShape {
+    /// Half the width and height of the box around the shape.
+    pub fn half_extents(&self) -> Vec2 {
+        match *self {
+            ColliderShape::Circle { radius } => Vec2::splat(radius),
+            ColliderShape::Capsule { radius, half_length } => Vec2::new(half_length + radius, radius),
+            ColliderShape::Aabb { half_size } => half_size,
+        }
+    }
+
+    /// Radius of the smallest circle around the whole shape.
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            ColliderShape::Circle { radius } => radius,
+            ColliderShape::Capsule { radius, half_length } => radius + half_length,
+            ColliderShape::Aabb { half_size } => half_size.length(),
+        }
+    }
+
+    /// Thickness of the shape across its narrowest side. Contact ranges
+    /// scale with it.
+    pub fn inner_radius(&self) -> f32 {
+        match *self {
+            ColliderShape::Circle { radius } | ColliderShape::Capsule { radius, .. } => radius,
+            ColliderShape::Aabb { half_size } => half_size.min_element(),
+        }
+    }
+
+    /// How far the surface lies outside the core.
+    fn rounding(&self) -> f32 {
+        match *self {
+            ColliderShape::Circle { radius } | ColliderShape::Capsule { radius, .. } => radius,
+            ColliderShape::Aabb { .. } => 0.0,
+        }
+    }
+
+    /// Point of the core closest to `point`: the centre of a circle, a
+    /// point on a capsule's spine or a point in the box.
+    pub fn core_point(&self, center: Vec2, point: Vec2) -> Vec2 {
+        match *self {
+            ColliderShape::Circle { .. } => center,
+            ColliderShape::Capsule { half_length, .. } => {
+                let along = (point.x - center.x).clamp(-half_length, half_length);
+                center + Vec2::new(along, 0.0)
+            }
+            ColliderShape::Aabb { half_size } => point.clamp(center - half_size, center + half_size),
+        }
+    }
+
+    /// Whether the shape at `center` overlaps the rectangle `min..max`.
+    pub fn overlaps_rect(&self, center: Vec2, min: Vec2, max: Vec2) -> bool {
+        match *self {
+            ColliderShape::Aabb { half_size } => {
+                let (low, high) = (center - half_size, center + half_size);
+                low.x <= max.x && high.x >= min.x && low.y <= max.y && high.y >= min.y
+            }
+            // Nearest points on the spine and the rectangle, refined once
+            _ => {
+                let core = self.core_point(center, (min + max) / 2.0);
+                let core = self.core_point(center, core.clamp(min, max));
+                core.distance_squared(core.clamp(min, max)) <= self.rounding() * self.rounding()
+            }
+        }
+    }
+
+    /// Direction from shape `a` to shape `b` and the gap between their
+    /// surfaces, negative once they overlap. Exact for circles and capsules,
+    /// a close estimate when boxes are involved.
+    pub fn separation(a: &ColliderShape, a_center: Vec2, b: &ColliderShape, b_center: Vec2) -> (Vec2, f32) {
+        let pa = a.core_point(a_center, b_center);
+        let pb = b.core_point(b_center, pa);
+        let pa = a.core_point(a_center, pb);
+        let delta = pb - pa;
+        let distance = delta.length();
+        let direction = if distance > 0.01 {
+            delta / distance
+        } else {
+            // Cores overlap; push apart along the line between the centres
+            (b_center - a_center).normalize_or(Vec2::X)
+        };
+        (direction, distance - a.rounding() - b.rounding())
+    }
+}
@@ -1,101 +1,92 @@
 use bevy::prelude::*;
-use std::collections::{HashMap, hash_map::Entry};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::{HashMap, HashSet, hash_map::Entry};
 
-use super::{CollisionMap, TileMarker, TileType};
-use crate::config::map::{TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y};
+use super::{CollisionMap, Edges, TileType};
+use crate::config::map::TILE_SIZE;
+use crate::config::terrain::{LAVA_CHANCE, SPIKES_CHANCE, TOXIC_POOL_CHANCE};
 
 /// Resource to track if collision map has been built.
-#[derive(Resource, Default, PartialEq, Eq)]
+#[derive(Resource, Reflect, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
 pub struct CollisionMapBuilt(pub bool);
 
-pub fn build_collision_map(
-    mut commands: Commands,
-    mut built: ResMut<CollisionMapBuilt>,
-    tile_query: Query<(&TileMarker, &Transform)>,
-) {
-    // Need at least one tile to proceed
-    let mut tile_iter = tile_query.iter();
-    let Some((first_marker, first_transform)) = tile_iter.next() else {
-        return; // WFC hasn't generated tiles yet
-    };
-
-    // Calculate grid origin (centered map)
-    let grid_origin_x = -TILE_SIZE * TOTAL_GRID_X as f32 / 2.0;
-    let grid_origin_y = -TILE_SIZE * TOTAL_GRID_Y as f32 / 2.0;
-
-    // Track bounds and layer info
-    let (mut min_x, mut max_x) = (i32::MAX, i32::MIN);
-    let (mut min_y, mut max_y) = (i32::MAX, i32::MIN);
-    let mut layer_tracker: HashMap<(i32, i32), (TileType, f32)> = HashMap::new();
-    let mut tile_count: usize = 0;
-
-    // Process all tiles, keeping only the topmost at each position
-    let mut process_tile = |marker: &TileMarker, transform: &Transform| {
-        tile_count += 1;
-
-        let world_x = transform.translation.x;
-        let world_y = transform.translation.y;
-        let world_z = transform.translation.z;
-        
-        let grid_x = ((world_x - grid_origin_x) / TILE_SIZE).floor() as i32;
-        let grid_y = ((world_y - grid_origin_y) / TILE_SIZE).floor() as i32;
-
-        min_x = min_x.min(grid_x);
-        max_x = max_x.max(grid_x);
-        min_y = min_y.min(grid_y);
-        max_y = max_y.max(grid_y);
+/// Gathers the topmost tile type of each grid cell while a map is being
+/// built, from generated grid data, a level file or a save.
+#[derive(Default)]
+pub struct CollisionMapBuilder {
+    /// Tile type and draw depth of the highest tile in each cell so far
+    cells: HashMap<IVec2, (TileType, f32)>,
+    /// Cells with a bridge deck, kept apart from the ground they span
+    bridges: HashSet<IVec2>,
+    /// One-way edges of each cell that has any
+    one_way: HashMap<IVec2, Edges>,
+}
 
-        // Keep only the topmost layer (highest Z)
-        match layer_tracker.entry((grid_x, grid_y)) {
+impl CollisionMapBuilder {
+    /// Records a tile; the one drawn on top (highest depth) decides the cell.
+    /// Bridges go on their own layer and leave the ground below alone.
+    pub fn add(&mut self, cell: IVec2, depth: f32, tile_type: TileType) {
+        if tile_type == TileType::Bridge {
+            self.bridges.insert(cell);
+            self.cells.entry(cell).or_insert((TileType::Empty, f32::MIN));
+            return;
+        }
+        match self.cells.entry(cell) {
             Entry::Occupied(mut entry) => {
-                if world_z > entry.get().1 {
-                    *entry.get_mut() = (marker.tile_type, world_z);
+                if depth > entry.get().1 {
+                    *entry.get_mut() = (tile_type, depth);
                 }
             }
             Entry::Vacant(entry) => {
-                entry.insert((marker.tile_type, world_z));
+                entry.insert((tile_type, depth));
             }
         }
-    };
-
-    // Process first tile and remaining
-    process_tile(first_marker, first_transform);
-    for (marker, transform) in tile_iter {
-        process_tile(marker, transform);
     }
 
-    // Calculate actual dimensions
-    let actual_width = (max_x - min_x + 1) as i32;
-    let actual_height = (max_y - min_y + 1) as i32;
+    /// Makes `edges` of a cell one-way; see `CollisionMap::set_one_way`.
+    pub fn add_one_way(&mut self, cell: IVec2, edges: Edges) {
+        let entry = self.one_way.entry(cell).or_default();
+        *entry = entry.union(edges);
+    }
 
-    // Create the collision map
-    let mut map = CollisionMap::new(
-        actual_width,
-        actual_height,
-        TILE_SIZE,
-        grid_origin_x,
-        grid_origin_y,
-    );
+    /// Builds the map with cell (0, 0) at `origin`, then adds shores, and
+    /// hazards when given a seed to scatter them with. `None` without any
+    /// tiles.
+    pub fn build(self, origin: Vec2, hazard_seed: Option<u64>) -> Option<CollisionMap> {
+        let min = self.cells.keys().copied().reduce(IVec2::min)?;
+        let max = self.cells.keys().copied().reduce(IVec2::max)?;
+        let size = max - min + IVec2::ONE;
+
+        // Maps that don't cover cell (0, 0), like imported ones, start further in
+        let corner = origin + min.as_vec2() * TILE_SIZE;
+        let mut map = CollisionMap::new(size.x, size.y, TILE_SIZE, corner.x, corner.y);
+        for (cell, (tile_type, _depth)) in &self.cells {
+            let local = *cell - min;
+            map.set_tile(local.x, local.y, *tile_type);
+        }
+        for cell in &self.bridges {
+            let local = *cell - min;
+            map.set_bridge(local.x, local.y);
+        }
+        for (cell, edges) in &self.one_way {
+            let local = *cell - min;
+            map.set_one_way(local.x, local.y, *edges);
+        }
 
-    // Populate the map from layer tracker
-    for ((grid_x, grid_y), (tile_type, _z)) in layer_tracker.iter() {
-        // Convert world grid to local array coordinates
-        let local_x = grid_x - min_x;
-        let local_y = grid_y - min_y;
-        map.set_tile(local_x, local_y, *tile_type);
+        // Post-processing: Convert water edges to shore
+        convert_water_edges_to_shore(&mut map);
+        if let Some(seed) = hazard_seed {
+            place_hazards(&mut map, seed);
+        }
+        Some(map)
     }
-
-    // Post-processing: Convert water edges to shore
-    convert_water_edges_to_shore(&mut map);
-    // Insert as resource and mark built
-    commands.insert_resource(map);
-    built.0 = true;
 }
 
 fn convert_water_edges_to_shore(map: &mut CollisionMap) {
     let mut shores = Vec::new();
 
-    // Find water tiles that touch walkable tiles
+    // Find water tiles that touch walkable ground (water under a bridge stays water)
     for y in 0..map.height() {
         for x in 0..map.width() {
             if map.get_tile(x, y) != Some(TileType::Water) {
@@ -111,7 +102,7 @@ fn convert_water_edges_to_shore(map: &mut CollisionMap) {
             ];
 
             for (nx, ny) in neighbors {
-                if map.is_walkable(nx, ny) {
+                if map.is_ground_walkable(nx, ny) {
                     shores.push((x, y));
                     break;
                 }
@@ -122,6 +113,23 @@ fn convert_water_edges_to_shore(map: &mut CollisionMap) {
     for (x, y) in shores {
         map.set_tile(x, y, TileType::Shore);
     }
+}
 
+/// Scatters hazard tiles over the map. Seeded from the world seed so a
+/// reloaded save gets the same hazards.
+fn place_hazards(map: &mut CollisionMap, seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    map.set_hazard_seed(seed);
 
-}
\ No newline at end of file
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let hazard = match map.get_tile(x, y) {
+                Some(TileType::Dirt) if rng.gen_bool(LAVA_CHANCE) => TileType::Lava,
+                Some(TileType::Dirt) if rng.gen_bool(SPIKES_CHANCE) => TileType::Spikes,
+                Some(TileType::Shore) if rng.gen_bool(TOXIC_POOL_CHANCE) => TileType::ToxicPool,
+                _ => continue,
+            };
+            map.set_tile(x, y, hazard);
+        }
+    }
+}
@@ -2,9 +2,11 @@ use bevy::prelude::*;
 
 use serde::{Serialize, Deserialize};
 
+use crate::config::terrain::{LAVA_DPS, SPIKES_DPS, TOXIC_POOL_DPS};
+
 /// Tile types for collision detection.
 /// Each type has different walkability and collision behavior.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum TileType {
     // Walkable terrain
     #[default]
@@ -13,6 +15,11 @@ pub enum TileType {
     Grass,
     YellowGrass,
     Shore,  // Water edges (walkable)
+    Bridge, // Deck over water; a second walkable layer above the ground
+    // Walkable hazards that hurt on contact
+    Lava,
+    Spikes,
+    ToxicPool,
     // Non-walkable obstacles
     Water,
     Tree,
@@ -25,6 +32,20 @@ impl TileType {
         !matches!(self, TileType::Water | TileType::Tree | TileType::Rock)
     }
 
+    /// Damage per second dealt to anything standing on this tile, if it's a hazard.
+    pub fn hazard_damage(&self) -> Option<f32> {
+        match self {
+            TileType::Lava => Some(LAVA_DPS),
+            TileType::Spikes => Some(SPIKES_DPS),
+            TileType::ToxicPool => Some(TOXIC_POOL_DPS),
+            _ => None,
+        }
+    }
+
+    pub fn is_hazard(&self) -> bool {
+        self.hazard_damage().is_some()
+    }
+
     /// Get the collision adjustment for this tile type.
     /// Positive = push player away, negative = allow corner cutting.
     pub fn collision_adjustment(&self) -> f32 {
@@ -35,7 +56,8 @@ impl TileType {
     }
 }
 
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
 pub struct TileMarker {
     pub tile_type: TileType,
 }
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use super::ColliderShape;
+use crate::characters::collider::Collider;
+use crate::enemy::SpawningIn;
+use crate::logging::targets;
+
+/// Area that notices colliders walking in and out without blocking them:
+/// quest areas, cave entrances, boss arenas, ambushes.
+///
+/// Crossings are reported with [`TriggerEntered`] and [`TriggerExited`],
+/// triggered on the zone entity.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct TriggerZone {
+    pub shape: ColliderShape,
+    /// Colliders inside as of the last update
+    #[reflect(ignore)]
+    occupants: Vec<Entity>,
+}
+
+impl TriggerZone {
+    pub fn rect(size: Vec2) -> Self {
+        Self::new(ColliderShape::Aabb { half_size: size / 2.0 })
+    }
+
+    pub fn new(shape: ColliderShape) -> Self {
+        Self { shape, occupants: Vec::new() }
+    }
+
+    pub fn occupants(&self) -> &[Entity] {
+        &self.occupants
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.occupants.contains(&entity)
+    }
+}
+
+/// A collider started overlapping a trigger zone.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct TriggerEntered {
+    /// The zone
+    pub entity: Entity,
+    /// The collider's owner
+    pub other: Entity,
+}
+
+/// A collider stopped overlapping a trigger zone, or was despawned inside it.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct TriggerExited {
+    /// The zone
+    pub entity: Entity,
+    /// The collider's owner, possibly despawned already
+    pub other: Entity,
+}
+
+/// Compares what overlaps each zone with last frame and reports the difference.
+pub fn update_trigger_zones(
+    mut commands: Commands,
+    mut zones: Query<(Entity, &Transform, &mut TriggerZone)>,
+    colliders: Query<(Entity, &Transform, &Collider), Without<SpawningIn>>,
+) {
+    for (zone, zone_transform, mut trigger) in &mut zones {
+        let center = zone_transform.translation.truncate();
+        let inside: Vec<Entity> = colliders
+            .iter()
+            .filter(|(_, transform, collider)| {
+                let position = collider.world_position(transform);
+                ColliderShape::separation(&trigger.shape, center, &collider.shape, position).1 <= 0.0
+            })
+            .map(|(entity, ..)| entity)
+            .collect();
+
+        for other in inside.iter().filter(|entity| !trigger.contains(**entity)) {
+            commands.trigger(TriggerEntered { entity: zone, other: *other });
+        }
+        for other in trigger.occupants.iter().filter(|entity| !inside.contains(entity)) {
+            commands.trigger(TriggerExited { entity: zone, other: *other });
+        }
+        trigger.occupants = inside;
+    }
+}
+
+pub fn log_trigger_entered(entered: On<TriggerEntered>) {
+    debug!(target: targets::COLLISION, "{} entered trigger zone {}", entered.other, entered.entity);
+}
+
+pub fn log_trigger_exited(exited: On<TriggerExited>) {
+    debug!(target: targets::COLLISION, "{} left trigger zone {}", exited.other, exited.entity);
+}
@@ -0,0 +1,248 @@
+// src/combat/ability.rs
+use bevy::prelude::*;
+
+use super::events::Heal;
+use super::faction::Faction;
+use super::invulnerable::Invulnerable;
+use super::power_registry::{PowerId, PowerRegistry};
+use super::projectile::{fire_power, ProjectileOwner};
+use crate::characters::config::{Ability, CharacterEntry};
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::characters::physics::Velocity;
+use crate::config::combat::{FAMILIAR_FIRE_INTERVAL, FAMILIAR_ORBIT_RADIUS, FAMILIAR_RANGE};
+use crate::enemy::Enemy;
+use crate::input_map::PlayerActions;
+use crate::lighting::LightSource;
+use crate::logging::targets;
+use crate::rendering::YSorted;
+use crate::state::GameplayEntity;
+
+const AURA_Z_OFFSET: f32 = -0.5;
+const FAMILIAR_SIZE: f32 = 8.0;
+/// Radians per second
+const FAMILIAR_ORBIT_SPEED: f32 = 2.5;
+
+/// Seconds until the player's signature ability is ready. Kept across
+/// character switches so swapping can't reset it.
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct AbilityCooldown(pub f32);
+
+/// Overrides movement with a fixed velocity until it runs out.
+#[derive(Component, Debug)]
+pub struct Dash {
+    velocity: Vec2,
+    remaining: f32,
+}
+
+/// Child of whoever cast it, so it follows them.
+#[derive(Component, Debug)]
+pub struct HealAura {
+    radius: f32,
+    heal_per_second: f32,
+    remaining: f32,
+    /// Counts down to the next heal pulse
+    pulse: f32,
+}
+
+#[derive(Component, Debug)]
+pub struct Familiar {
+    owner: Entity,
+    power: PowerId,
+    angle: f32,
+    remaining: f32,
+    fire_timer: f32,
+}
+
+/// Q uses the current character's signature ability when it's off cooldown.
+pub fn use_signature_ability(
+    mut commands: Commands,
+    actions: Res<PlayerActions>,
+    time: Res<Time>,
+    mut players: Query<
+        (Entity, &CharacterEntry, &Velocity, &Facing, &mut AbilityCooldown),
+        With<Player>,
+    >,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Ok((player, character, velocity, facing, mut cooldown)) = players.single_mut() else {
+        return;
+    };
+    cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+
+    if !actions.ability || cooldown.0 > 0.0 {
+        return;
+    }
+    let Some(signature) = &character.ability else {
+        return;
+    };
+    cooldown.0 = signature.cooldown;
+
+    match &signature.ability {
+        Ability::Dash { speed, duration } => {
+            let direction = if velocity.is_moving() {
+                velocity.normalize()
+            } else {
+                facing.direction()
+            };
+            commands.entity(player).insert((
+                Dash {
+                    velocity: direction * *speed,
+                    remaining: *duration,
+                },
+                Invulnerable::from_seconds(*duration),
+            ));
+        }
+        Ability::HealAura {
+            radius,
+            heal_per_second,
+            duration,
+        } => {
+            commands.spawn((
+                HealAura {
+                    radius: *radius,
+                    heal_per_second: *heal_per_second,
+                    remaining: *duration,
+                    pulse: 0.0,
+                },
+                Mesh2d(meshes.add(Annulus::new(radius - 3.0, *radius))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgba(0.3, 1.0, 0.4, 0.5)))),
+                Transform::from_xyz(0.0, 0.0, AURA_Z_OFFSET),
+                ChildOf(player),
+            ));
+        }
+        Ability::Summon {
+            power,
+            count,
+            lifetime,
+        } => {
+            for i in 0..*count {
+                let angle = i as f32 * std::f32::consts::TAU / *count as f32;
+                commands.spawn((
+                    Familiar {
+                        owner: player,
+                        power: power.clone(),
+                        angle,
+                        remaining: *lifetime,
+                        // Staggered so they don't all fire on the same frame
+                        fire_timer: FAMILIAR_FIRE_INTERVAL * i as f32 / *count as f32,
+                    },
+                    Sprite::from_color(Color::srgb(0.9, 0.8, 1.0), Vec2::splat(FAMILIAR_SIZE)),
+                    LightSource::new(48.0),
+                    // Hovers at the owner's body height, like a spell
+                    YSorted::EFFECT,
+                    Transform::default(),
+                    GameplayEntity,
+                ));
+            }
+        }
+    }
+    info!(target: targets::COMBAT, "{} used {:?}", character.name, signature.ability);
+}
+
+/// Runs after input and AI have set this frame's velocity.
+pub fn apply_dashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut dashers: Query<(Entity, &mut Dash, &mut Velocity)>,
+) {
+    for (entity, mut dash, mut velocity) in &mut dashers {
+        velocity.0 = dash.velocity;
+        dash.remaining -= time.delta_secs();
+        if dash.remaining <= 0.0 {
+            commands.entity(entity).remove::<Dash>();
+        }
+    }
+}
+
+/// Heals every ally inside an aura once a second, starting the moment it's cast.
+pub fn pulse_heal_auras(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut auras: Query<(Entity, &mut HealAura, &GlobalTransform)>,
+    allies: Query<(Entity, &Faction, &GlobalTransform)>,
+) {
+    let dt = time.delta_secs();
+    for (aura_entity, mut aura, aura_transform) in &mut auras {
+        aura.remaining -= dt;
+        if aura.remaining <= 0.0 {
+            commands.entity(aura_entity).despawn();
+            continue;
+        }
+        aura.pulse -= dt;
+        if aura.pulse > 0.0 {
+            continue;
+        }
+        aura.pulse += 1.0;
+
+        let center = aura_transform.translation().truncate();
+        for (ally, faction, transform) in &allies {
+            if *faction != Faction::Player
+                || transform.translation().truncate().distance(center) > aura.radius
+            {
+                continue;
+            }
+            commands.trigger(Heal {
+                target: ally,
+                amount: aura.heal_per_second,
+            });
+        }
+    }
+}
+
+/// Circles familiars around their owner and has them shoot the nearest
+/// enemy in range. They vanish when their time is up or the owner is gone.
+pub fn update_familiars(
+    mut commands: Commands,
+    time: Res<Time>,
+    powers: Res<PowerRegistry>,
+    mut familiars: Query<(Entity, &mut Familiar, &mut Transform)>,
+    owners: Query<&GlobalTransform, Without<Familiar>>,
+    enemies: Query<&GlobalTransform, (With<Enemy>, Without<Familiar>)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut familiar, mut transform) in &mut familiars {
+        familiar.remaining -= dt;
+        let Ok(owner) = owners.get(familiar.owner) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if familiar.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        familiar.angle += FAMILIAR_ORBIT_SPEED * dt;
+        let position =
+            owner.translation().truncate() + Vec2::from_angle(familiar.angle) * FAMILIAR_ORBIT_RADIUS;
+        // Depth comes from YSorted, so it passes in front of and behind its owner
+        transform.translation = position.extend(transform.translation.z);
+
+        familiar.fire_timer -= dt;
+        if familiar.fire_timer > 0.0 {
+            continue;
+        }
+        let nearest = enemies
+            .iter()
+            .map(|enemy| enemy.translation().truncate())
+            .filter(|enemy| enemy.distance(position) <= FAMILIAR_RANGE)
+            .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+        let Some(target) = nearest else {
+            continue;
+        };
+
+        familiar.fire_timer = FAMILIAR_FIRE_INTERVAL;
+        let direction = (target - position).normalize_or_zero().extend(0.0);
+        fire_power(
+            &mut commands,
+            &powers,
+            &familiar.power,
+            transform.translation,
+            direction,
+            ProjectileOwner::Player,
+            entity,
+        );
+    }
+}
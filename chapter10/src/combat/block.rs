@@ -0,0 +1,149 @@
+// src/combat/block.rs
+use bevy::prelude::*;
+
+use crate::characters::input::Player;
+use crate::characters::state::CharacterState;
+use crate::config::combat::{
+    BLOCK_STAMINA_DRAIN, PARRY_WINDOW_SECS, STAMINA_MAX, STAMINA_REGEN, STAMINA_REGEN_DELAY,
+};
+use crate::input_map::PlayerActions;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
+
+/// Spent by blocking; refills after a short rest.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    /// Seconds left before regeneration starts again
+    pub regen_delay: f32,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self {
+            current: STAMINA_MAX,
+            max: STAMINA_MAX,
+            regen_delay: 0.0,
+        }
+    }
+}
+
+impl Stamina {
+    pub fn spend(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+        self.regen_delay = STAMINA_REGEN_DELAY;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.current <= 0.0
+    }
+}
+
+/// A raised shield cuts incoming damage; raised just before an impact, it parries.
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct Shield {
+    pub raised: bool,
+    /// `Time::elapsed_secs` when the shield last went up
+    pub raised_at: f32,
+}
+
+impl Shield {
+    /// Whether an impact at `now` lands inside the parry window.
+    pub fn can_parry(&self, now: f32) -> bool {
+        self.raised && now - self.raised_at <= PARRY_WINDOW_SECS
+    }
+}
+
+/// Holding (or toggling) Alt raises the shield while there's stamina for it.
+/// Runs before movement input, which turns a raised shield into
+/// `CharacterState::Blocking`.
+pub fn handle_block_input(
+    mut commands: Commands,
+    mut actions: ResMut<PlayerActions>,
+    time: Res<Time>,
+    mut players: Query<(&mut Shield, &Stamina, &CharacterState, &GlobalTransform), With<Player>>,
+) {
+    let Ok((mut shield, stamina, state, transform)) = players.single_mut() else {
+        return;
+    };
+
+    // A toggled block drops with the shield rather than springing back up
+    // once stamina refills
+    if actions.blocking && stamina.is_empty() {
+        actions.release_block();
+    }
+
+    let wants_block = actions.blocking && !stamina.is_empty() && *state != CharacterState::Jumping;
+
+    if wants_block && !shield.raised {
+        shield.raised = true;
+        shield.raised_at = time.elapsed_secs();
+        spawn_shield_burst(&mut commands, transform.translation(), ShieldFeedback::Raise);
+    } else if !wants_block && shield.raised {
+        shield.raised = false;
+    }
+}
+
+/// Drains stamina while the shield is up and refills it once lowered.
+pub fn update_stamina(time: Res<Time>, mut query: Query<(&Shield, &mut Stamina)>) {
+    let dt = time.delta_secs();
+    for (shield, mut stamina) in &mut query {
+        if shield.raised {
+            stamina.spend(BLOCK_STAMINA_DRAIN * dt);
+        } else if stamina.regen_delay > 0.0 {
+            stamina.regen_delay -= dt;
+        } else if stamina.current < stamina.max {
+            stamina.current = (stamina.current + STAMINA_REGEN * dt).min(stamina.max);
+        }
+    }
+}
+
+/// Tints the sprite while the shield is up, on top of the held guard frame.
+pub fn update_shield_tint(mut query: Query<(&Shield, &mut Sprite), Changed<Shield>>) {
+    for (shield, mut sprite) in &mut query {
+        sprite.color = if shield.raised {
+            Color::srgb(0.75, 0.85, 1.2)
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShieldFeedback {
+    Raise,
+    Block,
+    Parry,
+}
+
+/// One-shot spark burst for raising, blocking with and parrying with the shield.
+pub fn spawn_shield_burst(commands: &mut Commands, position: Vec3, feedback: ShieldFeedback) {
+    let (color, count, speed) = match feedback {
+        ShieldFeedback::Raise => (Color::srgb(0.6, 0.8, 2.0), 6, 60.0),
+        ShieldFeedback::Block => (Color::srgb(1.5, 1.5, 1.8), 10, 120.0),
+        ShieldFeedback::Parry => (Color::srgb(3.0, 2.5, 0.8), 24, 220.0),
+    };
+    let config = ParticleConfig {
+        lifetime: 0.35,
+        lifetime_variance: 0.1,
+        speed,
+        speed_variance: speed * 0.3,
+        direction: Vec3::Y,
+        direction_variance: std::f32::consts::PI,
+        scale: 0.8,
+        scale_variance: 0.3,
+        color,
+        emission_shape: EmissionShape::Circle { radius: 12.0 },
+        ..default()
+    };
+
+    commands.spawn((
+        ParticleEmitter::new(0.016, count, config).one_shot(),
+        Transform::from_translation(position),
+        GlobalTransform::from(Transform::from_translation(position)),
+        GameplayEntity,
+    ));
+}
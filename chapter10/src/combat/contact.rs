@@ -0,0 +1,121 @@
+// src/combat/contact.rs
+use bevy::prelude::*;
+
+use super::faction::{Faction, FactionHostility};
+use super::health::Health;
+use super::invulnerable::Invulnerable;
+use crate::characters::collider::Collider;
+use crate::characters::physics::Velocity;
+use crate::collision::Layer;
+use crate::config::combat::{
+    CONTACT_RANGE_MARGIN, HIT_FLASH_SECS, INVULNERABLE_SECS, KNOCKBACK_SECS, KNOCKBACK_SPEED,
+};
+use crate::enemy::{EnemyLevel, Flying, SpawningIn};
+use crate::rendering::FlashEffect;
+
+/// Hurts hostile entities whose collider touches this one, at most once per `cooldown`.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct ContactDamage {
+    pub damage: f32,
+    pub cooldown: Timer,
+}
+
+impl ContactDamage {
+    pub fn new(damage: f32, interval_secs: f32) -> Self {
+        let mut cooldown = Timer::from_seconds(interval_secs, TimerMode::Once);
+        // Ready to bite on first contact
+        cooldown.tick(cooldown.duration());
+        Self { damage, cooldown }
+    }
+}
+
+/// Shoves an entity along `velocity` for a moment, overriding its own movement.
+#[derive(Component, Debug, Clone)]
+pub struct Knockback {
+    pub velocity: Vec2,
+    pub remaining: f32,
+}
+
+/// Layer and whether the entity flies.
+type Height = (&'static Layer, Has<Flying>);
+
+/// Walkers only touch on the same layer; flyers reach both.
+fn same_height((layer, flying): (&Layer, bool), (other_layer, other_flying): (&Layer, bool)) -> bool {
+    flying || other_flying || layer == other_layer
+}
+
+/// Deals contact damage to hostile entities touching an attacker, knocking
+/// them back and granting a short invulnerability window. Invulnerable
+/// entities are skipped, and so are walkers on another layer (a bridge and
+/// the ground below it); flyers reach both.
+pub fn apply_contact_damage(
+    mut commands: Commands,
+    time: Res<Time>,
+    hostility: Res<FactionHostility>,
+    mut attackers: Query<
+        (Entity, &Transform, &Collider, &Faction, &mut ContactDamage, Option<&EnemyLevel>, Height),
+        Without<SpawningIn>,
+    >,
+    mut victims: Query<
+        (Entity, &Transform, &Collider, &Faction, &mut Health, Height),
+        (Without<Invulnerable>, Without<SpawningIn>),
+    >,
+) {
+    // Invulnerable is inserted through commands, so track this frame's hits by hand
+    let mut hit_this_frame: Vec<Entity> = Vec::new();
+
+    for (attacker, transform, collider, faction, mut contact, level, height) in &mut attackers {
+        contact.cooldown.tick(time.delta());
+        if !contact.cooldown.is_finished() {
+            continue;
+        }
+
+        for (victim, victim_transform, victim_collider, victim_faction, mut health, victim_height) in &mut victims {
+            if victim == attacker
+                || hit_this_frame.contains(&victim)
+                || !health.is_alive()
+                || !hostility.is_hostile(*faction, *victim_faction)
+                || !same_height(height, victim_height)
+            {
+                continue;
+            }
+            // Gap between the shapes, so big enemies hit from their flanks
+            let (away, gap) = collider.separation(transform, victim_collider, victim_transform);
+            if gap > collider.contact_gap(victim_collider, CONTACT_RANGE_MARGIN - 1.0) {
+                continue;
+            }
+
+            let damage = contact.damage * level.map_or(1.0, EnemyLevel::damage_multiplier);
+            health.take_damage(&mut commands, victim, damage);
+            commands.entity(victim).try_insert((
+                Knockback {
+                    velocity: away * KNOCKBACK_SPEED,
+                    remaining: KNOCKBACK_SECS,
+                },
+                Invulnerable::from_seconds(INVULNERABLE_SECS),
+                FlashEffect::from_seconds(HIT_FLASH_SECS),
+            ));
+            hit_this_frame.push(victim);
+            contact.cooldown.reset();
+            break;
+        }
+    }
+}
+
+/// Replaces movement with the knockback shove. Runs before collision checks
+/// so walls still stop it.
+pub fn apply_knockback(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Knockback, &mut Velocity)>,
+) {
+    for (entity, mut knockback, mut velocity) in &mut query {
+        knockback.remaining -= time.delta_secs();
+        if knockback.remaining <= 0.0 {
+            commands.entity(entity).remove::<Knockback>();
+            continue;
+        }
+        velocity.0 = knockback.velocity;
+    }
+}
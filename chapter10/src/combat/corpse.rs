@@ -0,0 +1,79 @@
+// src/combat/corpse.rs
+use bevy::prelude::*;
+
+use crate::config::combat::CORPSE_SECS;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
+
+/// Corpses start out this dark and fade to nothing from there
+const CORPSE_TINT: f32 = 0.45;
+/// How far the corpse sinks into the ground as it fades
+const CORPSE_SINK: f32 = 12.0;
+
+/// What's left of a dead enemy: a copy of its last sprite frame with no
+/// collider, AI or health, fading out until it despawns.
+#[derive(Component, Debug, Clone)]
+pub struct Corpse {
+    pub timer: Timer,
+    start_y: f32,
+}
+
+/// Replaces a dying enemy with an inert corpse and a burst of particles in
+/// the colour of its power.
+pub fn spawn_death_effects(
+    commands: &mut Commands,
+    sprite: Option<&Sprite>,
+    transform: &Transform,
+    burst_color: Color,
+) {
+    if let Some(sprite) = sprite {
+        let mut corpse_sprite = sprite.clone();
+        corpse_sprite.color = Color::srgb(CORPSE_TINT, CORPSE_TINT, CORPSE_TINT);
+        commands.spawn((
+            Corpse {
+                timer: Timer::from_seconds(CORPSE_SECS, TimerMode::Once),
+                start_y: transform.translation.y,
+            },
+            corpse_sprite,
+            *transform,
+            GameplayEntity,
+        ));
+    }
+
+    let config = ParticleConfig {
+        lifetime: 0.7,
+        lifetime_variance: 0.2,
+        speed: 160.0,
+        speed_variance: 60.0,
+        direction: Vec3::Y,
+        direction_variance: std::f32::consts::PI,
+        scale: 1.2,
+        scale_variance: 0.4,
+        color: burst_color,
+        emission_shape: EmissionShape::Circle { radius: 12.0 },
+        ..default()
+    };
+    commands.spawn((
+        ParticleEmitter::new(0.016, 40, config).one_shot(),
+        Transform::from_translation(transform.translation),
+        GlobalTransform::from(*transform),
+        GameplayEntity,
+    ));
+}
+
+/// Sinks and fades corpses, despawning them when they're gone.
+pub fn fade_corpses(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corpses: Query<(Entity, &mut Corpse, &mut Sprite, &mut Transform)>,
+) {
+    for (entity, mut corpse, mut sprite, mut transform) in &mut corpses {
+        corpse.timer.tick(time.delta());
+        let progress = corpse.timer.fraction();
+        sprite.color.set_alpha(1.0 - progress);
+        transform.translation.y = corpse.start_y - CORPSE_SINK * progress;
+        if corpse.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,5 +1,6 @@
 // src/combat/events.rs
-use super::power_type::PowerType;
+use super::power_registry::PowerId;
+use crate::buffs::BuffSpec;
 use bevy::prelude::*;
 
 /// Event triggered when a projectile hits a target entity.
@@ -7,7 +8,24 @@ use bevy::prelude::*;
 pub struct ProjectileHit {
     pub target: Entity,
     pub damage: f32,
-    pub power_type: PowerType,
+    pub power_type: PowerId,
+    /// Debuff the power leaves on whoever it hits
+    pub inflicts: Option<BuffSpec>,
+}
+
+/// Event triggered to restore health: regen, pickups, abilities.
+/// Goes through the same observer pipeline as damage.
+#[derive(Event)]
+pub struct Heal {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+/// Written wherever a projectile ends: on a hit, against a wall or when it expires.
+#[derive(Message, Debug, Clone)]
+pub struct ProjectileImpact {
+    pub position: Vec3,
+    pub power_type: PowerId,
 }
 
 /// Event triggered when an entity's health reaches zero.
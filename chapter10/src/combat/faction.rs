@@ -0,0 +1,55 @@
+// src/combat/faction.rs
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+/// Which side an entity fights for. Added automatically to players and enemies.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum Faction {
+    Player,
+    Monsters,
+}
+
+/// Which factions' projectiles can hurt which. Symmetric: if A is hostile to B,
+/// B is hostile to A. Same-faction hits are governed by `CombatSettings` instead.
+#[derive(Resource, Debug, Clone)]
+pub struct FactionHostility {
+    hostile: HashSet<(Faction, Faction)>,
+}
+
+impl Default for FactionHostility {
+    fn default() -> Self {
+        let mut hostility = Self {
+            hostile: HashSet::new(),
+        };
+        hostility.set_hostile(Faction::Player, Faction::Monsters, true);
+        hostility
+    }
+}
+
+impl FactionHostility {
+    pub fn is_hostile(&self, a: Faction, b: Faction) -> bool {
+        self.hostile.contains(&(a, b))
+    }
+
+    pub fn set_hostile(&mut self, a: Faction, b: Faction, hostile: bool) {
+        if hostile {
+            self.hostile.insert((a, b));
+            self.hostile.insert((b, a));
+        } else {
+            self.hostile.remove(&(a, b));
+            self.hostile.remove(&(b, a));
+        }
+    }
+}
+
+/// Global switches for the riskier projectile rules. Both only apply to powers
+/// that opt in through their `ProjectileBehaviors`.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct CombatSettings {
+    /// Projectiles with `friendly_fire` can hit their own faction.
+    pub friendly_fire: bool,
+    /// Blasts can hurt whoever fired them.
+    pub self_damage: bool,
+}
@@ -0,0 +1,52 @@
+// src/combat/floating_text.rs
+use bevy::prelude::*;
+
+use crate::state::GameplayEntity;
+
+const FLOAT_SPEED: f32 = 40.0;
+const FLOAT_LIFETIME: f32 = 0.8;
+const FLOAT_Y_OFFSET: f32 = 30.0;
+/// Above the darkness overlay so numbers stay readable at night
+const FLOAT_Z: f32 = 950.0;
+
+/// A number that drifts upward and fades out.
+#[derive(Component)]
+pub struct FloatingText {
+    remaining: f32,
+}
+
+pub const DAMAGE_COLOR: Color = Color::srgb(1.0, 0.9, 0.9);
+pub const HEAL_COLOR: Color = Color::srgb(0.3, 1.0, 0.4);
+
+pub fn spawn_floating_number(commands: &mut Commands, position: Vec3, amount: f32, color: Color) {
+    commands.spawn((
+        FloatingText {
+            remaining: FLOAT_LIFETIME,
+        },
+        Text2d::new(format!("{}", amount.round() as i32)),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(color),
+        Transform::from_translation(Vec3::new(position.x, position.y + FLOAT_Y_OFFSET, FLOAT_Z)),
+        GameplayEntity,
+    ));
+}
+
+pub fn update_floating_text(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut FloatingText, &mut Transform, &mut TextColor)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut text, mut transform, mut color) in &mut query {
+        text.remaining -= dt;
+        if text.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation.y += FLOAT_SPEED * dt;
+        color.0.set_alpha(text.remaining / FLOAT_LIFETIME);
+    }
+}
@@ -0,0 +1,146 @@
+// src/combat/hazards.rs
+use bevy::prelude::*;
+
+use super::health::Health;
+use super::terrain::GroundHazard;
+use crate::characters::collider::Collider;
+use crate::collision::{ColliderShape, CollisionMap, Layer, TileType};
+use crate::enemy::Flying;
+use crate::lighting::LightSource;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
+
+/// Hazard tiles that don't hurt this entity (fire demons wading through lava, ...).
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct HazardImmunity(pub Vec<TileType>);
+
+impl HazardImmunity {
+    pub fn is_immune(&self, tile: TileType) -> bool {
+        self.0.contains(&tile)
+    }
+}
+
+/// Height of the hazard overlays: above the ground layer, below props and characters.
+const HAZARD_OVERLAY_Z: f32 = 1.5;
+
+/// Ticks damage on every `Health` owner whose collider overlaps a hazard tile
+/// or stands in burning ground or a toxic pool. Flyers hover over them, and
+/// anyone on a bridge walks above them. Overlapping several hazards at once
+/// only applies the worst one.
+pub fn hazard_contact_damage(
+    mut commands: Commands,
+    time: Res<Time>,
+    collision_map: Option<Res<CollisionMap>>,
+    ground_hazards: Query<(&GroundHazard, &Transform)>,
+    mut victims: Query<
+        (Entity, &Transform, &Collider, &Layer, &mut Health, Option<&HazardImmunity>),
+        Without<Flying>,
+    >,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let dt = time.delta_secs();
+
+    for (entity, transform, collider, layer, mut health, immunity) in &mut victims {
+        if !health.is_alive() || *layer == Layer::Bridge {
+            continue;
+        }
+        let position = collider.world_position(transform);
+        let on_tiles = overlapped_tiles(&map, position, &collider.shape)
+            .filter_map(|tile| Some((tile, tile.hazard_damage()?)));
+        let in_ground_hazards = ground_hazards
+            .iter()
+            .filter(|(hazard, hazard_transform)| {
+                hazard_transform.translation.truncate().distance(position) <= hazard.radius
+            })
+            .map(|(hazard, _)| (hazard.kind.tile(), hazard.damage_per_second));
+
+        let damage_per_second = on_tiles
+            .chain(in_ground_hazards)
+            .filter(|(tile, _)| !immunity.is_some_and(|immunity| immunity.is_immune(*tile)))
+            .map(|(_, damage)| damage)
+            .fold(0.0, f32::max);
+
+        if damage_per_second > 0.0 {
+            health.take_damage(&mut commands, entity, damage_per_second * dt);
+        }
+    }
+}
+
+/// Tile types under a collider shape, including tiles it only clips.
+fn overlapped_tiles<'a>(
+    map: &'a CollisionMap,
+    center: Vec2,
+    shape: &'a ColliderShape,
+) -> impl Iterator<Item = TileType> + 'a {
+    let extents = shape.half_extents();
+    let min = map.world_to_grid(center - extents);
+    let max = map.world_to_grid(center + extents);
+    let half = Vec2::splat(map.tile_size() / 2.0);
+
+    (min.y..=max.y)
+        .flat_map(move |y| (min.x..=max.x).map(move |x| (x, y)))
+        .filter(move |&(x, y)| {
+            let tile_center = map.grid_to_world(x, y);
+            shape.overlaps_rect(center, tile_center - half, tile_center + half)
+        })
+        .filter_map(|(x, y)| map.get_tile(x, y))
+}
+
+/// Gives each hazard tile a tinted overlay and a particle emitter once the
+/// collision map (which decides where hazards are) has been built.
+pub fn spawn_hazard_tile_visuals(mut commands: Commands, map: Res<CollisionMap>) {
+    let size = Vec2::splat(map.tile_size());
+
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let Some(tile) = map.get_tile(x, y).filter(TileType::is_hazard) else {
+                continue;
+            };
+            let (overlay, particles) = hazard_visuals(tile);
+            let position = map.grid_to_world(x, y).extend(HAZARD_OVERLAY_Z);
+
+            let mut entity = commands.spawn((
+                Sprite::from_color(overlay, size),
+                Transform::from_translation(position),
+                GameplayEntity,
+            ));
+            if tile == TileType::Lava {
+                entity.insert(
+                    LightSource::new(map.tile_size() * 2.0)
+                        .with_color(Color::srgb(1.0, 0.4, 0.1))
+                        .with_flicker(0.2),
+                );
+            }
+            entity.with_child((
+                ParticleEmitter::new(0.25, 1, particles),
+                Transform::IDENTITY,
+                GlobalTransform::from(Transform::from_translation(position)),
+            ));
+        }
+    }
+}
+
+fn hazard_visuals(tile: TileType) -> (Color, ParticleConfig) {
+    let (overlay, color, speed) = match tile {
+        TileType::Lava => (Color::srgba(0.9, 0.25, 0.05, 0.8), Color::srgb(3.0, 0.8, 0.1), 30.0),
+        TileType::Spikes => (Color::srgba(0.35, 0.35, 0.4, 0.8), Color::srgb(0.8, 0.8, 0.9), 5.0),
+        _ => (Color::srgba(0.3, 0.7, 0.2, 0.7), Color::srgb(0.4, 2.0, 0.3), 12.0),
+    };
+    let particles = ParticleConfig {
+        lifetime: 1.0,
+        lifetime_variance: 0.3,
+        speed,
+        speed_variance: 5.0,
+        direction: Vec3::Y,
+        direction_variance: 0.3,
+        scale: 0.8,
+        scale_variance: 0.2,
+        color,
+        emission_shape: EmissionShape::Circle { radius: 10.0 },
+        ..default()
+    };
+    (overlay, particles)
+}
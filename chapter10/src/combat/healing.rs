@@ -0,0 +1,190 @@
+// src/combat/healing.rs
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::events::Heal;
+use super::health::Health;
+use crate::characters::input::Player;
+use crate::config::healing::{
+    BONUS_HEALTH_DECAY, PICKUP_DROP_CHANCE, PICKUP_HEAL, PICKUP_LIFETIME, PICKUP_RADIUS, REGEN_DELAY_SECS,
+    REGEN_RATE, REGEN_TICK_SECS,
+};
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::game_rng::GameRng;
+use crate::state::GameplayEntity;
+
+/// Slowly restores health once the owner has gone `delay` seconds without damage.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Regeneration {
+    /// Health per second
+    pub rate: f32,
+    /// Seconds without damage before regen kicks in
+    pub delay: f32,
+    since_damage: f32,
+    since_tick: f32,
+    /// Health last frame, to notice damage from any source
+    last_health: f32,
+}
+
+impl Default for Regeneration {
+    fn default() -> Self {
+        Self::new(REGEN_RATE, REGEN_DELAY_SECS)
+    }
+}
+
+impl Regeneration {
+    pub fn new(rate: f32, delay: f32) -> Self {
+        Self {
+            rate,
+            delay,
+            since_damage: 0.0,
+            since_tick: 0.0,
+            last_health: f32::MAX,
+        }
+    }
+}
+
+/// Heals in once-a-second `Heal` events so regen shows up like any other healing.
+pub fn regenerate_health(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &Health, &mut Regeneration)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, health, mut regen) in &mut query {
+        if health.current < regen.last_health {
+            regen.since_damage = 0.0;
+            regen.since_tick = 0.0;
+        }
+        regen.last_health = health.current;
+
+        regen.since_damage += dt;
+        if regen.since_damage < regen.delay || health.current >= health.max {
+            continue;
+        }
+
+        regen.since_tick += dt;
+        if regen.since_tick >= REGEN_TICK_SECS {
+            regen.since_tick -= REGEN_TICK_SECS;
+            commands.trigger(Heal {
+                target: entity,
+                amount: regen.rate * REGEN_TICK_SECS,
+            });
+        }
+    }
+}
+
+/// Wears temporary bonus max health down over time.
+pub fn decay_bonus_health(time: Res<Time>, mut query: Query<&mut Health>) {
+    let decay = BONUS_HEALTH_DECAY * time.delta_secs();
+    for mut health in &mut query {
+        // Only touch health that has a bonus, so Changed<Health> stays meaningful
+        if health.bonus > 0.0 {
+            health.decay_bonus(decay);
+        }
+    }
+}
+
+/// Floating heart-ish orb that heals the player on touch.
+#[derive(Component, Debug)]
+pub struct HealthPickup {
+    pub amount: f32,
+    remaining: f32,
+}
+
+/// Gives a killed enemy a chance to drop a healing orb.
+pub fn maybe_drop_health_pickup(commands: &mut Commands, rng: &mut GameRng, position: Vec3) {
+    if !rng.0.gen_bool(PICKUP_DROP_CHANCE) {
+        return;
+    }
+    spawn_health_pickup(commands, position, PICKUP_HEAL);
+}
+
+pub fn spawn_health_pickup(commands: &mut Commands, position: Vec3, amount: f32) {
+    let particles = ParticleConfig {
+        lifetime: 0.6,
+        lifetime_variance: 0.2,
+        speed: 15.0,
+        speed_variance: 5.0,
+        direction: Vec3::Y,
+        direction_variance: 0.5,
+        scale: 0.6,
+        scale_variance: 0.2,
+        color: Color::srgb(0.4, 2.0, 0.5),
+        emission_shape: EmissionShape::Circle { radius: 6.0 },
+        ..default()
+    };
+
+    commands
+        .spawn((
+            HealthPickup {
+                amount,
+                remaining: PICKUP_LIFETIME,
+            },
+            Sprite::from_color(Color::srgb(0.3, 0.9, 0.4), Vec2::splat(10.0)),
+            Transform::from_translation(position),
+            GameplayEntity,
+        ))
+        .with_child((
+            ParticleEmitter::new(0.2, 1, particles),
+            Transform::IDENTITY,
+            GlobalTransform::from(Transform::from_translation(position)),
+        ));
+}
+
+pub fn collect_health_pickups(
+    mut commands: Commands,
+    time: Res<Time>,
+    players: Query<(Entity, &Transform), With<Player>>,
+    mut pickups: Query<(Entity, &mut HealthPickup, &Transform)>,
+) {
+    let player = players.single().ok();
+    let dt = time.delta_secs();
+
+    for (entity, mut pickup, transform) in &mut pickups {
+        pickup.remaining -= dt;
+        if pickup.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let Some((player, player_transform)) = player else {
+            continue;
+        };
+        let distance = player_transform
+            .translation
+            .truncate()
+            .distance(transform.translation.truncate());
+        if distance <= PICKUP_RADIUS {
+            commands.trigger(Heal {
+                target: player,
+                amount: pickup.amount,
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Green sparkle on whoever got healed.
+pub fn spawn_heal_particles(commands: &mut Commands, position: Vec3) {
+    let config = ParticleConfig {
+        lifetime: 0.7,
+        lifetime_variance: 0.2,
+        speed: 40.0,
+        speed_variance: 15.0,
+        direction: Vec3::Y,
+        direction_variance: 0.8,
+        scale: 0.7,
+        scale_variance: 0.2,
+        color: Color::srgb(0.4, 2.0, 0.5),
+        emission_shape: EmissionShape::Circle { radius: 14.0 },
+        ..default()
+    };
+    commands.spawn((
+        ParticleEmitter::new(0.016, 8, config).one_shot(),
+        Transform::from_translation(position),
+        GlobalTransform::from(Transform::from_translation(position)),
+        GameplayEntity,
+    ));
+}
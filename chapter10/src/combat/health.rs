@@ -1,24 +1,66 @@
 use bevy::prelude::*;
 
 /// Health component for any damageable entity (player, enemies).
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct Health {
     pub current: f32,
     pub max: f32,
+    /// Temporary extra max health from items and shrines. Decays back to zero;
+    /// `current` may sit above `max` while it lasts.
+    pub bonus: f32,
 }
 
 impl Health {
     pub fn new(max: f32) -> Self {
-        Self { current: max, max }
+        Self { current: max, max, bonus: 0.0 }
+    }
+
+    /// Max health including the temporary bonus.
+    pub fn effective_max(&self) -> f32 {
+        self.max + self.bonus
     }
 
     pub fn is_alive(&self) -> bool {
         self.current > 0.0
     }
 
-    /// Returns health as a ratio in [0, 1].
+    /// Returns base health as a ratio in [0, 1]; overheal is left to `bonus_ratio`.
     pub fn ratio(&self) -> f32 {
-        self.current / self.max
+        (self.current / self.max).min(1.0)
+    }
+
+    /// Health held above `max`, as a fraction of `max`.
+    pub fn bonus_ratio(&self) -> f32 {
+        (self.current - self.max).max(0.0) / self.max
+    }
+
+    /// Restores up to `amount`, capped at the effective max. Returns how much was actually healed.
+    pub fn heal(&mut self, amount: f32) -> f32 {
+        let before = self.current;
+        self.current = (self.current + amount).min(self.effective_max()).max(before);
+        self.current - before
+    }
+
+    /// Changes base max health keeping the same fraction of it, e.g. when the
+    /// player switches to a sturdier character. The bonus scales along.
+    pub fn rescale(&mut self, new_max: f32) {
+        let scale = new_max / self.max;
+        self.current *= scale;
+        self.bonus *= scale;
+        self.max = new_max;
+    }
+
+    /// Raises max health by `amount` for a while and fills the new headroom.
+    pub fn add_bonus(&mut self, amount: f32) {
+        self.bonus += amount;
+        self.current += amount;
+    }
+
+    /// Shrinks the bonus by `amount`, taking any health above the new cap with it.
+    pub fn decay_bonus(&mut self, amount: f32) {
+        self.bonus = (self.bonus - amount).max(0.0);
+        self.current = self.current.min(self.effective_max());
     }
 
     pub fn take_damage(
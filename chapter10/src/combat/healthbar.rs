@@ -1,115 +1,217 @@
 use bevy::prelude::*;
 use super::health::Health;
+use crate::characters::config::CharacterEntry;
+use crate::rendering::ColorMode;
 
+/// Smallest bar, used as is for regular-sized characters
 const HEALTHBAR_WIDTH: f32 = 50.0;
 const HEALTHBAR_HEIGHT: f32 = 6.0;
 const HEALTHBAR_Y_OFFSET: f32 = 43.0;
+/// Bigger sprites get a bar this share of their width
+const HEALTHBAR_WIDTH_SHARE: f32 = 0.6;
+/// Gap between the top of the sprite frame and the bar
+const HEALTHBAR_HEAD_GAP: f32 = 5.0;
 const HEALTHBAR_Z_OFFSET: f32 = 1.0;
 /// Small z bump so the foreground always renders on top of the background.
 const HEALTHBAR_FG_Z_BUMP: f32 = 0.01;
 
+/// Marker: this entity is the dark backing of a healthbar.
+#[derive(Component)]
+pub struct HealthBarBackground;
+
 /// Marker: this entity is the colored fill of a healthbar.
 #[derive(Component)]
 pub struct HealthBarForeground;
 
-/// Links a healthbar entity back to its owner character.
+/// Marker: this entity is the overheal extension of a healthbar.
 #[derive(Component)]
-pub struct HealthBarOwner(pub Entity);
+pub struct HealthBarBonus;
+
+/// Links an owner to its healthbar entities. The bars are children of the owner,
+/// so they follow it through transform propagation and despawn with it.
+#[derive(Component)]
+pub struct HealthBars {
+    pub background: Entity,
+    pub foreground: Entity,
+    pub bonus: Entity,
+}
 
-/// Spawns a background + foreground healthbar pair for each entity that gains Health.
+/// Bar width and height above the owner, in unscaled world units.
+#[derive(Debug, Clone, Copy)]
+struct BarLayout {
+    width: f32,
+    y_offset: f32,
+}
+
+impl BarLayout {
+    /// Sized from the owner's sprite frame, so large enemies get a longer
+    /// bar that clears their head.
+    fn for_owner(character: Option<&CharacterEntry>, transform: &Transform) -> Self {
+        let size = character.map_or(Vec2::ZERO, |c| c.sprite_size(transform.scale));
+        Self {
+            width: (size.x * HEALTHBAR_WIDTH_SHARE).max(HEALTHBAR_WIDTH),
+            y_offset: (size.y / 2.0 + HEALTHBAR_HEAD_GAP).max(HEALTHBAR_Y_OFFSET),
+        }
+    }
+}
+
+/// Height of the top edge of an owner's healthbar, for labels stacked above it.
+pub fn healthbar_top(character: Option<&CharacterEntry>, transform: &Transform) -> f32 {
+    BarLayout::for_owner(character, transform).y_offset + HEALTHBAR_HEIGHT / 2.0
+}
+
+/// Spawns a background, foreground and overheal segment as children of each entity that gains Health.
 pub fn spawn_healthbars(
     mut commands: Commands,
-    new_health: Query<(Entity, &GlobalTransform, &Health), Added<Health>>,
+    new_health: Query<(Entity, &Transform, &Health, Option<&CharacterEntry>), Added<Health>>,
+    mode: Res<ColorMode>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    for (owner, transform, health) in &new_health {
-        let pos = transform.translation();
-        let bg_pos = Vec3::new(pos.x, pos.y + HEALTHBAR_Y_OFFSET, pos.z + HEALTHBAR_Z_OFFSET);
-        let fg_pos = Vec3::new(pos.x, pos.y + HEALTHBAR_Y_OFFSET, pos.z + HEALTHBAR_Z_OFFSET + HEALTHBAR_FG_Z_BUMP);
+    for (owner, owner_transform, health, character) in &new_health {
+        // Undo the owner's scale so bars keep the same on-screen size and offset
+        let inverse_scale = owner_transform.scale.recip();
+        let ratio = health.ratio();
+        let layout = BarLayout::for_owner(character, owner_transform);
 
         // Background: dark gray
         let bg_mesh = meshes.add(Rectangle::new(HEALTHBAR_WIDTH, HEALTHBAR_HEIGHT));
         let bg_mat = materials.add(ColorMaterial::from(Color::srgb(0.2, 0.2, 0.2)));
-        commands.spawn((
-            Mesh2d(bg_mesh),
-            MeshMaterial2d(bg_mat),
-            Transform::from_translation(bg_pos),
-            HealthBarOwner(owner),
-        ));
+        let background = commands
+            .spawn((
+                Mesh2d(bg_mesh),
+                MeshMaterial2d(bg_mat),
+                background_transform(layout, inverse_scale),
+                HealthBarBackground,
+                ChildOf(owner),
+            ))
+            .id();
 
         // Foreground: color derived from actual health ratio
-        let ratio = health.ratio();
         let fg_mesh = meshes.add(Rectangle::new(HEALTHBAR_WIDTH, HEALTHBAR_HEIGHT));
-        let fg_mat = materials.add(ColorMaterial::from(health_color(ratio)));
-        commands.spawn((
-            Mesh2d(fg_mesh),
-            MeshMaterial2d(fg_mat),
-            Transform::from_translation(fg_pos).with_scale(Vec3::new(ratio.max(0.001), 1.0, 1.0)),
-            HealthBarOwner(owner),
-            HealthBarForeground,
-        ));
+        let fg_mat = materials.add(ColorMaterial::from(mode.health_color(ratio)));
+        let foreground = commands
+            .spawn((
+                Mesh2d(fg_mesh),
+                MeshMaterial2d(fg_mat),
+                foreground_transform(ratio, layout, inverse_scale),
+                HealthBarForeground,
+                ChildOf(owner),
+            ))
+            .id();
+
+        // Overheal: grows to the right of the full bar
+        let bonus_mesh = meshes.add(Rectangle::new(HEALTHBAR_WIDTH, HEALTHBAR_HEIGHT));
+        let bonus_mat = materials.add(ColorMaterial::from(mode.bonus_color()));
+        let bonus = commands
+            .spawn((
+                Mesh2d(bonus_mesh),
+                MeshMaterial2d(bonus_mat),
+                bonus_transform(health.bonus_ratio(), layout, inverse_scale),
+                HealthBarBonus,
+                ChildOf(owner),
+            ))
+            .id();
+
+        commands.entity(owner).insert(HealthBars {
+            background,
+            foreground,
+            bonus,
+        });
     }
 }
 
+/// Keeps the owner's transform query apart from the bars'.
+type NotABar = (Without<HealthBarBackground>, Without<HealthBarForeground>, Without<HealthBarBonus>);
 
-/// Despawns bars whose owner no longer exists.
+/// Refills bars whose owner's health changed this frame. Position is handled by the hierarchy.
+/// Switching characters rescales health, so the bar is also resized here.
 pub fn update_healthbars(
-    mut commands: Commands,
-    mut bars: Query<(
-        Entity,
-        &HealthBarOwner,
+    owners: Query<(&Transform, &Health, &HealthBars, Option<&CharacterEntry>), (Changed<Health>, NotABar)>,
+    mut bars: Query<(&mut Transform, &MeshMaterial2d<ColorMaterial>), With<HealthBarForeground>>,
+    mut bonus_bars: Query<&mut Transform, (With<HealthBarBonus>, Without<HealthBarForeground>)>,
+    mut backgrounds: Query<
         &mut Transform,
-        Has<HealthBarForeground>,
-        &MeshMaterial2d<ColorMaterial>,
-    )>,
-    owners: Query<(&GlobalTransform, &Health)>,
+        (With<HealthBarBackground>, Without<HealthBarForeground>, Without<HealthBarBonus>),
+    >,
+    mode: Res<ColorMode>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    for (bar_entity, owner_ref, mut transform, is_foreground, mat_handle) in bars.iter_mut() {
-        let Ok((owner_transform, health)) = owners.get(owner_ref.0) else {
-            // If the owner is gone (despawned), clean up the health bar
-            commands.entity(bar_entity).despawn();
+    for (owner_transform, health, healthbars, character) in owners.iter() {
+        let inverse_scale = owner_transform.scale.recip();
+        let layout = BarLayout::for_owner(character, owner_transform);
+        if let Ok(mut transform) = backgrounds.get_mut(healthbars.background) {
+            *transform = background_transform(layout, inverse_scale);
+        }
+        if let Ok(mut transform) = bonus_bars.get_mut(healthbars.bonus) {
+            *transform = bonus_transform(health.bonus_ratio(), layout, inverse_scale);
+        }
+
+        let Ok((mut transform, mat_handle)) = bars.get_mut(healthbars.foreground) else {
             continue;
         };
 
-        let owner_pos = owner_transform.translation();
         let ratio = health.ratio();
+        *transform = foreground_transform(ratio, layout, inverse_scale);
+
+        if let Some(mat) = materials.get_mut(&mat_handle.0) {
+            mat.color = mode.health_color(ratio);
+        }
+    }
+}
 
-        if is_foreground {
-            // Scale foreground width to match health ratio
-            transform.scale.x = ratio.max(0.001);
-            
-            // Reposition foreground to stay left-aligned as it shrinks
-            // (Scaling happens from center, so we need to offset position)
-            transform.translation = Vec3::new(
-                owner_pos.x - (HEALTHBAR_WIDTH * (1.0 - ratio) / 2.0),
-                owner_pos.y + HEALTHBAR_Y_OFFSET,
-                owner_pos.z + HEALTHBAR_Z_OFFSET + HEALTHBAR_FG_Z_BUMP,
-            );
-            
-            // Update color (Green -> Yellow -> Red)
+/// Repaints every bar when the colour mode changes.
+pub fn recolor_healthbars(
+    mode: Res<ColorMode>,
+    owners: Query<(&Health, &HealthBars)>,
+    bars: Query<&MeshMaterial2d<ColorMaterial>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (health, healthbars) in &owners {
+        let fills = [
+            (healthbars.foreground, mode.health_color(health.ratio())),
+            (healthbars.bonus, mode.bonus_color()),
+        ];
+        for (bar, color) in fills {
+            let Ok(mat_handle) = bars.get(bar) else {
+                continue;
+            };
             if let Some(mat) = materials.get_mut(&mat_handle.0) {
-                mat.color = health_color(ratio);
+                mat.color = color;
             }
-        } else {
-            // Background simply stays centered above the owner
-            transform.translation = Vec3::new(
-                owner_pos.x,
-                owner_pos.y + HEALTHBAR_Y_OFFSET,
-                owner_pos.z + HEALTHBAR_Z_OFFSET,
-            );
         }
     }
 }
 
-/// Green → Yellow → Red, continuous at ratio = 0.5.
-fn health_color(ratio: f32) -> Color {
-    if ratio >= 0.5 {
-        let t = (ratio - 0.5) * 2.0; // 1.0 at full, 0.0 at half
-        Color::srgb(1.0 - t * 0.8, 0.8, 0.2)
-    } else {
-        let t = ratio * 2.0; // 1.0 at half, 0.0 at empty
-        Color::srgb(1.0, t * 0.8, 0.2)
-    }
-}
\ No newline at end of file
+/// Background sits centered above the owner (in unscaled world units),
+/// stretched from the base mesh width to the layout's.
+fn background_transform(layout: BarLayout, inverse_scale: Vec3) -> Transform {
+    let offset = Vec3::new(0.0, layout.y_offset, HEALTHBAR_Z_OFFSET);
+    Transform::from_translation(offset * inverse_scale)
+        .with_scale(Vec3::new(layout.width / HEALTHBAR_WIDTH, 1.0, 1.0) * inverse_scale)
+}
+
+/// Foreground scales its width to the health ratio and stays left-aligned as it shrinks
+/// (scaling happens from center, so we need to offset position).
+fn foreground_transform(ratio: f32, layout: BarLayout, inverse_scale: Vec3) -> Transform {
+    let offset = Vec3::new(
+        -(layout.width * (1.0 - ratio) / 2.0),
+        layout.y_offset,
+        HEALTHBAR_Z_OFFSET + HEALTHBAR_FG_Z_BUMP,
+    );
+    let stretch = ratio.max(0.001) * layout.width / HEALTHBAR_WIDTH;
+    Transform::from_translation(offset * inverse_scale)
+        .with_scale(Vec3::new(stretch, 1.0, 1.0) * inverse_scale)
+}
+
+/// Overheal segment starts at the bar's right end and grows outward with `bonus_ratio`.
+fn bonus_transform(bonus_ratio: f32, layout: BarLayout, inverse_scale: Vec3) -> Transform {
+    let offset = Vec3::new(
+        layout.width * (1.0 + bonus_ratio) / 2.0,
+        layout.y_offset,
+        HEALTHBAR_Z_OFFSET + HEALTHBAR_FG_Z_BUMP,
+    );
+    let stretch = bonus_ratio.max(0.001) * layout.width / HEALTHBAR_WIDTH;
+    Transform::from_translation(offset * inverse_scale)
+        .with_scale(Vec3::new(stretch, 1.0, 1.0) * inverse_scale)
+}
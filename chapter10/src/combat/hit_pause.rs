@@ -0,0 +1,66 @@
+// src/combat/hit_pause.rs
+use bevy::prelude::*;
+
+use crate::config::combat::{HIT_PAUSE_SCALE, HIT_PAUSE_SECS};
+
+/// Brief slow-down of the virtual clock that sells heavy impacts.
+/// Counted in real time, since virtual time is what it slows.
+#[derive(Resource)]
+pub struct HitPause {
+    remaining: f32,
+    scale: f32,
+    /// Reduced-motion setting: 1 for the full pause, 0 to skip it
+    duration_scale: f32,
+}
+
+impl Default for HitPause {
+    fn default() -> Self {
+        Self {
+            remaining: 0.0,
+            scale: 1.0,
+            duration_scale: 1.0,
+        }
+    }
+}
+
+impl HitPause {
+    /// Starts the standard impact pause.
+    pub fn trigger(&mut self) {
+        self.trigger_with(HIT_PAUSE_SECS, HIT_PAUSE_SCALE);
+    }
+
+    /// Overlapping pauses don't stack: the longest duration and strongest
+    /// slow-down win.
+    pub fn trigger_with(&mut self, seconds: f32, scale: f32) {
+        let seconds = seconds * self.duration_scale;
+        if seconds <= 0.0 {
+            return;
+        }
+        if self.remaining <= 0.0 {
+            self.scale = scale;
+        } else {
+            self.scale = self.scale.min(scale);
+        }
+        self.remaining = self.remaining.max(seconds);
+    }
+
+    pub fn set_duration_scale(&mut self, duration_scale: f32) {
+        self.duration_scale = duration_scale;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+
+    /// Multiplier for the virtual clock right now.
+    pub fn scale(&self) -> f32 {
+        if self.is_active() { self.scale } else { 1.0 }
+    }
+}
+
+/// Counts the pause down. `time_scale::apply_time_scale` turns it into clock speed.
+pub fn tick_hit_pause(real_time: Res<Time<Real>>, mut hit_pause: ResMut<HitPause>) {
+    if hit_pause.is_active() {
+        hit_pause.remaining -= real_time.delta_secs();
+    }
+}
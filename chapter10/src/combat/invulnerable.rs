@@ -0,0 +1,44 @@
+// src/combat/invulnerable.rs
+use bevy::prelude::*;
+
+use crate::config::combat::INVULNERABLE_FLICKER_HZ;
+
+/// Ignores projectile hits and contact damage until the timer runs out.
+/// Given to the player after every hit so overlapping attacks can't chain.
+#[derive(Component, Debug, Clone)]
+pub struct Invulnerable(pub Timer);
+
+impl Invulnerable {
+    pub fn from_seconds(secs: f32) -> Self {
+        Self(Timer::from_seconds(secs, TimerMode::Once))
+    }
+}
+
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Invulnerable)>,
+) {
+    for (entity, mut invulnerable) in &mut query {
+        invulnerable.0.tick(time.delta());
+        if invulnerable.0.is_finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
+
+/// Blinks the sprite while invulnerable.
+pub fn flicker_invulnerable(mut query: Query<(&Invulnerable, &mut Sprite)>) {
+    for (invulnerable, mut sprite) in &mut query {
+        let phase = (invulnerable.0.elapsed_secs() * INVULNERABLE_FLICKER_HZ * 2.0) as u32;
+        let alpha = if phase % 2 == 0 { 0.35 } else { 1.0 };
+        sprite.color.set_alpha(alpha);
+    }
+}
+
+/// Leaves the sprite fully opaque when the window ends mid-blink.
+pub fn on_invulnerable_removed(removed: On<Remove, Invulnerable>, mut sprites: Query<&mut Sprite>) {
+    if let Ok(mut sprite) = sprites.get_mut(removed.entity) {
+        sprite.color.set_alpha(1.0);
+    }
+}
@@ -0,0 +1,149 @@
+// src/combat/lock_on.rs
+use bevy::prelude::*;
+
+use crate::characters::config::CharacterEntry;
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::config::combat::{LOCK_ON_BREAK_RANGE, LOCK_ON_RANGE};
+use crate::enemy::Enemy;
+use crate::input_map::PlayerActions;
+use crate::logging::targets;
+
+const RETICLE_Z_OFFSET: f32 = 0.5;
+const RETICLE_RADIUS: f32 = 22.0;
+/// Larger sprites get a ring this share of their frame width
+const RETICLE_WIDTH_SHARE: f32 = 0.3;
+
+/// Enemy the player is locked onto, if any. Aim and facing snap toward it
+/// and the camera leans its way.
+#[derive(Component, Debug, Default)]
+pub struct LockOn {
+    pub target: Option<Entity>,
+}
+
+/// Ring drawn under the locked enemy. A child of the enemy, so it follows
+/// it around and goes away with it.
+#[derive(Component)]
+pub struct Reticle {
+    /// Undoes the enemy's scale, like the healthbars do
+    base_scale: Vec3,
+}
+
+/// Tab locks onto the nearest enemy in range, then cycles outward by distance,
+/// wrapping back to the nearest.
+pub fn cycle_lock_on(
+    mut commands: Commands,
+    actions: Res<PlayerActions>,
+    mut players: Query<(&Transform, &mut LockOn), With<Player>>,
+    enemies: Query<(Entity, &Transform, Option<&CharacterEntry>), With<Enemy>>,
+    reticles: Query<Entity, With<Reticle>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !actions.cycle_lock_on {
+        return;
+    }
+    let Ok((player_transform, mut lock)) = players.single_mut() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let mut candidates: Vec<(Entity, f32)> = enemies
+        .iter()
+        .map(|(entity, t, _)| (entity, t.translation.truncate().distance(player_pos)))
+        .filter(|(_, distance)| *distance <= LOCK_ON_RANGE)
+        .collect();
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let next = match lock.target.and_then(|t| candidates.iter().position(|(e, _)| *e == t)) {
+        Some(index) => candidates.get(index + 1).or(candidates.first()),
+        None => candidates.first(),
+    };
+    let Some(&(target, _)) = next else {
+        return;
+    };
+    if lock.target == Some(target) {
+        return;
+    }
+
+    for reticle in &reticles {
+        commands.entity(reticle).despawn();
+    }
+    lock.target = Some(target);
+
+    let Ok((_, target_transform, character)) = enemies.get(target) else {
+        return;
+    };
+    let base_scale = target_transform.scale.recip();
+    // Sized to the sprite so the ring reads around large enemies too
+    let radius = character.map_or(RETICLE_RADIUS, |c| {
+        (c.sprite_size(target_transform.scale).x * RETICLE_WIDTH_SHARE).max(RETICLE_RADIUS)
+    });
+    commands.spawn((
+        Reticle { base_scale },
+        Mesh2d(meshes.add(Annulus::new(radius - 2.0, radius))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgb(1.0, 0.25, 0.2)))),
+        Transform::from_xyz(0.0, 0.0, RETICLE_Z_OFFSET).with_scale(base_scale),
+        ChildOf(target),
+    ));
+    debug!(target: targets::COMBAT, "Locked onto {:?}", target);
+}
+
+/// Drops the lock when the target dies (and despawns) or gets too far away.
+pub fn release_lock_on(
+    mut commands: Commands,
+    mut players: Query<(&Transform, &mut LockOn), With<Player>>,
+    enemies: Query<&Transform, With<Enemy>>,
+    reticles: Query<Entity, With<Reticle>>,
+) {
+    let Ok((player_transform, mut lock)) = players.single_mut() else {
+        return;
+    };
+    let Some(target) = lock.target else {
+        return;
+    };
+
+    let in_range = enemies.get(target).is_ok_and(|t| {
+        t.translation.truncate().distance(player_transform.translation.truncate())
+            <= LOCK_ON_BREAK_RANGE
+    });
+    if in_range {
+        return;
+    }
+
+    lock.target = None;
+    for reticle in &reticles {
+        commands.entity(reticle).despawn();
+    }
+    debug!(target: targets::COMBAT, "Lock released");
+}
+
+/// Turns the player toward the locked target after movement picked a facing.
+pub fn face_lock_on_target(
+    mut players: Query<(&Transform, &LockOn, &mut Facing), With<Player>>,
+    enemies: Query<&Transform, (With<Enemy>, Without<Player>)>,
+) {
+    let Ok((transform, lock, mut facing)) = players.single_mut() else {
+        return;
+    };
+    let Some(target) = lock.target.and_then(|t| enemies.get(t).ok()) else {
+        return;
+    };
+
+    let to_target = (target.translation - transform.translation).truncate();
+    if to_target == Vec2::ZERO {
+        return;
+    }
+    let new_facing = Facing::from_velocity(to_target);
+    if *facing != new_facing {
+        *facing = new_facing;
+    }
+}
+
+/// Slow pulse so the reticle reads as UI rather than part of the sprite.
+pub fn pulse_reticles(time: Res<Time>, mut reticles: Query<(&Reticle, &mut Transform)>) {
+    let pulse = 1.0 + 0.08 * (time.elapsed_secs() * 6.0).sin();
+    for (reticle, mut transform) in &mut reticles {
+        transform.scale = reticle.base_scale * pulse;
+    }
+}
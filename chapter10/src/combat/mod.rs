@@ -1,43 +1,187 @@
 // src/combat/mod.rs
+mod ability;
+mod block;
+mod contact;
+mod corpse;
 mod events; 
+mod faction;
+mod floating_text;
+mod healing;
 mod observers; 
 pub mod health;
 pub mod healthbar; 
+mod hazards;
+mod hit_pause;
+mod invulnerable;
+mod lock_on;
 
 mod player_combat;
+mod power_registry;
 mod power_type;
+pub mod projectile;
 pub mod systems;
+pub mod terrain;
 
+pub use ability::AbilityCooldown;
+pub use block::{Shield, Stamina};
+pub use contact::{ContactDamage, Knockback};
+pub use events::{EntityDeath, Heal, ProjectileHit, ProjectileImpact};
+pub use faction::{CombatSettings, Faction, FactionHostility};
+pub use hazards::HazardImmunity;
+pub use healing::Regeneration;
 pub use health::Health; 
-pub use healthbar::HealthBarOwner;
+pub use hit_pause::HitPause;
+pub use invulnerable::Invulnerable;
+pub use lock_on::LockOn;
 
 pub use player_combat::PlayerCombat;
+pub use power_registry::{PowerId, PowerList, PowerRegistry};
 pub use power_type::{PowerType, PowerVisuals};
-pub use systems::{debug_switch_power, handle_power_input, spawn_projectile, ProjectileOwner}; 
+pub use projectile::{fire_power, ProjectileOwner};
+pub use systems::{debug_switch_power, handle_power_input};
 
 use bevy::prelude::*;
-use crate::state::GameState; 
+use bevy_common_assets::ron::RonAssetPlugin;
+use crate::characters::collider::validate_movement;
+use crate::characters::input::handle_player_input;
+use crate::console::ConsoleAppExt;
+use crate::rendering::ColorMode;
+use crate::schedule::{world_rebuilt, GameSet};
 
-pub struct CombatPlugin;
+/// `settings` seeds the `CombatSettings` resource, which can also be changed at runtime.
+#[derive(Default)]
+pub struct CombatPlugin {
+    pub settings: CombatSettings,
+}
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         app
+            .insert_resource(self.settings)
+            .init_resource::<FactionHostility>()
+            .register_type::<Faction>()
+            .register_type::<Health>()
+            .register_type::<Shield>()
+            .register_type::<Regeneration>()
+            .register_type::<ContactDamage>()
+            .register_type::<Stamina>()
+            .register_type::<HazardImmunity>()
+            .register_type::<PlayerCombat>()
+            .register_type::<AbilityCooldown>()
+            .register_type::<PowerType>()
+            .register_type::<PowerId>()
+            .add_plugins(RonAssetPlugin::<PowerList>::new(&["powers.ron"]))
+            .init_resource::<PowerRegistry>()
+            .add_systems(Startup, power_registry::load_powers)
+            .add_systems(Update, power_registry::sync_power_registry)
+            .add_systems(
+                Update,
+                power_registry::sync_power_colors.run_if(resource_changed::<ColorMode>),
+            )
+            .init_resource::<HitPause>()
+            // Runs in every state so a pause can't outlive the fight that caused it
+            .add_systems(Update, hit_pause::tick_hit_pause)
+            .add_message::<ProjectileImpact>()
+            .add_console_command("power", "Switch the player's power: power [id]", power_registry::power_command)
             // Register observers for combat events
             .add_observer(observers::on_projectile_hit) 
             .add_observer(observers::on_entity_death) 
+            .add_observer(observers::on_heal)
+            .add_systems(
+                Update,
+                (handle_power_input, debug_switch_power, ability::use_signature_ability)
+                    .chain()
+                    .in_set(GameSet::Input),
+            )
+            // After input and AI have picked this frame's velocity
+            .add_systems(
+                Update,
+                (
+                    ability::apply_dashes,
+                    ability::pulse_heal_auras,
+                    ability::update_familiars,
+                )
+                    .in_set(GameSet::Combat),
+            )
+            // The shield has to be up before movement input picks the character state
+            .add_systems(
+                Update,
+                block::handle_block_input
+                    .before(handle_player_input)
+                    .in_set(GameSet::Input),
+            )
+            .add_systems(
+                Update,
+                (
+                    lock_on::cycle_lock_on,
+                    lock_on::face_lock_on_target.after(handle_player_input),
+                )
+                    .in_set(GameSet::Input),
+            )
+            .add_systems(Update, lock_on::release_lock_on.in_set(GameSet::PostPhysics))
+            .add_systems(Update, block::update_stamina.in_set(GameSet::Combat))
+            .add_systems(
+                Update,
+                (
+                    healing::regenerate_health,
+                    healing::decay_bonus_health,
+                    healing::collect_health_pickups,
+                )
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(
+                Update,
+                (contact::apply_contact_damage, invulnerable::tick_invulnerability)
+                    .chain()
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(
+                Update,
+                contact::apply_knockback
+                    .before(validate_movement)
+                    .in_set(GameSet::Physics),
+            )
+            .add_systems(
+                Update,
+                (
+                    block::update_shield_tint,
+                    invulnerable::flicker_invulnerable,
+                    lock_on::pulse_reticles,
+                    floating_text::update_floating_text,
+                    corpse::fade_corpses,
+                )
+                    .chain()
+                    .in_set(GameSet::Render),
+            )
+            .add_observer(invulnerable::on_invulnerable_removed)
             .add_systems(
                 Update,
                 (
-                    handle_power_input,
-                    debug_switch_power,
-                    systems::move_projectiles, 
-                    systems::check_projectile_hits,
-                    healthbar::spawn_healthbars,
-                    healthbar::update_healthbars,
+                    projectile::inherit_shooter_layer,
+                    projectile::move_projectiles,
+                    projectile::check_projectile_hits,
+                    terrain::terrain_reaction,
+                    terrain::tick_ground_hazards,
+                    hazards::hazard_contact_damage,
                 )
                     .chain()
-                    .run_if(in_state(GameState::Playing)),
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(
+                Update,
+                hazards::spawn_hazard_tile_visuals.run_if(world_rebuilt),
+            )
+            .add_systems(
+                Update,
+                (healthbar::spawn_healthbars, healthbar::update_healthbars)
+                    .chain()
+                    .in_set(GameSet::Render),
+            )
+            .add_systems(
+                Update,
+                healthbar::recolor_healthbars
+                    .in_set(GameSet::Render)
+                    .run_if(resource_changed::<ColorMode>),
             );
     }
 }
\ No newline at end of file
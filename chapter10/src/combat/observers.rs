@@ -1,44 +1,152 @@
 // src/combat/observers.rs
-use super::events::{EntityDeath, ProjectileHit};
+use super::block::{spawn_shield_burst, Shield, ShieldFeedback, Stamina};
+use super::corpse::spawn_death_effects;
+use super::events::{EntityDeath, Heal, ProjectileHit};
+use super::floating_text::{spawn_floating_number, DAMAGE_COLOR, HEAL_COLOR};
+use super::healing::{maybe_drop_health_pickup, spawn_heal_particles};
 use super::health::Health;
+use super::hit_pause::HitPause;
+use super::invulnerable::Invulnerable;
+use super::power_registry::PowerRegistry;
 use bevy::prelude::*;
 use crate::characters::input::Player; 
+use crate::enemy::{Enemy, EnemyCombat};
+use crate::game_rng::GameRng;
+use crate::config::combat::{
+    BLOCK_DAMAGE_REDUCTION, BLOCK_HIT_STAMINA_COST, HEAVY_HIT_DAMAGE, HIT_FLASH_SECS,
+    INVULNERABLE_SECS,
+};
+use crate::rendering::FlashEffect;
 use crate::state::GameState;
+use crate::transitions::TransitionRequest;
+use crate::telemetry::GameplayEvent;
 
 /// Observer that handles projectile hits by applying damage to the target.
+/// A raised shield absorbs part of the damage at the cost of stamina.
+/// Invulnerable targets shrug the hit off; the player becomes invulnerable after one.
 pub fn on_projectile_hit(
     hit: On<ProjectileHit>,
     mut healths: Query<&mut Health>,
+    mut shields: Query<(&Shield, &mut Stamina)>,
+    targets: Query<(Has<Player>, Has<Invulnerable>, &GlobalTransform)>,
     mut commands: Commands,
+    mut hit_pause: ResMut<HitPause>,
+    mut telemetry: MessageWriter<GameplayEvent>,
 ) {
     let Ok(mut health) = healths.get_mut(hit.target) else {
         return;
     };
+    let Ok((is_player, invulnerable, transform)) = targets.get(hit.target) else {
+        return;
+    };
+    if invulnerable {
+        return;
+    }
+    let position = transform.translation();
 
-    health.take_damage(&mut commands, hit.target, hit.damage);
+    let mut damage = hit.damage;
+    if let Ok((shield, mut stamina)) = shields.get_mut(hit.target) {
+        if shield.raised {
+            let absorbed = damage * BLOCK_DAMAGE_REDUCTION;
+            damage -= absorbed;
+            stamina.spend(absorbed * BLOCK_HIT_STAMINA_COST);
+            spawn_shield_burst(&mut commands, position, ShieldFeedback::Block);
+        }
+    }
+
+    health.take_damage(&mut commands, hit.target, damage);
+    spawn_floating_number(&mut commands, position, damage, DAMAGE_COLOR);
+    commands.entity(hit.target).try_insert(FlashEffect::from_seconds(HIT_FLASH_SECS));
+    if is_player {
+        commands
+            .entity(hit.target)
+            .try_insert(Invulnerable::from_seconds(INVULNERABLE_SECS));
+    }
 
-    info!(
-        "{:?} hit for {} damage! HP: {:.0}/{:.0}",
-        hit.power_type, hit.damage, health.current, health.max
-    );
+    // Freeze-frame on kills and on heavy hits to the player
+    let heavy = if is_player {
+        damage >= HEAVY_HIT_DAMAGE
+    } else {
+        !health.is_alive()
+    };
+    if heavy {
+        hit_pause.trigger();
+    }
+
+    telemetry.write(GameplayEvent::DamageDealt {
+        target: hit.target,
+        amount: damage,
+        power_type: hit.power_type.clone(),
+        remaining: health.current,
+        max: health.max,
+    });
+}
+
+/// Observer that applies healing, mirroring `on_projectile_hit`.
+pub fn on_heal(
+    heal: On<Heal>,
+    mut healths: Query<(&mut Health, &GlobalTransform)>,
+    mut commands: Commands,
+    mut telemetry: MessageWriter<GameplayEvent>,
+) {
+    let Ok((mut health, transform)) = healths.get_mut(heal.target) else {
+        return;
+    };
+    if !health.is_alive() {
+        return;
+    }
+
+    let healed = health.heal(heal.amount);
+    if healed <= 0.0 {
+        return;
+    }
+
+    let position = transform.translation();
+    spawn_heal_particles(&mut commands, position);
+    spawn_floating_number(&mut commands, position, healed, HEAL_COLOR);
+
+    telemetry.write(GameplayEvent::Healed {
+        target: heal.target,
+        amount: healed,
+        remaining: health.current,
+        max: health.max,
+    });
 }
 
 /// Observer that handles entity death by despawning the entity.
+/// Enemies burst into particles of their power's colour and leave a fading,
+/// inert corpse behind, and sometimes a healing orb.
+#[allow(clippy::too_many_arguments)]
 pub fn on_entity_death(
     death: On<EntityDeath>,
     mut commands: Commands, 
     players: Query<(), With<Player>>, 
-    mut next_state: ResMut<NextState<GameState>>,
+    enemies: Query<(&GlobalTransform, &Transform, Option<&Sprite>, Option<&EnemyCombat>), With<Enemy>>,
+    powers: Res<PowerRegistry>,
+    mut transitions: MessageWriter<TransitionRequest>,
+    mut telemetry: MessageWriter<GameplayEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     let entity = death.entity;
     let is_player = players.get(entity).is_ok();
 
-    info!("Entity {:?} defeated!", death.entity);
+    telemetry.write(GameplayEvent::EntityDied {
+        entity,
+        was_player: is_player,
+    });
+    if let Ok((global_transform, transform, sprite, combat)) = enemies.get(entity) {
+        maybe_drop_health_pickup(&mut commands, &mut rng, global_transform.translation());
+
+        let burst_color = combat
+            .and_then(|combat| powers.get(&combat.power_type))
+            .and_then(|definition| powers.visuals(definition, Vec3::Y))
+            .map_or(Color::WHITE, |visuals| visuals.primary.color);
+        spawn_death_effects(&mut commands, sprite, transform, burst_color);
+    }
     commands.entity(death.entity).despawn();
 
     // Add this line
     if is_player { 
-        info!("Player defeated! Game Over."); 
-        next_state.set(GameState::GameOver);
+        transitions.write(TransitionRequest { to: GameState::GameOver });
     }
 }
\ No newline at end of file
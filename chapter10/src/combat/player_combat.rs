@@ -1,26 +1,28 @@
+use super::power_registry::PowerId;
 use super::power_type::PowerType;
 use bevy::prelude::*;
 
 /// Attach to any entity that can use powers (player, NPCs)
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct PlayerCombat {
-    pub power_type: PowerType,
+    pub power_type: PowerId,
     pub cooldown: Timer,
 }
 
 impl Default for PlayerCombat {
     fn default() -> Self {
         Self {
-            power_type: PowerType::Fire,
+            power_type: PowerType::Fire.into(),
             cooldown: Timer::from_seconds(0.5, TimerMode::Once),
         }
     }
 }
 
 impl PlayerCombat {
-    pub fn new(power_type: PowerType) -> Self {
+    pub fn new(power_type: impl Into<PowerId>) -> Self {
         Self {
-            power_type,
+            power_type: power_type.into(),
             cooldown: Timer::from_seconds(0.5, TimerMode::Once),
         }
     }
@@ -0,0 +1,214 @@
+// src/combat/power_registry.rs
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::power_type::{PowerType, PowerVisuals};
+use super::player_combat::PlayerCombat;
+use super::projectile::{ProjectileBehaviors, ProjectileSpec};
+use super::terrain::TerrainReaction;
+use crate::buffs::BuffSpec;
+use crate::characters::input::Player;
+use crate::console::ConsoleResult;
+use crate::rendering::ColorMode;
+use crate::logging::targets;
+
+/// String id of a registered power ("fire", "arcane", ...).
+#[derive(Reflect, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PowerId(pub String);
+
+impl From<PowerType> for PowerId {
+    fn from(power_type: PowerType) -> Self {
+        Self(power_type.id().to_string())
+    }
+}
+
+impl From<&str> for PowerId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl Default for PowerId {
+    fn default() -> Self {
+        PowerType::default().into()
+    }
+}
+
+impl fmt::Display for PowerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Builds a power's particle visuals for a firing direction.
+pub type VisualsPreset = fn(Vec3) -> PowerVisuals;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowerDefinition {
+    pub id: String,
+    pub damage: f32,
+    /// Seconds between shots for the player
+    pub cooldown: f32,
+    pub speed: f32,
+    pub radius: f32,
+    pub lifetime: f32,
+    /// Name of a registered visuals preset; `None` fires an invisible hitbox
+    #[serde(default)]
+    pub visuals: Option<String>,
+    #[serde(default)]
+    pub behaviors: ProjectileBehaviors,
+    /// What happens to the tile where the projectile lands
+    #[serde(default)]
+    pub terrain_reaction: Option<TerrainReaction>,
+    /// Radius of the light the projectile casts in the dark, if any
+    #[serde(default)]
+    pub light_radius: Option<f32>,
+    /// Debuff applied to targets it hits (burning, slowed)
+    #[serde(default)]
+    pub inflicts: Option<BuffSpec>,
+}
+
+impl PowerDefinition {
+    pub fn projectile_spec(&self) -> ProjectileSpec {
+        ProjectileSpec {
+            speed: self.speed,
+            lifetime: self.lifetime,
+            radius: self.radius,
+            damage: self.damage,
+            behaviors: self.behaviors,
+            inflicts: self.inflicts,
+        }
+    }
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct PowerList {
+    pub powers: Vec<PowerDefinition>,
+}
+
+/// Every power the game knows about, keyed by id. Defaults come from
+/// powers.ron; other code can `register` more at startup.
+#[derive(Resource)]
+pub struct PowerRegistry {
+    handle: Handle<PowerList>,
+    powers: HashMap<String, PowerDefinition>,
+    presets: HashMap<String, VisualsPreset>,
+    /// Recolours the built-in powers' particles for colourblind players
+    color_mode: ColorMode,
+}
+
+impl Default for PowerRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            handle: Handle::default(),
+            powers: HashMap::new(),
+            presets: HashMap::new(),
+            color_mode: ColorMode::default(),
+        };
+        for power_type in PowerType::ALL {
+            registry.register_visuals(power_type.id(), power_type.visuals_preset());
+        }
+        registry
+    }
+}
+
+impl PowerRegistry {
+    pub fn is_loaded(&self) -> bool {
+        !self.powers.is_empty()
+    }
+
+    pub fn get(&self, id: &PowerId) -> Option<&PowerDefinition> {
+        self.powers.get(&id.0)
+    }
+
+    /// Registered ids in alphabetical order.
+    pub fn ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.powers.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Adds or replaces a power.
+    pub fn register(&mut self, definition: PowerDefinition) {
+        self.powers.insert(definition.id.clone(), definition);
+    }
+
+    pub fn register_visuals(&mut self, name: &str, preset: VisualsPreset) {
+        self.presets.insert(name.to_string(), preset);
+    }
+
+    /// Particle visuals for a power, if it has a known preset.
+    pub fn visuals(&self, definition: &PowerDefinition, direction: Vec3) -> Option<PowerVisuals> {
+        let name = definition.visuals.as_ref()?;
+        let Some(preset) = self.presets.get(name) else {
+            warn!(target: targets::COMBAT, "Power '{}' uses unknown visuals '{}'", definition.id, name);
+            return None;
+        };
+        let mut visuals = preset(direction);
+
+        let builtin = PowerType::ALL.into_iter().find(|power| power.id() == name.as_str());
+        if let Some(colors) = builtin.and_then(|power| self.color_mode.power_colors(power)) {
+            visuals.primary.color = colors.primary;
+            if let Some(core) = &mut visuals.core {
+                core.color = colors.core;
+            }
+        }
+        Some(visuals)
+    }
+}
+
+/// Hands the colour mode to the registry, so projectiles fired from now on
+/// use its palette.
+pub fn sync_power_colors(mode: Res<ColorMode>, mut registry: ResMut<PowerRegistry>) {
+    registry.color_mode = *mode;
+}
+
+pub fn load_powers(asset_server: Res<AssetServer>, mut registry: ResMut<PowerRegistry>) {
+    registry.handle = asset_server.load("powers/powers.ron");
+}
+
+/// Merges powers.ron into the registry on load and on hot reload.
+pub fn sync_power_registry(
+    mut events: MessageReader<AssetEvent<PowerList>>,
+    lists: Res<Assets<PowerList>>,
+    mut registry: ResMut<PowerRegistry>,
+) {
+    for event in events.read() {
+        if !event.is_loaded_with_dependencies(&registry.handle)
+            && !event.is_modified(&registry.handle)
+        {
+            continue;
+        }
+        let Some(list) = lists.get(&registry.handle) else {
+            continue;
+        };
+
+        for definition in &list.powers {
+            registry.register(definition.clone());
+        }
+        info!(target: targets::COMBAT, "Loaded {} powers", list.powers.len());
+    }
+}
+
+/// Console: `power <id>` switches the player's power, `power` lists them.
+pub fn power_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let registry = world.resource::<PowerRegistry>();
+    let Some(&id) = args.first() else {
+        return Ok(format!("powers: {}", registry.ids().join(", ")));
+    };
+    let power = PowerId::from(id);
+    if registry.get(&power).is_none() {
+        return Err(format!("Unknown power '{}' ({})", id, registry.ids().join(", ")));
+    }
+
+    let mut players = world.query_filtered::<&mut PlayerCombat, With<Player>>();
+    let mut combat = players
+        .single_mut(world)
+        .map_err(|_| "No player to give the power to".to_string())?;
+    combat.power_type = power;
+    Ok(format!("Switched to {}", id))
+}
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
+use super::power_registry::VisualsPreset;
 use crate::particles::components::{EmissionShape, ParticleConfig};
 use serde::{Serialize, Deserialize};
 
 /// The different magical powers available
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PowerType {
     #[default]
     Fire,
@@ -20,33 +21,33 @@ pub struct PowerVisuals {
     pub core_particles_per_spawn: u32,
 }
 
+/// Built-in powers. Gameplay refers to powers by `PowerId`; these are just
+/// shorthands for the ids that ship with the game, plus their visuals presets.
 impl PowerType {
-    /// Get visual configuration for this power
-    pub fn visuals(&self, direction: Vec3) -> PowerVisuals {
-        match self {
-            PowerType::Fire => Self::fire_visuals(direction),
-            PowerType::Arcane => Self::arcane_visuals(direction),
-            PowerType::Shadow => Self::shadow_visuals(direction),
-            PowerType::Poison => Self::poison_visuals(direction),
-        }
-    }
+    pub const ALL: [PowerType; 4] = [
+        PowerType::Fire,
+        PowerType::Arcane,
+        PowerType::Shadow,
+        PowerType::Poison,
+    ];
 
-    pub fn damage(&self) -> f32 {
+    /// Registry id of this power
+    pub fn id(&self) -> &'static str {
         match self {
-            PowerType::Fire => 25.0,
-            PowerType::Arcane => 35.0,
-            PowerType::Shadow => 20.0,
-            PowerType::Poison => 15.0,
+            PowerType::Fire => "fire",
+            PowerType::Arcane => "arcane",
+            PowerType::Shadow => "shadow",
+            PowerType::Poison => "poison",
         }
     }
 
-    /// Hitbox collision radius. Larger for powers that spread visually.
-    pub fn hitbox_radius(&self) -> f32 {
+    /// Visual configuration for this power, registered under its id
+    pub fn visuals_preset(&self) -> VisualsPreset {
         match self {
-            PowerType::Fire => 30.0,
-            PowerType::Arcane => 18.0,
-            PowerType::Shadow => 15.0,
-            PowerType::Poison => 25.0,
+            PowerType::Fire => Self::fire_visuals,
+            PowerType::Arcane => Self::arcane_visuals,
+            PowerType::Shadow => Self::shadow_visuals,
+            PowerType::Poison => Self::poison_visuals,
         }
     }
 
@@ -0,0 +1,411 @@
+// src/combat/projectile.rs
+use super::block::{spawn_shield_burst, Shield, ShieldFeedback};
+use super::faction::{CombatSettings, Faction, FactionHostility};
+use super::events::ProjectileImpact;
+use super::health::Health;
+use super::hit_pause::HitPause;
+use super::power_registry::{PowerId, PowerRegistry};
+use super::power_type::PowerVisuals;
+use crate::buffs::BuffSpec;
+use crate::collision::{CollisionMap, Layer, TileType};
+use crate::enemy::{Flying, SpawningIn};
+use crate::lighting::LightSource;
+use crate::particles::components::ParticleEmitter;
+use crate::rendering::YSorted;
+use crate::state::GameplayEntity;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::logging::targets;
+
+/// Who fired a projectile determines which entities it can hit.
+#[derive(Component, Clone, Copy, Debug)]
+pub enum ProjectileOwner {
+    Player,
+    Enemy,
+}
+
+impl ProjectileOwner {
+    pub fn faction(self) -> Faction {
+        match self {
+            ProjectileOwner::Player => Faction::Player,
+            ProjectileOwner::Enemy => Faction::Monsters,
+        }
+    }
+
+    /// The other side, for parried projectiles.
+    pub fn reflected(self) -> Self {
+        match self {
+            ProjectileOwner::Player => ProjectileOwner::Enemy,
+            ProjectileOwner::Enemy => ProjectileOwner::Player,
+        }
+    }
+}
+
+/// Optional behaviors layered on top of the basic "fly straight, hit once" hitbox.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectileBehaviors {
+    /// Keeps flying after a hit; each target is only damaged once.
+    pub pierce: bool,
+    /// Despawns when it enters a non-walkable tile, other than the kind it
+    /// was fired over.
+    pub blocked_by_walls: bool,
+    /// Can hit the firer's own faction when `CombatSettings::friendly_fire` is on.
+    pub friendly_fire: bool,
+    /// Explodes on impact, damaging everything hittable within this radius.
+    pub blast_radius: Option<f32>,
+}
+
+/// Everything the hitbox needs, independent of what (if anything) it looks like.
+#[derive(Clone, Debug)]
+pub struct ProjectileSpec {
+    pub speed: f32,
+    /// Seconds before the projectile expires
+    pub lifetime: f32,
+    pub radius: f32,
+    pub damage: f32,
+    pub behaviors: ProjectileBehaviors,
+    pub inflicts: Option<BuffSpec>,
+}
+
+/// Invisible hitbox that travels and checks for collisions.
+#[derive(Component)]
+pub struct Projectile {
+    pub velocity: Vec3,
+    pub lifetime: f32,
+    pub radius: f32,
+    pub damage: f32,
+    pub behaviors: ProjectileBehaviors,
+    pub inflicts: Option<BuffSpec>,
+    /// Reported on hits (telemetry, resistances)
+    pub power_type: PowerId,
+    pub owner: ProjectileOwner,
+    /// Entity that fired it; never hit directly, only by its own blast
+    pub shooter: Entity,
+    /// Targets already damaged, so piercing projectiles hit each one once
+    pub hits: Vec<Entity>,
+    /// Flies on its shooter's layer: over a bridge or under it
+    pub layer: Layer,
+    /// Blocking tile it was fired over, like the water under a raft. It
+    /// flies on over that kind of tile instead of stopping at once
+    pub launched_over: Option<TileType>,
+}
+
+/// Marker for projectile effects
+#[derive(Component)]
+pub struct ProjectileEffect {
+    pub power_type: PowerId,
+}
+
+/// Spawns a projectile hitbox with no visuals. Pair with
+/// [`attach_projectile_visuals`] to make it visible.
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    spec: &ProjectileSpec,
+    position: Vec3,
+    direction: Vec3,
+    power_type: PowerId,
+    owner: ProjectileOwner,
+    shooter: Entity,
+) -> Entity {
+    commands
+        .spawn((
+            Projectile {
+                velocity: direction.normalize_or_zero() * spec.speed,
+                lifetime: spec.lifetime,
+                radius: spec.radius,
+                damage: spec.damage,
+                behaviors: spec.behaviors,
+                inflicts: spec.inflicts,
+                power_type,
+                owner,
+                shooter,
+                hits: Vec::new(),
+                layer: Layer::Ground,
+                launched_over: None,
+            },
+            Transform::from_translation(position),
+            YSorted::EFFECT,
+            GameplayEntity,
+        ))
+        .id()
+}
+
+/// Attaches particle emitters to a projectile. The emitters ride along as
+/// children, so they despawn with the projectile.
+pub fn attach_projectile_visuals(
+    commands: &mut Commands,
+    projectile: Entity,
+    position: Vec3,
+    power_type: &PowerId,
+    visuals: &PowerVisuals,
+) {
+    // GlobalTransform is set up front because they emit before propagation runs.
+    let global_transform = GlobalTransform::from(Transform::from_translation(position));
+
+    // Primary particles
+    let primary_emitter =
+        ParticleEmitter::new(0.016, visuals.particles_per_spawn, visuals.primary.clone())
+            .one_shot();
+
+    commands.spawn((
+        primary_emitter,
+        Transform::IDENTITY,
+        global_transform,
+        ProjectileEffect { power_type: power_type.clone() },
+        ChildOf(projectile),
+    ));
+
+    // Core particles (if the power has a core)
+    if let Some(ref core_config) = visuals.core {
+        let core_emitter =
+            ParticleEmitter::new(0.016, visuals.core_particles_per_spawn, core_config.clone())
+                .one_shot();
+
+        commands.spawn((
+            core_emitter,
+            Transform::IDENTITY,
+            global_transform,
+            ProjectileEffect { power_type: power_type.clone() },
+            ChildOf(projectile),
+        ));
+    }
+}
+
+/// Fires a registered power's projectile: its hitbox plus its particle visuals.
+pub fn fire_power(
+    commands: &mut Commands,
+    registry: &PowerRegistry,
+    power: &PowerId,
+    position: Vec3,
+    direction: Vec3,
+    owner: ProjectileOwner,
+    shooter: Entity,
+) -> Option<Entity> {
+    let Some(definition) = registry.get(power) else {
+        warn!(target: targets::COMBAT, "Tried to fire unknown power '{}'", power);
+        return None;
+    };
+
+    let projectile = spawn_projectile(
+        commands,
+        &definition.projectile_spec(),
+        position,
+        direction,
+        power.clone(),
+        owner,
+        shooter,
+    );
+    if let Some(visuals) = registry.visuals(definition, direction) {
+        attach_projectile_visuals(commands, projectile, position, power, &visuals);
+    }
+    if let Some(radius) = definition.light_radius {
+        commands
+            .entity(projectile)
+            .insert(LightSource::new(radius).with_flicker(0.15));
+    }
+    Some(projectile)
+}
+
+/// Puts freshly fired projectiles on their shooter's layer, and notes the
+/// blocking tile they were fired over, if any.
+pub fn inherit_shooter_layer(
+    mut projectiles: Query<(&mut Projectile, &Transform), Added<Projectile>>,
+    shooters: Query<&Layer>,
+    collision_map: Option<Res<CollisionMap>>,
+) {
+    for (mut projectile, transform) in &mut projectiles {
+        if let Ok(layer) = shooters.get(projectile.shooter) {
+            projectile.layer = *layer;
+        }
+        let Some(map) = collision_map.as_ref() else {
+            continue;
+        };
+        let position = transform.translation.truncate();
+        if !map.is_world_pos_walkable_on(position, projectile.layer) {
+            let cell = map.world_to_grid(position);
+            projectile.launched_over = map.get_tile(cell.x, cell.y);
+        }
+    }
+}
+
+/// Moves projectile hitboxes forward and despawns them on timeout.
+pub fn move_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut impacts: MessageWriter<ProjectileImpact>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut proj, mut transform) in projectiles.iter_mut() {
+        proj.lifetime -= dt;
+        if proj.lifetime <= 0.0 {
+            impacts.write(proj.impact(transform.translation));
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += proj.velocity * dt;
+
+        if proj.behaviors.blocked_by_walls
+            && collision_map
+                .as_ref()
+                .is_some_and(|map| proj.blocked_at(map, transform.translation.truncate()))
+        {
+            impacts.write(proj.impact(transform.translation));
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+impl Projectile {
+    /// Whether a wall at `position` stops it. The kind of tile it was fired
+    /// over doesn't, so shots from a raft cross the river.
+    fn blocked_at(&self, map: &CollisionMap, position: Vec2) -> bool {
+        if map.is_world_pos_walkable_on(position, self.layer) {
+            return false;
+        }
+        let cell = map.world_to_grid(position);
+        let tile = map.get_tile(cell.x, cell.y);
+        tile.is_none() || tile != self.launched_over
+    }
+
+    /// Whether a direct hit on `target` counts, per faction rules. Walkers
+    /// on the other layer are out of reach; flyers never are.
+    fn can_hit(
+        &self,
+        target: Entity,
+        faction: Faction,
+        height: TargetHeight,
+        hostility: &FactionHostility,
+        settings: &CombatSettings,
+    ) -> bool {
+        if target == self.shooter || self.hits.contains(&target) || !self.reaches(height) {
+            return false;
+        }
+        let own = self.owner.faction();
+        hostility.is_hostile(own, faction)
+            || (faction == own && self.behaviors.friendly_fire && settings.friendly_fire)
+    }
+
+    fn reaches(&self, (layer, flying): TargetHeight) -> bool {
+        flying || layer.copied().unwrap_or_default() == self.layer
+    }
+
+    fn impact(&self, position: Vec3) -> ProjectileImpact {
+        ProjectileImpact {
+            position,
+            power_type: self.power_type.clone(),
+        }
+    }
+}
+
+/// Layer (if the target has one) and whether it flies.
+type TargetHeight<'a> = (Option<&'a Layer>, bool);
+
+/// Turns a projectile around and hands it to the parrying entity's side.
+fn parry(
+    commands: &mut Commands,
+    registry: &PowerRegistry,
+    proj_entity: Entity,
+    proj: &mut Projectile,
+    parried_by: Entity,
+    position: Vec3,
+    back_to_shooter: Option<Vec3>,
+) {
+    let speed = proj.velocity.length();
+    let direction = back_to_shooter
+        .filter(|dir| *dir != Vec3::ZERO)
+        .unwrap_or(-proj.velocity.normalize_or_zero());
+
+    proj.velocity = direction * speed;
+    proj.owner = proj.owner.reflected();
+    proj.shooter = parried_by;
+    proj.hits.clear();
+
+    // The original particles flew off in the old direction
+    let visuals = registry
+        .get(&proj.power_type)
+        .and_then(|definition| registry.visuals(definition, direction));
+    if let Some(visuals) = visuals {
+        attach_projectile_visuals(commands, proj_entity, position, &proj.power_type, &visuals);
+    }
+    spawn_shield_burst(commands, position, ShieldFeedback::Parry);
+    debug!(target: targets::COMBAT, "Parried {} projectile", proj.power_type);
+}
+
+/// Checks each projectile hitbox against its valid targets; triggers hit events on collision.
+/// A target whose shield went up within the parry window sends the projectile back instead.
+#[allow(clippy::too_many_arguments)]
+pub fn check_projectile_hits(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectiles: Query<(Entity, &mut Projectile, &Transform)>,
+    targets: Query<
+        (Entity, &GlobalTransform, &Faction, Option<&Layer>, Has<Flying>),
+        (With<Health>, Without<SpawningIn>),
+    >,
+    shields: Query<&Shield>,
+    registry: Res<PowerRegistry>,
+    hostility: Res<FactionHostility>,
+    settings: Res<CombatSettings>,
+    mut hit_pause: ResMut<HitPause>,
+    mut impacts: MessageWriter<ProjectileImpact>,
+) {
+    let now = time.elapsed_secs();
+    for (proj_entity, mut proj, proj_transform) in &mut projectiles {
+        let proj_pos = proj_transform.translation;
+
+        let hit_target = targets
+            .iter()
+            .find(|(entity, t, faction, layer, flying)| {
+                proj.can_hit(*entity, **faction, (*layer, *flying), &hostility, &settings)
+                    && proj_pos.distance(t.translation()) <= proj.radius
+            })
+            .map(|(e, ..)| e);
+
+        let Some(target) = hit_target else {
+            continue;
+        };
+
+        if shields.get(target).is_ok_and(|shield| shield.can_parry(now)) {
+            let back_to_shooter = targets
+                .get(proj.shooter)
+                .ok()
+                .map(|(_, t, ..)| (t.translation() - proj_pos).normalize_or_zero());
+            parry(&mut commands, &registry, proj_entity, &mut proj, target, proj_pos, back_to_shooter);
+            hit_pause.trigger();
+            continue;
+        }
+
+        // A blast hits everything in range (possibly the shooter), otherwise just the target
+        let victims: Vec<Entity> = match proj.behaviors.blast_radius {
+            Some(blast_radius) => targets
+                .iter()
+                .filter(|(entity, t, faction, layer, flying)| {
+                    let hittable = proj.can_hit(*entity, **faction, (*layer, *flying), &hostility, &settings)
+                        || (*entity == proj.shooter && settings.self_damage);
+                    hittable && proj_pos.distance(t.translation()) <= blast_radius
+                })
+                .map(|(e, ..)| e)
+                .collect(),
+            None => vec![target],
+        };
+
+        for victim in victims {
+            // Trigger hit event instead of directly applying damage
+            commands.trigger(super::events::ProjectileHit {
+                target: victim,
+                damage: proj.damage,
+                power_type: proj.power_type.clone(),
+                inflicts: proj.inflicts,
+            });
+            proj.hits.push(victim);
+        }
+
+        if !proj.behaviors.pierce {
+            impacts.write(proj.impact(proj_pos));
+            commands.entity(proj_entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// src/combat/terrain.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::events::ProjectileImpact;
+use super::power_registry::PowerRegistry;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::terrain::*;
+use crate::lighting::LightSource;
+use crate::logging::targets;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
+
+/// What a power does to the tile it lands on. Set per power in powers.ron.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerrainReaction {
+    /// Sets grass alight
+    Ignite,
+    /// Poisons water
+    Contaminate,
+    /// Dims lighting around the impact, whatever the tile
+    Darken,
+}
+
+impl TerrainReaction {
+    fn applies_to(self, tile: TileType) -> bool {
+        match self {
+            TerrainReaction::Ignite => matches!(tile, TileType::Grass | TileType::YellowGrass),
+            TerrainReaction::Contaminate => matches!(tile, TileType::Water | TileType::Shore),
+            TerrainReaction::Darken => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardKind {
+    BurningGround,
+    ToxicPool,
+}
+
+impl HazardKind {
+    /// Hazard tile this hurts like, which decides who is immune to it:
+    /// whatever wades through lava walks through fire too.
+    pub fn tile(self) -> TileType {
+        match self {
+            HazardKind::BurningGround => TileType::Lava,
+            HazardKind::ToxicPool => TileType::ToxicPool,
+        }
+    }
+}
+
+/// A patch of ground that damages every `Health` owner standing in it, dealt
+/// along with the hazard tiles by `hazard_contact_damage`.
+#[derive(Component, Debug)]
+pub struct GroundHazard {
+    pub kind: HazardKind,
+    /// Grid cell it sits on; a second impact on the same cell refreshes it
+    pub cell: IVec2,
+    pub radius: f32,
+    pub damage_per_second: f32,
+    pub remaining: f32,
+}
+
+/// Area where light is dimmed by `strength` (0..1) until it fades.
+#[derive(Component, Debug)]
+pub struct DimZone {
+    pub radius: f32,
+    pub strength: f32,
+    pub remaining: f32,
+}
+
+/// Turns projectile impacts into burning grass, toxic pools and shadow.
+pub fn terrain_reaction(
+    mut commands: Commands,
+    mut impacts: MessageReader<ProjectileImpact>,
+    registry: Res<PowerRegistry>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut hazards: Query<&mut GroundHazard>,
+) {
+    let Some(map) = collision_map else {
+        impacts.clear();
+        return;
+    };
+
+    for impact in impacts.read() {
+        let Some(reaction) = registry
+            .get(&impact.power_type)
+            .and_then(|definition| definition.terrain_reaction)
+        else {
+            continue;
+        };
+
+        let cell = map.world_to_grid(impact.position.truncate());
+        let Some(tile) = map.get_tile(cell.x, cell.y) else {
+            continue;
+        };
+        if !reaction.applies_to(tile) {
+            continue;
+        }
+
+        let center = map.grid_to_world(cell.x, cell.y).extend(impact.position.z);
+        match reaction {
+            TerrainReaction::Ignite => spawn_or_refresh_hazard(
+                &mut commands,
+                &mut hazards,
+                HazardKind::BurningGround,
+                cell,
+                center,
+            ),
+            TerrainReaction::Contaminate => spawn_or_refresh_hazard(
+                &mut commands,
+                &mut hazards,
+                HazardKind::ToxicPool,
+                cell,
+                center,
+            ),
+            TerrainReaction::Darken => {
+                commands
+                    .spawn((
+                        DimZone {
+                            radius: SHADOW_DIM_RADIUS,
+                            strength: SHADOW_DIM_STRENGTH,
+                            remaining: SHADOW_DIM_SECS,
+                        },
+                        Transform::from_translation(impact.position),
+                        GameplayEntity,
+                    ))
+                    .with_child((
+                        ParticleEmitter::new(0.15, 2, shadow_particles()),
+                        Transform::IDENTITY,
+                        GlobalTransform::from(Transform::from_translation(impact.position)),
+                    ));
+            }
+        }
+    }
+}
+
+fn spawn_or_refresh_hazard(
+    commands: &mut Commands,
+    hazards: &mut Query<&mut GroundHazard>,
+    kind: HazardKind,
+    cell: IVec2,
+    position: Vec3,
+) {
+    let (lifetime, damage_per_second) = match kind {
+        HazardKind::BurningGround => (BURNING_GROUND_SECS, BURNING_GROUND_DPS),
+        HazardKind::ToxicPool => (TOXIC_POOL_SECS, TOXIC_POOL_DPS),
+    };
+
+    if let Some(mut hazard) = hazards
+        .iter_mut()
+        .find(|hazard| hazard.kind == kind && hazard.cell == cell)
+    {
+        hazard.remaining = lifetime;
+        return;
+    }
+
+    debug!(target: targets::COMBAT, "{:?} at {:?}", kind, cell);
+    let mut hazard = commands.spawn((
+        GroundHazard {
+            kind,
+            cell,
+            radius: HAZARD_RADIUS,
+            damage_per_second,
+            remaining: lifetime,
+        },
+        Transform::from_translation(position),
+        GameplayEntity,
+    ));
+    if kind == HazardKind::BurningGround {
+        hazard.insert(LightSource::new(HAZARD_RADIUS * 3.0).with_flicker(0.3));
+    }
+    hazard.with_child((
+        ParticleEmitter::new(0.1, 2, hazard_particles(kind)),
+        Transform::IDENTITY,
+        GlobalTransform::from(Transform::from_translation(position)),
+    ));
+}
+
+fn hazard_particles(kind: HazardKind) -> ParticleConfig {
+    let (color, speed) = match kind {
+        HazardKind::BurningGround => (Color::srgb(3.0, 0.6, 0.1), 40.0),
+        HazardKind::ToxicPool => (Color::srgb(0.4, 2.0, 0.3), 15.0),
+    };
+    ParticleConfig {
+        lifetime: 0.8,
+        lifetime_variance: 0.3,
+        speed,
+        speed_variance: 10.0,
+        direction: Vec3::Y,
+        direction_variance: 0.4,
+        scale: 1.0,
+        scale_variance: 0.3,
+        color,
+        emission_shape: EmissionShape::Circle { radius: HAZARD_RADIUS * 0.6 },
+        ..default()
+    }
+}
+
+fn shadow_particles() -> ParticleConfig {
+    ParticleConfig {
+        lifetime: 1.2,
+        lifetime_variance: 0.3,
+        speed: 10.0,
+        speed_variance: 5.0,
+        direction: Vec3::Y,
+        direction_variance: std::f32::consts::PI,
+        scale: 2.0,
+        scale_variance: 0.5,
+        color: Color::srgba(0.1, 0.0, 0.2, 0.6),
+        emission_shape: EmissionShape::Circle { radius: SHADOW_DIM_RADIUS * 0.5 },
+        ..default()
+    }
+}
+
+/// Clears out expired hazards and dim zones.
+pub fn tick_ground_hazards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut hazards: Query<(Entity, &mut GroundHazard)>,
+    mut dim_zones: Query<(Entity, &mut DimZone)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut hazard) in &mut hazards {
+        hazard.remaining -= dt;
+        if hazard.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (entity, mut zone) in &mut dim_zones {
+        zone.remaining -= dt;
+        if zone.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,4 +1,14 @@
+pub mod assets {
+    /// Folder the asset server loads from
+    pub const ASSET_DIR: &str = "src/assets";
+}
+
 pub mod player {
+    use bevy::math::Vec2;
+
+    /// Where a new story game places the player (nudged onto a walkable tile)
+    pub const SPAWN_POSITION: Vec2 = Vec2::ZERO;
+
     /// Collision radius for the player's collider (in world units)
     pub const COLLIDER_RADIUS: f32 = 24.0; // Line update alert
     
@@ -7,6 +17,11 @@ pub mod player {
     
     /// Visual scale of the player sprite
     pub const PLAYER_SCALE: f32 = 1.2; // Line update alert (was 0.8)
+
+    /// Seconds between character switches
+    pub const SWITCH_COOLDOWN_SECS: f32 = 4.0;
+    /// Invulnerability granted while the new character appears
+    pub const SWITCH_INVULNERABLE_SECS: f32 = 0.5;
 }
 
 pub mod pickup {
@@ -18,8 +33,37 @@ pub mod enemy {
     /// Z-position for enemy rendering (same as player for consistent layering)
     pub const ENEMY_Z_POSITION: f32 = 20.0;
 
-    /// Visual scale of enemy sprites (same as player for consistency)
-    pub const ENEMY_SCALE: f32 = 1.2;
+    /// How long the player must stay away from a cleared zone before it repopulates (seconds)
+    pub const RESPAWN_DELAY_SECS: f32 = 180.0;
+
+    /// Beyond this distance from the camera, enemies switch to reduced simulation
+    pub const AI_LOD_RADIUS: f32 = 1400.0;
+
+    /// How often distant enemies run their AI (seconds)
+    pub const AI_LOD_TICK_SECS: f32 = 0.25;
+
+    /// Off-screen enemies within this distance of the player get an edge arrow
+    pub const INDICATOR_RANGE: f32 = 900.0;
+    /// Gap between the arrows and the edge of the screen
+    pub const INDICATOR_EDGE_MARGIN: f32 = 24.0;
+    /// Above the darkness overlay so arrows stay visible at night
+    pub const INDICATOR_Z: f32 = 960.0;
+
+    /// Chasing enemies spread over this many spots on a ring around the player,
+    /// inside the usual attack range so they can shoot from there
+    pub const SQUAD_SLOTS: usize = 8;
+    pub const SQUAD_RING_RADIUS: f32 = 110.0;
+    /// An enemy this close to its slot stops and attacks
+    pub const SQUAD_SLOT_ARRIVE_RADIUS: f32 = 24.0;
+
+    /// Extra damage per enemy level above 1, as a fraction
+    pub const LEVEL_DAMAGE_STEP: f32 = 0.15;
+    /// Extra health per enemy level above 1, as a fraction
+    pub const LEVEL_HEALTH_STEP: f32 = 0.25;
+    /// Width in tiles of each difficulty ring around the player's spawn point.
+    /// Every ring out adds a level.
+    pub const DIFFICULTY_RING_TILES: f32 = 24.0;
+    pub const MAX_ENEMY_LEVEL: u32 = 10;
 } 
 
 /// Map/terrain configuration
@@ -43,6 +87,12 @@ pub mod map {
     
     /// Z-height of each layer (used for Y-based depth sorting)
     pub const NODE_SIZE_Z: f32 = 1.0; // Add this line
+
+    /// Cost of carving through a tree or rock to connect walkable regions
+    pub const CARVE_COST_PROP: u32 = 2;
+
+    /// Cost of carving through water; higher, as it cuts up lakes and rivers
+    pub const CARVE_COST_WATER: u32 = 5;
 }
 
 pub mod camera {
@@ -51,4 +101,352 @@ pub mod camera {
     
     /// Z position for the camera (must be high to see all layers)
     pub const CAMERA_Z: f32 = 1000.0;
-}
\ No newline at end of file
+
+    /// How far toward a locked-on enemy the camera aims (0 = player, 1 = enemy)
+    pub const LOCK_ON_CAMERA_BIAS: f32 = 0.35;
+}
+
+pub mod screen_effects {
+    /// Below this share of health the view gets a red vignette
+    pub const LOW_HEALTH_RATIO: f32 = 0.25;
+    /// Vignette heartbeats per second
+    pub const VIGNETTE_PULSE_HZ: f32 = 1.2;
+    pub const SCREEN_FLASH_SECS: f32 = 0.3;
+    /// Time for the pause desaturation to ease fully in or out
+    pub const DESATURATE_SECS: f32 = 0.4;
+    /// Time for the terrain colour grade to settle after the ground changes
+    pub const GRADE_BLEND_SECS: f32 = 2.0;
+    /// Over the world and its floating text, under the camera (CAMERA_Z)
+    pub const SCREEN_EFFECTS_Z: f32 = 960.0;
+}
+
+pub mod rumble {
+    /// Rumble strength is capped here; full strength on cheap pads is harsh
+    pub const MAX_RUMBLE: f32 = 0.8;
+    /// Damage to the player that rumbles at full strength
+    pub const FULL_RUMBLE_DAMAGE: f32 = 40.0;
+    pub const DAMAGE_RUMBLE_SECS: f32 = 0.25;
+    /// Strength of the kick from a shot, per point of the power's damage
+    pub const SHOT_RUMBLE_PER_DAMAGE: f32 = 0.01;
+    pub const SHOT_RUMBLE_SECS: f32 = 0.08;
+    /// Blasts further than this from the player aren't felt
+    pub const BLAST_RUMBLE_RANGE: f32 = 600.0;
+    pub const BLAST_RUMBLE_SECS: f32 = 0.35;
+}
+
+pub mod screen_shake {
+    /// Furthest the view is thrown at full trauma, in pixels
+    pub const MAX_SHAKE_OFFSET: f32 = 10.0;
+    /// Trauma lost per second; shake strength goes with its square
+    pub const SHAKE_DECAY: f32 = 1.5;
+    /// Damage to the player that adds full trauma
+    pub const FULL_SHAKE_DAMAGE: f32 = 40.0;
+    /// Blasts further than this from the player don't shake the view
+    pub const BLAST_SHAKE_RANGE: f32 = 600.0;
+}
+
+pub mod accessibility {
+    /// Settings steps for the reduced-motion multipliers, 0 turns an effect off
+    pub const MOTION_STEPS: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+    /// UI text size steps, kept small enough that menu buttons still fit
+    pub const TEXT_SCALES: [f32; 3] = [1.0, 1.15, 1.3];
+    /// Dyslexia-friendly font (OpenDyslexic, SIL Open Font License). It isn't
+    /// shipped, so the option only shows once it's copied into the assets
+    pub const DYSLEXIC_FONT_PATH: &str = "fonts/OpenDyslexic-Regular.otf";
+}
+
+pub mod combat {
+    /// How long a hit-pause lasts, in real (unscaled) seconds
+    pub const HIT_PAUSE_SECS: f32 = 0.04;
+
+    /// Virtual time speed during a hit-pause
+    pub const HIT_PAUSE_SCALE: f32 = 0.1;
+
+    /// Damage to the player at or above this counts as a heavy hit
+    pub const HEAVY_HIT_DAMAGE: f32 = 25.0;
+
+    /// Share of incoming damage a raised shield absorbs
+    pub const BLOCK_DAMAGE_REDUCTION: f32 = 0.7;
+    /// Stamina per second spent holding the shield up
+    pub const BLOCK_STAMINA_DRAIN: f32 = 15.0;
+    /// Stamina spent per point of damage absorbed
+    pub const BLOCK_HIT_STAMINA_COST: f32 = 1.0;
+    /// Raising the shield this soon before an impact reflects the projectile
+    pub const PARRY_WINDOW_SECS: f32 = 0.15;
+
+    pub const STAMINA_MAX: f32 = 100.0;
+    /// Stamina regained per second once the shield is down
+    pub const STAMINA_REGEN: f32 = 30.0;
+    /// Seconds after spending stamina before it starts to regenerate
+    pub const STAMINA_REGEN_DELAY: f32 = 0.8;
+
+    /// Colliders within (sum of radii * this) count as touching; boxes use
+    /// their shorter half side as the radius. Movement keeps
+    /// them from truly overlapping, so this has to be a little over 1.
+    pub const CONTACT_RANGE_MARGIN: f32 = 1.2;
+    pub const KNOCKBACK_SPEED: f32 = 350.0;
+    pub const KNOCKBACK_SECS: f32 = 0.15;
+    /// Invulnerability window after the player takes a hit
+    pub const INVULNERABLE_SECS: f32 = 0.6;
+    /// Sprite blinks per second while invulnerable
+    pub const INVULNERABLE_FLICKER_HZ: f32 = 10.0;
+    /// How long a hit sprite takes to fade back from white
+    pub const HIT_FLASH_SECS: f32 = 0.15;
+
+    /// Tab only locks onto enemies this close; the lock breaks past the second range
+    pub const LOCK_ON_RANGE: f32 = 400.0;
+    pub const LOCK_ON_BREAK_RANGE: f32 = 550.0;
+
+    /// Familiars circle the player at this distance and shoot enemies within range
+    pub const FAMILIAR_ORBIT_RADIUS: f32 = 40.0;
+    pub const FAMILIAR_RANGE: f32 = 300.0;
+    pub const FAMILIAR_FIRE_INTERVAL: f32 = 1.2;
+
+    /// Seconds a dead enemy's corpse takes to fade away
+    pub const CORPSE_SECS: f32 = 3.0;
+}
+
+pub mod healing {
+    /// Out-of-combat regeneration, in health per second
+    pub const REGEN_RATE: f32 = 2.0;
+    /// Seconds without taking damage before regeneration starts
+    pub const REGEN_DELAY_SECS: f32 = 5.0;
+    /// Regen is applied in chunks this far apart, each a `Heal` event
+    pub const REGEN_TICK_SECS: f32 = 1.0;
+
+    /// Healing orbs dropped by enemies
+    pub const PICKUP_HEAL: f32 = 25.0;
+    pub const PICKUP_DROP_CHANCE: f64 = 0.25;
+    pub const PICKUP_RADIUS: f32 = 24.0;
+    /// Seconds before an uncollected orb fades away
+    pub const PICKUP_LIFETIME: f32 = 20.0;
+
+    /// Temporary max health lost per second once granted
+    pub const BONUS_HEALTH_DECAY: f32 = 1.0;
+}
+
+pub mod buffs {
+    /// Seconds a shrine buff lasts
+    pub const SHRINE_BUFF_SECS: f32 = 30.0;
+    /// Seconds before a used shrine can be used again
+    pub const SHRINE_COOLDOWN_SECS: f32 = 90.0;
+    pub const SHRINE_INTERACT_RADIUS: f32 = 40.0;
+
+    /// Shrines placed per map, at least this far apart and from the player's spawn
+    pub const SHRINE_COUNT: usize = 6;
+    pub const SHRINE_MIN_SPACING: f32 = 400.0;
+    pub const SHRINE_SAFE_RADIUS: f32 = 200.0;
+
+    /// Extra damage per stack, as a fraction
+    pub const DAMAGE_BUFF: f32 = 0.25;
+    pub const MAX_DAMAGE_STACKS: u32 = 3;
+    /// Extra movement speed, as a fraction
+    pub const SPEED_BUFF: f32 = 0.3;
+    /// Health per second
+    pub const REGEN_BUFF: f32 = 3.0;
+
+    pub const MAX_BURNING_STACKS: u32 = 3;
+    /// Slows can't take movement below this fraction of normal speed
+    pub const MIN_SPEED_MULTIPLIER: f32 = 0.3;
+}
+
+pub mod waypoints {
+    /// Waypoints clear once the player is this close
+    pub const ARRIVE_RADIUS: f32 = 48.0;
+    /// Above the lighting overlay and floating text, below the edge indicators
+    pub const WAYPOINT_Z: f32 = 955.0;
+}
+
+pub mod checkpoints {
+    pub const CHECKPOINT_INTERACT_RADIUS: f32 = 40.0;
+    /// Checkpoints placed per map, at least this far apart and from the player's spawn
+    pub const CHECKPOINT_COUNT: usize = 4;
+    pub const CHECKPOINT_MIN_SPACING: f32 = 600.0;
+    pub const CHECKPOINT_SAFE_RADIUS: f32 = 300.0;
+    /// Share of max health the player comes back with
+    pub const RESPAWN_HEALTH_FRACTION: f32 = 0.5;
+    /// Grace period after respawning, so nearby enemies can't finish the job
+    pub const RESPAWN_INVULNERABLE_SECS: f32 = 2.0;
+}
+
+pub mod fast_travel {
+    pub const OBELISK_INTERACT_RADIUS: f32 = 48.0;
+    /// One obelisk per name on generated maps, at least this far apart
+    pub const OBELISK_NAMES: [&str; 5] =
+        ["Ashen Spire", "Moonwell", "Old Crossing", "Raven Rock", "Sunken Gate"];
+    pub const OBELISK_MIN_SPACING: f32 = 1200.0;
+    /// Travellers arrive this far below an obelisk, in front of it
+    pub const ARRIVAL_OFFSET: f32 = 56.0;
+}
+
+pub mod terrain {
+    /// Radius of burning ground and toxic pools, about one tile
+    pub const HAZARD_RADIUS: f32 = 32.0;
+
+    pub const BURNING_GROUND_SECS: f32 = 4.0;
+    pub const BURNING_GROUND_DPS: f32 = 10.0;
+
+    pub const TOXIC_POOL_SECS: f32 = 6.0;
+    pub const TOXIC_POOL_DPS: f32 = 6.0;
+
+    /// Shadow impacts dim lights within this radius
+    pub const SHADOW_DIM_RADIUS: f32 = 96.0;
+    /// How much light is taken away at the centre (0..1)
+    pub const SHADOW_DIM_STRENGTH: f32 = 0.6;
+    pub const SHADOW_DIM_SECS: f32 = 3.0;
+
+    /// Damage per second from hazard tiles
+    pub const LAVA_DPS: f32 = 30.0;
+    pub const SPIKES_DPS: f32 = 15.0;
+
+    /// Chance for a dirt tile to become spikes or lava, and a shore tile a toxic pool
+    pub const SPIKES_CHANCE: f64 = 0.01;
+    pub const LAVA_CHANCE: f64 = 0.004;
+    pub const TOXIC_POOL_CHANCE: f64 = 0.05;
+}
+
+pub mod world_events {
+    /// Hours of the in-game clock: a night's event is announced at dusk,
+    /// starts at nightfall and is survived at dawn
+    pub const ANNOUNCE_HOUR: f32 = 18.0;
+    pub const NIGHTFALL_HOUR: f32 = 20.0;
+    pub const DAWN_HOUR: f32 = 5.0;
+    /// Every this many nights is a horde night; other nights on the blood
+    /// moon's cycle get a blood moon
+    pub const HORDE_NIGHT_EVERY: u32 = 3;
+    pub const BLOOD_MOON_EVERY: u32 = 2;
+
+    /// Horde batches keep coming until this many horde enemies are alive
+    pub const HORDE_SPAWN_INTERVAL: f32 = 6.0;
+    pub const HORDE_BATCH: usize = 3;
+    pub const HORDE_MAX_ALIVE: usize = 18;
+    /// Horde enemies spawn off-screen and always know where the player is
+    pub const HORDE_SPAWN_DISTANCE_MIN: f32 = 700.0;
+    pub const HORDE_SPAWN_DISTANCE_MAX: f32 = 1000.0;
+    pub const HORDE_DETECTION_RANGE: f32 = 3000.0;
+
+    /// Buffs the blood moon keeps topped up on every enemy, as fractions
+    pub const BLOOD_MOON_DAMAGE: f32 = 0.25;
+    pub const BLOOD_MOON_SPEED: f32 = 0.2;
+    /// They wear off this long after dawn
+    pub const BLOOD_MOON_BUFF_SECS: f32 = 10.0;
+
+    /// Seconds the "survived" banner stays up
+    pub const RECAP_SECS: f32 = 6.0;
+}
+
+pub mod lighting {
+    /// Real seconds for a full in-game day at 1x speed
+    pub const DAY_LENGTH_SECS: f32 = 600.0;
+    /// Hour a new game starts at
+    pub const START_HOUR: f32 = 8.0;
+
+    /// Darkness at midnight outdoors and anytime in caves (0..1)
+    pub const NIGHT_DARKNESS: f32 = 0.85;
+    pub const CAVE_DARKNESS: f32 = 0.95;
+
+    pub const PLAYER_LIGHT_RADIUS: f32 = 160.0;
+    /// Cap on the radius items can add to the player's light
+    pub const MAX_LIGHT_BONUS: f32 = 160.0;
+    pub const TORCH_LIGHT_RADIUS: f32 = 200.0;
+
+    /// Lights sent to the shader; must match MAX_LIGHTS in lighting.wgsl
+    pub const MAX_LIGHTS: usize = 64;
+
+    /// The darkness quad covers the view at any sensible zoom
+    pub const OVERLAY_SIZE: f32 = 8192.0;
+    /// Above the world, below the camera (CAMERA_Z)
+    pub const OVERLAY_Z: f32 = 900.0;
+    /// Light glows draw on top of the darkness
+    pub const GLOW_Z: f32 = 901.0;
+    /// Glow opacity at full darkness
+    pub const GLOW_ALPHA: f32 = 0.25;
+}
+
+pub mod ambience {
+    /// Looping layers, not shipped with the game; any that are missing from
+    /// the assets folder are left out
+    pub const BIRDS_TRACK: &str = "ambience/birds.ogg";
+    pub const CRICKETS_TRACK: &str = "ambience/crickets.ogg";
+    pub const WATER_TRACK: &str = "ambience/water.ogg";
+    pub const WIND_TRACK: &str = "ambience/wind.ogg";
+
+    pub const AMBIENCE_VOLUME: f32 = 0.4;
+    /// Time for a layer to fade fully in or out, long enough that walking
+    /// along a shore doesn't flicker between water and forest
+    pub const AMBIENCE_CROSSFADE_SECS: f32 = 3.0;
+    /// How loud crickets get by water or bare rock, next to the forest's
+    pub const NIGHT_UNDERLAY: f32 = 0.5;
+}
+
+pub mod music {
+    /// Looping layers, started together so they stay in time while they
+    /// crossfade. Not shipped with the game; any that are missing from the
+    /// assets folder are left out
+    pub const EXPLORATION_TRACK: &str = "music/exploration.ogg";
+    pub const COMBAT_TRACK: &str = "music/combat.ogg";
+    pub const BOSS_TRACK: &str = "music/boss.ogg";
+    /// One-shots over the layers, also optional
+    pub const WAVE_CLEAR_STINGER: &str = "music/wave_clear.ogg";
+    pub const DEATH_STINGER: &str = "music/death.ogg";
+
+    pub const MUSIC_VOLUME: f32 = 0.6;
+    pub const STINGER_VOLUME: f32 = 0.8;
+    /// Time for a layer to fade fully in or out
+    pub const CROSSFADE_SECS: f32 = 1.5;
+    /// Aggroed enemies that bring the combat layer up to full volume
+    pub const FULL_COMBAT_ENEMIES: usize = 4;
+    /// The combat layer never drops below this while a fight is on
+    pub const MIN_COMBAT_INTENSITY: f32 = 0.5;
+    /// Quiet spell before the music settles back to exploring, so a fight
+    /// that drifts in and out of range doesn't flip it back and forth
+    pub const COMBAT_COOLDOWN_SECS: f32 = 6.0;
+    /// How long a sprung ambush keeps the combat layer up
+    pub const AMBUSH_HINT_SECS: f32 = 10.0;
+}
+
+
+pub mod props {
+    use bevy::math::Vec2;
+
+    /// Mass the player pushes with; a prop of equal mass moves at half their speed
+    pub const PUSHER_MASS: f32 = 10.0;
+    pub const CRATE_MASS: f32 = 10.0;
+    pub const BARREL_MASS: f32 = 6.0;
+    /// Share of a sliding prop's speed lost per second once nobody pushes it
+    pub const PROP_FRICTION: f32 = 8.0;
+    /// Contact margin (see `Collider::contact_gap`) at which the player pushes;
+    /// a little wider than the one that stops them, so a blocked push still lands
+    pub const PUSH_CONTACT_MARGIN: f32 = 0.15;
+
+    /// Rafts are three tiles wide and two deep
+    pub const PLATFORM_HALF_SIZE: Vec2 = Vec2::new(48.0, 32.0);
+    pub const PLATFORM_SPEED: f32 = 60.0;
+    /// Pause at each waypoint, long enough to get on or off
+    pub const PLATFORM_DOCK_SECS: f32 = 2.0;
+}
+
+pub mod video {
+    /// Window sizes offered on the video page, in windowed mode
+    pub const WINDOWED_RESOLUTIONS: [(u32, u32); 5] = [
+        (1280, 720),
+        (1366, 768),
+        (1600, 900),
+        (1920, 1080),
+        (2560, 1440),
+    ];
+    /// Frame rate caps offered on the video page, after "Off"
+    pub const FPS_CAPS: [u32; 4] = [30, 60, 120, 144];
+    /// Update rate while the window is in the background, with throttling on
+    pub const BACKGROUND_FPS: f32 = 10.0;
+}
+
+pub mod demo {
+    /// Time without input on the main menu before the attract demo starts
+    pub const ATTRACT_IDLE_SECS: f32 = 30.0;
+    /// File in the data directory that `demo_record` writes and attract
+    /// mode plays back
+    pub const DEMO_FILE: &str = "attract.demo.ron";
+    /// The demo that ships with the game, used when none has been recorded
+    pub const BUNDLED_DEMO: &str = "demos/attract.demo.ron";
+}
@@ -0,0 +1,98 @@
+// src/console/commands.rs
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use super::DevConsole;
+
+/// Output of a console command: a message on success, an error otherwise.
+pub type ConsoleResult = Result<String, String>;
+
+/// Signature of a console command handler. Receives the arguments after the command name.
+pub type ConsoleCommandFn = fn(&mut World, &[&str]) -> ConsoleResult;
+
+#[derive(Clone, Copy)]
+pub struct ConsoleCommand {
+    pub help: &'static str,
+    pub run: ConsoleCommandFn,
+}
+
+/// Registry of all console commands, keyed by name.
+#[derive(Resource, Default)]
+pub struct ConsoleCommands {
+    commands: BTreeMap<&'static str, ConsoleCommand>,
+}
+
+impl ConsoleCommands {
+    pub fn register(&mut self, name: &'static str, help: &'static str, run: ConsoleCommandFn) {
+        if self.commands.insert(name, ConsoleCommand { help, run }).is_some() {
+            warn!("Console command '{}' registered twice", name);
+        }
+    }
+}
+
+pub trait ConsoleAppExt {
+    fn add_console_command(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        run: ConsoleCommandFn,
+    ) -> &mut Self;
+}
+
+impl ConsoleAppExt for App {
+    fn add_console_command(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        run: ConsoleCommandFn,
+    ) -> &mut Self {
+        self.init_resource::<ConsoleCommands>();
+        self.world_mut()
+            .resource_mut::<ConsoleCommands>()
+            .register(name, help, run);
+        self
+    }
+}
+
+/// Runs every line submitted since last frame.
+pub fn execute_pending_commands(world: &mut World) {
+    let pending = std::mem::take(&mut world.resource_mut::<DevConsole>().pending);
+
+    for line in pending {
+        let mut parts = line.split_whitespace();
+        let Some(name) = parts.next() else {
+            continue;
+        };
+        let args: Vec<&str> = parts.collect();
+
+        let command = world.resource::<ConsoleCommands>().commands.get(name).copied();
+        let output = match command {
+            Some(command) => (command.run)(world, &args),
+            None => Err(format!("Unknown command '{}' (try 'help')", name)),
+        };
+
+        let mut console = world.resource_mut::<DevConsole>();
+        console.print(format!("> {}", line));
+        match output {
+            Ok(message) if message.is_empty() => {}
+            Ok(message) => console.print(message),
+            Err(message) => console.print(format!("error: {}", message)),
+        }
+    }
+}
+
+pub fn help(world: &mut World, _args: &[&str]) -> ConsoleResult {
+    let lines: Vec<String> = world
+        .resource::<ConsoleCommands>()
+        .commands
+        .iter()
+        .map(|(name, command)| format!("{} — {}", name, command.help))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+pub fn clear(world: &mut World, _args: &[&str]) -> ConsoleResult {
+    world.resource_mut::<DevConsole>().output.clear();
+    Ok(String::new())
+}
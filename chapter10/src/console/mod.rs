@@ -0,0 +1,50 @@
+// src/console/mod.rs
+mod commands;
+mod ui;
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+
+pub use commands::{ConsoleAppExt, ConsoleCommands, ConsoleResult};
+
+/// Number of output lines kept in the console scrollback.
+const MAX_OUTPUT_LINES: usize = 12;
+
+/// In-game developer console, toggled with the backquote key.
+///
+/// Other plugins register commands with `app.add_console_command(...)`.
+#[derive(Resource, Default)]
+pub struct DevConsole {
+    pub open: bool,
+    pub input: String,
+    pub output: Vec<String>,
+    /// Submitted lines waiting to be executed
+    pending: Vec<String>,
+}
+
+impl DevConsole {
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.output.push(line.into());
+        let overflow = self.output.len().saturating_sub(MAX_OUTPUT_LINES);
+        self.output.drain(..overflow);
+    }
+}
+
+/// Run condition: true while the console is closed (gameplay input is live).
+pub fn console_closed(console: Res<DevConsole>) -> bool {
+    !console.open
+}
+
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevConsole>()
+            .init_resource::<ConsoleCommands>()
+            .add_console_command("help", "List available commands", commands::help)
+            .add_console_command("clear", "Clear console output", commands::clear)
+            // Read typing right after input is collected, so gameplay never sees it
+            .add_systems(PreUpdate, ui::handle_console_input.after(InputSystems))
+            .add_systems(Update, (commands::execute_pending_commands, ui::sync_console_ui).chain());
+    }
+}
@@ -0,0 +1,107 @@
+// src/console/ui.rs
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::DevConsole;
+
+#[derive(Component)]
+pub struct ConsoleUi;
+
+#[derive(Component)]
+pub struct ConsoleText;
+
+/// Toggles the console and, while open, turns keyboard input into console text.
+/// Keys are consumed so gameplay systems don't react to typing.
+pub fn handle_console_input(
+    mut console: ResMut<DevConsole>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut keyboard_events: MessageReader<KeyboardInput>,
+) {
+    if keys.just_pressed(KeyCode::Backquote) {
+        console.open = !console.open;
+        keys.reset_all();
+        keyboard_events.clear();
+        return;
+    }
+
+    if !console.open {
+        keyboard_events.clear();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.pending.push(line);
+                }
+            }
+            Key::Escape => console.open = false,
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+
+    keys.reset_all();
+}
+
+/// Spawns, updates, and removes the console overlay to match `DevConsole`.
+pub fn sync_console_ui(
+    mut commands: Commands,
+    console: Res<DevConsole>,
+    existing: Query<Entity, With<ConsoleUi>>,
+    mut texts: Query<&mut Text, With<ConsoleText>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    if !console.open {
+        for entity in existing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let content = format!("{}\n> {}_", console.output.join("\n"), console.input);
+
+    if let Ok(mut text) = texts.single_mut() {
+        **text = content;
+        return;
+    }
+
+    commands
+        .spawn((
+            ConsoleUi,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            GlobalZIndex(200),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ConsoleText,
+                Text::new(content),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 1.0, 0.7)),
+            ));
+        });
+}
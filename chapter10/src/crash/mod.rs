@@ -0,0 +1,205 @@
+// src/crash/mod.rs
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future::poll_once, AsyncComputeTaskPool, Task};
+
+use crate::logging::logs_directory;
+use crate::map::generate::WorldGenSettings;
+use crate::save::data::{emergency_meta_path, emergency_save_path, encode_save, EncodedSave};
+use crate::save::ui::{save_timestamp, WorldSnapshot};
+use crate::schedule::world_ready;
+use crate::state::{GameMode, GameState};
+use crate::telemetry::GameplayEvent;
+
+/// How often the emergency snapshot is refreshed while playing.
+const SNAPSHOT_INTERVAL_SECS: f32 = 20.0;
+/// Telemetry events kept for the crash report.
+const RECENT_EVENT_COUNT: usize = 32;
+
+/// State the panic hook can read without access to the `World`.
+struct CrashContext {
+    seed: Option<u64>,
+    snapshot: Option<EncodedSave>,
+    recent_events: VecDeque<String>,
+}
+
+static CRASH_CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext {
+    seed: None,
+    snapshot: None,
+    recent_events: VecDeque::new(),
+});
+
+/// Never blocks: a panic on a thread that holds the lock must not deadlock the hook.
+fn context() -> Option<MutexGuard<'static, CrashContext>> {
+    match CRASH_CONTEXT.try_lock() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+#[derive(Resource)]
+struct SnapshotTimer(Timer);
+
+/// Snapshot being encoded on the task pool, kept off the main thread like
+/// the saves are.
+#[derive(Resource)]
+struct SnapshotTask(Task<Result<EncodedSave, String>>);
+
+/// Installs a panic hook that writes an emergency save from the latest
+/// snapshot and a crash report next to the logs.
+pub struct CrashPlugin;
+
+impl Plugin for CrashPlugin {
+    fn build(&self, app: &mut App) {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            handle_panic(info);
+            previous_hook(info);
+        }));
+
+        app.insert_resource(SnapshotTimer(Timer::from_seconds(
+            SNAPSHOT_INTERVAL_SECS,
+            TimerMode::Repeating,
+        )))
+        .add_systems(OnEnter(GameState::MainMenu), clear_snapshot)
+        .add_systems(OnEnter(GameState::Loading), clear_snapshot)
+        .add_systems(
+            Update,
+            (
+                record_seed.run_if(resource_changed::<WorldGenSettings>),
+                record_recent_events,
+                capture_snapshot
+                    .run_if(world_ready)
+                    .run_if(resource_equals(GameMode::Story)),
+                poll_snapshot_task.run_if(resource_exists::<SnapshotTask>),
+            ),
+        );
+    }
+}
+
+fn record_seed(settings: Res<WorldGenSettings>) {
+    if let Some(mut context) = context() {
+        context.seed = Some(settings.seed);
+    }
+}
+
+fn record_recent_events(mut events: MessageReader<GameplayEvent>, time: Res<Time>) {
+    if events.is_empty() {
+        return;
+    }
+    let Some(mut context) = context() else {
+        return;
+    };
+
+    for event in events.read() {
+        context
+            .recent_events
+            .push_back(format!("[{:>8.2}s] {:?}", time.elapsed_secs(), event));
+        if context.recent_events.len() > RECENT_EVENT_COUNT {
+            context.recent_events.pop_front();
+        }
+    }
+}
+
+fn clear_snapshot(mut commands: Commands) {
+    // Dropping the task cancels it, so a stale snapshot can't land later
+    commands.remove_resource::<SnapshotTask>();
+    if let Some(mut context) = context() {
+        context.snapshot = None;
+    }
+}
+
+fn capture_snapshot(
+    mut commands: Commands,
+    mut timer: ResMut<SnapshotTimer>,
+    time: Res<Time>,
+    in_flight: Option<Res<SnapshotTask>>,
+    snapshot: WorldSnapshot,
+) {
+    timer.0.tick(time.delta());
+    if !timer.0.just_finished() || in_flight.is_some() {
+        return;
+    }
+
+    // No player yet (or mid-load): keep the previous snapshot
+    let Ok(data) = snapshot.capture("Emergency save".into(), save_timestamp()) else {
+        return;
+    };
+    let task = AsyncComputeTaskPool::get().spawn(async move { encode_save(&data) });
+    commands.insert_resource(SnapshotTask(task));
+}
+
+fn poll_snapshot_task(mut commands: Commands, mut snapshot: ResMut<SnapshotTask>) {
+    let Some(encoded) = block_on(poll_once(&mut snapshot.0)) else {
+        return;
+    };
+    commands.remove_resource::<SnapshotTask>();
+    if let (Ok(encoded), Some(mut context)) = (encoded, context()) {
+        context.snapshot = Some(encoded);
+    }
+}
+
+fn handle_panic(info: &PanicHookInfo) {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "<non-string panic payload>".into());
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "<unknown>".into());
+    let thread = std::thread::current().name().unwrap_or("<unnamed>").to_string();
+
+    let mut report = String::new();
+    let _ = writeln!(report, "Crash report — {}", chrono::Local::now().to_rfc3339());
+    let _ = writeln!(report, "Thread: {}", thread);
+    let _ = writeln!(report, "Panic: {}", message);
+    let _ = writeln!(report, "Location: {}", location);
+
+    match context() {
+        Some(context) => {
+            match context.seed {
+                Some(seed) => {
+                    let _ = writeln!(report, "World seed: {}", seed);
+                }
+                None => {
+                    let _ = writeln!(report, "World seed: <none>");
+                }
+            }
+
+            let save_status = match &context.snapshot {
+                Some(snapshot) => match snapshot.write_to(&emergency_save_path(), &emergency_meta_path()) {
+                    Ok(()) => format!("written to {:?}", emergency_save_path()),
+                    Err(e) => format!("failed: {}", e),
+                },
+                None => "no snapshot available".into(),
+            };
+            let _ = writeln!(report, "Emergency save: {}", save_status);
+
+            let _ = writeln!(report, "\nRecent events (oldest first):");
+            for event in &context.recent_events {
+                let _ = writeln!(report, "  {}", event);
+            }
+        }
+        None => {
+            let _ = writeln!(report, "Crash context locked by the panicking thread; no save written");
+        }
+    }
+
+    let path = logs_directory().join(format!(
+        "crash_{}.txt",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    ));
+    let written = std::fs::create_dir_all(logs_directory()).and_then(|_| std::fs::write(&path, &report));
+    match written {
+        Ok(()) => eprintln!("Crash report written to {:?}", path),
+        Err(e) => eprintln!("Failed to write crash report: {}\n{}", e, report),
+    }
+}
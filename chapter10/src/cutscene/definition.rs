@@ -0,0 +1,45 @@
+// src/cutscene/definition.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// One step of a cutscene. Positions are offsets from where the player
+/// stands when the cutscene starts, since the world is generated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CutsceneStep {
+    /// Pans the camera to `offset` over `duration` seconds
+    MoveCamera { offset: (f32, f32), duration: f32 },
+    /// Spawns a prefab at `offset`
+    Spawn { prefab: String, offset: (f32, f32) },
+    /// Shows a line in the dialogue box for `duration` seconds
+    Dialogue {
+        speaker: Option<String>,
+        text: String,
+        duration: f32,
+    },
+    Wait(f32),
+    /// Fades the screen toward black (`to: 1.0`) or back (`to: 0.0`)
+    Fade { to: f32, duration: f32 },
+}
+
+impl CutsceneStep {
+    pub fn duration(&self) -> f32 {
+        match self {
+            CutsceneStep::MoveCamera { duration, .. }
+            | CutsceneStep::Dialogue { duration, .. }
+            | CutsceneStep::Fade { duration, .. } => *duration,
+            CutsceneStep::Wait(duration) => *duration,
+            CutsceneStep::Spawn { .. } => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CutsceneDefinition {
+    pub name: String,
+    pub steps: Vec<CutsceneStep>,
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct CutsceneList {
+    pub cutscenes: Vec<CutsceneDefinition>,
+}
@@ -0,0 +1,95 @@
+// src/cutscene/mod.rs
+mod definition;
+mod overlay;
+mod player;
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use crate::console::ConsoleAppExt;
+use crate::logging::targets;
+use crate::state::{GameMode, GameState, PlayState};
+
+use definition::{CutsceneDefinition, CutsceneList};
+
+/// Cutscene definitions from cutscenes.ron, indexed by name.
+/// Rebuilt whenever the asset (re)loads.
+#[derive(Resource, Default)]
+pub struct CutsceneRegistry {
+    handle: Handle<CutsceneList>,
+    cutscenes: HashMap<String, CutsceneDefinition>,
+}
+
+impl CutsceneRegistry {
+    pub fn is_loaded(&self) -> bool {
+        !self.cutscenes.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&CutsceneDefinition> {
+        self.cutscenes.get(name)
+    }
+}
+
+/// Scripted sequences of camera moves, spawns, dialogue lines, waits and
+/// fades, defined in RON. Used for the intro of a new story game.
+pub struct CutscenePlugin;
+
+impl Plugin for CutscenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<CutsceneList>::new(&["cutscenes.ron"]))
+            .init_resource::<CutsceneRegistry>()
+            .init_resource::<player::CutscenePlayer>()
+            .add_systems(Startup, load_cutscenes)
+            .add_systems(Update, sync_cutscene_registry)
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Loading,
+                    entered: GameState::Playing,
+                },
+                player::queue_intro.run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(
+                Update,
+                player::start_pending_cutscene
+                    .run_if(in_state(PlayState::Exploring).and(in_state(GameState::Playing))),
+            )
+            .add_systems(
+                Update,
+                (player::run_cutscene, overlay::sync_cutscene_overlay)
+                    .chain()
+                    .run_if(in_state(PlayState::InCutscene).and(in_state(GameState::Playing))),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), player::reset_cutscenes)
+            .add_console_command("cutscene", "Play a cutscene: cutscene <name>", player::cutscene_command);
+    }
+}
+
+fn load_cutscenes(asset_server: Res<AssetServer>, mut registry: ResMut<CutsceneRegistry>) {
+    registry.handle = asset_server.load("cutscenes/cutscenes.ron");
+}
+
+fn sync_cutscene_registry(
+    mut events: MessageReader<AssetEvent<CutsceneList>>,
+    lists: Res<Assets<CutsceneList>>,
+    mut registry: ResMut<CutsceneRegistry>,
+) {
+    for event in events.read() {
+        if !event.is_loaded_with_dependencies(&registry.handle)
+            && !event.is_modified(&registry.handle)
+        {
+            continue;
+        }
+        let Some(list) = lists.get(&registry.handle) else {
+            continue;
+        };
+
+        registry.cutscenes = list
+            .cutscenes
+            .iter()
+            .map(|cutscene| (cutscene.name.clone(), cutscene.clone()))
+            .collect();
+        info!(target: targets::STATE, "Loaded {} cutscenes", registry.cutscenes.len());
+    }
+}
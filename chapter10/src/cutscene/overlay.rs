@@ -0,0 +1,91 @@
+// src/cutscene/overlay.rs
+use bevy::prelude::*;
+
+use super::player::CutscenePlayer;
+use crate::state::PlayState;
+use crate::ui::{TextRole, UiTheme};
+
+/// Full-screen layer that fades to black. Despawned when the cutscene ends.
+#[derive(Component)]
+pub struct CutsceneOverlay;
+
+#[derive(Component)]
+pub struct DialogueBox;
+
+#[derive(Component)]
+pub struct DialogueText;
+
+pub fn spawn_cutscene_overlay(commands: &mut Commands, theme: &UiTheme) {
+    commands
+        .spawn((
+            CutsceneOverlay,
+            DespawnOnExit(PlayState::InCutscene),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::End,
+                padding: UiRect::bottom(Val::Px(48.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.0)),
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            // A child, so it stays readable over a full fade
+            parent
+                .spawn((
+                    DialogueBox,
+                    Node {
+                        width: Val::Percent(60.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.85)),
+                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                    Visibility::Hidden,
+                ))
+                .with_child((
+                    DialogueText,
+                    Text::new(""),
+                    theme.text(TextRole::Body),
+                    TextColor(Color::WHITE),
+                ));
+        });
+}
+
+/// Mirrors the player's fade level and current line onto the overlay.
+pub fn sync_cutscene_overlay(
+    cutscenes: Res<CutscenePlayer>,
+    mut overlay: Query<&mut BackgroundColor, With<CutsceneOverlay>>,
+    mut dialogue_box: Query<&mut Visibility, With<DialogueBox>>,
+    mut dialogue_text: Query<&mut Text, With<DialogueText>>,
+) {
+    if !cutscenes.is_changed() {
+        return;
+    }
+    if let Ok(mut background) = overlay.single_mut() {
+        background.0 = Color::BLACK.with_alpha(cutscenes.fade);
+    }
+    let (Ok(mut visibility), Ok(mut text)) = (dialogue_box.single_mut(), dialogue_text.single_mut())
+    else {
+        return;
+    };
+    match &cutscenes.line {
+        Some((speaker, line)) => {
+            let line = match speaker {
+                Some(speaker) => format!("{}: {}", speaker, line),
+                None => line.clone(),
+            };
+            if text.0 != line {
+                text.0 = line;
+            }
+            visibility.set_if_neq(Visibility::Inherited);
+        }
+        None => {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+}
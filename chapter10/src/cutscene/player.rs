@@ -0,0 +1,200 @@
+// src/cutscene/player.rs
+use bevy::prelude::*;
+
+use super::definition::CutsceneStep;
+use super::overlay::spawn_cutscene_overlay;
+use super::CutsceneRegistry;
+use crate::camera::MainCamera;
+use crate::characters::input::Player;
+use crate::config::player::PLAYER_Z_POSITION;
+use crate::console::ConsoleResult;
+use crate::logging::targets;
+use crate::prefabs::PrefabCommandsExt;
+use crate::ui::UiTheme;
+use crate::state::PlayState;
+
+/// Plays cutscenes. While one runs the game sits in `PlayState::InCutscene`,
+/// which stops player input and enemy AI; the camera is driven by the steps.
+#[derive(Resource, Default)]
+pub struct CutscenePlayer {
+    /// Requested but not started: waits for the player and the definitions to load
+    pending: Option<String>,
+    active: Option<ActiveCutscene>,
+    /// Screen darkness, 0..1, for the overlay
+    pub fade: f32,
+    /// Line the dialogue box shows, as (speaker, text)
+    pub line: Option<(Option<String>, String)>,
+}
+
+struct ActiveCutscene {
+    name: String,
+    steps: Vec<CutsceneStep>,
+    index: usize,
+    /// Seconds into the current step
+    elapsed: f32,
+    /// Player position at the start; step offsets are relative to it
+    anchor: Vec2,
+    /// Camera position and fade when the current step began, for interpolating
+    camera_from: Vec2,
+    fade_from: f32,
+    step_started: bool,
+}
+
+impl CutscenePlayer {
+    pub fn queue(&mut self, name: impl Into<String>) {
+        self.pending = Some(name.into());
+    }
+}
+
+/// A fresh story game opens on the intro. Loaded games go straight to Playing
+/// without passing through Loading, so they skip it.
+pub fn queue_intro(mut player: ResMut<CutscenePlayer>) {
+    player.queue("intro");
+}
+
+/// Starts a queued cutscene once there's a player to anchor it to.
+pub fn start_pending_cutscene(
+    mut commands: Commands,
+    mut cutscenes: ResMut<CutscenePlayer>,
+    registry: Res<CutsceneRegistry>,
+    players: Query<&Transform, With<Player>>,
+    camera: Query<&Transform, With<MainCamera>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+    theme: Res<UiTheme>,
+) {
+    if cutscenes.pending.is_none() || !registry.is_loaded() {
+        return;
+    }
+    let (Ok(player), Ok(camera)) = (players.single(), camera.single()) else {
+        return;
+    };
+    let Some(name) = cutscenes.pending.take() else {
+        return;
+    };
+    let Some(definition) = registry.get(&name) else {
+        warn!(target: targets::STATE, "Unknown cutscene '{}'", name);
+        return;
+    };
+
+    info!(target: targets::STATE, "Playing cutscene '{}'", name);
+    cutscenes.active = Some(ActiveCutscene {
+        name,
+        steps: definition.steps.clone(),
+        index: 0,
+        elapsed: 0.0,
+        anchor: player.translation.truncate(),
+        camera_from: camera.translation.truncate(),
+        fade_from: 0.0,
+        step_started: false,
+    });
+    cutscenes.fade = 0.0;
+    cutscenes.line = None;
+    spawn_cutscene_overlay(&mut commands, &theme);
+    next_play_state.set(PlayState::InCutscene);
+}
+
+/// Steps through the active cutscene. Zero-length steps (spawns, instant
+/// fades) all run in the same frame. Escape or Space skips to the end,
+/// still spawning anything the rest of the cutscene would have.
+pub fn run_cutscene(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cutscenes: ResMut<CutscenePlayer>,
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    let CutscenePlayer {
+        active, fade, line, ..
+    } = &mut *cutscenes;
+    let Some(cutscene) = active.as_mut() else {
+        next_play_state.set(PlayState::Exploring);
+        return;
+    };
+    let Ok(mut camera) = camera.single_mut() else {
+        return;
+    };
+
+    if keys.any_just_pressed([KeyCode::Escape, KeyCode::Space]) {
+        for step in &cutscene.steps[cutscene.index..] {
+            if let CutsceneStep::Spawn { prefab, offset } = step {
+                spawn_at(&mut commands, prefab, cutscene.anchor + Vec2::from(*offset));
+            }
+        }
+        info!(target: targets::STATE, "Skipped cutscene '{}'", cutscene.name);
+        *active = None;
+        next_play_state.set(PlayState::Exploring);
+        return;
+    }
+
+    cutscene.elapsed += time.delta_secs();
+    while let Some(step) = cutscene.steps.get(cutscene.index) {
+        if !cutscene.step_started {
+            cutscene.step_started = true;
+            cutscene.camera_from = camera.translation.truncate();
+            cutscene.fade_from = *fade;
+            match step {
+                CutsceneStep::Spawn { prefab, offset } => {
+                    spawn_at(&mut commands, prefab, cutscene.anchor + Vec2::from(*offset));
+                }
+                CutsceneStep::Dialogue { speaker, text, .. } => {
+                    *line = Some((speaker.clone(), text.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        let duration = step.duration();
+        let t = if duration > 0.0 {
+            (cutscene.elapsed / duration).min(1.0)
+        } else {
+            1.0
+        };
+        match step {
+            CutsceneStep::MoveCamera { offset, .. } => {
+                let target = cutscene.anchor + Vec2::from(*offset);
+                let eased = t * t * (3.0 - 2.0 * t);
+                let position = cutscene.camera_from.lerp(target, eased);
+                camera.translation.x = position.x.round();
+                camera.translation.y = position.y.round();
+            }
+            CutsceneStep::Fade { to, .. } => {
+                *fade = cutscene.fade_from.lerp(*to, t);
+            }
+            _ => {}
+        }
+
+        if cutscene.elapsed < duration {
+            return;
+        }
+        if matches!(step, CutsceneStep::Dialogue { .. }) {
+            *line = None;
+        }
+        cutscene.elapsed -= duration;
+        cutscene.index += 1;
+        cutscene.step_started = false;
+    }
+
+    debug!(target: targets::STATE, "Cutscene '{}' finished", cutscene.name);
+    *active = None;
+    next_play_state.set(PlayState::Exploring);
+}
+
+fn spawn_at(commands: &mut Commands, prefab: &str, position: Vec2) {
+    commands.spawn_prefab(prefab, Transform::from_translation(position.extend(PLAYER_Z_POSITION)));
+}
+
+/// Drops any cutscene in progress, e.g. when returning to the main menu.
+pub fn reset_cutscenes(mut cutscenes: ResMut<CutscenePlayer>) {
+    *cutscenes = CutscenePlayer::default();
+}
+
+/// Console: `cutscene <name>` plays a cutscene from cutscenes.ron.
+pub fn cutscene_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let name = args.first().ok_or("Usage: cutscene <name>")?;
+    if world.resource::<CutsceneRegistry>().get(name).is_none() {
+        return Err(format!("Unknown cutscene '{}'", name));
+    }
+    world.resource_mut::<CutscenePlayer>().queue(*name);
+    Ok(format!("Playing '{}'", name))
+}
@@ -0,0 +1,233 @@
+// src/demo/attract.rs
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+
+use crate::accessibility::AccessibilityUIState;
+use crate::config::demo::{ATTRACT_IDLE_SECS, BUNDLED_DEMO};
+use crate::input_map::PlayerActions;
+use crate::logging::targets;
+use crate::map::generate::WorldGenSettings;
+use crate::save::SaveLoadUIState;
+use crate::state::{ConfirmDialog, GameMode, GameState};
+use crate::transitions::TransitionRequest;
+use crate::ui::{TextRole, UiTheme};
+use crate::video::VideoUIState;
+
+use super::recording::{demo_file_path, DemoRecording, RecordedFrame};
+
+/// The demo the main menu falls back to when left alone.
+#[derive(Resource, Debug, Clone)]
+pub struct AttractDemo(pub DemoRecording);
+
+/// The demo from the assets, until it has loaded.
+#[derive(Resource)]
+pub struct BundledDemo(pub Handle<DemoRecording>);
+
+/// Present while a demo run plays instead of the player.
+#[derive(Resource, Debug)]
+pub struct DemoPlayback {
+    frames: Vec<RecordedFrame>,
+    next: usize,
+    /// Game time into the current frame, less its length. The next frame
+    /// starts once this goes above 0
+    elapsed: f32,
+    /// Set once the way back to the menu is under way
+    leaving: bool,
+}
+
+impl DemoPlayback {
+    pub fn new(recording: &DemoRecording) -> Self {
+        Self {
+            frames: recording.frames.clone(),
+            next: 0,
+            elapsed: 0.0,
+            leaving: false,
+        }
+    }
+}
+
+/// Counts down while the main menu goes untouched.
+#[derive(Resource, Debug)]
+pub struct AttractIdle(pub Timer);
+
+impl Default for AttractIdle {
+    fn default() -> Self {
+        Self(Timer::from_seconds(ATTRACT_IDLE_SECS, TimerMode::Once))
+    }
+}
+
+#[derive(Component)]
+pub struct DemoOverlay;
+
+/// Prefers a demo recorded next to the executable, falling back to the one
+/// in the assets.
+pub fn load_attract_demo(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let path = demo_file_path();
+    let recorded = path
+        .exists()
+        .then(|| DemoRecording::load(&path))
+        .and_then(|loaded| {
+            loaded
+                .map_err(|e| warn!(target: targets::STATE, "Recorded demo not loaded: {}", e))
+                .ok()
+        });
+    match recorded {
+        Some(recording) => commands.insert_resource(AttractDemo(recording)),
+        None => commands.insert_resource(BundledDemo(asset_server.load(BUNDLED_DEMO))),
+    }
+}
+
+pub fn adopt_bundled_demo(
+    mut commands: Commands,
+    bundled: Res<BundledDemo>,
+    recordings: Res<Assets<DemoRecording>>,
+) {
+    if let Some(recording) = recordings.get(&bundled.0) {
+        commands.insert_resource(AttractDemo(recording.clone()));
+        commands.remove_resource::<BundledDemo>();
+    }
+}
+
+/// A key, mouse button or gamepad button went down this frame.
+pub fn any_button_pressed(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+) -> bool {
+    keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || gamepads.iter().any(|gamepad| gamepad.get_just_pressed().next().is_some())
+}
+
+/// Someone is using the menu: a panel or dialog is up, or the mouse moved.
+pub fn menu_in_use(
+    mut motion: MessageReader<MouseMotion>,
+    save_ui: Res<SaveLoadUIState>,
+    video_ui: Res<VideoUIState>,
+    accessibility_ui: Res<AccessibilityUIState>,
+    dialogs: Query<(), With<ConfirmDialog>>,
+) -> bool {
+    // Drained every frame so old movement doesn't count later
+    let moved = motion.read().count() > 0;
+    moved || save_ui.active || video_ui.active || accessibility_ui.active || !dialogs.is_empty()
+}
+
+pub fn reset_attract_idle(mut idle: ResMut<AttractIdle>) {
+    idle.0.reset();
+}
+
+/// Starts the demo once the menu has sat idle long enough, the same way
+/// New Game starts a run but on the recording's map.
+pub fn start_attract_demo(
+    time: Res<Time<Real>>, // The menu runs on real time
+    mut idle: ResMut<AttractIdle>,
+    demo: Res<AttractDemo>,
+    mut game_mode: ResMut<GameMode>,
+    mut world_gen: ResMut<WorldGenSettings>,
+    mut transitions: MessageWriter<TransitionRequest>,
+    mut commands: Commands,
+) {
+    if !idle.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    *game_mode = GameMode::Story;
+    *world_gen = demo.0.settings();
+    commands.insert_resource(DemoPlayback::new(&demo.0));
+    transitions.write(TransitionRequest { to: GameState::Loading });
+    info!(target: targets::STATE, "Menu idle, starting the attract demo");
+}
+
+/// Feeds the recording to gameplay in place of the keys, by game time so a
+/// different frame rate still plays it at the recorded speed.
+pub fn play_demo_frame(
+    time: Res<Time>,
+    mut playback: ResMut<DemoPlayback>,
+    mut actions: ResMut<PlayerActions>,
+    mut transitions: MessageWriter<TransitionRequest>,
+) {
+    actions.clear_one_shots();
+
+    playback.elapsed += time.delta_secs();
+    loop {
+        match playback.frames.get(playback.next).copied() {
+            Some(frame) if playback.elapsed > 0.0 => {
+                playback.elapsed -= frame.dt;
+                playback.next += 1;
+                frame.apply(&mut actions);
+            }
+            _ => break,
+        }
+    }
+
+    if playback.next == playback.frames.len() && playback.elapsed > 0.0 {
+        leave_demo(&mut playback, &mut transitions);
+    }
+}
+
+/// Any button ends the demo. The press is swallowed so it doesn't also
+/// pause the game on its way out.
+pub fn exit_demo_on_input(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+    mut playback: ResMut<DemoPlayback>,
+    mut transitions: MessageWriter<TransitionRequest>,
+) {
+    keys.reset_all();
+    mouse.reset_all();
+    leave_demo(&mut playback, &mut transitions);
+}
+
+/// The demo never shows the pause menu or game over screen. Pausing on
+/// focus loss or dying ends it instead.
+pub fn end_demo(
+    mut playback: ResMut<DemoPlayback>,
+    mut transitions: MessageWriter<TransitionRequest>,
+) {
+    leave_demo(&mut playback, &mut transitions);
+}
+
+fn leave_demo(playback: &mut DemoPlayback, transitions: &mut MessageWriter<TransitionRequest>) {
+    if playback.leaving {
+        return;
+    }
+    playback.leaving = true;
+    transitions.write(TransitionRequest { to: GameState::MainMenu });
+}
+
+/// Back on the menu the world has been cleared, so the demo is over.
+pub fn stop_demo(mut commands: Commands, mut idle: ResMut<AttractIdle>) {
+    commands.remove_resource::<DemoPlayback>();
+    idle.0.reset();
+}
+
+pub fn spawn_demo_overlay(mut commands: Commands, theme: Res<UiTheme>) {
+    commands
+        .spawn((
+            DespawnOnExit(GameState::Playing),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                bottom: Val::Px(48.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            GlobalZIndex(100),
+        ))
+        .with_child((
+            DemoOverlay,
+            Text::new("Press any key"),
+            theme.text(TextRole::Heading),
+            TextColor(Color::WHITE),
+        ));
+}
+
+/// Pulses the overlay so it reads as a prompt rather than part of the HUD.
+pub fn pulse_demo_overlay(
+    time: Res<Time<Real>>,
+    mut texts: Query<&mut TextColor, With<DemoOverlay>>,
+) {
+    let alpha = 0.6 + 0.4 * (time.elapsed_secs() * 3.0).sin();
+    for mut color in &mut texts {
+        color.0.set_alpha(alpha);
+    }
+}
@@ -0,0 +1,94 @@
+// src/demo/mod.rs
+mod attract;
+mod recording;
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use crate::console::ConsoleAppExt;
+use crate::input_map::read_player_actions;
+use crate::map::level::MapSource;
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+pub use attract::{AttractDemo, DemoPlayback};
+pub use recording::{DemoRecorder, DemoRecording};
+
+/// Attract mode. Left alone on the main menu, the game plays back a
+/// recorded run behind a "Press any key" prompt, and any button brings the
+/// menu back. Recordings are the player's actions frame by frame on a known
+/// seed, made with `demo_record`; one ships in assets/demos.
+pub struct DemoPlugin;
+
+impl Plugin for DemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DemoRecorder>()
+            .init_resource::<attract::AttractIdle>()
+            .add_plugins(RonAssetPlugin::<DemoRecording>::new(&["demo.ron"]))
+            .add_systems(Startup, attract::load_attract_demo)
+            .add_systems(
+                Update,
+                attract::adopt_bundled_demo.run_if(resource_exists::<attract::BundledDemo>),
+            )
+            .add_systems(OnExit(GameState::Loading), recording::start_recording)
+            .add_systems(
+                Update,
+                recording::record_frame
+                    .after(read_player_actions)
+                    .in_set(GameSet::Actions),
+            )
+            // Attract mode on the menu
+            .add_systems(OnEnter(GameState::MainMenu), attract::stop_demo)
+            .add_systems(
+                Update,
+                (
+                    attract::reset_attract_idle
+                        .run_if(attract::any_button_pressed.or(attract::menu_in_use)),
+                    attract::start_attract_demo
+                        .run_if(resource_exists::<AttractDemo>)
+                        .run_if(resource_equals(MapSource::Procedural))
+                        .run_if(not(resource_exists::<DemoPlayback>)),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::MainMenu)),
+            )
+            // Playing the demo. Input is ignored while it loads so the map
+            // generation isn't left behind half done
+            .add_systems(
+                Update,
+                attract::play_demo_frame
+                    .in_set(GameSet::Actions)
+                    .run_if(resource_exists::<DemoPlayback>),
+            )
+            .add_systems(
+                PreUpdate,
+                attract::exit_demo_on_input
+                    .after(InputSystems)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_exists::<DemoPlayback>)
+                    .run_if(attract::any_button_pressed),
+            )
+            .add_systems(
+                OnEnter(GameState::Paused),
+                attract::end_demo.run_if(resource_exists::<DemoPlayback>),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                attract::end_demo.run_if(resource_exists::<DemoPlayback>),
+            )
+            .add_systems(
+                OnEnter(GameState::Playing),
+                attract::spawn_demo_overlay.run_if(resource_exists::<DemoPlayback>),
+            )
+            .add_systems(
+                Update,
+                attract::pulse_demo_overlay.run_if(resource_exists::<DemoPlayback>),
+            )
+            .add_console_command(
+                "demo_record",
+                "Record the next new game as the attract demo: demo_record [stop [file.ron]]",
+                recording::demo_record_command,
+            );
+    }
+}
@@ -0,0 +1,182 @@
+// src/demo/recording.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::config::demo::DEMO_FILE;
+use crate::console::ConsoleResult;
+use crate::input_map::PlayerActions;
+use crate::logging::targets;
+use crate::map::generate::WorldGenSettings;
+use crate::save::data::data_directory;
+use crate::state::GameMode;
+
+use super::DemoPlayback;
+
+/// `PlayerActions` for a stretch of game time. Recordings hold one per
+/// frame; a hand-written demo can hold a key down with one long frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(default)]
+pub struct RecordedFrame {
+    pub dt: f32,
+    pub movement: [f32; 2],
+    pub running: bool,
+    pub blocking: bool,
+    pub jump: bool,
+    pub fire: bool,
+    pub ability: bool,
+    pub cycle_lock_on: bool,
+    pub interact: bool,
+    pub select_slot: Option<usize>,
+}
+
+impl RecordedFrame {
+    pub fn from_actions(actions: &PlayerActions, dt: f32) -> Self {
+        Self {
+            dt,
+            movement: actions.movement.to_array(),
+            running: actions.running,
+            blocking: actions.blocking,
+            jump: actions.jump,
+            fire: actions.fire,
+            ability: actions.ability,
+            cycle_lock_on: actions.cycle_lock_on,
+            interact: actions.interact,
+            select_slot: actions.select_slot,
+        }
+    }
+
+    /// Held actions take this frame's value. One-shots are added to what's
+    /// already pressed, so none are lost when several frames play at once.
+    pub fn apply(&self, actions: &mut PlayerActions) {
+        actions.movement = Vec2::from_array(self.movement);
+        actions.running = self.running;
+        actions.blocking = self.blocking;
+        actions.jump |= self.jump;
+        actions.fire |= self.fire;
+        actions.ability |= self.ability;
+        actions.cycle_lock_on |= self.cycle_lock_on;
+        actions.interact |= self.interact;
+        actions.select_slot = self.select_slot.or(actions.select_slot);
+    }
+}
+
+/// A story run's inputs from the first playing frame, with the map it was
+/// played on. The seed also seeds `GameRng`, so playing it back on the same
+/// map repeats the run's drops and spawns along with the player's moves.
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone)]
+pub struct DemoRecording {
+    pub seed: u64,
+    pub chunks_x: u32,
+    pub chunks_y: u32,
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl DemoRecording {
+    pub fn new(settings: &WorldGenSettings) -> Self {
+        Self {
+            seed: settings.seed,
+            chunks_x: settings.chunks_x,
+            chunks_y: settings.chunks_y,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn settings(&self) -> WorldGenSettings {
+        WorldGenSettings {
+            chunks_x: self.chunks_x,
+            chunks_y: self.chunks_y,
+            seed: self.seed,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        ron::from_str(&text).map_err(|e| format!("RON error: {}", e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = ron::ser::to_string_pretty(self, PrettyConfig::default())
+            .map_err(|e| format!("RON error: {}", e))?;
+        fs::write(path, text).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+    }
+}
+
+/// A demo recorded with `demo_record`, next to the executable. Played
+/// instead of the one that ships in the assets.
+pub fn demo_file_path() -> PathBuf {
+    data_directory().join(DEMO_FILE)
+}
+
+/// Set up from the console. Armed, it starts recording when the next story
+/// run starts playing, and keeps going until stopped.
+#[derive(Resource, Debug, Default)]
+pub struct DemoRecorder {
+    pub armed: bool,
+    pub recording: Option<DemoRecording>,
+}
+
+pub fn start_recording(
+    mut recorder: ResMut<DemoRecorder>,
+    game_mode: Res<GameMode>,
+    settings: Res<WorldGenSettings>,
+    playback: Option<Res<DemoPlayback>>,
+) {
+    if !recorder.armed || *game_mode != GameMode::Story || playback.is_some() {
+        return;
+    }
+    recorder.armed = false;
+    recorder.recording = Some(DemoRecording::new(&settings));
+    info!(target: targets::STATE, "Recording a demo on seed {}", settings.seed);
+}
+
+/// Runs right after the keys are mapped, so it sees what gameplay sees.
+pub fn record_frame(
+    time: Res<Time>,
+    actions: Res<PlayerActions>,
+    mut recorder: ResMut<DemoRecorder>,
+) {
+    if let Some(recording) = recorder.recording.as_mut() {
+        recording.frames.push(RecordedFrame::from_actions(&actions, time.delta_secs()));
+    }
+}
+
+pub fn demo_record_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let mut recorder = world.resource_mut::<DemoRecorder>();
+    match args {
+        [] => {
+            recorder.armed = true;
+            Ok("Recording starts with the next new game; demo_record stop to finish".into())
+        }
+        ["stop", file @ ..] if file.len() <= 1 => {
+            let Some(recording) = recorder.recording.take() else {
+                recorder.armed = false;
+                return Err("Not recording".into());
+            };
+            let path = file.first().map_or_else(demo_file_path, PathBuf::from);
+            recording.save(&path)?;
+            info!(
+                target: targets::STATE,
+                "Saved a {}-frame demo to {}",
+                recording.frames.len(),
+                path.display()
+            );
+            let message = format!(
+                "Saved {} frames to {}",
+                recording.frames.len(),
+                path.display()
+            );
+            // Recorded over the attract demo, so play the new one from now on
+            if path == demo_file_path() {
+                world.remove_resource::<super::attract::BundledDemo>();
+                world.insert_resource(super::AttractDemo(recording));
+            }
+            Ok(message)
+        }
+        _ => Err("Usage: demo_record [stop [file.ron]]".into()),
+    }
+}
@@ -1,6 +1,12 @@
 // src/enemy/ai.rs
-use super::components::{AIBehavior, Enemy, EnemyPath};
+use super::components::{AIBehavior, Enemy, EnemyCombat, EnemyPath, Kiting};
+use super::orders::EnemyOrders;
+use super::flying::Flying;
+use super::spawn_in::SpawningIn;
+use super::lod::SimulationLod;
+use super::squad::SquadManager;
 use crate::characters::{
+    collider::Collider,
     config::CharacterEntry,
     facing::Facing,
     input::Player,
@@ -8,23 +14,73 @@ use crate::characters::{
     state::CharacterState,
 };
 use crate::collision::CollisionMap;
+use crate::combat::ContactDamage;
+use crate::config::enemy::SQUAD_SLOT_ARRIVE_RADIUS;
 use bevy::prelude::*;
 
-/// AI system that makes enemies follow the player using A* pathfinding
+/// How far ahead a kiting enemy checks the ground before stepping there
+const KITE_PROBE_DISTANCE: f32 = 32.0;
+
+/// Direction a kiting enemy should move to open distance from the player:
+/// straight back if that's clear, otherwise sideways, trying the current
+/// strafe side first. `None` when boxed in.
+fn kite_direction(
+    map: &CollisionMap,
+    collider: &Collider,
+    transform: &Transform,
+    to_player: Vec2,
+    kiting: &mut Kiting,
+    flying: bool,
+) -> Option<Vec2> {
+    let away = -to_player.normalize_or_zero();
+    let is_clear = |direction: Vec2| {
+        let probe = collider.world_position(transform) + direction * KITE_PROBE_DISTANCE;
+        if flying {
+            map.clamp_to_bounds(probe, collider.clearance()) == probe
+        } else {
+            map.is_shape_clear(probe, &collider.shape)
+        }
+    };
+
+    if is_clear(away) {
+        return Some(away);
+    }
+    let side = away.perp() * kiting.strafe_side;
+    if is_clear(side) {
+        return Some(side);
+    }
+    if is_clear(-side) {
+        kiting.strafe_side = -kiting.strafe_side;
+        return Some(-side);
+    }
+    None
+}
+
+/// AI system that makes enemies follow the player using A* pathfinding.
+/// Enemies holding a squad slot head for it rather than the player.
+/// Enemies under orders from a script or behavior tree are left alone.
 pub fn enemy_follow_player(
     time: Res<Time>,
     collision_map: Option<Res<CollisionMap>>,
+    squad: Res<SquadManager>,
     mut enemy_query: Query<
         (
+            Entity,
             &Transform,
             &mut CharacterState,
             &mut Velocity,
             &mut Facing,
             &CharacterEntry,
+            &Collider,
             &AIBehavior,
             &mut EnemyPath,
+            &mut SimulationLod,
+            Option<&EnemyCombat>,
+            Option<&ContactDamage>,
+            Has<Flying>,
+            Option<&mut Kiting>,
         ),
-        With<Enemy>,
+        (With<Enemy>, Without<SpawningIn>, Without<EnemyOrders>),
     >,
     player_query: Query<&Transform, With<Player>>,
 ) {
@@ -37,11 +93,30 @@ pub fn enemy_follow_player(
     };
 
     let player_pos = player_transform.translation.truncate();
-    let delta = time.delta_secs();
+    let frame_delta = time.delta_secs();
 
-    for (enemy_transform, mut state, mut velocity, mut facing, character, ai, mut path) in
-        enemy_query.iter_mut()
+    for (
+        enemy,
+        enemy_transform,
+        mut state,
+        mut velocity,
+        mut facing,
+        character,
+        collider,
+        ai,
+        mut path,
+        mut lod,
+        combat,
+        contact,
+        flying,
+        kiting,
+    ) in enemy_query.iter_mut()
     {
+        // Distant enemies only think every few frames; velocity carries them in between
+        let Some(delta) = lod.tick(frame_delta) else {
+            continue;
+        };
+
         let enemy_pos = enemy_transform.translation.truncate();
         let to_player = player_pos - enemy_pos;
         let distance = to_player.length();
@@ -55,16 +130,54 @@ pub fn enemy_follow_player(
             continue;
         }
 
-        // Within attack range - stop and attack
+        // Ranged kiters back off when the player gets too close
+        if let Some(mut kiting) = kiting.filter(|k| distance < k.min_distance) {
+            let retreat = kite_direction(
+                &collision_map,
+                collider,
+                enemy_transform,
+                to_player,
+                &mut kiting,
+                flying,
+            );
+            if let Some(direction) = retreat {
+                if *state != CharacterState::Walking {
+                    *state = CharacterState::Walking;
+                }
+                // Keep facing the player so shots still go their way
+                let new_facing = Facing::from_velocity(to_player);
+                if *facing != new_facing {
+                    *facing = new_facing;
+                }
+                *velocity = calculate_velocity(*state, direction, character);
+                if path.has_path() {
+                    *path = EnemyPath::default();
+                }
+                continue;
+            }
+        }
+
+        // Within attack range (or at its squad slot) - stop and attack
         // Use hysteresis: different threshold for staying vs entering attack mode
         // This prevents oscillation at the boundary
-        let attack_threshold = if *state == CharacterState::Idle {
-            ai.attack_range + 20.0 // Stay in attack mode even if player moves slightly away
+        let hysteresis = if *state == CharacterState::Idle {
+            20.0 // Stay in attack mode even if player moves slightly away
         } else {
-            ai.attack_range // Enter attack mode at normal range
+            0.0 // Enter attack mode at normal range
         };
         
-        if distance <= attack_threshold {
+        // Enemies that bite keep pressing in while their ranged attack recharges
+        let closing_in = contact.is_some()
+            && combat.is_none_or(|c| c.cooldown.elapsed() < c.cooldown.duration());
+
+        let slot = if closing_in { None } else { squad.slot_for(enemy) };
+        let goal = slot.unwrap_or(player_pos);
+        let in_position = match slot {
+            Some(slot) => enemy_pos.distance(slot) <= SQUAD_SLOT_ARRIVE_RADIUS + hysteresis,
+            None => distance <= ai.attack_range + hysteresis && !closing_in,
+        };
+
+        if in_position {
             if *state != CharacterState::Idle {
                 *state = CharacterState::Idle;
             }
@@ -81,12 +194,20 @@ pub fn enemy_follow_player(
             continue;
         }
 
-        // Need to move toward player - use pathfinding
+        // Need to move toward the goal - use pathfinding, keeping big enemies
+        // out of gaps they can't fit through
         path.recalc_timer -= delta;
+        let clearance = collider.clearance();
         
-        // Recalculate path if we don't have one
-        if !path.has_path() {
-            if let Some(waypoints) = collision_map.find_path(enemy_pos, player_pos) {
+        if lod.reduced || flying {
+            // Too far to matter, or nothing in the way: drop any path and
+            // head straight for the goal
+            if path.has_path() {
+                *path = EnemyPath::default();
+            }
+        } else if !path.has_path() {
+            // Recalculate path if we don't have one
+            if let Some(waypoints) = collision_map.find_path(enemy_pos, goal, clearance) {
                 path.set_path(waypoints);
                 path.recalc_timer = EnemyPath::RECALC_INTERVAL;
             }
@@ -94,7 +215,7 @@ pub fn enemy_follow_player(
             // Periodically update existing path  
             path.recalc_timer = EnemyPath::RECALC_INTERVAL;
             
-            if let Some(waypoints) = collision_map.find_path(enemy_pos, player_pos) {
+            if let Some(waypoints) = collision_map.find_path(enemy_pos, goal, clearance) {
                 path.set_path(waypoints);
             }
         }
@@ -132,7 +253,7 @@ pub fn enemy_follow_player(
             }
         } else {
             // No path available - fallback to direct movement
-            let direction = to_player.normalize_or_zero();
+            let direction = (goal - enemy_pos).normalize_or_zero();
             
             if *state != CharacterState::Walking {
                 *state = CharacterState::Walking;
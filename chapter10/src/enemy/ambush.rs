@@ -0,0 +1,80 @@
+// src/enemy/ambush.rs
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::difficulty::ScaleWithDistance;
+use super::spawn::{get_valid_spawn_position, spawn_clearance, spawn_enemy};
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TriggerEntered, TriggerZone};
+use crate::config::enemy::ENEMY_Z_POSITION;
+use crate::config::music::AMBUSH_HINT_SECS;
+use crate::music::{AudioState, MusicHint};
+use crate::prefabs::PrefabRegistry;
+use crate::state::GameplayEntity;
+use crate::logging::targets;
+
+/// Enemies that appear around a trigger zone the first time the player
+/// walks into it.
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default)]
+pub struct Ambush {
+    /// Character names of the enemies
+    pub enemies: Vec<String>,
+}
+
+/// Places an ambush area of `size` centred on `center`. `enemies` is a comma
+/// separated list of characters, as written in a level file.
+pub fn spawn_ambush(commands: &mut Commands, center: Vec2, size: Vec2, enemies: &str) {
+    let enemies: Vec<String> = enemies
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect();
+    if enemies.is_empty() || size.min_element() <= 0.0 {
+        warn!(target: targets::ENEMY, "Skipping ambush at {:?} without enemies or area", center);
+        return;
+    }
+    commands.spawn((
+        TriggerZone::rect(size),
+        Ambush { enemies },
+        Transform::from_translation(center.extend(0.0)),
+        GameplayEntity,
+    ));
+}
+
+/// Springs an ambush when the player enters its zone: its enemies spawn in a
+/// ring inside the area and the zone goes away.
+pub fn spring_ambush(
+    entered: On<TriggerEntered>,
+    mut commands: Commands,
+    ambushes: Query<(&Ambush, &TriggerZone, &Transform)>,
+    players: Query<(), With<Player>>,
+    collision_map: Option<Res<CollisionMap>>,
+    prefabs: Res<PrefabRegistry>,
+    mut audio: ResMut<AudioState>,
+) {
+    let Ok((ambush, zone, transform)) = ambushes.get(entered.entity) else {
+        return;
+    };
+    let Some(map) = collision_map else {
+        return;
+    };
+    if !players.contains(entered.other) {
+        return;
+    }
+
+    let center = transform.translation.truncate();
+    let spread = zone.shape.half_extents().min_element() / 2.0;
+    let count = ambush.enemies.len();
+    for (i, name) in ambush.enemies.iter().enumerate() {
+        let desired = center + Vec2::from_angle(i as f32 * TAU / count as f32) * spread;
+        let position = get_valid_spawn_position(&map, desired, spawn_clearance(&prefabs, name));
+        spawn_enemy(&mut commands, position.extend(ENEMY_Z_POSITION), name, ScaleWithDistance);
+    }
+    commands.entity(entered.entity).despawn();
+    // Straight into the fight, before the enemies have finished spawning in
+    audio.push_hint(MusicHint::Intensity { level: 1.0, secs: AMBUSH_HINT_SECS });
+    info!(target: targets::ENEMY, "Ambush sprung: {} enemies", count);
+}
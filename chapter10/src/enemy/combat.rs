@@ -1,21 +1,30 @@
 // src/enemy/combat.rs
-use super::components::{AIBehavior, Enemy, EnemyCombat};
+use super::components::{AIBehavior, Enemy, EnemyCombat, EnemyLevel};
+use super::orders::EnemyOrders;
+use super::spawn_in::SpawningIn;
 use crate::characters::input::Player;
-use crate::combat::systems::{spawn_projectile, ProjectileOwner};
+use crate::combat::projectile::Projectile;
+use crate::combat::{fire_power, PowerRegistry, ProjectileOwner};
+use crate::telemetry::GameplayEvent;
 use bevy::prelude::*;
 
-/// System that handles enemy attacks
+/// System that handles enemy attacks. Enemies under orders attack when told to.
 pub fn enemy_attack(
     mut commands: Commands,
     time: Res<Time>,
-    mut enemy_query: Query<(&GlobalTransform, &mut EnemyCombat, &AIBehavior), With<Enemy>>,
+    powers: Res<PowerRegistry>,
+    mut enemy_query: Query<
+        (Entity, &GlobalTransform, &mut EnemyCombat, &AIBehavior),
+        (With<Enemy>, Without<SpawningIn>, Without<EnemyOrders>),
+    >,
     player_query: Query<&Transform, With<Player>>,
+    mut telemetry: MessageWriter<GameplayEvent>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
     };
 
-    for (enemy_transform, mut combat, ai) in enemy_query.iter_mut() {
+    for (enemy, enemy_transform, mut combat, ai) in enemy_query.iter_mut() {
         // Tick the cooldown timer
         combat.cooldown.tick(time.delta());
 
@@ -31,17 +40,38 @@ pub fn enemy_attack(
             let to_player = (player_pos - enemy_pos).normalize();
             let spawn_position = enemy_pos + to_player * 5.0;
 
-            // Get visuals from power type (using actual direction to player)
-            let visuals = combat.power_type.visuals(to_player);
-
-            // Spawn projectile (reuse existing function!)
-            spawn_projectile(&mut commands, spawn_position, combat.power_type, &visuals, ProjectileOwner::Enemy);
-
+            // Same hitbox and visuals as the player's version of this power
+            let fired = fire_power(
+                &mut commands,
+                &powers,
+                &combat.power_type,
+                spawn_position,
+                to_player,
+                ProjectileOwner::Enemy,
+                enemy,
+            );
 
             // Reset cooldown for next attack
             combat.cooldown.reset();
 
-            info!("Enemy fired {:?} projectile at player!", combat.power_type);
+            if fired.is_some() {
+                telemetry.write(GameplayEvent::ProjectileFired {
+                    power_type: combat.power_type.clone(),
+                    owner: ProjectileOwner::Enemy,
+                });
+            }
+        }
+    }
+}
+/// Scales freshly fired projectiles by their shooter's level, the same way
+/// damage buffs do.
+pub fn apply_level_damage(
+    mut projectiles: Query<&mut Projectile, Added<Projectile>>,
+    shooters: Query<&EnemyLevel>,
+) {
+    for mut projectile in &mut projectiles {
+        if let Ok(level) = shooters.get(projectile.shooter) {
+            projectile.damage *= level.damage_multiplier();
         }
     }
-}
\ No newline at end of file
+}
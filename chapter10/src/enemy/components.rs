@@ -1,38 +1,72 @@
 // src/enemy/components.rs
-use crate::combat::PowerType;
+use super::lod::SimulationLod;
+use crate::buffs::Buffs;
+use crate::combat::{Faction, PowerId, PowerType};
+use crate::config::enemy::{LEVEL_DAMAGE_STEP, LEVEL_HEALTH_STEP};
 use bevy::prelude::*;
 
 /// Marker component for enemy entities
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[require(SimulationLod, Faction = Faction::Monsters, Buffs, EnemyLevel)]
 pub struct Enemy;
 
+/// How tough an enemy is, starting at 1. Higher levels hit harder.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub struct EnemyLevel(pub u32);
+
+impl Default for EnemyLevel {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl EnemyLevel {
+    /// Multiplier on the damage this enemy deals
+    pub fn damage_multiplier(&self) -> f32 {
+        1.0 + LEVEL_DAMAGE_STEP * self.0.saturating_sub(1) as f32
+    }
+
+    /// Multiplier on the enemy's base max health
+    pub fn health_multiplier(&self) -> f32 {
+        1.0 + LEVEL_HEALTH_STEP * self.0.saturating_sub(1) as f32
+    }
+}
+
 /// Combat capabilities for enemies
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct EnemyCombat {
-    pub power_type: PowerType,
+    pub power_type: PowerId,
     pub cooldown: Timer,
 }
 
 impl Default for EnemyCombat {
     fn default() -> Self {
         Self {
-            power_type: PowerType::Shadow, // Graveyard reaper uses shadow magic
+            power_type: PowerType::Shadow.into(), // Graveyard reaper uses shadow magic
             cooldown: Timer::from_seconds(2.0, TimerMode::Once), // Slower than player
         }
     }
 }
 
 impl EnemyCombat {
-    pub fn new(power_type: PowerType, cooldown_seconds: f32) -> Self {
+    pub fn new(power_type: impl Into<PowerId>, cooldown_seconds: f32) -> Self {
         Self {
-            power_type,
+            power_type: power_type.into(),
             cooldown: Timer::from_seconds(cooldown_seconds, TimerMode::Once),
         }
     }
 }
 
+/// An enemy whose fight switches the music to the boss layer.
+#[derive(Component, Debug, Default)]
+pub struct Boss;
+
 /// AI behavior state for enemies
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct AIBehavior {
     pub attack_range: f32,
     pub detection_range: f32,
@@ -56,7 +90,34 @@ impl AIBehavior {
     }
 }
 
-#[derive(Component, Default)]
+/// Ranged enemies with this back away when the player gets closer than
+/// `min_distance`, keeping to the band between it and their attack range.
+/// Backed against a wall, they strafe sideways instead.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Kiting {
+    pub min_distance: f32,
+    /// Side (1 or -1) the enemy strafes to, kept until that way is blocked
+    pub strafe_side: f32,
+}
+
+impl Default for Kiting {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+impl Kiting {
+    pub fn new(min_distance: f32) -> Self {
+        Self {
+            min_distance,
+            strafe_side: 1.0,
+        }
+    }
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
 pub struct EnemyPath {
     /// Waypoints in world coordinates
     pub waypoints: Vec<Vec2>,
@@ -0,0 +1,117 @@
+// src/enemy/difficulty.rs
+use bevy::prelude::*;
+
+use super::components::EnemyLevel;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::combat::Health;
+use crate::config::enemy::{DIFFICULTY_RING_TILES, MAX_ENEMY_LEVEL};
+use crate::config::player::SPAWN_POSITION;
+use crate::logging::targets;
+use crate::ui::{TextRole, UiTheme};
+
+/// Spawned enemies with this get their level from how far from the player's
+/// spawn point they are, with health to match. Saved enemies don't carry it,
+/// since their level and health were already scaled.
+#[derive(Component)]
+pub struct ScaleWithDistance;
+
+/// Level of enemies around `position`: rings of `DIFFICULTY_RING_TILES`
+/// tiles centred on the player's spawn point, one level per ring.
+pub fn level_at(map: &CollisionMap, position: Vec2) -> EnemyLevel {
+    let offset = map.world_to_grid(position) - map.world_to_grid(SPAWN_POSITION);
+    let ring = (offset.as_vec2().length() / DIFFICULTY_RING_TILES) as u32;
+    EnemyLevel((1 + ring).min(MAX_ENEMY_LEVEL))
+}
+
+/// Levels up freshly spawned enemies for their distance from spawn.
+pub fn apply_distance_difficulty(
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    mut enemies: Query<(Entity, &Transform, &mut EnemyLevel, &mut Health), Added<ScaleWithDistance>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+
+    for (enemy, transform, mut level, mut health) in &mut enemies {
+        *level = level_at(&map, transform.translation.truncate());
+        let multiplier = level.health_multiplier();
+        health.max *= multiplier;
+        health.current *= multiplier;
+        commands.entity(enemy).remove::<ScaleWithDistance>();
+        debug!(target: targets::ENEMY, "{:?} spawned at level {}", enemy, level.0);
+    }
+}
+
+/// Tint for a level, from green near spawn to red at the outer rings.
+pub fn danger_color(level: EnemyLevel) -> Color {
+    let danger = (level.0 - 1) as f32 / (MAX_ENEMY_LEVEL - 1) as f32;
+    Color::srgb(0.4, 0.9, 0.4).mix(&Color::srgb(1.0, 0.3, 0.25), danger)
+}
+
+#[derive(Component)]
+pub struct ZoneDangerHud;
+
+#[derive(Component)]
+pub struct ZoneDangerText;
+
+/// Level readout in the top-left corner. Re-entering Playing (from pause or
+/// a load) keeps the existing one.
+pub fn spawn_zone_danger_hud(
+    mut commands: Commands,
+    theme: Res<UiTheme>,
+    existing: Query<(), With<ZoneDangerHud>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            ZoneDangerHud,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.0),
+                left: Val::Px(16.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ZoneDangerText,
+                Text::new(""),
+                theme.text(TextRole::Caption),
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Shows the level of enemies around the player, tinted by `danger_color`.
+pub fn update_zone_danger_hud(
+    collision_map: Option<Res<CollisionMap>>,
+    players: Query<&Transform, With<Player>>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<ZoneDangerText>>,
+) {
+    let (Some(map), Ok(player)) = (collision_map, players.single()) else {
+        return;
+    };
+    let Ok((mut text, mut color)) = texts.single_mut() else {
+        return;
+    };
+
+    let level = level_at(&map, player.translation.truncate());
+    let label = format!("Danger: Lv {}", level.0);
+    if text.0 != label {
+        text.0 = label;
+        color.0 = danger_color(level);
+    }
+}
+
+pub fn despawn_zone_danger_hud(mut commands: Commands, query: Query<Entity, With<ZoneDangerHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,19 @@
+// src/enemy/flying.rs
+use bevy::prelude::*;
+
+/// Movement mode for enemies that fly. They ignore tile walkability (but
+/// stay inside the map), path straight at the player, float over ground
+/// hazards and other walkers, and draw above ground props. Only the
+/// player's projectiles can reach them.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Flying {
+    /// How far above its shadow the sprite hovers
+    pub altitude: f32,
+}
+
+impl Default for Flying {
+    fn default() -> Self {
+        Self { altitude: 32.0 }
+    }
+}
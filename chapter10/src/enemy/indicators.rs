@@ -0,0 +1,114 @@
+// src/enemy/indicators.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::components::{AIBehavior, Enemy, EnemyCombat};
+use crate::camera::{MainCamera, ViewBounds};
+use crate::characters::input::Player;
+use crate::config::enemy::{INDICATOR_EDGE_MARGIN, INDICATOR_RANGE, INDICATOR_Z};
+use crate::state::GameplayEntity;
+
+const ARROW_LENGTH: f32 = 18.0;
+const ARROW_WIDTH: f32 = 12.0;
+
+/// Arrow pinned to the edge of the view, pointing at an off-screen enemy.
+#[derive(Component)]
+pub struct OffscreenIndicator {
+    pub target: Entity,
+}
+
+/// How worrying an off-screen enemy is, 0..1. Enemies already hunting the
+/// player rank above idle ones, ranged attackers above melee, near above far.
+fn threat_level(distance: f32, ai: &AIBehavior, ranged: bool) -> f32 {
+    let hunting = if distance <= ai.detection_range { 0.5 } else { 0.0 };
+    let ranged = if ranged { 0.2 } else { 0.0 };
+    let closeness = 0.3 * (1.0 - distance / INDICATOR_RANGE).clamp(0.0, 1.0);
+    hunting + ranged + closeness
+}
+
+/// Yellow for mild threats through red for enemies closing in.
+fn threat_color(threat: f32) -> Color {
+    Color::srgb(1.0, 0.85 * (1.0 - threat), 0.1)
+}
+
+/// Places an arrow on the view edge for every enemy in range but off screen,
+/// using this frame's camera bounds. Arrows are spawned and despawned as
+/// enemies leave and enter the view.
+pub fn update_offscreen_indicators(
+    mut commands: Commands,
+    camera: Query<(&Transform, &Projection), With<MainCamera>>,
+    players: Query<&Transform, With<Player>>,
+    enemies: Query<(Entity, &Transform, &AIBehavior, Has<EnemyCombat>), With<Enemy>>,
+    mut indicators: Query<
+        (Entity, &OffscreenIndicator, &mut Transform, &MeshMaterial2d<ColorMaterial>),
+        (Without<Enemy>, Without<MainCamera>, Without<Player>),
+    >,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Some(view) = camera
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| ViewBounds::from_camera(transform, projection))
+    else {
+        return;
+    };
+    let Ok(player_transform) = players.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let mut existing: HashMap<Entity, Entity> = indicators
+        .iter()
+        .map(|(indicator, link, _, _)| (link.target, indicator))
+        .collect();
+
+    for (enemy, transform, ai, ranged) in &enemies {
+        let position = transform.translation.truncate();
+        let distance = position.distance(player_pos);
+        if view.contains(position) || distance > INDICATOR_RANGE {
+            continue;
+        }
+
+        let edge = view.edge_point(position, INDICATOR_EDGE_MARGIN);
+        let threat = threat_level(distance, ai, ranged);
+        let rotation = Quat::from_rotation_z((position - edge).to_angle());
+        let scale = Vec3::splat(0.8 + 0.6 * threat);
+
+        match existing.remove(&enemy).and_then(|e| indicators.get_mut(e).ok()) {
+            Some((_, _, mut indicator_transform, material)) => {
+                indicator_transform.translation = edge.extend(INDICATOR_Z);
+                indicator_transform.rotation = rotation;
+                indicator_transform.scale = scale;
+                if let Some(material) = materials.get_mut(&material.0) {
+                    material.color = threat_color(threat);
+                }
+            }
+            None => {
+                // Triangle pointing along +X, turned by `rotation`
+                let arrow = Triangle2d::new(
+                    Vec2::new(ARROW_LENGTH / 2.0, 0.0),
+                    Vec2::new(-ARROW_LENGTH / 2.0, ARROW_WIDTH / 2.0),
+                    Vec2::new(-ARROW_LENGTH / 2.0, -ARROW_WIDTH / 2.0),
+                );
+                commands.spawn((
+                    OffscreenIndicator { target: enemy },
+                    Mesh2d(meshes.add(arrow)),
+                    MeshMaterial2d(materials.add(ColorMaterial::from(threat_color(threat)))),
+                    Transform {
+                        translation: edge.extend(INDICATOR_Z),
+                        rotation,
+                        scale,
+                    },
+                    GameplayEntity,
+                ));
+            }
+        }
+    }
+
+    // Enemies that came into view, wandered off or died
+    for indicator in existing.into_values() {
+        commands.entity(indicator).despawn();
+    }
+}
@@ -0,0 +1,62 @@
+// src/enemy/lod.rs
+use bevy::prelude::*;
+
+use super::components::Enemy;
+use crate::camera::MainCamera;
+use crate::config::enemy::{AI_LOD_RADIUS, AI_LOD_TICK_SECS};
+
+/// Slices of the reduced tick interval that distant enemies are spread across,
+/// so they don't all think on the same frame.
+const LOD_PHASES: u64 = 8;
+
+/// Simulation level of detail. Enemies far from the camera think a few times
+/// per second, move in straight lines, and don't play animations.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
+pub struct SimulationLod {
+    pub reduced: bool,
+    /// Time banked since the last reduced-rate AI tick
+    pub accumulator: f32,
+}
+
+impl SimulationLod {
+    /// Returns the delta to simulate this frame, or `None` if this enemy skips the frame.
+    pub fn tick(&mut self, delta: f32) -> Option<f32> {
+        if !self.reduced {
+            return Some(delta);
+        }
+
+        self.accumulator += delta;
+        if self.accumulator < AI_LOD_TICK_SECS {
+            return None;
+        }
+        Some(std::mem::take(&mut self.accumulator))
+    }
+}
+
+pub fn update_simulation_lod(
+    camera_query: Query<&Transform, With<MainCamera>>,
+    mut enemy_query: Query<(Entity, &Transform, &mut SimulationLod), With<Enemy>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let camera_pos = camera_transform.translation.truncate();
+
+    for (entity, transform, mut lod) in enemy_query.iter_mut() {
+        let distance = camera_pos.distance(transform.translation.truncate());
+
+        // Hysteresis so enemies on the boundary don't flip every frame
+        let reduced = if lod.reduced {
+            distance > AI_LOD_RADIUS * 0.9
+        } else {
+            distance > AI_LOD_RADIUS
+        };
+
+        if reduced != lod.reduced {
+            lod.reduced = reduced;
+            let phase = (entity.to_bits() % LOD_PHASES) as f32 / LOD_PHASES as f32;
+            lod.accumulator = phase * AI_LOD_TICK_SECS;
+        }
+    }
+}
@@ -1,37 +1,131 @@
 // src/enemy/mod.rs
 pub mod ai;
+pub mod ambush;
 pub mod combat;
 pub mod components;
+pub mod difficulty;
+mod flying;
+mod indicators;
+pub mod lod;
+mod nameplate;
+pub mod orders;
+pub mod respawn;
 pub mod spawn;
+mod spawn_in;
+mod squad;
 
 use crate::collision::CollisionMapBuilt;
-use crate::state::GameState;
+use crate::combat::projectile::check_projectile_hits;
+use crate::schedule::{world_ready, GameSet};
+use crate::state::{GameMode, GameState, PlayState};
 use bevy::prelude::*;
 use spawn::EnemiesSpawned;
 
-pub use components::{AIBehavior, Enemy, EnemyCombat};
+pub use components::{AIBehavior, Boss, Enemy, EnemyCombat, EnemyLevel};
+pub use flying::Flying;
+pub use lod::SimulationLod;
+pub use nameplate::NameplateSettings;
+pub use orders::{EnemyOrders, MoveGoal};
 pub use spawn::spawn_enemy;
+pub use spawn_in::SpawningIn;
+pub use squad::SquadManager;
 
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app
+            .register_type::<Enemy>()
+            .register_type::<EnemyCombat>()
+            .register_type::<AIBehavior>()
+            .register_type::<components::EnemyPath>()
+            .register_type::<components::Kiting>()
+            .register_type::<SimulationLod>()
+            .register_type::<Flying>()
+            .register_type::<EnemyLevel>()
+            .register_type::<NameplateSettings>()
+            .init_resource::<NameplateSettings>()
+            .register_type::<respawn::HomeZone>()
+            .register_type::<EnemiesSpawned>()
             .init_resource::<EnemiesSpawned>()
+            .init_resource::<respawn::RespawnDirector>()
+            .init_resource::<squad::SquadManager>()
+            .register_type::<ambush::Ambush>()
+            .add_observer(respawn::on_zone_enemy_death)
+            .add_observer(ambush::spring_ambush)
             // Spawn enemies AFTER collision map is ready (prevents spawning on obstacles)
             .add_systems(
                 Update,
                 spawn::spawn_test_enemies
                     .run_if(resource_equals(CollisionMapBuilt(true)))
                     .run_if(resource_equals(EnemiesSpawned(false)))
+                    .run_if(resource_equals(GameMode::Story))
                     .run_if(in_state(GameState::Playing)),
             )
             // Enemy AI and combat systems
             .add_systems(
                 Update,
-                (ai::enemy_follow_player, combat::enemy_attack)
+                (
+                    lod::update_simulation_lod,
+                    squad::assign_squad_slots,
+                    ai::enemy_follow_player,
+                    orders::follow_enemy_orders,
+                )
                     .chain()
-                    .run_if(in_state(GameState::Playing)),
-            );
+                    .in_set(GameSet::AI),
+            )
+            .add_systems(
+                Update,
+                (
+                    difficulty::apply_distance_difficulty,
+                    spawn_in::begin_spawn_in,
+                    spawn_in::tick_spawn_in,
+                )
+                    .chain()
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(
+                Update,
+                (combat::enemy_attack, orders::fire_ordered_attacks)
+                    .in_set(GameSet::Combat)
+                    .run_if(in_state(PlayState::Exploring)),
+            )
+            .add_systems(
+                Update,
+                combat::apply_level_damage
+                    .before(check_projectile_hits)
+                    .in_set(GameSet::Combat),
+            )
+            .add_systems(
+                Update,
+                (
+                    indicators::update_offscreen_indicators,
+                    nameplate::attach_nameplates,
+                    nameplate::update_nameplates,
+                    nameplate::sync_nameplate_visibility,
+                )
+                    .in_set(GameSet::Render),
+            )
+            // Cleared zones repopulate while the player is elsewhere (story mode only)
+            .add_systems(
+                Update,
+                (respawn::track_zone_visits, respawn::respawn_cleared_zones)
+                    .chain()
+                    .run_if(resource_equals(GameMode::Story))
+                    .run_if(world_ready),
+            )
+            // Story worlds get harder the farther the player strays from spawn
+            .add_systems(
+                OnEnter(GameState::Playing),
+                difficulty::spawn_zone_danger_hud.run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(
+                Update,
+                difficulty::update_zone_danger_hud
+                    .in_set(GameSet::Render)
+                    .run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), difficulty::despawn_zone_danger_hud)
+            .add_systems(OnEnter(GameState::MainMenu), difficulty::despawn_zone_danger_hud);
     }
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+// src/enemy/nameplate.rs
+use bevy::prelude::*;
+
+use super::components::{Enemy, EnemyLevel};
+use crate::characters::config::CharacterEntry;
+use crate::combat::healthbar::healthbar_top;
+
+/// Gap between the top of the healthbar and the nameplate
+const NAMEPLATE_GAP: f32 = 8.0;
+const NAMEPLATE_FONT_SIZE: f32 = 11.0;
+
+/// Whether enemies show their name and level above the healthbar.
+/// Toggled from the pause menu.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Default)]
+pub struct NameplateSettings {
+    pub visible: bool,
+}
+
+impl Default for NameplateSettings {
+    fn default() -> Self {
+        Self { visible: true }
+    }
+}
+
+/// Name and level label, a child of its enemy like the healthbars.
+#[derive(Component)]
+pub struct Nameplate;
+
+/// "graveyard_reaper" -> "Graveyard Reaper"
+fn display_name(id: &str) -> String {
+    id.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn nameplate_text(character: &CharacterEntry, level: &EnemyLevel) -> String {
+    format!("{}  Lv {}", display_name(&character.name), level.0)
+}
+
+/// Gives new enemies a nameplate just above their healthbar.
+pub fn attach_nameplates(
+    mut commands: Commands,
+    enemies: Query<(Entity, &Transform, &CharacterEntry, &EnemyLevel), Added<Enemy>>,
+    settings: Res<NameplateSettings>,
+) {
+    for (enemy, transform, character, level) in &enemies {
+        // Undo the enemy's scale so text stays the same size on screen
+        let inverse_scale = transform.scale.recip();
+        let y = healthbar_top(Some(character), transform) + NAMEPLATE_GAP;
+        commands.spawn((
+            Nameplate,
+            Text2d::new(nameplate_text(character, level)),
+            TextFont {
+                font_size: NAMEPLATE_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.85)),
+            Transform::from_translation(Vec3::new(0.0, y, 1.0) * inverse_scale)
+                .with_scale(inverse_scale),
+            visibility(&settings),
+            ChildOf(enemy),
+        ));
+    }
+}
+
+/// Rewrites labels whose enemy changed level after spawning.
+pub fn update_nameplates(
+    enemies: Query<(&CharacterEntry, &EnemyLevel, &Children), Changed<EnemyLevel>>,
+    mut nameplates: Query<&mut Text2d, With<Nameplate>>,
+) {
+    for (character, level, children) in &enemies {
+        for child in children.iter() {
+            if let Ok(mut text) = nameplates.get_mut(child) {
+                text.0 = nameplate_text(character, level);
+            }
+        }
+    }
+}
+
+/// Shows or hides every nameplate when the setting flips.
+pub fn sync_nameplate_visibility(
+    settings: Res<NameplateSettings>,
+    mut nameplates: Query<&mut Visibility, With<Nameplate>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut shown in &mut nameplates {
+        shown.set_if_neq(visibility(&settings));
+    }
+}
+
+fn visibility(settings: &NameplateSettings) -> Visibility {
+    if settings.visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
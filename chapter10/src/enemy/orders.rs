@@ -0,0 +1,165 @@
+// src/enemy/orders.rs
+use bevy::prelude::*;
+
+use super::components::{EnemyCombat, EnemyPath};
+use super::spawn_in::SpawningIn;
+use crate::characters::{
+    collider::Collider,
+    config::CharacterEntry,
+    facing::Facing,
+    input::Player,
+    physics::{calculate_velocity, Velocity},
+    state::CharacterState,
+};
+use crate::collision::CollisionMap;
+use crate::combat::{fire_power, PowerRegistry, ProjectileOwner};
+use crate::telemetry::GameplayEvent;
+
+/// Where an enemy that has orders wants to go.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum MoveGoal {
+    #[default]
+    Stop,
+    /// Straight at a point, ignoring obstacles
+    Toward(Vec2),
+    /// Along a path found around obstacles
+    PathTo(Vec2),
+}
+
+/// Decisions made for an enemy by something other than the built-in AI: a
+/// script or a behavior tree. The built-in AI leaves enemies with orders
+/// alone; these systems carry the orders out every frame, however often
+/// they're decided.
+#[derive(Component, Debug, Clone, Default)]
+pub struct EnemyOrders {
+    pub movement: MoveGoal,
+    /// Fire at the player whenever the attack is ready
+    pub attack: bool,
+}
+
+/// Moves enemies the way their orders say, with the same pathing and facing
+/// rules as the built-in AI. Enemies standing still to attack face the player.
+pub fn follow_enemy_orders(
+    time: Res<Time>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut enemies: Query<
+        (
+            &Transform,
+            &EnemyOrders,
+            &mut CharacterState,
+            &mut Velocity,
+            &mut Facing,
+            &mut EnemyPath,
+            &CharacterEntry,
+            &Collider,
+        ),
+        Without<SpawningIn>,
+    >,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Some(collision_map) = collision_map else {
+        return;
+    };
+    let player = player.single().ok().map(|t| t.translation.truncate());
+    let delta = time.delta_secs();
+
+    for (transform, orders, mut state, mut velocity, mut facing, mut path, character, collider) in
+        &mut enemies
+    {
+        let position = transform.translation.truncate();
+        let target = match orders.movement {
+            MoveGoal::Stop => None,
+            MoveGoal::Toward(point) => {
+                if path.has_path() {
+                    *path = EnemyPath::default();
+                }
+                Some(point)
+            }
+            MoveGoal::PathTo(goal) => {
+                path.recalc_timer -= delta;
+                if !path.has_path() || path.recalc_timer <= 0.0 {
+                    path.recalc_timer = EnemyPath::RECALC_INTERVAL;
+                    if let Some(waypoints) =
+                        collision_map.find_path(position, goal, collider.clearance())
+                    {
+                        path.set_path(waypoints);
+                    }
+                }
+                if path
+                    .current_waypoint()
+                    .is_some_and(|w| w.distance(position) < EnemyPath::WAYPOINT_THRESHOLD)
+                {
+                    path.advance();
+                }
+                Some(path.current_waypoint().unwrap_or(goal))
+            }
+        };
+
+        let direction = target.map_or(Vec2::ZERO, |t| (t - position).normalize_or_zero());
+        let new_state = if direction == Vec2::ZERO {
+            CharacterState::Idle
+        } else {
+            CharacterState::Walking
+        };
+        if *state != new_state {
+            *state = new_state;
+        }
+
+        let look = match player {
+            Some(player) if direction == Vec2::ZERO && orders.attack => player - position,
+            _ => direction,
+        };
+        if look != Vec2::ZERO {
+            let new_facing = Facing::from_velocity(look);
+            if *facing != new_facing {
+                *facing = new_facing;
+            }
+        }
+        *velocity = calculate_velocity(*state, direction, character);
+    }
+}
+
+/// Fires at the player for enemies ordered to attack, once the cooldown
+/// allows.
+pub fn fire_ordered_attacks(
+    mut commands: Commands,
+    time: Res<Time>,
+    powers: Res<PowerRegistry>,
+    mut enemies: Query<
+        (Entity, &GlobalTransform, &mut EnemyCombat, &EnemyOrders),
+        Without<SpawningIn>,
+    >,
+    player: Query<&Transform, With<Player>>,
+    mut telemetry: MessageWriter<GameplayEvent>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+
+    for (enemy, transform, mut combat, orders) in &mut enemies {
+        combat.cooldown.tick(time.delta());
+        if !orders.attack || !combat.cooldown.is_finished() {
+            continue;
+        }
+
+        let position = transform.translation();
+        let to_player = (player.translation - position).normalize_or_zero();
+        let fired = fire_power(
+            &mut commands,
+            &powers,
+            &combat.power_type,
+            position + to_player * 5.0,
+            to_player,
+            ProjectileOwner::Enemy,
+            enemy,
+        );
+        combat.cooldown.reset();
+
+        if fired.is_some() {
+            telemetry.write(GameplayEvent::ProjectileFired {
+                power_type: combat.power_type.clone(),
+                owner: ProjectileOwner::Enemy,
+            });
+        }
+    }
+}
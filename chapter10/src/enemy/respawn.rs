@@ -0,0 +1,263 @@
+// src/enemy/respawn.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::Enemy;
+use super::difficulty::level_at;
+use super::spawn::{spawn_clearance, spawn_enemy};
+use crate::camera::MainCamera;
+use crate::characters::config::CharactersList;
+use crate::characters::input::Player;
+use crate::characters::spawn::CharactersListResource;
+use crate::collision::CollisionMap;
+use crate::combat::{EntityDeath, Health};
+use crate::config::enemy::{ENEMY_Z_POSITION, RESPAWN_DELAY_SECS};
+use crate::config::map::{GRID_X, GRID_Y, TILE_SIZE};
+use crate::game_rng::GameRng;
+use crate::map::generate::WorldGenSettings;
+use crate::prefabs::PrefabRegistry;
+use crate::logging::targets;
+
+/// Zones line up with map chunks (chunks share their border row/column).
+const ZONE_WIDTH: i32 = GRID_X as i32 - 1;
+const ZONE_HEIGHT: i32 = GRID_Y as i32 - 1;
+/// How often cleared zones are checked for respawning (seconds).
+const RESPAWN_CHECK_INTERVAL: f32 = 2.0;
+/// Attempts to find a valid off-screen spawn point per enemy.
+const SPAWN_ATTEMPTS: usize = 20;
+/// Extra screen margin (pixels) so enemies never pop in right at the edge.
+const OFFSCREEN_MARGIN: f32 = 64.0;
+
+/// The zone an enemy belongs to and repopulates.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct HomeZone(pub IVec2);
+
+impl HomeZone {
+    pub fn from_world(position: Vec2, settings: &WorldGenSettings) -> Self {
+        let grid = ((position - settings.grid_origin()) / TILE_SIZE).floor().as_ivec2();
+        Self(IVec2::new(
+            grid.x.div_euclid(ZONE_WIDTH),
+            grid.y.div_euclid(ZONE_HEIGHT),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ZoneState {
+    /// Character names of the enemies that live in this zone
+    pub population: Vec<String>,
+    /// Respawn clock time when the last enemy of the zone died
+    pub cleared_at: Option<f64>,
+    /// Respawn clock time when the player was last inside the zone
+    pub last_visited: f64,
+}
+
+/// Repopulates cleared zones once the player has been away long enough.
+#[derive(Resource)]
+pub struct RespawnDirector {
+    /// Seconds of play time, advanced only while playing (persisted in saves)
+    pub clock: f64,
+    pub zones: HashMap<IVec2, ZoneState>,
+    check_timer: Timer,
+}
+
+impl Default for RespawnDirector {
+    fn default() -> Self {
+        Self {
+            clock: 0.0,
+            zones: HashMap::new(),
+            check_timer: Timer::from_seconds(RESPAWN_CHECK_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl RespawnDirector {
+    /// Record an enemy as part of a zone's population.
+    pub fn register_spawn(&mut self, zone: HomeZone, character_name: &str) {
+        self.zones
+            .entry(zone.0)
+            .or_default()
+            .population
+            .push(character_name.to_string());
+    }
+
+    pub fn mark_cleared(&mut self, zone: HomeZone) {
+        let clock = self.clock;
+        if let Some(state) = self.zones.get_mut(&zone.0) {
+            state.cleared_at = Some(clock);
+            info!(target: targets::ENEMY, "Zone {:?} cleared", zone.0);
+        }
+    }
+
+    fn ready_to_respawn(&self, player_zone: Option<HomeZone>) -> Vec<IVec2> {
+        self.zones
+            .iter()
+            .filter(|(id, _)| player_zone.map_or(true, |z| z.0 != **id))
+            .filter(|(_, state)| {
+                state.cleared_at.is_some_and(|cleared| {
+                    self.clock - cleared.max(state.last_visited) >= RESPAWN_DELAY_SECS as f64
+                })
+            })
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
+/// Advances the respawn clock and remembers when the player was last in each zone.
+pub fn track_zone_visits(
+    time: Res<Time>,
+    settings: Res<WorldGenSettings>,
+    mut director: ResMut<RespawnDirector>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    director.clock += time.delta_secs_f64();
+
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let zone = HomeZone::from_world(player_transform.translation.truncate(), &settings);
+    let clock = director.clock;
+    if let Some(state) = director.zones.get_mut(&zone.0) {
+        state.last_visited = clock;
+    }
+}
+
+/// Observer: when the last enemy of a zone dies, the zone is marked as cleared.
+pub fn on_zone_enemy_death(
+    death: On<EntityDeath>,
+    enemies: Query<(Entity, &HomeZone), With<Enemy>>,
+    mut director: ResMut<RespawnDirector>,
+) {
+    let Ok((_, zone)) = enemies.get(death.entity) else {
+        return;
+    };
+
+    let survivors = enemies
+        .iter()
+        .filter(|(entity, other)| *entity != death.entity && *other == zone)
+        .count();
+
+    if survivors == 0 {
+        director.mark_cleared(*zone);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn respawn_cleared_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<WorldGenSettings>,
+    mut director: ResMut<RespawnDirector>,
+    prefabs: Res<PrefabRegistry>,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    player_query: Query<&Transform, With<Player>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !director.check_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let Some(collision_map) = collision_map else {
+        return;
+    };
+    let Some(characters_list) = characters_list_res
+        .as_ref()
+        .and_then(|res| characters_lists.get(&res.handle))
+    else {
+        return;
+    };
+    let base_health = |name: &str| {
+        let character = prefabs.get(name)?.character.as_deref()?;
+        characters_list
+            .characters
+            .iter()
+            .find(|c| c.name == character)
+            .map(|c| c.max_health)
+    };
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    let player_zone = player_query
+        .single()
+        .ok()
+        .map(|t| HomeZone::from_world(t.translation.truncate(), &settings));
+
+    let rng = &mut rng.0;
+
+    for zone_id in director.ready_to_respawn(player_zone) {
+        let population = director.zones[&zone_id].population.clone();
+
+        // Every enemy needs a valid off-screen spot, otherwise try again later
+        let positions: Option<Vec<Vec2>> = population
+            .iter()
+            .map(|name| {
+                let clearance = spawn_clearance(&prefabs, name);
+                find_zone_spawn_position(&collision_map, camera, camera_transform, zone_id, clearance, rng)
+            })
+            .collect();
+        let Some(positions) = positions else {
+            continue;
+        };
+
+        for (name, position) in population.iter().zip(positions) {
+            let Some(base_health) = base_health(name) else {
+                warn!(target: targets::ENEMY, "No character for respawned enemy '{}'", name);
+                continue;
+            };
+            // Levelled from the start, as a loaded enemy is
+            let level = level_at(&collision_map, position);
+            spawn_enemy(
+                &mut commands,
+                position.extend(ENEMY_Z_POSITION),
+                name,
+                (HomeZone(zone_id), level, Health::new(base_health * level.health_multiplier())),
+            );
+        }
+
+        if let Some(state) = director.zones.get_mut(&zone_id) {
+            state.cleared_at = None;
+        }
+        info!(target: targets::ENEMY, "Zone {:?} repopulated with {} enemies", zone_id, population.len());
+    }
+}
+
+/// Random walkable point inside the zone that the camera can't currently see.
+fn find_zone_spawn_position(
+    collision_map: &CollisionMap,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    zone: IVec2,
+    clearance: f32,
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    (0..SPAWN_ATTEMPTS).find_map(|_| {
+        let grid_x = zone.x * ZONE_WIDTH + rng.gen_range(0..ZONE_WIDTH);
+        let grid_y = zone.y * ZONE_HEIGHT + rng.gen_range(0..ZONE_HEIGHT);
+        let candidate = collision_map.grid_to_world(grid_x, grid_y);
+
+        let valid = collision_map.is_circle_clear(candidate, clearance)
+            && !is_on_screen(camera, camera_transform, candidate);
+        valid.then_some(candidate)
+    })
+}
+
+fn is_on_screen(camera: &Camera, camera_transform: &GlobalTransform, position: Vec2) -> bool {
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return false;
+    };
+    let Ok(screen_pos) = camera.world_to_viewport(camera_transform, position.extend(0.0)) else {
+        return false;
+    };
+
+    screen_pos.x >= -OFFSCREEN_MARGIN
+        && screen_pos.y >= -OFFSCREEN_MARGIN
+        && screen_pos.x <= viewport.x + OFFSCREEN_MARGIN
+        && screen_pos.y <= viewport.y + OFFSCREEN_MARGIN
+}
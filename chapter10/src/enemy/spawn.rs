@@ -1,90 +1,51 @@
 // src/enemy/spawn.rs
-use super::components::{AIBehavior, Enemy, EnemyCombat, EnemyPath};
-use crate::characters::{
-    animation::{AnimationController, AnimationTimer, DEFAULT_ANIMATION_FRAME_TIME},
-    collider::Collider,
-    config::{CharacterEntry, CharactersList},
-    facing::Facing,
-    physics::Velocity,
-    spawn::CharactersListResource, // Add this line
-    state::CharacterState,
-};
+use super::difficulty::ScaleWithDistance;
+use super::respawn::{HomeZone, RespawnDirector};
+use crate::characters::{config::CharactersList, spawn::CharactersListResource};
 use crate::collision::CollisionMap;
-use crate::config::enemy::{ENEMY_SCALE, ENEMY_Z_POSITION};
+use crate::config::enemy::ENEMY_Z_POSITION;
+use crate::config::player::COLLIDER_RADIUS;
+use crate::map::generate::WorldGenSettings;
+use crate::map::level::LevelLayout;
+use crate::prefabs::{PrefabCommandsExt, PrefabRegistry};
 use bevy::prelude::*;
-use crate::combat::Health;
+use crate::logging::targets;
 
-/// Spawn an enemy at the given position
+/// Spawn an enemy from its prefab at the given position.
+/// `bundle` overrides prefab components (e.g. scaled health for survival waves).
 pub fn spawn_enemy(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
-    characters_list: &CharactersList,
     position: Vec3,
-    character_name: &str,
-) -> Option<Entity> {
-    // Find the character entry by name
-    let character_entry = characters_list
-        .characters
-        .iter()
-        .find(|c| c.name == character_name)?;
-
-    // Create atlas layout
-    let max_row = character_entry.calculate_max_animation_row();
-    let layout = atlas_layouts.add(TextureAtlasLayout::from_grid(
-        UVec2::splat(character_entry.tile_size),
-        character_entry.atlas_columns as u32,
-        (max_row + 1) as u32,
-        None,
-        None,
-    ));
-
-    // Load texture
-    let texture = asset_server.load(&character_entry.texture_path);
-
-    // Create sprite
-    let sprite = Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 });
-
-    // Spawn enemy entity with all necessary components
+    prefab: &str,
+    bundle: impl Bundle,
+) -> Entity {
     let entity = commands
-        .spawn((
-            Enemy,
-            sprite,
-            Transform::from_translation(position).with_scale(Vec3::splat(ENEMY_SCALE)),
-            GlobalTransform::default(),
-            AnimationController::default(),
-            CharacterState::default(),
-            Velocity::default(),
-            Facing::default(),
-            Collider::default(),
-            EnemyCombat::default(),
-            Health::new(character_entry.max_health), 
-            AIBehavior::default(),
-            EnemyPath::default(),  // Add this line
-            AnimationTimer(Timer::from_seconds(
-                DEFAULT_ANIMATION_FRAME_TIME,
-                TimerMode::Repeating,
-            )),
-            character_entry.clone(),
-        ))
+        .spawn_prefab(prefab, (Transform::from_translation(position), bundle))
         .id();
 
-    info!("Spawned enemy '{}' at {:?}", character_name, position);
+    debug!(target: targets::ENEMY, "Spawned enemy '{}' at {:?}", prefab, position);
 
-     Some(entity)
+    entity
 }
 
 /// Resource to track if enemies have been spawned
-#[derive(Resource, Default, PartialEq, Eq)]
+#[derive(Resource, Reflect, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
 pub struct EnemiesSpawned(pub bool);
 
+/// Room an enemy needs around its spawn point. Big enemies carry their
+/// collider in the prefab; everything else fits the default footprint.
+pub fn spawn_clearance(prefabs: &PrefabRegistry, name: &str) -> f32 {
+    prefabs
+        .get(name)
+        .and_then(|prefab| prefab.collider())
+        .map_or(COLLIDER_RADIUS, |collider| collider.clearance())
+}
+
 /// Validate and adjust spawn position to ensure it's on a walkable tile
-fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) -> Vec2 {
-    // Use circle check with enemy collision radius for robust detection
-    let enemy_radius = 12.0; // Approximate enemy collision radius
-    
+pub fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2, radius: f32) -> Vec2 {
     // Check if the desired position is clear (considering radius)
-    if collision_map.is_circle_clear(desired_pos, enemy_radius) {
+    if collision_map.is_circle_clear(desired_pos, radius) {
         return desired_pos;
     }
 
@@ -93,6 +54,7 @@ fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) ->
     if let Some(walkable) = collision_map.find_nearest_walkable(grid_pos) {
         let world_pos = collision_map.grid_to_world(walkable.x, walkable.y);
         info!(
+            target: targets::ENEMY,
             "Adjusted spawn from {:?} to {:?} (was on obstacle)",
             desired_pos, world_pos
         );
@@ -100,19 +62,23 @@ fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) ->
     }
 
     // Fallback to original (shouldn't happen in a valid map)
-    warn!("Could not find walkable spawn position near {:?}", desired_pos);
+    warn!(target: targets::ENEMY, "Could not find walkable spawn position near {:?}", desired_pos);
     desired_pos
 }
 
-/// System to spawn test enemies when collision map is ready
+/// System to spawn test enemies when collision map is ready, or the enemies
+/// of a hand-made level
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_test_enemies(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     characters_lists: Res<Assets<CharactersList>>,
     characters_list_res: Option<Res<CharactersListResource>>, // Add this line
+    prefabs: Res<PrefabRegistry>,
     collision_map: Option<Res<CollisionMap>>,
     mut enemies_spawned: ResMut<EnemiesSpawned>,
+    mut respawn_director: ResMut<RespawnDirector>,
+    settings: Res<WorldGenSettings>,
+    level: Option<Res<LevelLayout>>,
 ) {
     // Wait for collision map
     let Some(collision_map) = collision_map else {
@@ -124,29 +90,42 @@ pub fn spawn_test_enemies(
         return;
     };
 
-    // Get the character list asset
-    let Some(characters_list) = characters_lists.get(&characters_list_res.handle) else {
+    // Prefabs resolve their characters from the list, so both must be loaded
+    if characters_lists.get(&characters_list_res.handle).is_none() || !prefabs.is_loaded() {
         return;
-    };
+    }
 
     // Define desired spawn positions
-    let spawn_positions = [Vec2::new(200.0, 0.0), Vec2::new(-200.0, 100.0)];
+    let spawns: Vec<(&str, Vec2)> = match &level {
+        Some(level) => level
+            .enemies
+            .iter()
+            .map(|(name, position)| (name.as_str(), *position))
+            .collect(),
+        None => vec![
+            ("graveyard_reaper", Vec2::new(200.0, 0.0)),
+            ("graveyard_reaper", Vec2::new(-200.0, 100.0)),
+        ],
+    };
+
+    for (character_name, desired_pos) in spawns {
+        let radius = spawn_clearance(&prefabs, character_name);
 
-    for desired_pos in spawn_positions {
         // Validate position against collision map
-        let valid_pos = get_valid_spawn_position(&collision_map, desired_pos);
+        let valid_pos = get_valid_spawn_position(&collision_map, desired_pos, radius);
 
+        // Remember where this enemy lives so its zone can repopulate later
+        let zone = HomeZone::from_world(valid_pos, &settings);
         spawn_enemy(
             &mut commands,
-            &asset_server,
-            &mut atlas_layouts,
-            characters_list,
             Vec3::new(valid_pos.x, valid_pos.y, ENEMY_Z_POSITION),
-            "graveyard_reaper",
+            character_name,
+            (zone, ScaleWithDistance),
         );
+        respawn_director.register_spawn(zone, character_name);
     }
 
     // Mark enemies as spawned so this system doesn't run again
     enemies_spawned.0 = true;
-    info!("Enemies spawned with validated positions");
+    info!(target: targets::ENEMY, "Enemies spawned with validated positions");
 }
\ No newline at end of file
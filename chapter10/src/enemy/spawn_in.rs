@@ -0,0 +1,83 @@
+// src/enemy/spawn_in.rs
+use bevy::prelude::*;
+
+use crate::logging::targets;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+
+/// Wisps rise from this far below the enemy's centre, around its feet
+const WISP_FEET_OFFSET: f32 = -24.0;
+
+/// Enemy still materializing. Until the timer runs out it fades in, can't
+/// move, attack, collide or be hit, and ignores the player. Archetypes set
+/// the duration with the `SpawnIn` prefab component.
+#[derive(Component, Debug, Clone)]
+pub struct SpawningIn(pub Timer);
+
+impl SpawningIn {
+    pub fn from_seconds(secs: f32) -> Self {
+        Self(Timer::from_seconds(secs, TimerMode::Once))
+    }
+}
+
+/// Particle emitter drawing wisps up around a materializing enemy.
+#[derive(Component)]
+pub struct SpawnInWisps;
+
+/// Starts the fade from invisible and attaches the rising wisps.
+pub fn begin_spawn_in(
+    mut commands: Commands,
+    mut spawning: Query<(Entity, &GlobalTransform, Option<&mut Sprite>), Added<SpawningIn>>,
+) {
+    for (enemy, transform, sprite) in &mut spawning {
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(0.0);
+        }
+
+        let config = ParticleConfig {
+            lifetime: 0.6,
+            lifetime_variance: 0.2,
+            speed: 60.0,
+            speed_variance: 20.0,
+            direction: Vec3::Y,
+            direction_variance: 0.3,
+            scale: 0.8,
+            scale_variance: 0.3,
+            color: Color::srgb(1.6, 1.2, 2.4),
+            emission_shape: EmissionShape::Circle { radius: 20.0 },
+            ..default()
+        };
+        let position = transform.translation() + Vec3::new(0.0, WISP_FEET_OFFSET, 0.0);
+        commands.entity(enemy).with_child((
+            SpawnInWisps,
+            ParticleEmitter::new(0.05, 2, config),
+            Transform::from_xyz(0.0, WISP_FEET_OFFSET, 0.0),
+            GlobalTransform::from(Transform::from_translation(position)),
+        ));
+    }
+}
+
+/// Fades materializing enemies in, then lets them loose.
+pub fn tick_spawn_in(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut spawning: Query<(Entity, &mut SpawningIn, Option<&mut Sprite>, Option<&Children>)>,
+    wisps: Query<(), With<SpawnInWisps>>,
+) {
+    for (enemy, mut spawn_in, sprite, children) in &mut spawning {
+        spawn_in.0.tick(time.delta());
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(spawn_in.0.fraction());
+        }
+        if !spawn_in.0.is_finished() {
+            continue;
+        }
+
+        commands.entity(enemy).remove::<SpawningIn>();
+        for child in children.iter().flat_map(|children| children.iter()) {
+            if wisps.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        debug!(target: targets::ENEMY, "{:?} finished spawning in", enemy);
+    }
+}
@@ -0,0 +1,88 @@
+// src/enemy/squad.rs
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::components::{AIBehavior, Enemy};
+use super::spawn_in::SpawningIn;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::config::enemy::{SQUAD_RING_RADIUS, SQUAD_SLOTS};
+use crate::config::player::COLLIDER_RADIUS;
+
+/// Spreads chasing enemies around the player. Each one claims a spot on a
+/// ring around the player and paths there, instead of everyone heading for
+/// the player's own tile. Enemies past the slot count chase the player as
+/// before.
+#[derive(Resource, Default)]
+pub struct SquadManager {
+    /// Slot index each chasing enemy has claimed
+    claims: HashMap<Entity, usize>,
+    /// This frame's ring positions, `None` where the collision map blocks the spot
+    slots: Vec<Option<Vec2>>,
+}
+
+impl SquadManager {
+    /// World position of the slot `enemy` holds, if it holds one.
+    pub fn slot_for(&self, enemy: Entity) -> Option<Vec2> {
+        let index = *self.claims.get(&enemy)?;
+        self.slots.get(index).copied().flatten()
+    }
+}
+
+/// Moves the ring with the player and hands out slots. Enemies keep their
+/// slot while it stays open, and newcomers, closest first, take the free
+/// slot nearest to them.
+pub fn assign_squad_slots(
+    mut squad: ResMut<SquadManager>,
+    collision_map: Option<Res<CollisionMap>>,
+    players: Query<&Transform, With<Player>>,
+    enemies: Query<(Entity, &Transform, &AIBehavior), (With<Enemy>, Without<SpawningIn>)>,
+) {
+    let SquadManager { claims, slots } = &mut *squad;
+    let (Some(map), Ok(player)) = (collision_map, players.single()) else {
+        claims.clear();
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    *slots = (0..SQUAD_SLOTS)
+        .map(|i| {
+            let angle = TAU * i as f32 / SQUAD_SLOTS as f32;
+            let position = player_pos + Vec2::from_angle(angle) * SQUAD_RING_RADIUS;
+            map.is_circle_clear(position, COLLIDER_RADIUS).then_some(position)
+        })
+        .collect();
+
+    let mut chasers: Vec<(Entity, Vec2, f32)> = enemies
+        .iter()
+        .filter_map(|(entity, transform, ai)| {
+            let position = transform.translation.truncate();
+            let distance = position.distance(player_pos);
+            (distance <= ai.detection_range).then_some((entity, position, distance))
+        })
+        .collect();
+    chasers.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    // Enemies that died or lost interest, and slots that ended up in a wall
+    claims.retain(|enemy, slot| {
+        slots[*slot].is_some() && chasers.iter().any(|(chaser, _, _)| chaser == enemy)
+    });
+
+    for (enemy, position, _) in chasers {
+        if claims.contains_key(&enemy) {
+            continue;
+        }
+        let nearest_free = slots
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !claims.values().any(|claimed| claimed == index))
+            .filter_map(|(index, slot)| slot.map(|slot| (index, slot.distance(position))))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((index, _)) = nearest_free else {
+            break;
+        };
+        claims.insert(enemy, index);
+    }
+}
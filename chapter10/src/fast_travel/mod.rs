@@ -0,0 +1,44 @@
+// src/fast_travel/mod.rs
+mod obelisk;
+mod world_map;
+
+use bevy::prelude::*;
+
+use crate::collision::CollisionMap;
+use crate::schedule::{world_rebuilt, GameSet};
+use crate::state::{GameMode, PlayState};
+
+pub use obelisk::{obelisk_bundle, Obelisk};
+
+/// Obelisks spread over the map by the point-of-interest pass. Touching one
+/// attunes it and opens the world map, which offers a trip to any other
+/// attuned obelisk behind a confirmation and a fade to black.
+pub struct FastTravelPlugin;
+
+impl Plugin for FastTravelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(obelisk::on_obelisk_interact)
+            .add_observer(world_map::arrive_at_obelisk)
+            .add_systems(
+                Update,
+                obelisk::place_obelisks
+                    .run_if(world_rebuilt)
+                    .run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(
+                Update,
+                obelisk::light_attuned_obelisks.in_set(GameSet::Render),
+            )
+            .add_systems(
+                OnEnter(PlayState::UsingUI),
+                world_map::spawn_world_map
+                    .run_if(resource_exists::<world_map::TravelFrom>)
+                    .run_if(resource_exists::<CollisionMap>),
+            )
+            .add_systems(OnExit(PlayState::UsingUI), world_map::forget_travel_origin)
+            .add_systems(
+                Update,
+                world_map::handle_travel_buttons.run_if(in_state(PlayState::UsingUI)),
+            );
+    }
+}
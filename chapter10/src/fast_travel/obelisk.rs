@@ -0,0 +1,127 @@
+// src/fast_travel/obelisk.rs
+use bevy::prelude::*;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use super::world_map::TravelFrom;
+use crate::collision::CollisionMap;
+use crate::config::fast_travel::{OBELISK_INTERACT_RADIUS, OBELISK_MIN_SPACING, OBELISK_NAMES};
+use crate::interaction::{Interact, Interactable};
+use crate::lighting::LightSource;
+use crate::logging::targets;
+use crate::map::generate::WorldGenSettings;
+use crate::map::level::LevelLayout;
+use crate::state::{GameplayEntity, PlayState};
+
+/// Level with shrines and checkpoints
+const OBELISK_Z: f32 = 3.0;
+const OBELISK_SIZE: Vec2 = Vec2::new(18.0, 44.0);
+const DORMANT_COLOR: Color = Color::srgb(0.3, 0.3, 0.35);
+const ATTUNED_COLOR: Color = Color::srgb(0.6, 0.5, 1.0);
+/// Room around an obelisk, so travellers always have somewhere to stand
+const OBELISK_CLEARANCE: f32 = 64.0;
+
+/// Standing stone for fast travel. Touching one attunes it; attuned
+/// obelisks show up on the world map as destinations.
+#[derive(Component, Debug, Clone)]
+pub struct Obelisk {
+    pub name: String,
+    pub attuned: bool,
+}
+
+/// Everything an obelisk spawns with; saves rebuild them from this too.
+pub fn obelisk_bundle(name: impl Into<String>, position: Vec2, attuned: bool) -> impl Bundle {
+    (
+        Obelisk {
+            name: name.into(),
+            attuned,
+        },
+        Sprite::from_color(DORMANT_COLOR, OBELISK_SIZE),
+        Interactable::new("Touch", OBELISK_INTERACT_RADIUS),
+        LightSource {
+            intensity: 0.0,
+            ..LightSource::new(OBELISK_INTERACT_RADIUS * 2.0).with_color(ATTUNED_COLOR)
+        },
+        Transform::from_translation(position.extend(OBELISK_Z)),
+        GameplayEntity,
+    )
+}
+
+/// Point-of-interest pass for obelisks: spread far apart over open ground,
+/// seeded from the world seed. Hand-made levels choose the spots, and a
+/// loaded save already brought its own.
+pub fn place_obelisks(
+    mut commands: Commands,
+    map: Res<CollisionMap>,
+    settings: Res<WorldGenSettings>,
+    level: Option<Res<LevelLayout>>,
+    existing: Query<(), With<Obelisk>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+    let placed = match level {
+        Some(level) => level.obelisks.clone(),
+        None => pick_obelisk_spots(&map, settings.seed),
+    };
+
+    for (i, &position) in placed.iter().enumerate() {
+        let name = OBELISK_NAMES
+            .get(i)
+            .map_or_else(|| format!("Obelisk {}", i + 1), |name| name.to_string());
+        commands.spawn(obelisk_bundle(name, position, false));
+    }
+
+    info!(target: targets::MAP, "Placed {} obelisks", placed.len());
+}
+
+fn pick_obelisk_spots(map: &CollisionMap, seed: u64) -> Vec<Vec2> {
+    let mut rng = StdRng::seed_from_u64(seed ^ 0x0B31_15C0);
+
+    let mut candidates: Vec<Vec2> = (0..map.height())
+        .flat_map(|y| (0..map.width()).map(move |x| (x, y)))
+        .map(|(x, y)| map.grid_to_world(x, y))
+        .filter(|pos| map.is_circle_clear(*pos, OBELISK_CLEARANCE))
+        .collect();
+    candidates.shuffle(&mut rng);
+
+    let mut placed: Vec<Vec2> = Vec::new();
+    for position in candidates {
+        if placed.len() >= OBELISK_NAMES.len() {
+            break;
+        }
+        if placed.iter().any(|p| p.distance(position) < OBELISK_MIN_SPACING) {
+            continue;
+        }
+        placed.push(position);
+    }
+    placed
+}
+
+/// Touching an obelisk attunes it, then opens the world map from there.
+pub fn on_obelisk_interact(
+    interact: On<Interact>,
+    mut commands: Commands,
+    mut obelisks: Query<&mut Obelisk>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    let Ok(mut obelisk) = obelisks.get_mut(interact.target) else {
+        return;
+    };
+
+    if !obelisk.attuned {
+        obelisk.attuned = true;
+        info!(target: targets::PLAYER, "Attuned to obelisk '{}'", obelisk.name);
+    }
+    commands.insert_resource(TravelFrom(interact.target));
+    next_play_state.set(PlayState::UsingUI);
+}
+
+/// Attuned obelisks glow; dormant ones stay grey.
+pub fn light_attuned_obelisks(
+    mut obelisks: Query<(&Obelisk, &mut Sprite, &mut LightSource), Changed<Obelisk>>,
+) {
+    for (obelisk, mut sprite, mut light) in &mut obelisks {
+        sprite.color = if obelisk.attuned { ATTUNED_COLOR } else { DORMANT_COLOR };
+        light.intensity = if obelisk.attuned { 1.0 } else { 0.0 };
+    }
+}
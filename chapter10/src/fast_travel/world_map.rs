@@ -0,0 +1,251 @@
+// src/fast_travel/world_map.rs
+use bevy::prelude::*;
+
+use super::obelisk::Obelisk;
+use crate::camera::MainCamera;
+use crate::characters::collider::Collider;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::config::fast_travel::ARRIVAL_OFFSET;
+use crate::enemy::difficulty::{danger_color, level_at};
+use crate::logging::targets;
+use crate::state::{ConfirmDialog, PlayState};
+use crate::transitions::{FadeThrough, ScreenCovered};
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+
+const MAP_WIDTH: f32 = 640.0;
+const MARKER_SIZE: f32 = 14.0;
+/// Columns of the danger overlay; rows follow the map's aspect ratio
+const DANGER_COLUMNS: usize = 24;
+const DANGER_ALPHA: f32 = 0.35;
+const HERE_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const DESTINATION_COLORS: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.6, 0.5, 1.0),
+    hovered: Color::srgb(0.75, 0.68, 1.0),
+    pressed: Color::srgb(0.9, 0.85, 1.0),
+};
+
+/// The obelisk the world map was opened at; trips start from there.
+#[derive(Resource)]
+pub struct TravelFrom(pub Entity);
+
+/// A confirmed trip, carried out once the screen is black.
+#[derive(Resource)]
+pub struct PendingTravel {
+    pub name: String,
+    pub position: Vec2,
+}
+
+#[derive(Component)]
+pub struct WorldMap;
+
+/// Marker of an attuned obelisk the player can travel to.
+#[derive(Component)]
+pub struct TravelButton {
+    name: String,
+    position: Vec2,
+}
+
+/// Lays the whole map out on one panel, tinted by how dangerous each part
+/// is, with a marker on every attuned obelisk. Only the one opened at isn't
+/// a destination.
+pub fn spawn_world_map(
+    mut commands: Commands,
+    from: Res<TravelFrom>,
+    map: Res<CollisionMap>,
+    obelisks: Query<(Entity, &Obelisk, &Transform)>,
+    theme: Res<UiTheme>,
+) {
+    let extent = Vec2::new(map.width() as f32, map.height() as f32) * map.tile_size();
+    let origin = map.origin();
+    let panel = Vec2::new(MAP_WIDTH, MAP_WIDTH * extent.y / extent.x);
+
+    commands
+        .spawn((
+            WorldMap,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            // Under confirm dialogs and the screen fade
+            GlobalZIndex(50),
+            DespawnOnExit(PlayState::UsingUI),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("WORLD MAP"),
+                theme.text(TextRole::Heading),
+                TextColor(Color::WHITE),
+            ));
+
+            root.spawn((
+                Node {
+                    width: Val::Px(panel.x),
+                    height: Val::Px(panel.y),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.12, 0.16, 0.1, 0.95)),
+                BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+            ))
+            .with_children(|panel_node| {
+                // Level of the enemies around each patch, the same rings
+                // the zone danger HUD reads
+                let rows = (DANGER_COLUMNS as f32 * extent.y / extent.x).ceil().max(1.0) as usize;
+                let grid = Vec2::new(DANGER_COLUMNS as f32, rows as f32);
+                for row in 0..rows {
+                    for column in 0..DANGER_COLUMNS {
+                        let patch = Vec2::new(column as f32, row as f32);
+                        let level = level_at(&map, origin + (patch + 0.5) / grid * extent);
+                        panel_node.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(patch.x / grid.x * 100.0),
+                                bottom: Val::Percent(patch.y / grid.y * 100.0),
+                                width: Val::Percent(100.0 / grid.x),
+                                height: Val::Percent(100.0 / grid.y),
+                                ..default()
+                            },
+                            BackgroundColor(danger_color(level).with_alpha(DANGER_ALPHA)),
+                        ));
+                    }
+                }
+
+                for (entity, obelisk, transform) in &obelisks {
+                    if !obelisk.attuned {
+                        continue;
+                    }
+                    let position = transform.translation.truncate();
+                    let relative = (position - origin) / extent;
+                    let here = entity == from.0;
+
+                    panel_node
+                        .spawn(Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(relative.x * 100.0),
+                            bottom: Val::Percent(relative.y * 100.0),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        })
+                        .with_children(|marker| {
+                            let color = if here { HERE_COLOR } else { DESTINATION_COLORS.normal };
+                            let mut pin = marker.spawn((
+                                Node {
+                                    width: Val::Px(MARKER_SIZE),
+                                    height: Val::Px(MARKER_SIZE),
+                                    ..default()
+                                },
+                                BackgroundColor(color),
+                            ));
+                            if !here {
+                                pin.insert((
+                                    GameButton::new(DESTINATION_COLORS),
+                                    TravelButton {
+                                        name: obelisk.name.clone(),
+                                        position,
+                                    },
+                                ));
+                            }
+                            let label = if here {
+                                format!("{} (here)", obelisk.name)
+                            } else {
+                                obelisk.name.clone()
+                            };
+                            marker.spawn((
+                                Text::new(label),
+                                theme.text(TextRole::Small),
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                }
+            });
+
+            root.spawn((
+                Text::new("Redder areas hold stronger enemies"),
+                theme.text(TextRole::Caption),
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+            ));
+            root.spawn((
+                Text::new("Click an obelisk to travel there - Esc to close"),
+                theme.text(TextRole::Caption),
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+            ));
+        });
+}
+
+/// Asks before travelling; confirming fades out and queues the trip.
+pub fn handle_travel_buttons(
+    mut commands: Commands,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&TravelButton>,
+) {
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
+            continue;
+        };
+        let (name, position) = (button.name.clone(), button.position);
+        commands.spawn(ConfirmDialog::open(format!("Travel to {}?", name), move |world| {
+            world.insert_resource(PendingTravel { name, position });
+            world.write_message(FadeThrough);
+        }));
+    }
+}
+
+pub fn forget_travel_origin(mut commands: Commands) {
+    commands.remove_resource::<TravelFrom>();
+}
+
+/// At the bottom of the fade: moves the player in front of the destination
+/// and closes the map. The spot is checked against the collision map, and
+/// the trip is called off if the player doesn't fit anywhere near it.
+pub fn arrive_at_obelisk(
+    _covered: On<ScreenCovered>,
+    mut commands: Commands,
+    pending: Option<Res<PendingTravel>>,
+    map: Option<Res<CollisionMap>>,
+    mut players: Query<(&mut Transform, &Collider), With<Player>>,
+    mut cameras: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+    commands.remove_resource::<PendingTravel>();
+    next_play_state.set(PlayState::Exploring);
+
+    let (Some(map), Ok((mut transform, collider))) = (map, players.single_mut()) else {
+        return;
+    };
+    let Some(spot) = arrival_spot(&map, pending.position, collider) else {
+        warn!(target: targets::PLAYER, "No room to arrive at '{}', staying put", pending.name);
+        return;
+    };
+
+    let center = spot - collider.offset;
+    transform.translation = center.extend(transform.translation.z);
+    // Snap the camera too, so it doesn't sweep across the map on fade-in
+    if let Ok(mut camera) = cameras.single_mut() {
+        camera.translation.x = center.x;
+        camera.translation.y = center.y;
+    }
+    info!(target: targets::PLAYER, "Travelled to '{}' at {:?}", pending.name, center);
+}
+
+/// Where the player's collider lands: in front of the obelisk, or the
+/// nearest walkable cell if that's blocked.
+fn arrival_spot(map: &CollisionMap, obelisk: Vec2, collider: &Collider) -> Option<Vec2> {
+    let wanted = obelisk - Vec2::new(0.0, ARRIVAL_OFFSET);
+    if map.is_shape_clear(wanted, &collider.shape) {
+        return Some(wanted);
+    }
+    let cell = map.find_nearest_walkable(map.world_to_grid(wanted))?;
+    let spot = map.grid_to_world(cell.x, cell.y);
+    map.is_shape_clear(spot, &collider.shape).then_some(spot)
+}
@@ -0,0 +1,39 @@
+// src/feedback/mod.rs
+mod rumble;
+mod shake;
+
+use bevy::prelude::*;
+
+use crate::schedule::GameSet;
+
+pub use rumble::RumbleSettings;
+
+/// Feedback for big moments: gamepad rumble when the player is hurt, fires,
+/// or is caught near an explosion, and screen shake for hits and blasts,
+/// both scaled by how big the moment was.
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RumbleSettings>()
+            .init_resource::<RumbleSettings>()
+            .init_resource::<shake::ScreenShake>()
+            .add_systems(
+                Update,
+                (rumble::rumble_on_gameplay_events, rumble::rumble_on_blasts)
+                    .in_set(GameSet::Render),
+            )
+            // Shake comes off before the camera follows and goes back on after
+            .add_systems(Update, shake::remove_screen_shake.in_set(GameSet::Physics))
+            .add_systems(
+                Update,
+                (
+                    shake::shake_on_player_damage,
+                    shake::shake_on_blasts,
+                    shake::apply_screen_shake,
+                )
+                    .chain()
+                    .in_set(GameSet::Render),
+            );
+    }
+}
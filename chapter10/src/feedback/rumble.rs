@@ -0,0 +1,122 @@
+// src/feedback/rumble.rs
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::characters::input::Player;
+use crate::combat::{PowerRegistry, ProjectileImpact, ProjectileOwner};
+use crate::config::rumble::{
+    BLAST_RUMBLE_RANGE, BLAST_RUMBLE_SECS, DAMAGE_RUMBLE_SECS, FULL_RUMBLE_DAMAGE, MAX_RUMBLE,
+    SHOT_RUMBLE_PER_DAMAGE, SHOT_RUMBLE_SECS,
+};
+use crate::telemetry::GameplayEvent;
+
+/// Whether connected gamepads rumble. Toggled from the pause menu.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Default)]
+pub struct RumbleSettings {
+    pub enabled: bool,
+}
+
+impl Default for RumbleSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Sends the same rumble to every connected gamepad.
+fn rumble(
+    requests: &mut MessageWriter<GamepadRumbleRequest>,
+    gamepads: &Query<Entity, With<Gamepad>>,
+    intensity: GamepadRumbleIntensity,
+    secs: f32,
+) {
+    for gamepad in gamepads {
+        requests.write(GamepadRumbleRequest::Add {
+            gamepad,
+            intensity,
+            duration: Duration::from_secs_f32(secs),
+        });
+    }
+}
+
+/// Heavy motor when the player is hurt, light motor when they fire.
+/// Both scale with the damage involved.
+pub fn rumble_on_gameplay_events(
+    settings: Res<RumbleSettings>,
+    mut events: MessageReader<GameplayEvent>,
+    registry: Res<PowerRegistry>,
+    player: Query<(), With<Player>>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    if !settings.enabled || gamepads.is_empty() {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        match event {
+            GameplayEvent::DamageDealt { target, amount, .. } if player.contains(*target) => {
+                let strength = (amount / FULL_RUMBLE_DAMAGE).min(1.0) * MAX_RUMBLE;
+                let intensity = GamepadRumbleIntensity::strong_motor(strength);
+                rumble(&mut requests, &gamepads, intensity, DAMAGE_RUMBLE_SECS);
+            }
+            GameplayEvent::ProjectileFired {
+                power_type,
+                owner: ProjectileOwner::Player,
+            } => {
+                let Some(definition) = registry.get(power_type) else {
+                    continue;
+                };
+                let strength = (definition.damage * SHOT_RUMBLE_PER_DAMAGE).min(MAX_RUMBLE);
+                let intensity = GamepadRumbleIntensity::weak_motor(strength);
+                rumble(&mut requests, &gamepads, intensity, SHOT_RUMBLE_SECS);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Both motors for explosions, fading out with distance from the player.
+pub fn rumble_on_blasts(
+    settings: Res<RumbleSettings>,
+    mut impacts: MessageReader<ProjectileImpact>,
+    registry: Res<PowerRegistry>,
+    player: Query<&Transform, With<Player>>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    let Ok(player_transform) = player.single() else {
+        impacts.clear();
+        return;
+    };
+    if !settings.enabled || gamepads.is_empty() {
+        impacts.clear();
+        return;
+    }
+
+    let player_pos = player_transform.translation.truncate();
+    for impact in impacts.read() {
+        let Some(definition) = registry.get(&impact.power_type) else {
+            continue;
+        };
+        if definition.behaviors.blast_radius.is_none() {
+            continue;
+        }
+
+        let distance = player_pos.distance(impact.position.truncate());
+        let falloff = 1.0 - (distance / BLAST_RUMBLE_RANGE).min(1.0);
+        if falloff <= 0.0 {
+            continue;
+        }
+
+        let strength = falloff * MAX_RUMBLE;
+        let intensity = GamepadRumbleIntensity {
+            strong_motor: strength,
+            weak_motor: strength,
+        };
+        rumble(&mut requests, &gamepads, intensity, BLAST_RUMBLE_SECS);
+    }
+}
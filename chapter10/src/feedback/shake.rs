@@ -0,0 +1,104 @@
+// src/feedback/shake.rs
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::accessibility::AccessibilitySettings;
+use crate::camera::MainCamera;
+use crate::characters::input::Player;
+use crate::combat::{PowerRegistry, ProjectileImpact};
+use crate::config::screen_shake::{
+    BLAST_SHAKE_RANGE, FULL_SHAKE_DAMAGE, MAX_SHAKE_OFFSET, SHAKE_DECAY,
+};
+use crate::telemetry::GameplayEvent;
+
+/// Camera shake built up by hits and blasts and worn off over time.
+#[derive(Resource, Debug, Default)]
+pub struct ScreenShake {
+    /// 0 to 1; the view moves with its square, so small knocks stay subtle
+    trauma: f32,
+    /// Offset added to the camera this frame, taken back off before it follows
+    applied: Vec2,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+pub fn shake_on_player_damage(
+    mut events: MessageReader<GameplayEvent>,
+    player: Query<(), With<Player>>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    for event in events.read() {
+        match event {
+            GameplayEvent::DamageDealt { target, amount, .. } if player.contains(*target) => {
+                shake.add_trauma(amount / FULL_SHAKE_DAMAGE);
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn shake_on_blasts(
+    mut impacts: MessageReader<ProjectileImpact>,
+    registry: Res<PowerRegistry>,
+    player: Query<&Transform, With<Player>>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    let Ok(player_transform) = player.single() else {
+        impacts.clear();
+        return;
+    };
+
+    let player_pos = player_transform.translation.truncate();
+    for impact in impacts.read() {
+        let is_blast = registry
+            .get(&impact.power_type)
+            .is_some_and(|definition| definition.behaviors.blast_radius.is_some());
+        if !is_blast {
+            continue;
+        }
+        let distance = player_pos.distance(impact.position.truncate());
+        shake.add_trauma(1.0 - (distance / BLAST_SHAKE_RANGE).min(1.0));
+    }
+}
+
+/// Takes last frame's shake off the camera, so following starts from where
+/// the camera really is.
+pub fn remove_screen_shake(
+    mut shake: ResMut<ScreenShake>,
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+) {
+    let Ok(mut transform) = camera.single_mut() else {
+        return;
+    };
+    transform.translation -= shake.applied.extend(0.0);
+    shake.applied = Vec2::ZERO;
+}
+
+/// Throws the camera by a random whole-pixel offset for the current trauma,
+/// scaled by the screen shake setting, and lets the trauma wear off.
+pub fn apply_screen_shake(
+    time: Res<Time>,
+    settings: Res<AccessibilitySettings>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera: Query<&mut Transform, With<MainCamera>>,
+) {
+    if shake.trauma <= 0.0 {
+        return;
+    }
+    let Ok(mut transform) = camera.single_mut() else {
+        return;
+    };
+
+    let strength = shake.trauma * shake.trauma * settings.screen_shake * MAX_SHAKE_OFFSET;
+    let mut rng = rand::thread_rng();
+    let offset = Vec2::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0)) * strength;
+    let offset = offset.round();
+
+    transform.translation += offset.extend(0.0);
+    shake.applied = offset;
+    shake.trauma = (shake.trauma - SHAKE_DECAY * time.delta_secs()).max(0.0);
+}
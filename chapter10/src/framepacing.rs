@@ -0,0 +1,55 @@
+// src/framepacing.rs
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy::winit::{UpdateMode, WinitSettings};
+
+use crate::config::video::BACKGROUND_FPS;
+use crate::video::VideoSettings;
+
+/// Keeps the game from drawing more frames than it needs: an optional frame
+/// rate cap, and a slow trickle of updates while the window is in the
+/// background. Both come from the video settings.
+pub struct FramePacingPlugin;
+
+impl Plugin for FramePacingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            sync_background_throttle.run_if(resource_changed::<VideoSettings>),
+        )
+        .add_systems(Last, limit_frame_rate);
+    }
+}
+
+/// Lets winit go quiet while the window is unfocused, waking only on input
+/// or every 1 / BACKGROUND_FPS seconds.
+fn sync_background_throttle(settings: Res<VideoSettings>, mut winit: ResMut<WinitSettings>) {
+    winit.unfocused_mode = if settings.background_throttle {
+        UpdateMode::reactive_low_power(Duration::from_secs_f32(1.0 / BACKGROUND_FPS))
+    } else {
+        UpdateMode::Continuous
+    };
+}
+
+/// Sleeps off whatever is left of the frame's budget under the cap. Runs
+/// last so the whole frame's work counts against it.
+fn limit_frame_rate(
+    settings: Res<VideoSettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut last_frame: Local<Option<Instant>>,
+) {
+    let focused = windows.single().is_ok_and(|window| window.focused);
+    // In the background winit already spaces the updates out
+    let cap = settings.fps_cap.filter(|_| focused || !settings.background_throttle);
+
+    if let (Some(cap), Some(last)) = (cap, *last_frame) {
+        let budget = Duration::from_secs_f32(1.0 / cap as f32);
+        if let Some(remaining) = budget.checked_sub(last.elapsed()) {
+            thread::sleep(remaining);
+        }
+    }
+    *last_frame = Some(Instant::now());
+}
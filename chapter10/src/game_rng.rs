@@ -0,0 +1,33 @@
+// src/game_rng.rs
+use bevy::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::map::generate::WorldGenSettings;
+use crate::state::GameState;
+
+/// Randomness that changes how a run plays out: drops, enemy respawns,
+/// survival waves and world events. Reseeded from the map seed as each run
+/// loads, so the same map and the same inputs play out the same way, which
+/// demo playback relies on. Cosmetic randomness (particles, screen shake)
+/// keeps using `thread_rng`.
+#[derive(Resource)]
+pub struct GameRng(pub StdRng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+fn reseed_game_rng(settings: Res<WorldGenSettings>, mut rng: ResMut<GameRng>) {
+    rng.0 = StdRng::seed_from_u64(settings.seed ^ 0x9E37_79B9);
+}
+
+pub struct GameRngPlugin;
+
+impl Plugin for GameRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRng>()
+            .add_systems(OnEnter(GameState::Loading), reseed_game_rng);
+    }
+}
@@ -0,0 +1,121 @@
+// src/input_map.rs
+use bevy::prelude::*;
+
+use crate::accessibility::AccessibilitySettings;
+use crate::demo::DemoPlayback;
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+const MOVEMENT_KEYS: [(KeyCode, Vec2); 4] = [
+    (KeyCode::ArrowLeft, Vec2::NEG_X),
+    (KeyCode::ArrowRight, Vec2::X),
+    (KeyCode::ArrowUp, Vec2::Y),
+    (KeyCode::ArrowDown, Vec2::NEG_Y),
+];
+const RUN_KEYS: [KeyCode; 2] = [KeyCode::ShiftLeft, KeyCode::ShiftRight];
+const BLOCK_KEYS: [KeyCode; 2] = [KeyCode::AltLeft, KeyCode::AltRight];
+const FIRE_KEYS: [KeyCode; 2] = [KeyCode::ControlLeft, KeyCode::ControlRight];
+const SLOT_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
+    KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6,
+    KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+];
+
+/// What the player is asking for this frame. Player input and combat read
+/// this rather than polling keys, so bindings and the hold/toggle options
+/// live in one place.
+#[derive(Resource, Debug, Default)]
+pub struct PlayerActions {
+    pub movement: Vec2,
+    pub running: bool,
+    pub blocking: bool,
+    /// The one-shot actions are only set on the frame they're pressed
+    pub jump: bool,
+    pub fire: bool,
+    pub ability: bool,
+    pub cycle_lock_on: bool,
+    pub interact: bool,
+    /// Digit key pressed, from 0 for the 1 key. Picks a character (and in
+    /// debug builds a power)
+    pub select_slot: Option<usize>,
+}
+
+impl PlayerActions {
+    /// Drops a toggled block, for when the shield is forced down.
+    pub fn release_block(&mut self) {
+        self.blocking = false;
+    }
+
+    /// Clears the one-shot actions, ready for the next frame's presses.
+    pub fn clear_one_shots(&mut self) {
+        self.jump = false;
+        self.fire = false;
+        self.ability = false;
+        self.cycle_lock_on = false;
+        self.interact = false;
+        self.select_slot = None;
+    }
+}
+
+/// Held, or flipped on each press in toggle mode.
+fn held_or_toggled(
+    input: &ButtonInput<KeyCode>,
+    keys: [KeyCode; 2],
+    toggle: bool,
+    was: bool,
+) -> bool {
+    if toggle {
+        was ^ input.any_just_pressed(keys)
+    } else {
+        input.any_pressed(keys)
+    }
+}
+
+pub fn read_player_actions(
+    input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    settings: Res<AccessibilitySettings>,
+    mut actions: ResMut<PlayerActions>,
+) {
+    actions.movement = MOVEMENT_KEYS
+        .iter()
+        .filter(|(key, _)| input.pressed(*key))
+        .map(|(_, dir)| *dir)
+        .sum();
+    actions.running = held_or_toggled(&input, RUN_KEYS, settings.toggle_run, actions.running);
+    actions.blocking =
+        held_or_toggled(&input, BLOCK_KEYS, settings.toggle_block, actions.blocking);
+    actions.jump = input.just_pressed(KeyCode::Space);
+    actions.fire = input.any_just_pressed(FIRE_KEYS);
+    actions.ability = input.just_pressed(KeyCode::KeyQ);
+    actions.cycle_lock_on = input.just_pressed(KeyCode::Tab);
+    // The gamepad's south button also uses whatever the prompt is showing
+    actions.interact = input.just_pressed(KeyCode::KeyE)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    actions.select_slot = SLOT_KEYS.iter().position(|&key| input.just_pressed(key));
+}
+
+/// A new run, or a death, starts with nothing toggled on.
+fn reset_player_actions(mut actions: ResMut<PlayerActions>) {
+    *actions = PlayerActions::default();
+}
+
+/// Maps raw keys to `PlayerActions` at the start of each gameplay frame.
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerActions>()
+            // A playing demo fills in the actions itself
+            .add_systems(
+                Update,
+                read_player_actions
+                    .in_set(GameSet::Actions)
+                    .run_if(not(resource_exists::<DemoPlayback>)),
+            )
+            .add_systems(OnEnter(GameState::Loading), reset_player_actions)
+            .add_systems(OnEnter(GameState::GameOver), reset_player_actions);
+    }
+}
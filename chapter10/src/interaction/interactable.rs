@@ -0,0 +1,99 @@
+// src/interaction/interactable.rs
+use bevy::prelude::*;
+
+use crate::input_map::PlayerActions;
+use crate::logging::targets;
+
+/// Something the player can use by walking up to it and pressing interact.
+/// The prompt shows `verb` ("Open", "Talk", "Pray") above the nearest one.
+#[derive(Component, Debug, Clone)]
+pub struct Interactable {
+    pub verb: String,
+    /// How close the player has to be
+    pub radius: f32,
+    /// Disabled interactables (a recharging shrine) get no prompt and ignore presses
+    pub enabled: bool,
+}
+
+impl Interactable {
+    pub fn new(verb: impl Into<String>, radius: f32) -> Self {
+        Self {
+            verb: verb.into(),
+            radius,
+            enabled: true,
+        }
+    }
+}
+
+/// Event triggered on the focused interactable when the player presses interact.
+#[derive(Event)]
+pub struct Interact {
+    pub target: Entity,
+}
+
+/// Which device the player touched last, so prompts show the matching glyph.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputDevice {
+    #[default]
+    Keyboard,
+    Gamepad,
+}
+
+impl InputDevice {
+    pub fn interact_glyph(self) -> &'static str {
+        match self {
+            InputDevice::Keyboard => "E",
+            InputDevice::Gamepad => "(A)",
+        }
+    }
+}
+
+/// The interactable the prompt is showing, if any. Set by `interaction_prompt`.
+#[derive(Resource, Debug, Default)]
+pub struct InteractionFocus(pub Option<Entity>);
+
+/// Switches `InputDevice` on the first press (or stick push) from the other device.
+pub fn track_input_device(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut device: ResMut<InputDevice>,
+) {
+    let gamepad_used = gamepads.iter().any(|gamepad| {
+        gamepad.get_just_pressed().next().is_some() || gamepad.left_stick().length() > 0.5
+    });
+    let keyboard_used =
+        keys.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some();
+
+    let used = if gamepad_used {
+        InputDevice::Gamepad
+    } else if keyboard_used {
+        InputDevice::Keyboard
+    } else {
+        return;
+    };
+    if *device != used {
+        debug!(target: targets::PLAYER, "Input device: {:?}", used);
+        *device = used;
+    }
+}
+
+/// E (or the gamepad's south button) uses whatever the prompt is showing.
+pub fn interact(
+    mut commands: Commands,
+    actions: Res<PlayerActions>,
+    focus: Res<InteractionFocus>,
+    interactables: Query<&Interactable>,
+) {
+    if !actions.interact {
+        return;
+    }
+    // Re-check: the target may have been disabled or despawned since the prompt updated
+    let Some(target) = focus
+        .0
+        .filter(|target| interactables.get(*target).is_ok_and(|i| i.enabled))
+    else {
+        return;
+    };
+    commands.trigger(Interact { target });
+}
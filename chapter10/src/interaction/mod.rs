@@ -0,0 +1,27 @@
+// src/interaction/mod.rs
+mod interactable;
+mod prompt;
+
+use bevy::prelude::*;
+
+use crate::schedule::GameSet;
+
+pub use interactable::{Interact, Interactable};
+
+/// Walk-up interactions: the `Interactable` component, the prompt shown
+/// above the nearest one and the `Interact` event fired when it's used.
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<interactable::InputDevice>()
+            .init_resource::<interactable::InteractionFocus>()
+            .add_systems(
+                Update,
+                (interactable::track_input_device, interactable::interact)
+                    .chain()
+                    .in_set(GameSet::Input),
+            )
+            .add_systems(Update, prompt::interaction_prompt.in_set(GameSet::Render));
+    }
+}
@@ -0,0 +1,98 @@
+// src/interaction/prompt.rs
+use bevy::prelude::*;
+
+use super::interactable::{InputDevice, Interactable, InteractionFocus};
+use crate::characters::input::Player;
+use crate::state::GameplayEntity;
+
+const PROMPT_Y_OFFSET: f32 = 32.0;
+/// Level with the floating combat text, above the darkness overlay
+const PROMPT_Z: f32 = 950.0;
+const PROMPT_HEIGHT: f32 = 20.0;
+/// Rough width of one glyph at the prompt's font size, for sizing the backing
+const CHAR_WIDTH: f32 = 7.5;
+
+/// The "E — Open" widget. There is one, moved to whichever interactable is in focus.
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+#[derive(Component)]
+pub struct PromptBacking;
+
+fn spawn_prompt(commands: &mut Commands) {
+    commands
+        .spawn((
+            InteractionPrompt,
+            Text2d::new(""),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_xyz(0.0, 0.0, PROMPT_Z),
+            Visibility::Hidden,
+            GameplayEntity,
+        ))
+        .with_child((
+            PromptBacking,
+            Sprite::from_color(Color::srgba(0.0, 0.0, 0.0, 0.6), Vec2::new(1.0, PROMPT_HEIGHT)),
+            Transform::from_xyz(0.0, 0.0, -0.1),
+        ));
+}
+
+/// Picks the nearest enabled interactable in range, then moves the prompt
+/// above it and rewrites its text for the current input device. Spawns the
+/// prompt if it's missing (first frame, or after a load cleared the world).
+pub fn interaction_prompt(
+    mut commands: Commands,
+    device: Res<InputDevice>,
+    mut focus: ResMut<InteractionFocus>,
+    players: Query<&Transform, With<Player>>,
+    interactables: Query<(Entity, &Interactable, &GlobalTransform)>,
+    mut prompts: Query<
+        (&mut Text2d, &mut Transform, &mut Visibility, &Children),
+        (With<InteractionPrompt>, Without<Player>),
+    >,
+    mut backings: Query<&mut Sprite, With<PromptBacking>>,
+) {
+    let Ok((mut text, mut transform, mut visibility, children)) = prompts.single_mut() else {
+        spawn_prompt(&mut commands);
+        return;
+    };
+
+    let nearest = players.single().ok().and_then(|player| {
+        let player_pos = player.translation.truncate();
+        interactables
+            .iter()
+            .filter(|(_, interactable, _)| interactable.enabled)
+            .map(|(entity, interactable, t)| {
+                let position = t.translation().truncate();
+                (entity, interactable, position, position.distance(player_pos))
+            })
+            .filter(|(_, interactable, _, distance)| *distance <= interactable.radius)
+            .min_by(|a, b| a.3.total_cmp(&b.3))
+    });
+
+    let focused = nearest.map(|(entity, ..)| entity);
+    if focus.0 != focused {
+        focus.0 = focused;
+    }
+
+    let Some((_, interactable, position, _)) = nearest else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    let label = format!("{} \u{2014} {}", device.interact_glyph(), interactable.verb);
+    if text.0 != label {
+        for child in children.iter() {
+            if let Ok(mut sprite) = backings.get_mut(child) {
+                sprite.custom_size =
+                    Some(Vec2::new(label.chars().count() as f32 * CHAR_WIDTH + 12.0, PROMPT_HEIGHT));
+            }
+        }
+        text.0 = label;
+    }
+    transform.translation = Vec3::new(position.x, position.y + PROMPT_Y_OFFSET, PROMPT_Z);
+    visibility.set_if_neq(Visibility::Inherited);
+}
@@ -8,7 +8,7 @@ use std::fmt;
 use crate::config::pickup::DEFAULT_RADIUS;
 
 /// Types of items that can be collected.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ItemKind {
     Plant1,
     Plant2,
@@ -25,6 +25,22 @@ impl ItemKind {
             ItemKind::Plant4 => "Fern",
         }
     }
+
+    /// Radius each carried item adds to the player's light. Mushrooms glow.
+    pub fn light_bonus(&self) -> f32 {
+        match self {
+            ItemKind::Plant3 => 8.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Temporary max health granted when picked up. Ferns are hardy.
+    pub fn bonus_health(&self) -> f32 {
+        match self {
+            ItemKind::Plant4 => 15.0,
+            _ => 0.0,
+        }
+    }
 }
 
 impl fmt::Display for ItemKind {
@@ -33,7 +49,8 @@ impl fmt::Display for ItemKind {
     }
 }
 
-#[derive(Component, Debug)]
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
 pub struct Pickable {
     pub kind: ItemKind,
     pub radius: f32,
@@ -48,7 +65,8 @@ impl Pickable {
     }
 }
 
-#[derive(Resource, Default, Debug)]
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource, Default)]
 pub struct Inventory {
     items: HashMap<ItemKind, u32>,
 }
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::state::GameState;
+use crate::schedule::GameSet;
 
 mod inventory;
 mod systems;
@@ -13,10 +13,13 @@ pub struct InventoryPlugin;
 
 impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Inventory>()
+        app.register_type::<Inventory>()
+            .register_type::<Pickable>()
+            .register_type::<ItemKind>()
+            .init_resource::<Inventory>()
             .add_systems(
                 Update,
-                handle_pickups.run_if(in_state(GameState::Playing)),
+                handle_pickups.in_set(GameSet::PostPhysics),
             );
     }
 }
\ No newline at end of file
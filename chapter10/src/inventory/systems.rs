@@ -1,16 +1,19 @@
 use bevy::prelude::*;
 
 use crate::characters::input::Player;
+use crate::combat::Health;
 use super::inventory::{Pickable, Inventory};
+use crate::telemetry::GameplayEvent;
 
 /// System that checks for and processes item pickups.
 pub fn handle_pickups(
     mut commands: Commands,
     mut inventory: ResMut<Inventory>,
-    player_query: Query<&Transform, With<Player>>,
+    mut player_query: Query<(&Transform, &mut Health), With<Player>>,
     pickables: Query<(Entity, &GlobalTransform, &Pickable)>,
+    mut telemetry: MessageWriter<GameplayEvent>,
 ) {
-    let Ok(player_transform) = player_query.single() else {
+    let Ok((player_transform, mut health)) = player_query.single_mut() else {
         return;
     };
 
@@ -23,17 +26,21 @@ pub fn handle_pickups(
         let distance_sq = player_pos.distance_squared(item_pos);
         
         if distance_sq <= pickable.radius * pickable.radius {
-            collected.push((entity, pickable.kind));
+            collected.push((entity, pickable.kind, item_pos));
         }
     }
 
     // Process collected items
-    for (entity, kind) in collected {
+    for (entity, kind, position) in collected {
         commands.entity(entity).despawn();
         let count = inventory.add(kind);
-        info!(
-            " Picked up {} (total: {}) — inventory: {}",
-            kind, count, inventory.summary()
-        );
+        if kind.bonus_health() > 0.0 {
+            health.add_bonus(kind.bonus_health());
+        }
+        telemetry.write(GameplayEvent::ItemPicked {
+            kind,
+            total: count,
+            position,
+        });
     }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+// src/lighting/cave.rs
+use bevy::prelude::*;
+
+use super::day_night::LightingEnvironment;
+use crate::characters::input::Player;
+use crate::collision::{TriggerEntered, TriggerExited, TriggerZone};
+use crate::logging::targets;
+use crate::state::GameplayEntity;
+
+/// Area lit as a cave while the player is inside, placed by a level's
+/// `cave` objects.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default)]
+pub struct CaveArea;
+
+/// Places a cave area of `size` centred on `center`.
+pub fn spawn_cave(commands: &mut Commands, center: Vec2, size: Vec2) {
+    if size.min_element() <= 0.0 {
+        warn!(target: targets::MAP, "Skipping cave at {:?} without an area", center);
+        return;
+    }
+    commands.spawn((
+        TriggerZone::rect(size),
+        CaveArea,
+        Transform::from_translation(center.extend(0.0)),
+        GameplayEntity,
+    ));
+}
+
+pub fn enter_cave(
+    entered: On<TriggerEntered>,
+    caves: Query<(), With<CaveArea>>,
+    players: Query<(), With<Player>>,
+    mut environment: ResMut<LightingEnvironment>,
+) {
+    if caves.contains(entered.entity) && players.contains(entered.other) {
+        *environment = LightingEnvironment::Cave;
+    }
+}
+
+pub fn leave_cave(
+    exited: On<TriggerExited>,
+    caves: Query<(), With<CaveArea>>,
+    players: Query<(), With<Player>>,
+    mut environment: ResMut<LightingEnvironment>,
+) {
+    if caves.contains(exited.entity) && players.contains(exited.other) {
+        *environment = LightingEnvironment::Outdoors;
+    }
+}
@@ -0,0 +1,105 @@
+// src/lighting/day_night.rs
+use bevy::prelude::*;
+
+use crate::config::lighting::{CAVE_DARKNESS, DAY_LENGTH_SECS, NIGHT_DARKNESS, START_HOUR};
+use crate::console::ConsoleResult;
+
+/// Where the player is, for lighting purposes. Caves ignore the clock.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Default)]
+pub enum LightingEnvironment {
+    #[default]
+    Outdoors,
+    Cave,
+}
+
+/// In-game clock driving the outdoor darkness.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct DayNightCycle {
+    /// Hour of the day, 0.0..24.0
+    pub hour: f32,
+    /// Real seconds for a full day at 1x game speed
+    pub day_length_secs: f32,
+}
+
+impl Default for DayNightCycle {
+    fn default() -> Self {
+        Self {
+            hour: START_HOUR,
+            day_length_secs: DAY_LENGTH_SECS,
+        }
+    }
+}
+
+impl DayNightCycle {
+    /// How far into night the current hour is, from 0 in full daylight
+    /// (7:00-18:00) to 1 from 20:00 until 5:00, easing in between.
+    pub fn night(&self) -> f32 {
+        match self.hour {
+            h if h < 5.0 => 1.0,
+            h if h < 7.0 => 1.0 - smoothstep((h - 5.0) / 2.0),
+            h if h < 18.0 => 0.0,
+            h if h < 20.0 => smoothstep((h - 18.0) / 2.0),
+            _ => 1.0,
+        }
+    }
+
+    /// Outdoor darkness for the current hour, up to `NIGHT_DARKNESS`.
+    pub fn darkness(&self) -> f32 {
+        self.night() * NIGHT_DARKNESS
+    }
+}
+
+fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+pub fn advance_day_night(time: Res<Time>, mut cycle: ResMut<DayNightCycle>) {
+    let hours = time.delta_secs() * 24.0 / cycle.day_length_secs;
+    cycle.hour = (cycle.hour + hours).rem_euclid(24.0);
+}
+
+/// New games start in the morning, outdoors.
+pub fn reset_day_night(mut cycle: ResMut<DayNightCycle>, mut environment: ResMut<LightingEnvironment>) {
+    *cycle = DayNightCycle::default();
+    *environment = LightingEnvironment::Outdoors;
+}
+
+pub fn ambient_darkness(cycle: &DayNightCycle, environment: LightingEnvironment) -> f32 {
+    match environment {
+        LightingEnvironment::Outdoors => cycle.darkness(),
+        LightingEnvironment::Cave => CAVE_DARKNESS,
+    }
+}
+
+/// Console: `time <hour>` jumps the clock, e.g. `time 22`; no argument shows it.
+pub fn time_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let Some(value) = args.first() else {
+        return Ok(format!("time: {}", format_hour(world.resource::<DayNightCycle>().hour)));
+    };
+    let hour: f32 = value
+        .parse()
+        .map_err(|_| format!("Not a number: '{}'", value))?;
+    if !(0.0..24.0).contains(&hour) {
+        return Err("Hour must be between 0 and 24".to_string());
+    }
+
+    world.resource_mut::<DayNightCycle>().hour = hour;
+    Ok(format!("time: {}", format_hour(hour)))
+}
+
+/// Console: `cave` toggles between outdoor and cave lighting.
+pub fn cave_command(world: &mut World, _args: &[&str]) -> ConsoleResult {
+    let mut environment = world.resource_mut::<LightingEnvironment>();
+    *environment = match *environment {
+        LightingEnvironment::Outdoors => LightingEnvironment::Cave,
+        LightingEnvironment::Cave => LightingEnvironment::Outdoors,
+    };
+    Ok(format!("lighting: {:?}", *environment))
+}
+
+fn format_hour(hour: f32) -> String {
+    format!("{:02}:{:02}", hour as u32, (hour.fract() * 60.0) as u32)
+}
@@ -0,0 +1,137 @@
+// src/lighting/light.rs
+use bevy::prelude::*;
+
+use super::overlay::AmbientDarkness;
+use crate::characters::input::Player;
+use crate::config::lighting::{GLOW_ALPHA, GLOW_Z, MAX_LIGHT_BONUS, PLAYER_LIGHT_RADIUS};
+use crate::inventory::Inventory;
+use crate::particles::ParticleMaterial;
+use crate::state::GameplayEntity;
+
+/// Something that pushes back the darkness: the player, torches, fire.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct LightSource {
+    /// Distance at which the light has faded out completely
+    pub radius: f32,
+    /// 1.0 fully cancels night darkness at the centre
+    pub intensity: f32,
+    /// Tint of the additive glow drawn over the darkness
+    pub color: Color,
+    /// How much the intensity wobbles (0 = steady, 1 = wild)
+    pub flicker: f32,
+}
+
+impl LightSource {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            intensity: 1.0,
+            color: Color::srgb(1.0, 0.75, 0.4),
+            flicker: 0.0,
+        }
+    }
+
+    /// The player's own light, before item bonuses.
+    pub fn player() -> Self {
+        Self::new(PLAYER_LIGHT_RADIUS)
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_flicker(mut self, flicker: f32) -> Self {
+        self.flicker = flicker;
+        self
+    }
+
+    /// Intensity at `time`, flicker included. `seed` keeps neighbouring
+    /// lights from flickering in sync.
+    pub fn current_intensity(&self, time: f32, seed: f32) -> f32 {
+        if self.flicker == 0.0 {
+            return self.intensity;
+        }
+        let wobble = (time * 11.0 + seed).sin() * (time * 7.3 + seed * 0.5).sin();
+        self.intensity * (1.0 - self.flicker * 0.5 * (1.0 + wobble))
+    }
+}
+
+/// Light radius is a player stat: glowing mushrooms in the inventory widen it.
+pub fn update_player_light(
+    inventory: Res<Inventory>,
+    mut players: Query<&mut LightSource, With<Player>>,
+) {
+    let bonus: f32 = inventory
+        .items()
+        .iter()
+        .map(|(kind, count)| kind.light_bonus() * *count as f32)
+        .sum();
+    let radius = PLAYER_LIGHT_RADIUS + bonus.min(MAX_LIGHT_BONUS);
+
+    for mut light in &mut players {
+        if light.radius != radius {
+            light.radius = radius;
+        }
+    }
+}
+
+/// Additive glow sprite that follows a `LightSource`.
+#[derive(Component)]
+pub struct LightGlow {
+    source: Entity,
+}
+
+/// Unit quad shared by every glow; scaled to the light's diameter.
+#[derive(Resource)]
+pub struct GlowMesh(Handle<Mesh>);
+
+pub fn setup_glow_mesh(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    commands.insert_resource(GlowMesh(meshes.add(Rectangle::new(1.0, 1.0))));
+}
+
+pub fn spawn_light_glows(
+    mut commands: Commands,
+    glow_mesh: Res<GlowMesh>,
+    mut materials: ResMut<Assets<ParticleMaterial>>,
+    lights: Query<(Entity, &LightSource), Added<LightSource>>,
+) {
+    for (source, light) in &lights {
+        commands.spawn((
+            LightGlow { source },
+            Mesh2d(glow_mesh.0.clone()),
+            MeshMaterial2d(materials.add(ParticleMaterial::new(light.color.with_alpha(0.0)))),
+            Transform::default(),
+            GameplayEntity,
+        ));
+    }
+}
+
+/// Keeps glows on their light, fades them in with the darkness and removes
+/// them once the light is gone.
+pub fn update_light_glows(
+    mut commands: Commands,
+    time: Res<Time>,
+    darkness: Res<AmbientDarkness>,
+    lights: Query<(&GlobalTransform, &LightSource)>,
+    mut glows: Query<(Entity, &LightGlow, &mut Transform, &MeshMaterial2d<ParticleMaterial>)>,
+    mut materials: ResMut<Assets<ParticleMaterial>>,
+) {
+    for (entity, glow, mut transform, material) in &mut glows {
+        let Ok((light_transform, light)) = lights.get(glow.source) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let position = light_transform.translation();
+        transform.translation = position.truncate().extend(GLOW_Z);
+        transform.scale = Vec3::splat(light.radius * 2.0);
+
+        if let Some(material) = materials.get_mut(&material.0) {
+            let intensity = light.current_intensity(time.elapsed_secs(), position.x * 0.1);
+            let alpha = (GLOW_ALPHA * intensity * darkness.0).clamp(0.0, 1.0);
+            material.color = light.color.with_alpha(alpha).to_linear();
+        }
+    }
+}
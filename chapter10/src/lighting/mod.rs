@@ -0,0 +1,62 @@
+// src/lighting/mod.rs
+mod cave;
+mod day_night;
+mod light;
+mod overlay;
+mod torch;
+
+use bevy::{prelude::*, sprite_render::Material2dPlugin};
+
+use crate::console::ConsoleAppExt;
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+use overlay::{AmbientDarkness, LightingMaterial};
+
+pub use cave::{spawn_cave, CaveArea};
+pub use day_night::{DayNightCycle, LightingEnvironment};
+pub use light::LightSource;
+pub use torch::{spawn_torch, Torch};
+
+/// Darkness overlay driven by the day/night cycle (or a cave), with lights
+/// from the player, torches and fire cutting through it.
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<LightingMaterial>::default())
+            .register_type::<LightSource>()
+            .register_type::<DayNightCycle>()
+            .register_type::<LightingEnvironment>()
+            .register_type::<CaveArea>()
+            .init_resource::<DayNightCycle>()
+            .init_resource::<LightingEnvironment>()
+            .init_resource::<AmbientDarkness>()
+            .add_systems(
+                Startup,
+                (overlay::spawn_darkness_overlay, light::setup_glow_mesh),
+            )
+            .add_systems(OnEnter(GameState::Loading), day_night::reset_day_night)
+            .add_systems(
+                Update,
+                (day_night::advance_day_night, light::update_player_light)
+                    .in_set(GameSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                (
+                    overlay::update_ambient_darkness,
+                    light::spawn_light_glows,
+                    light::update_light_glows,
+                    overlay::update_darkness_overlay,
+                )
+                    .chain()
+                    .in_set(GameSet::Render),
+            )
+            .add_observer(cave::enter_cave)
+            .add_observer(cave::leave_cave)
+            .add_console_command("time", "Set the time of day: time [hour]", day_night::time_command)
+            .add_console_command("cave", "Toggle cave darkness", day_night::cave_command)
+            .add_console_command("torch", "Place a torch at the player", torch::torch_command);
+    }
+}
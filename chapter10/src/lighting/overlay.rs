@@ -0,0 +1,128 @@
+// src/lighting/overlay.rs
+use bevy::{
+    prelude::*,
+    reflect::TypePath,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+
+use super::day_night::{ambient_darkness, DayNightCycle, LightingEnvironment};
+use super::light::LightSource;
+use crate::camera::MainCamera;
+use crate::combat::terrain::DimZone;
+use crate::config::lighting::{MAX_LIGHTS, OVERLAY_SIZE, OVERLAY_Z};
+
+/// Ambient darkness this frame (0 = daylight, 1 = pitch black), before lights.
+#[derive(Resource, Debug, Default)]
+pub struct AmbientDarkness(pub f32);
+
+/// Data the darkness shader needs: ambient level plus every light in view
+/// packed as (x, y, radius, intensity). Dim zones are lights with negative intensity.
+#[derive(ShaderType, Debug, Clone)]
+pub struct LightingUniform {
+    pub ambient_darkness: f32,
+    pub light_count: u32,
+    pub lights: [Vec4; MAX_LIGHTS],
+}
+
+impl Default for LightingUniform {
+    fn default() -> Self {
+        Self {
+            ambient_darkness: 0.0,
+            light_count: 0,
+            lights: [Vec4::ZERO; MAX_LIGHTS],
+        }
+    }
+}
+
+/// Full-screen darkness with holes cut out around each light.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct LightingMaterial {
+    #[uniform(0)]
+    pub lighting: LightingUniform,
+}
+
+impl Material2d for LightingMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/lighting.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+/// Marker for the darkness quad, which rides along with the camera.
+#[derive(Component)]
+pub struct DarknessOverlay;
+
+pub fn spawn_darkness_overlay(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<LightingMaterial>>,
+) {
+    commands.spawn((
+        DarknessOverlay,
+        Mesh2d(meshes.add(Rectangle::new(OVERLAY_SIZE, OVERLAY_SIZE))),
+        MeshMaterial2d(materials.add(LightingMaterial::default())),
+        Transform::from_xyz(0.0, 0.0, OVERLAY_Z),
+    ));
+}
+
+pub fn update_ambient_darkness(
+    cycle: Res<DayNightCycle>,
+    environment: Res<LightingEnvironment>,
+    mut darkness: ResMut<AmbientDarkness>,
+) {
+    darkness.0 = ambient_darkness(&cycle, *environment);
+}
+
+/// Moves the overlay onto the camera and uploads the lights nearest to it.
+pub fn update_darkness_overlay(
+    time: Res<Time>,
+    darkness: Res<AmbientDarkness>,
+    camera: Query<&Transform, (With<MainCamera>, Without<DarknessOverlay>)>,
+    mut overlay: Query<(&mut Transform, &MeshMaterial2d<LightingMaterial>), With<DarknessOverlay>>,
+    lights: Query<(&GlobalTransform, &LightSource)>,
+    dim_zones: Query<(&GlobalTransform, &DimZone)>,
+    mut materials: ResMut<Assets<LightingMaterial>>,
+) {
+    let (Ok(camera), Ok((mut transform, material))) = (camera.single(), overlay.single_mut()) else {
+        return;
+    };
+    let center = camera.translation.truncate();
+    transform.translation = center.extend(OVERLAY_Z);
+
+    let elapsed = time.elapsed_secs();
+    let mut packed: Vec<Vec4> = lights
+        .iter()
+        .map(|(t, light)| {
+            let pos = t.translation().truncate();
+            let intensity = light.current_intensity(elapsed, pos.x * 0.1);
+            Vec4::new(pos.x, pos.y, light.radius, intensity)
+        })
+        .chain(dim_zones.iter().map(|(t, zone)| {
+            let pos = t.translation().truncate();
+            Vec4::new(pos.x, pos.y, zone.radius, -zone.strength)
+        }))
+        .collect();
+
+    // Only the lights closest to the camera fit in the uniform
+    if packed.len() > MAX_LIGHTS {
+        packed.sort_by(|a, b| {
+            let da = a.truncate().truncate().distance_squared(center);
+            let db = b.truncate().truncate().distance_squared(center);
+            da.total_cmp(&db)
+        });
+        packed.truncate(MAX_LIGHTS);
+    }
+
+    let Some(material) = materials.get_mut(&material.0) else {
+        return;
+    };
+    let uniform = &mut material.lighting;
+    uniform.ambient_darkness = darkness.0;
+    uniform.light_count = packed.len() as u32;
+    uniform.lights[..packed.len()].copy_from_slice(&packed);
+}
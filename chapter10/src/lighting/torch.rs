@@ -0,0 +1,58 @@
+// src/lighting/torch.rs
+use bevy::prelude::*;
+
+use super::light::LightSource;
+use crate::characters::input::Player;
+use crate::config::lighting::TORCH_LIGHT_RADIUS;
+use crate::console::ConsoleResult;
+use crate::particles::components::{EmissionShape, ParticleConfig, ParticleEmitter};
+use crate::state::GameplayEntity;
+
+#[derive(Component)]
+pub struct Torch;
+
+/// A flickering light with a small flame on top.
+pub fn spawn_torch(commands: &mut Commands, position: Vec3) -> Entity {
+    commands
+        .spawn((
+            Torch,
+            LightSource::new(TORCH_LIGHT_RADIUS).with_flicker(0.25),
+            Transform::from_translation(position),
+            GameplayEntity,
+        ))
+        .with_child((
+            ParticleEmitter::new(0.08, 1, flame_particles()),
+            Transform::IDENTITY,
+            GlobalTransform::from(Transform::from_translation(position)),
+        ))
+        .id()
+}
+
+fn flame_particles() -> ParticleConfig {
+    ParticleConfig {
+        lifetime: 0.5,
+        lifetime_variance: 0.15,
+        speed: 35.0,
+        speed_variance: 10.0,
+        direction: Vec3::Y,
+        direction_variance: 0.3,
+        scale: 0.7,
+        scale_variance: 0.2,
+        color: Color::srgb(3.0, 1.2, 0.2),
+        emission_shape: EmissionShape::Circle { radius: 3.0 },
+        ..default()
+    }
+}
+
+/// Console: `torch` drops a torch where the player stands.
+pub fn torch_command(world: &mut World, _args: &[&str]) -> ConsoleResult {
+    let mut players = world.query_filtered::<&Transform, With<Player>>();
+    let position = players
+        .single(world)
+        .map(|t| t.translation)
+        .map_err(|_| "No player to place the torch at".to_string())?;
+
+    spawn_torch(&mut world.commands(), position);
+    world.flush();
+    Ok(format!("Placed torch at ({:.0}, {:.0})", position.x, position.y))
+}
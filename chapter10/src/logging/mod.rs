@@ -0,0 +1,180 @@
+// src/logging/mod.rs
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use bevy::log::tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use bevy::log::tracing_subscriber::{self, reload, Layer, Registry};
+use bevy::log::{BoxedLayer, DEFAULT_FILTER};
+use bevy::prelude::*;
+
+use crate::console::{ConsoleAppExt, ConsoleResult};
+use crate::save::data::data_directory;
+
+/// Log targets, one per plugin. Use as `info!(target: targets::MAP, ...)`
+/// so output can be filtered per subsystem.
+pub mod targets {
+    pub const MAP: &str = "map";
+    pub const COMBAT: &str = "combat";
+    pub const ENEMY: &str = "enemy";
+    pub const PLAYER: &str = "player";
+    pub const COLLISION: &str = "collision";
+    pub const INVENTORY: &str = "inventory";
+    pub const PARTICLES: &str = "particles";
+    pub const SAVE: &str = "save";
+    pub const STATE: &str = "state";
+    pub const SURVIVAL: &str = "survival";
+    pub const PREFABS: &str = "prefabs";
+    pub const MODS: &str = "mods";
+    pub const EVENTS: &str = "events";
+
+    pub const ALL: [&str; 13] = [
+        MAP, COMBAT, ENEMY, PLAYER, COLLISION, INVENTORY, PARTICLES, SAVE, STATE, SURVIVAL,
+        PREFABS, MODS, EVENTS,
+    ];
+}
+
+const LOG_FILE_NAME: &str = "game";
+/// Rotated log files kept alongside the current one (game.1.log, game.2.log, ...).
+const KEPT_LOG_FILES: usize = 3;
+
+/// Handle to the file layer's filter, swapped at runtime by `apply_log_filters`.
+static FILE_FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+pub fn logs_directory() -> PathBuf {
+    data_directory().join("logs")
+}
+
+/// Filter for the whole subscriber. Game targets pass through at debug so the
+/// file layer can be made more verbose at runtime without a restart.
+pub fn global_filter() -> String {
+    let targets: Vec<String> = targets::ALL.iter().map(|t| format!("{}=debug", t)).collect();
+    format!("{},{}", DEFAULT_FILTER, targets.join(","))
+}
+
+/// Per-target verbosity of the log file, editable from the console.
+#[derive(Resource, Clone)]
+pub struct LogFilters {
+    pub default: LevelFilter,
+    pub targets: BTreeMap<String, LevelFilter>,
+}
+
+impl Default for LogFilters {
+    fn default() -> Self {
+        Self {
+            default: LevelFilter::INFO,
+            targets: BTreeMap::new(),
+        }
+    }
+}
+
+impl LogFilters {
+    fn directives(&self) -> String {
+        let mut directives = self.default.to_string();
+        for (target, level) in &self.targets {
+            directives.push_str(&format!(",{}={}", target, level));
+        }
+        directives
+    }
+
+    fn env_filter(&self) -> EnvFilter {
+        EnvFilter::new(self.directives())
+    }
+}
+
+/// `LogPlugin::custom_layer` hook: a plain-text file layer with a reloadable filter.
+pub fn file_log_layer(_app: &mut App) -> Option<BoxedLayer> {
+    let file = match open_log_file() {
+        Ok(file) => file,
+        Err(e) => {
+            // The subscriber isn't installed yet, so logging macros would go nowhere
+            eprintln!("File logging disabled: {}", e);
+            return None;
+        }
+    };
+
+    let (filter, handle) = reload::Layer::new(LogFilters::default().env_filter());
+    let _ = FILE_FILTER.set(handle);
+
+    Some(
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(Mutex::new(file))
+            .with_filter(filter)
+            .boxed(),
+    )
+}
+
+/// Shifts game.log -> game.1.log -> ... and opens a fresh game.log.
+fn open_log_file() -> Result<File, String> {
+    let dir = logs_directory();
+    fs::create_dir_all(&dir).map_err(|e| format!("Create dir error: {}", e))?;
+
+    let path = |index: usize| match index {
+        0 => dir.join(format!("{}.log", LOG_FILE_NAME)),
+        n => dir.join(format!("{}.{}.log", LOG_FILE_NAME, n)),
+    };
+
+    let _ = fs::remove_file(path(KEPT_LOG_FILES));
+    for index in (0..KEPT_LOG_FILES).rev() {
+        let _ = fs::rename(path(index), path(index + 1));
+    }
+
+    File::create(path(0)).map_err(|e| format!("Create file error: {}", e))
+}
+
+fn apply_log_filters(filters: Res<LogFilters>) {
+    let Some(handle) = FILE_FILTER.get() else {
+        return;
+    };
+
+    if let Err(e) = handle.reload(filters.env_filter()) {
+        error!("Failed to update log filter: {}", e);
+    }
+}
+
+/// Console: `log <level> [target]`, e.g. `log debug combat` or `log warn`.
+fn log_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let (level, target) = match args {
+        [level] => (*level, None),
+        [level, target] => (*level, Some(*target)),
+        _ => {
+            let filters = world.resource::<LogFilters>();
+            return Ok(format!(
+                "log file filter: {} (usage: log <level> [target])",
+                filters.directives()
+            ));
+        }
+    };
+
+    let level: LevelFilter = level
+        .parse()
+        .map_err(|_| format!("Unknown level '{}'", level))?;
+
+    let mut filters = world.resource_mut::<LogFilters>();
+    match target {
+        Some(target) if !targets::ALL.contains(&target) => {
+            return Err(format!("Unknown target '{}' ({})", target, targets::ALL.join(", ")));
+        }
+        Some(target) => {
+            filters.targets.insert(target.to_string(), level);
+        }
+        None => {
+            filters.default = level;
+            filters.targets.clear();
+        }
+    }
+
+    Ok(format!("log file filter: {}", filters.directives()))
+}
+
+pub struct LoggingPlugin;
+
+impl Plugin for LoggingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogFilters>()
+            .add_console_command("log", "Set log file verbosity: log <level> [target]", log_command)
+            .add_systems(Update, apply_log_filters.run_if(resource_changed::<LogFilters>));
+    }
+}
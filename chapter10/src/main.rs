@@ -9,24 +9,72 @@ mod combat;
 mod particles;
 mod enemy;
 mod save;
+mod survival;
+mod telemetry;
+mod console;
+mod logging;
+mod crash;
+mod schedule;
+mod prefabs;
+mod time_scale;
+mod lighting;
+mod ambience;
+mod music;
+mod buffs;
+mod waypoints;
+mod interaction;
+mod cutscene;
+mod transitions;
+mod checkpoints;
+mod props;
+mod rendering;
+mod video;
+mod framepacing;
+mod cli;
+mod mods;
+mod optional_assets;
+mod scripting;
+mod behavior;
+mod fast_travel;
+mod world_events;
+mod feedback;
+mod accessibility;
+mod input_map;
+mod demo;
+mod game_rng;
+mod ui;
 
 use bevy::{
+    log::LogPlugin,
     prelude::*,
     window::{MonitorSelection, Window, WindowMode, WindowPlugin}, // Line update alert
 };
 
 use bevy_procedural_tilemaps::prelude::*;
 use crate::camera::CameraPlugin;
-use crate::map::generate::{setup_generator, prepare_tilemap_handles_resource, poll_map_generation};
-use crate::state::GameState;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::{
+    generation_pending, poll_map_generation, prepare_tilemap_handles_resource, report_atlas_error,
+    setup_generator, world_assets_loaded, WorldGenSettings,
+};
+use crate::map::hot_reload::{check_world_rules, sync_tilemap_atlas};
+use crate::map::level::{level_command, spawn_pending_level, MapSource, PendingLevel, TmxFile, TmxLoader};
+use crate::map::rules::{RulesDefinition, WorldRules};
+use crate::map::tile_animation::{animate_tiles, tag_animated_tile};
+use crate::map::tmx::{map_export_command, map_import_command};
+use crate::console::ConsoleAppExt;
+use crate::map::tilemap::TilemapDefinition;
+use bevy_common_assets::ron::RonAssetPlugin;
+use crate::state::{GameState, LoadingFailed};
 
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::BLACK)) // Line update alert
+        .register_asset_source(mods::MODS_SOURCE, mods::mods_source())
         .add_plugins(
             DefaultPlugins
                 .set(AssetPlugin {
-                    file_path: "src/assets".into(),
+                    file_path: config::assets::ASSET_DIR.into(),
                     ..default()
                 })
                 .set(WindowPlugin {
@@ -37,19 +85,89 @@ fn main() {
                     }),
                     ..default()
                 })
-                .set(ImagePlugin::default_nearest()),
+                .set(ImagePlugin::default_nearest())
+                .set(LogPlugin {
+                    filter: logging::global_filter(),
+                    custom_layer: logging::file_log_layer,
+                    ..default()
+                }),
         )
+        .add_plugins(cli::CliArgs::from_env())
         .add_plugins(state::StatePlugin)
+        .add_plugins(transitions::TransitionsPlugin)
+        .add_plugins(schedule::SchedulePlugin)
+        .add_plugins(input_map::InputMapPlugin)
         .add_plugins(CameraPlugin) // Add this line
+        .add_plugins(prefabs::PrefabsPlugin)
+        .add_plugins(mods::ModsPlugin)
+        .add_plugins(time_scale::TimeScalePlugin)
+        .add_plugins(lighting::LightingPlugin)
+        .add_plugins(ambience::AmbiencePlugin)
+        .add_plugins(music::MusicPlugin)
         .add_plugins(inventory::InventoryPlugin)
         .add_plugins(collision::CollisionPlugin)
         .add_plugins(characters::CharactersPlugin)
-        .add_plugins(combat::CombatPlugin)
+        .add_plugins(combat::CombatPlugin::default())
+        .add_plugins(buffs::BuffsPlugin)
+        .add_plugins(waypoints::WaypointsPlugin)
+        .add_plugins(checkpoints::CheckpointsPlugin)
+        .add_plugins(fast_travel::FastTravelPlugin)
+        .add_plugins(world_events::WorldEventsPlugin)
+        .add_plugins(interaction::InteractionPlugin)
+        .add_plugins(props::PropsPlugin)
+        .add_plugins(rendering::RenderingPlugin)
+        .add_plugins(feedback::FeedbackPlugin)
+        .add_plugins(video::VideoPlugin)
+        .add_plugins(accessibility::AccessibilityPlugin)
+        .add_plugins(demo::DemoPlugin)
+        .add_plugins(game_rng::GameRngPlugin)
+        .add_plugins(ui::UiPlugin)
+        .add_plugins(framepacing::FramePacingPlugin)
+        .add_plugins(cutscene::CutscenePlugin)
         .add_plugins(enemy::EnemyPlugin) 
+        .add_plugins(scripting::ScriptingPlugin)
+        .add_plugins(behavior::BehaviorPlugin)
         .add_plugins(particles::ParticlesPlugin)
         .add_plugins(save::SavePlugin)
+        .add_plugins(survival::SurvivalPlugin)
+        .add_plugins(telemetry::TelemetryPlugin)
+        .add_plugins(console::ConsolePlugin)
+        .add_plugins(logging::LoggingPlugin)
+        .add_plugins(crash::CrashPlugin)
+        .register_type::<WorldGenSettings>()
+        .init_resource::<WorldGenSettings>()
+        .register_type::<MapSource>()
+        .init_resource::<MapSource>()
+        .init_asset::<TmxFile>()
+        .init_asset_loader::<TmxLoader>()
+        .add_plugins(RonAssetPlugin::<TilemapDefinition>::new(&["atlas.ron"]))
+        .add_plugins(RonAssetPlugin::<RulesDefinition>::new(&["rules.ron"]))
         .add_systems(Startup, prepare_tilemap_handles_resource)
-        .add_systems(OnEnter(GameState::Loading), setup_generator)
+        .add_systems(
+            Update,
+            (
+                report_atlas_error.run_if(not(resource_exists::<LoadingFailed>)),
+                setup_generator.run_if(world_assets_loaded).run_if(generation_pending),
+            )
+                .run_if(in_state(GameState::Loading)),
+        )
         .add_systems(Update, poll_map_generation.run_if(in_state(GameState::Loading)))
+        .add_systems(
+            Update,
+            spawn_pending_level
+                .run_if(in_state(GameState::Loading))
+                .run_if(resource_exists::<PendingLevel>),
+        )
+        .add_systems(
+            Update,
+            (sync_tilemap_atlas, animate_tiles)
+                .chain()
+                .run_if(resource_exists::<TilemapHandles>),
+        )
+        .add_observer(tag_animated_tile)
+        .add_systems(Update, check_world_rules.run_if(resource_exists::<WorldRules>))
+        .add_console_command("map_export", "Export the map to Tiled: map_export [file.tmx]", map_export_command)
+        .add_console_command("map_import", "Replace the map with a Tiled map: map_import <file.tmx>", map_import_command)
+        .add_console_command("level", "Play a hand-made level in new games: level [name]", level_command)
         .run();
 }
\ No newline at end of file
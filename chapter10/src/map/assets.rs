@@ -1,7 +1,7 @@
 use bevy::{prelude::*};
 use bevy_procedural_tilemaps::prelude::*;
 use crate::collision::{TileMarker, TileType};
-use crate::map::tilemap::TILEMAP; 
+use crate::map::tilemap::{TileAnimationFrames, TilemapDefinition};
 use crate::inventory::{ItemKind, Pickable};
 
 
@@ -9,7 +9,7 @@ use crate::inventory::{ItemKind, Pickable};
 #[derive(Clone)]
 pub struct SpawnableAsset {
     /// Name of the sprite inside our tilemap atlas
-    sprite_name: &'static str,
+    sprite_name: String,
     /// Offset in grid coordinates (for multi-tile objects)
     grid_offset: GridDelta,
     /// Offset in world coordinates (fine positioning)
@@ -21,9 +21,9 @@ pub struct SpawnableAsset {
 }
 
 impl SpawnableAsset {
-    pub fn new(sprite_name: &'static str) -> Self {
+    pub fn new(sprite_name: impl Into<String>) -> Self {
         Self {
-            sprite_name,
+            sprite_name: sprite_name.into(),
             grid_offset: GridDelta::new(0, 0, 0),
             offset: Vec3::ZERO,
             tile_type: None, // Default: no extra components
@@ -45,12 +45,26 @@ impl SpawnableAsset {
         self.pickable = Some(kind);
         self
     }
+
+    /// Cell offset and collision type of this sprite, for building the
+    /// collision map without spawning anything.
+    pub fn collision_tile(&self) -> (IVec3, TileType) {
+        let offset = IVec3::new(self.grid_offset.dx, self.grid_offset.dy, self.grid_offset.dz);
+        (offset, self.tile_type.unwrap_or_default())
+    }
 }
 
 #[derive(Clone, Resource)]
 pub struct TilemapHandles {
     pub image: Handle<Image>,
     pub layout: Handle<TextureAtlasLayout>,
+    pub definition: Handle<TilemapDefinition>,
+    /// Sprite name of each atlas index, empty until the definition has loaded
+    pub sprite_names: Vec<String>,
+    pub animations: Vec<TileAnimationFrames>,
+    /// Why the first atlas description was rejected. A map can't be built
+    /// until a valid one loads
+    pub error: Option<String>,
 }
 
 impl TilemapHandles {
@@ -60,28 +74,48 @@ impl TilemapHandles {
             TextureAtlas::from(self.layout.clone()).with_index(atlas_index),
         )
     }
+
+    /// Whether the atlas description has loaded and the map can be built.
+    pub fn is_loaded(&self) -> bool {
+        !self.sprite_names.is_empty()
+    }
 }
 
+/// Starts loading the atlas image and its description. The layout is
+/// filled in by `sync_tilemap_atlas` once the description arrives.
 pub fn prepare_tilemap_handles(
     asset_server: &Res<AssetServer>,
     atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
     assets_directory: &str,
     tilemap_file: &str,
+    atlas_file: &str,
 ) -> TilemapHandles {
-    let image = asset_server.load::<Image>(format!("{assets_directory}/{tilemap_file}"));
-    let mut layout = TextureAtlasLayout::new_empty(TILEMAP.atlas_size());
-    for index in 0..TILEMAP.sprites.len() {
-        layout.add_texture(TILEMAP.sprite_rect(index));
+    TilemapHandles {
+        image: asset_server.load(format!("{assets_directory}/{tilemap_file}")),
+        layout: atlas_layouts.add(TextureAtlasLayout::new_empty(UVec2::ONE)),
+        definition: asset_server.load(format!("{assets_directory}/{atlas_file}")),
+        sprite_names: Vec::new(),
+        animations: Vec::new(),
+        error: None,
     }
-    let layout = atlas_layouts.add(layout);
+}
 
-    TilemapHandles { image, layout }
+/// Every sprite name the world rules refer to, for validating the atlas.
+pub fn used_sprite_names(assets_definitions: &[Vec<SpawnableAsset>]) -> Vec<&str> {
+    assets_definitions
+        .iter()
+        .flatten()
+        .map(|asset| asset.sprite_name.as_str())
+        .collect()
 }
 
 pub fn load_assets(
     tilemap_handles: &TilemapHandles,
+    definition: &TilemapDefinition,
     assets_definitions: Vec<Vec<SpawnableAsset>>,
-) -> ModelsAssets<Sprite> {
+) -> Result<ModelsAssets<Sprite>, String> {
+    definition.validate(used_sprite_names(&assets_definitions))?;
+
     let mut models_assets = ModelsAssets::<Sprite>::new();
     
     for (model_index, assets) in assets_definitions.into_iter().enumerate() {
@@ -94,8 +128,8 @@ pub fn load_assets(
                 pickable, // Add this line
             } = asset_def;
 
-            let Some(atlas_index) = TILEMAP.sprite_index(sprite_name) else {
-                panic!("Unknown atlas sprite '{}'", sprite_name);
+            let Some(atlas_index) = definition.sprite_index(&sprite_name) else {
+                return Err(format!("Unknown atlas sprite '{}'", sprite_name));
             };
 
             // Create the spawner function that adds components
@@ -112,7 +146,7 @@ pub fn load_assets(
             );
         }
     }
-    models_assets
+    Ok(models_assets)
 }
 
 fn create_spawner(
@@ -136,6 +170,9 @@ fn create_spawner(
         (Some(TileType::Shore), None) => |e: &mut EntityCommands| {
             e.insert(TileMarker::new(TileType::Shore));
         },
+        (Some(TileType::Bridge), None) => |e: &mut EntityCommands| {
+            e.insert(TileMarker::new(TileType::Bridge));
+        },
         (Some(TileType::Tree), None) => |e: &mut EntityCommands| {
             e.insert(TileMarker::new(TileType::Tree));
         },
@@ -1,5 +1,5 @@
 // src/map/generate.rs
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
@@ -10,23 +10,86 @@ use bevy_procedural_tilemaps::proc_gen::generator::model::ModelInstance;
 use bevy_procedural_tilemaps::proc_gen::generator::rules::Rules;
 use bevy_procedural_tilemaps::proc_gen::grid::GridData;
 
+use crate::collision::{CollisionMap, CollisionMapBuilder, CollisionMapBuilt, TileType};
 use crate::config::map::{
-    CHUNKS_X, CHUNKS_Y, GRID_X, GRID_Y, NODE_SIZE_Z, TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y,
+    CHUNKS_X, CHUNKS_Y, GRID_X, GRID_Y, NODE_SIZE_Z, TILE_SIZE,
 };
 use crate::map::{
     assets::{load_assets, prepare_tilemap_handles, TilemapHandles},
-    rules::build_world,
+    level::{start_level_load, LevelLayout, MapSource, PendingLevel},
+    rules::{RulesDefinition, WorldRules},
+    tilemap::TilemapDefinition,
 };
+use crate::logging::targets;
+use crate::state::{GameplayEntity, LoadingFailed};
 
 const ASSETS_PATH: &str = "tile_layers";
 const TILEMAP_FILE: &str = "tilemap.png";
+const ATLAS_FILE: &str = "tilemap.atlas.ron";
+const RULES_FILE: &str = "world.rules.ron";
 const NODE_SIZE: Vec3 = Vec3::new(TILE_SIZE, TILE_SIZE, NODE_SIZE_Z);
-const ASSETS_SCALE: Vec3 = Vec3::new(2.0, 2.0, 1.0);
+pub const ASSETS_SCALE: Vec3 = Vec3::new(2.0, 2.0, 1.0);
 const GRID_Z: u32 = 5;
 
 /// Maximum unpin radius for progressive corner unpinning fallback.
 const MAX_UNPIN_RADIUS: u32 = 5;
 
+/// Size and seed of the world to generate. Story mode uses the full map,
+/// survival mode a smaller arena. Each new settings value rolls a fresh seed.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct WorldGenSettings {
+    pub chunks_x: u32,
+    pub chunks_y: u32,
+    pub seed: u64,
+}
+
+impl Default for WorldGenSettings {
+    fn default() -> Self {
+        Self {
+            chunks_x: CHUNKS_X,
+            chunks_y: CHUNKS_Y,
+            seed: rand::random(),
+        }
+    }
+}
+
+impl WorldGenSettings {
+    /// Compact arena used by the endless survival mode.
+    pub fn survival() -> Self {
+        Self {
+            chunks_x: 3,
+            chunks_y: 3,
+            seed: rand::random(),
+        }
+    }
+
+    /// Total unique grid width (chunks overlap by 1 tile at borders)
+    pub fn total_grid_x(&self) -> u32 {
+        self.chunks_x * GRID_X - (self.chunks_x - 1)
+    }
+
+    /// Total unique grid height (chunks overlap by 1 tile at borders)
+    pub fn total_grid_y(&self) -> u32 {
+        self.chunks_y * GRID_Y - (self.chunks_y - 1)
+    }
+
+    /// Seed for one generation attempt of one chunk, so a whole map
+    /// (including fallback retries) can be reproduced from `seed`.
+    fn chunk_seed(&self, cx: u32, cy: u32, attempt: u32) -> u64 {
+        let salt = ((cx as u64) << 40) | ((cy as u64) << 20) | attempt as u64;
+        (self.seed ^ salt).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// World position of the bottom-left corner of the (centered) map.
+    pub fn grid_origin(&self) -> Vec2 {
+        Vec2::new(
+            -TILE_SIZE * self.total_grid_x() as f32 / 2.0,
+            -TILE_SIZE * self.total_grid_y() as f32 / 2.0,
+        )
+    }
+}
+
 /// Shared progress counter for the loading screen.
 #[derive(Resource)]
 pub struct MapGenProgress {
@@ -38,11 +101,20 @@ pub struct MapGenProgress {
 #[derive(Resource)]
 pub struct MapReady;
 
+/// The map in the world is exactly what these settings generate, so a save
+/// can keep the settings instead of every tile. Hand-made and imported maps
+/// don't have it.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GeneratedMap(pub WorldGenSettings);
+
 /// Stores the spawner and grid template needed after background generation completes.
 #[derive(Resource)]
 pub struct MapSpawnResources {
     spawner: NodesSpawner<Sprite>,
     grid_template: CartesianGrid<Cartesian3D>,
+    /// Cell offset and collision type of each sprite, per model
+    model_tiles: Vec<Vec<(IVec3, TileType)>>,
+    settings: WorldGenSettings,
 }
 
 /// Background task producing generated chunk data.
@@ -62,54 +134,133 @@ pub fn prepare_tilemap_handles_resource(
     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     let tilemap_handles =
-        prepare_tilemap_handles(&asset_server, &mut atlas_layouts, ASSETS_PATH, TILEMAP_FILE);
+        prepare_tilemap_handles(&asset_server, &mut atlas_layouts, ASSETS_PATH, TILEMAP_FILE, ATLAS_FILE);
     commands.insert_resource(tilemap_handles);
+    commands.insert_resource(WorldRules(asset_server.load(format!("{ASSETS_PATH}/{RULES_FILE}"))));
 }
 
+/// Generation needs the atlas description and the rules; the loading
+/// screen waits for both.
+pub fn world_assets_loaded(
+    tilemap_handles: Res<TilemapHandles>,
+    rules: Res<WorldRules>,
+    definitions: Res<Assets<RulesDefinition>>,
+) -> bool {
+    tilemap_handles.is_loaded() && definitions.contains(&rules.0)
+}
+
+/// Starts generating once per visit to the loading screen, and doesn't
+/// retry one that failed.
+pub fn generation_pending(
+    task: Option<Res<MapGenTask>>,
+    level: Option<Res<PendingLevel>>,
+    ready: Option<Res<MapReady>>,
+    failed: Option<Res<LoadingFailed>>,
+) -> bool {
+    task.is_none() && level.is_none() && ready.is_none() && failed.is_none()
+}
+
+/// Shows a rejected atlas description on the loading screen instead of
+/// waiting for an atlas that won't come.
+pub fn report_atlas_error(mut commands: Commands, tilemap_handles: Res<TilemapHandles>) {
+    if let Some(e) = &tilemap_handles.error {
+        commands.insert_resource(LoadingFailed(e.clone()));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn setup_generator(
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    map_source: Res<MapSource>,
     tilemap_handles: Res<TilemapHandles>,
+    definitions: Res<Assets<TilemapDefinition>>,
+    world_rules: Res<WorldRules>,
+    rules_definitions: Res<Assets<RulesDefinition>>,
+    settings: Res<WorldGenSettings>,
 ) {
+    if let MapSource::Level(name) = &*map_source {
+        start_level_load(&mut commands, &asset_server, name);
+        return;
+    }
+    commands.remove_resource::<LevelLayout>();
+
+    let (Some(definition), Some(rules_definition)) = (
+        definitions.get(&tilemap_handles.definition),
+        rules_definitions.get(&world_rules.0),
+    ) else {
+        return;
+    };
+
+    if let Err(e) =
+        start_generation(&mut commands, &tilemap_handles, definition, rules_definition, *settings)
+    {
+        error!(target: targets::MAP, "{}", e);
+        commands.insert_resource(LoadingFailed(e));
+    }
+}
+
+/// Starts generating the map `settings` describe on the task pool;
+/// `poll_map_generation` spawns it once done. Loading a save uses it to
+/// rebuild the map it was made on.
+pub fn start_generation(
+    commands: &mut Commands,
+    tilemap_handles: &TilemapHandles,
+    definition: &TilemapDefinition,
+    rules_definition: &RulesDefinition,
+    settings: WorldGenSettings,
+) -> Result<(), String> {
     // 1. Build rules, models, and assets (shared across all chunks)
-    let (assets_definitions, models, socket_collection) = build_world();
+    let (assets_definitions, models, socket_collection) = rules_definition
+        .compile()
+        .map_err(|e| format!("Invalid world rules: {}", e))?;
 
     let rules = RulesBuilder::new_cartesian_3d(models, socket_collection)
         .with_rotation_axis(Direction::ZForward)
         .build()
-        .unwrap();
+        .map_err(|e| format!("Invalid world rules: {}", e))?;
     let rules_arc = Arc::new(rules);
 
     let grid_template =
         CartesianGrid::new_cartesian_3d(GRID_X, GRID_Y, GRID_Z, false, false, false);
 
-    let models_assets = load_assets(&tilemap_handles, assets_definitions);
+    let model_tiles = assets_definitions
+        .iter()
+        .map(|assets| assets.iter().map(|asset| asset.collision_tile()).collect())
+        .collect();
+    let models_assets = load_assets(tilemap_handles, definition, assets_definitions)
+        .map_err(|e| format!("Cannot build the map: {}", e))?;
     let spawner = NodesSpawner::new(models_assets, NODE_SIZE, ASSETS_SCALE);
 
     // Store resources needed for spawning later
     commands.insert_resource(MapSpawnResources {
         spawner,
         grid_template: grid_template.clone(),
+        model_tiles,
+        settings,
     });
 
     // Initialize progress tracking
     let progress = Arc::new(AtomicU32::new(0));
     commands.insert_resource(MapGenProgress {
         current: progress.clone(),
-        total: CHUNKS_X * CHUNKS_Y,
+        total: settings.chunks_x * settings.chunks_y,
     });
 
     // Spawn the background task
     let pool = AsyncComputeTaskPool::get();
     let task = pool.spawn(async move {
-        generate_all_chunks(rules_arc, grid_template, progress)
+        generate_all_chunks(rules_arc, grid_template, settings, progress)
     });
     commands.insert_resource(MapGenTask(task));
+    Ok(())
 }
 
 pub fn poll_map_generation(
     mut commands: Commands,
     task: Option<ResMut<MapGenTask>>,
     resources: Option<Res<MapSpawnResources>>,
+    mut collision_map_built: ResMut<CollisionMapBuilt>,
 ) {
     let (Some(mut task), Some(resources)) = (task, resources) else {
         return;
@@ -120,16 +271,31 @@ pub fn poll_map_generation(
         return; // Still running...
     };
 
-    // Task finished! Spawn everything.
+    // Task finished! The collision map comes straight from the grid data,
+    // so it's ready before any tile spawns.
+    let mut carved = HashSet::new();
+    match build_collision_map(&chunks, &resources.grid_template, &resources.model_tiles, &resources.settings) {
+        Some(mut map) => {
+            carved.extend(map.connect_regions());
+            if !carved.is_empty() {
+                info!(target: targets::MAP, "Carved {} tiles to connect walkable regions", carved.len());
+            }
+            commands.insert_resource(map);
+            collision_map_built.0 = true;
+        }
+        None => error!(target: targets::MAP, "Generated map has no tiles"),
+    }
+
+    // Spawn everything.
     for chunk in &chunks {
         spawn_chunk_tiles(
             &mut commands,
             &resources.grid_template,
             &resources.spawner,
-            &chunk.grid_data,
-            chunk.chunk_offset,
-            chunk.chunk_x,
-            chunk.chunk_y,
+            chunk,
+            &resources.model_tiles,
+            &carved,
+            &resources.settings,
         );
     }
 
@@ -138,25 +304,36 @@ pub fn poll_map_generation(
     commands.remove_resource::<MapSpawnResources>();
     commands.remove_resource::<MapGenProgress>();
     commands.insert_resource(MapReady);
+    commands.insert_resource(GeneratedMap(resources.settings));
 
+    let settings = &resources.settings;
     info!(
-        "Map generation complete: {}x{} chunks, {}x{} total tiles",
-        CHUNKS_X, CHUNKS_Y, TOTAL_GRID_X, TOTAL_GRID_Y
+        target: targets::MAP,
+        "Map generation complete: {}x{} chunks, {}x{} total tiles (seed {})",
+        settings.chunks_x,
+        settings.chunks_y,
+        settings.total_grid_x(),
+        settings.total_grid_y(),
+        settings.seed
     );
 }
 
 fn generate_all_chunks(
     rules_arc: Arc<Rules<Cartesian3D>>,
     grid_template: CartesianGrid<Cartesian3D>,
+    settings: WorldGenSettings,
     progress: Arc<AtomicU32>,
 ) -> Vec<ChunkResult> {
+    let (chunks_x, chunks_y) = (settings.chunks_x, settings.chunks_y);
+    let (total_grid_x, total_grid_y) = (settings.total_grid_x(), settings.total_grid_y());
+
     let mut generated_chunks: HashMap<
         (u32, u32),
         GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>,
     > = HashMap::new();
 
-    for cy in 0..CHUNKS_Y {
-        for cx in 0..CHUNKS_X {
+    for cy in 0..chunks_y {
+        for cx in 0..chunks_x {
             // Seed borders from neighbors
             let initial_nodes = build_initial_nodes(cx, cy, &generated_chunks, &grid_template);
             let is_corner = cx > 0 && cy > 0;
@@ -169,22 +346,23 @@ fn generate_all_chunks(
                 is_corner,
                 cx,
                 cy,
+                &settings,
             );
 
             generated_chunks.insert((cx, cy), grid_data);
             progress.fetch_add(1, Ordering::Relaxed);
-            info!("Generated chunk ({}, {})", cx, cy);
+            debug!(target: targets::MAP, "Generated chunk ({}, {})", cx, cy);
         }
     }
 
     // Convert HashMap into results for spawning
-    let mut results = Vec::with_capacity((CHUNKS_X * CHUNKS_Y) as usize);
-    for cy in 0..CHUNKS_Y {
-        for cx in 0..CHUNKS_X {
+    let mut results = Vec::with_capacity((chunks_x * chunks_y) as usize);
+    for cy in 0..chunks_y {
+        for cx in 0..chunks_x {
             let grid_data = generated_chunks.remove(&(cx, cy)).unwrap();
             let chunk_offset = Vec3::new(
-                (cx as f32 * (GRID_X - 1) as f32 - TOTAL_GRID_X as f32 / 2.0) * TILE_SIZE,
-                (cy as f32 * (GRID_Y - 1) as f32 - TOTAL_GRID_Y as f32 / 2.0) * TILE_SIZE,
+                (cx as f32 * (GRID_X - 1) as f32 - total_grid_x as f32 / 2.0) * TILE_SIZE,
+                (cy as f32 * (GRID_Y - 1) as f32 - total_grid_y as f32 / 2.0) * TILE_SIZE,
                 0.0,
             );
             results.push(ChunkResult {
@@ -245,14 +423,18 @@ fn generate_chunk_with_fallback(
     is_corner: bool,
     cx: u32,
     cy: u32,
+    settings: &WorldGenSettings,
 ) -> GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>> {
     // Try with full initial nodes first
-    if let Some(data) = try_generate_chunk(rules, grid, initial_nodes) {
+    if let Some(data) = try_generate_chunk(rules, grid, initial_nodes, settings.chunk_seed(cx, cy, 0)) {
         return data;
     }
 
     if !is_corner {
-        panic!("Non-corner chunk ({}, {}) failed -- check your rules!", cx, cy);
+        panic!(
+            "Non-corner chunk ({}, {}) failed with seed {} -- check your rules!",
+            cx, cy, settings.seed
+        );
     }
 
     // Progressive unpinning: remove an L-shaped region at the corner
@@ -266,19 +448,20 @@ fn generate_chunk_with_fallback(
             .copied()
             .collect();
 
-        if let Some(data) = try_generate_chunk(rules, grid, &reduced) {
-            warn!("Corner chunk ({}, {}) needed unpin radius {}", cx, cy, radius);
+        if let Some(data) = try_generate_chunk(rules, grid, &reduced, settings.chunk_seed(cx, cy, radius)) {
+            warn!(target: targets::MAP, "Corner chunk ({}, {}) needed unpin radius {}", cx, cy, radius);
             return data;
         }
     }
 
-    panic!("Corner chunk ({}, {}) failed to generate.", cx, cy);
+    panic!("Corner chunk ({}, {}) failed to generate with seed {}.", cx, cy, settings.seed);
 }
 
 fn try_generate_chunk(
     rules: &Arc<Rules<Cartesian3D>>,
     grid: &CartesianGrid<Cartesian3D>,
     initial_nodes: &[((u32, u32, u32), ModelInstance)],
+    seed: u64,
 ) -> Option<GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>> {
     // In v0.3 we explicitly set border zones
     let num_directions = 6;
@@ -293,7 +476,7 @@ fn try_generate_chunk(
     let gen_builder = GeneratorBuilder::new()
         .with_shared_rules(rules.clone())
         .with_grid(grid.clone())
-        .with_rng(RngMode::RandomSeed)
+        .with_rng(RngMode::Seeded(seed))
         .with_node_heuristic(NodeSelectionHeuristic::MinimumRemainingValue)
         .with_model_heuristic(ModelSelectionHeuristic::WeightedProbability)
         .with_border_zones(border_zones);
@@ -318,15 +501,59 @@ fn try_generate_chunk(
     }
 }
 
+/// The right column and top row of a chunk repeat the next chunk's left
+/// column and bottom row; only the next chunk's copy is used.
+fn is_seam_duplicate(x: u32, y: u32, chunk_x: u32, chunk_y: u32, settings: &WorldGenSettings) -> bool {
+    (x == GRID_X - 1 && chunk_x < settings.chunks_x - 1) || (y == GRID_Y - 1 && chunk_y < settings.chunks_y - 1)
+}
+
+/// Draw depth of a tile: one step per layer, plus up to one more so lower
+/// rows draw in front across all chunks.
+fn tile_depth(layer: i32, global_y: u32, total_grid_y: u32) -> f32 {
+    NODE_SIZE.z * (layer as f32 + 0.5) + NODE_SIZE_Z * (1.0 - global_y as f32 / total_grid_y as f32)
+}
+
+/// Builds the collision map from the generated grid data, keeping the
+/// tile drawn on top of each cell like the spawned map shows it.
+fn build_collision_map(
+    chunks: &[ChunkResult],
+    grid: &CartesianGrid<Cartesian3D>,
+    model_tiles: &[Vec<(IVec3, TileType)>],
+    settings: &WorldGenSettings,
+) -> Option<CollisionMap> {
+    let total_grid_y = settings.total_grid_y();
+    let mut builder = CollisionMapBuilder::default();
+    for chunk in chunks {
+        for (node_index, instance) in chunk.grid_data.iter().enumerate() {
+            let Some(tiles) = model_tiles.get(instance.model_index) else {
+                continue;
+            };
+            let position = grid.pos_from_index(node_index);
+            if is_seam_duplicate(position.x, position.y, chunk.chunk_x, chunk.chunk_y, settings) {
+                continue;
+            }
+            let global_y = chunk.chunk_y * (GRID_Y - 1) + position.y;
+            let node = IVec2::new((chunk.chunk_x * (GRID_X - 1) + position.x) as i32, global_y as i32);
+            for (offset, tile_type) in tiles {
+                let depth = tile_depth(position.z as i32 + offset.z, global_y, total_grid_y);
+                builder.add(node + offset.truncate(), depth, *tile_type);
+            }
+        }
+    }
+    builder.build(settings.grid_origin(), Some(settings.seed))
+}
+
 fn spawn_chunk_tiles(
     commands: &mut Commands,
     grid: &CartesianGrid<Cartesian3D>,
     spawner: &NodesSpawner<Sprite>,
-    grid_data: &GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>,
-    chunk_offset: Vec3,
-    chunk_x: u32,
-    chunk_y: u32,
+    chunk: &ChunkResult,
+    model_tiles: &[Vec<(IVec3, TileType)>],
+    carved: &HashSet<IVec2>,
+    settings: &WorldGenSettings,
 ) {
+    let &ChunkResult { ref grid_data, chunk_offset, chunk_x, chunk_y } = chunk;
+    let total_grid_y = settings.total_grid_y();
     for (node_index, instance) in grid_data.iter().enumerate() {
         let Some(node_assets) = spawner.assets.get(&instance.model_index) else {
             continue;
@@ -334,16 +561,26 @@ fn spawn_chunk_tiles(
         let position = grid.pos_from_index(node_index);
 
         // Optimization: Skip overlap tiles
-        // The right column and top row are spawned by the next chunk.
-        if position.x == GRID_X - 1 && chunk_x < CHUNKS_X - 1 {
+        if is_seam_duplicate(position.x, position.y, chunk_x, chunk_y, settings) {
             continue;
         }
-        if position.y == GRID_Y - 1 && chunk_y < CHUNKS_Y - 1 {
+        let global_y = chunk_y * (GRID_Y - 1) + position.y;
+
+        // Blockers carved away to connect regions go with all their sprites,
+        // tree tops included. Generated maps start at node (0, 0), so map
+        // cells and node cells line up.
+        let node = IVec2::new((chunk_x * (GRID_X - 1) + position.x) as i32, global_y as i32);
+        let cleared = model_tiles.get(instance.model_index).is_some_and(|tiles| {
+            tiles.iter().any(|(offset, tile_type)| {
+                !tile_type.is_walkable() && carved.contains(&(node + offset.truncate()))
+            })
+        });
+        if cleared {
             continue;
         }
 
         for asset in node_assets.iter() {
-            let mut local_pos = Vec3::new(
+            let local_pos = Vec3::new(
                 asset.world_offset.x
                     + NODE_SIZE.x
                         * (position.x as f32 + asset.grid_offset.dx as f32 + 0.5),
@@ -351,14 +588,9 @@ fn spawn_chunk_tiles(
                     + NODE_SIZE.y
                         * (position.y as f32 + asset.grid_offset.dy as f32 + 0.5),
                 asset.world_offset.z
-                    + NODE_SIZE.z
-                        * (position.z as f32 + asset.grid_offset.dz as f32 + 0.5),
+                    + tile_depth(position.z as i32 + asset.grid_offset.dz, global_y, total_grid_y),
             );
 
-            // Global z_offset for correct depth sorting across all chunks
-            let global_y = chunk_y * (GRID_Y - 1) + position.y;
-            local_pos.z += NODE_SIZE_Z * (1.0 - global_y as f32 / TOTAL_GRID_Y as f32);
-
             let world_pos = Vec3::new(
                 chunk_offset.x + local_pos.x,
                 chunk_offset.y + local_pos.y,
@@ -374,6 +606,7 @@ fn spawn_chunk_tiles(
                 instance.rotation,
             );
             (asset.spawn_commands)(entity_commands);
+            entity_commands.insert(GameplayEntity);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+// src/map/hot_reload.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::collision::TileMarker;
+use crate::logging::targets;
+use crate::map::assets::{used_sprite_names, TilemapHandles};
+use crate::map::rules::{RulesDefinition, WorldRules};
+use crate::map::tilemap::TilemapDefinition;
+
+/// Builds the tile atlas once tilemap.atlas.ron has loaded, and rebuilds it
+/// whenever that file or tilemap.png changes on disk (with the `hot_reload`
+/// feature), so artists can iterate without restarting. Tiles share the
+/// image and layout handles, so swapping the layout in place re-skins every
+/// tile already in the world; tiles are matched up by sprite name in case
+/// sprites moved around in the description.
+pub fn sync_tilemap_atlas(
+    mut definition_events: MessageReader<AssetEvent<TilemapDefinition>>,
+    mut image_events: MessageReader<AssetEvent<Image>>,
+    mut handles: ResMut<TilemapHandles>,
+    definitions: Res<Assets<TilemapDefinition>>,
+    world_rules: Res<WorldRules>,
+    rules_definitions: Res<Assets<RulesDefinition>>,
+    images: Res<Assets<Image>>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut tiles: Query<&mut Sprite, With<TileMarker>>,
+) {
+    let definition_changed = definition_events.read().fold(false, |changed, event| {
+        changed
+            || event.is_loaded_with_dependencies(&handles.definition)
+            || event.is_modified(&handles.definition)
+    });
+    let image_changed = image_events.read().fold(false, |changed, event| {
+        changed || event.is_modified(&handles.image)
+    });
+    if !definition_changed && !image_changed {
+        return;
+    }
+    let Some(definition) = definitions.get(&handles.definition) else {
+        return;
+    };
+
+    // Sprite names are checked against the rules once both have loaded
+    let assets_definitions = rules_definitions
+        .get(&world_rules.0)
+        .and_then(|rules| rules.compile().ok())
+        .map(|(assets, _, _)| assets)
+        .unwrap_or_default();
+    if let Err(e) = definition.validate(used_sprite_names(&assets_definitions)) {
+        error!(target: targets::MAP, "{}", e);
+        // A bad edit keeps the atlas already in use; with none yet, the
+        // loading screen has to say why it can't go on
+        if !handles.is_loaded() {
+            handles.error = Some(e);
+        }
+        return;
+    }
+    let image_size = images.get(&handles.image).map(Image::size);
+    if let Some(size) = image_size.filter(|size| *size != definition.atlas_size()) {
+        warn!(
+            target: targets::MAP,
+            "tilemap.png is {}x{}, the atlas description expects {}x{}",
+            size.x, size.y, definition.atlas_width, definition.atlas_height
+        );
+    }
+
+    if let Err(e) = atlas_layouts.insert(&handles.layout, definition.atlas_layout()) {
+        error!(target: targets::MAP, "Could not replace the tile atlas layout: {}", e);
+        return;
+    }
+
+    let sprite_names = atlas_sprite_names(definition);
+    if handles.is_loaded() {
+        let new_index: HashMap<&str, usize> = sprite_names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), index))
+            .collect();
+
+        let mut reskinned = 0;
+        for mut sprite in &mut tiles {
+            let Some(atlas) = sprite.texture_atlas.as_mut() else {
+                continue;
+            };
+            // Sprites removed from the description fall back to the first one
+            let name = handles.sprite_names.get(atlas.index).map(String::as_str);
+            atlas.index = name.and_then(|name| new_index.get(name)).copied().unwrap_or(0);
+            reskinned += 1;
+        }
+        info!(target: targets::MAP, "Reloaded tilemap atlas, re-skinned {} tiles", reskinned);
+    } else {
+        info!(target: targets::MAP, "Loaded tilemap atlas with {} sprites", definition.sprites.len());
+    }
+
+    handles.sprite_names = sprite_names;
+    handles.animations = definition.animations();
+    handles.error = None;
+}
+
+/// Sprite name of each atlas index; animation frames share their sprite's name.
+fn atlas_sprite_names(definition: &TilemapDefinition) -> Vec<String> {
+    let first_frames = definition.sprites.iter().map(|sprite| sprite.name.clone());
+    let extra_frames = definition
+        .sprites
+        .iter()
+        .flat_map(|sprite| sprite.frames.iter().map(|_| sprite.name.clone()));
+    first_frames.chain(extra_frames).collect()
+}
+
+/// Compiles world.rules.ron on load and on hot reload, so mistakes show up
+/// right away instead of at the next map generation. The next map built
+/// picks up the new rules.
+pub fn check_world_rules(
+    mut events: MessageReader<AssetEvent<RulesDefinition>>,
+    world_rules: Res<WorldRules>,
+    rules_definitions: Res<Assets<RulesDefinition>>,
+) {
+    for event in events.read() {
+        if !event.is_loaded_with_dependencies(&world_rules.0) && !event.is_modified(&world_rules.0) {
+            continue;
+        }
+        let Some(rules) = rules_definitions.get(&world_rules.0) else {
+            continue;
+        };
+        match rules.compile() {
+            Ok(_) => info!(target: targets::MAP, "Loaded {} world generation models", rules.models.len()),
+            Err(e) => error!(target: targets::MAP, "Invalid world rules: {}", e),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// src/map/level.rs
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+
+use crate::collision::CollisionMapBuilt;
+use crate::console::ConsoleResult;
+use crate::logging::targets;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::{GeneratedMap, MapReady, WorldGenSettings};
+use crate::map::tilemap::TilemapDefinition;
+use crate::map::tmx::{read_tmx, spawn_map};
+
+const LEVELS_PATH: &str = "levels";
+
+/// Where the next map comes from: wave function collapse, or a hand-made
+/// Tiled level from src/assets/levels for tutorials and designed arenas.
+#[derive(Resource, Reflect, Debug, Clone, Default, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub enum MapSource {
+    #[default]
+    Procedural,
+    Level(String),
+}
+
+/// Spawn points and interactables of a hand-made level. Present only while
+/// one is loaded; the usual placement rules apply without it.
+#[derive(Resource, Debug, Default)]
+pub struct LevelLayout {
+    pub player_spawn: Option<Vec2>,
+    /// Character name and position of each enemy
+    pub enemies: Vec<(String, Vec2)>,
+    pub checkpoints: Vec<Vec2>,
+    pub shrines: Vec<Vec2>,
+    pub obelisks: Vec<Vec2>,
+}
+
+/// Raw text of a .tmx file. It's parsed once the atlas description is
+/// there to resolve its tiles.
+#[derive(Asset, TypePath, Debug)]
+pub struct TmxFile(pub String);
+
+#[derive(Default, TypePath)]
+pub struct TmxLoader;
+
+impl AssetLoader for TmxLoader {
+    type Asset = TmxFile;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        String::from_utf8(bytes)
+            .map(TmxFile)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tmx"]
+    }
+}
+
+/// Level file being loaded in place of generation.
+#[derive(Resource)]
+pub struct PendingLevel(pub Handle<TmxFile>);
+
+/// Starts loading the level named by `MapSource`.
+pub fn start_level_load(commands: &mut Commands, asset_server: &AssetServer, name: &str) {
+    info!(target: targets::MAP, "Loading level '{}'", name);
+    commands.insert_resource(PendingLevel(asset_server.load(format!("{LEVELS_PATH}/{name}.tmx"))));
+}
+
+/// Spawns the pending level once its file has loaded. A broken level falls
+/// back to a generated map so the loading screen never hangs.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_pending_level(
+    mut commands: Commands,
+    pending: Res<PendingLevel>,
+    asset_server: Res<AssetServer>,
+    files: Res<Assets<TmxFile>>,
+    handles: Res<TilemapHandles>,
+    definitions: Res<Assets<TilemapDefinition>>,
+    mut settings: ResMut<WorldGenSettings>,
+    mut map_source: ResMut<MapSource>,
+    mut collision_map_built: ResMut<CollisionMapBuilt>,
+) {
+    if asset_server.load_state(&pending.0).is_failed() {
+        error!(target: targets::MAP, "Could not load level {:?}, generating a map instead", map_source);
+        *map_source = MapSource::Procedural;
+        commands.remove_resource::<PendingLevel>();
+        return;
+    }
+    let (Some(file), Some(definition)) = (files.get(&pending.0), definitions.get(&handles.definition)) else {
+        return;
+    };
+    commands.remove_resource::<PendingLevel>();
+
+    let map = match read_tmx(&file.0, definition) {
+        Ok(map) => map,
+        Err(e) => {
+            error!(target: targets::MAP, "Invalid level {:?}: {}, generating a map instead", map_source, e);
+            *map_source = MapSource::Procedural;
+            return;
+        }
+    };
+    if let Some(seed) = map.seed {
+        settings.seed = seed;
+    }
+    let origin = map.origin(&settings);
+    let Some(collision_map) = map.collision_map(origin) else {
+        error!(target: targets::MAP, "Level {:?} has no tiles, generating a map instead", map_source);
+        *map_source = MapSource::Procedural;
+        return;
+    };
+    let layout = spawn_map(&mut commands, &handles, &map, origin);
+    commands.insert_resource(layout);
+    commands.insert_resource(collision_map);
+    collision_map_built.0 = true;
+    commands.insert_resource(MapReady);
+    commands.remove_resource::<GeneratedMap>();
+    info!(target: targets::MAP, "Level {:?} ready", map_source);
+}
+
+/// Picks the map source for the next new game: `level <name>` or `level` to
+/// go back to generated maps.
+pub fn level_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let source = match args.first() {
+        Some(name) => MapSource::Level(name.to_string()),
+        None => MapSource::Procedural,
+    };
+    let message = match &source {
+        MapSource::Procedural => "New games generate their map".to_string(),
+        MapSource::Level(name) => format!("New games load {LEVELS_PATH}/{name}.tmx"),
+    };
+    world.insert_resource(source);
+    Ok(message)
+}
@@ -2,5 +2,8 @@ pub mod assets;
 pub mod tilemap;
 pub mod rules;
 pub mod models;
-pub mod sockets;
-pub mod generate;
\ No newline at end of file
+pub mod generate;
+pub mod level;
+pub mod hot_reload;
+pub mod tile_animation;
+pub mod tmx;
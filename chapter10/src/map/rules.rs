@@ -1,672 +1,184 @@
 // src/map/rules.rs
-use crate::map::assets::SpawnableAsset;
-use crate::collision::TileType;
-use crate::map::models::TerrainModelBuilder;
-use crate::map::sockets::*;
+use std::collections::HashMap;
+
+use bevy::prelude::*;
 use bevy_procedural_tilemaps::prelude::*;
-use crate::inventory::ItemKind;
+use serde::{Deserialize, Serialize};
 
-fn build_dirt_layer(
-    terrain_model_builder: &mut TerrainModelBuilder,
-    terrain_sockets: &TerrainSockets,
-    socket_collection: &mut SocketCollection,
-) {
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.dirt.material, // right
-                x_neg: terrain_sockets.dirt.material, // left
-                z_pos: terrain_sockets.dirt.layer_up, // top
-                z_neg: terrain_sockets.dirt.layer_down, // bottom
-                y_pos: terrain_sockets.dirt.material, // up
-                y_neg: terrain_sockets.dirt.material, // down
-            },
-            vec![SpawnableAsset::new("dirt").with_tile_type(TileType::Dirt)],
-        )
-        .with_weight(20.);
+use crate::collision::TileType;
+use crate::inventory::ItemKind;
+use crate::map::assets::SpawnableAsset;
+use crate::map::models::TerrainModelBuilder;
 
-    socket_collection.add_connections(vec![(
-        terrain_sockets.dirt.material,
-        vec![terrain_sockets.dirt.material],
-    )]);
+/// Sockets a model offers on each side, by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SocketsDefinition {
+    pub x_pos: Vec<String>,
+    pub x_neg: Vec<String>,
+    pub y_pos: Vec<String>,
+    pub y_neg: Vec<String>,
+    pub z_pos: Vec<String>,
+    pub z_neg: Vec<String>,
 }
 
-fn build_yellow_grass_layer(
-    terrain_model_builder: &mut TerrainModelBuilder,
-    terrain_sockets: &TerrainSockets,
-    socket_collection: &mut SocketCollection,
-) {
-    // Void model - empty space where no yellow grass exists
-    terrain_model_builder.create_model(
-        SocketsCartesian3D::Simple {
-            x_pos: terrain_sockets.void,
-            x_neg: terrain_sockets.void,
-            z_pos: terrain_sockets.yellow_grass.layer_up,
-            z_neg: terrain_sockets.yellow_grass.layer_down,
-            y_pos: terrain_sockets.void,
-            y_neg: terrain_sockets.void,
-        },
-        Vec::new(),
-    );
-
-    // Main yellow grass tile
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.grass.material,
-                x_neg: terrain_sockets.grass.material,
-                z_pos: terrain_sockets.yellow_grass.layer_up,
-                z_neg: terrain_sockets.yellow_grass.yellow_grass_fill_down,
-                y_pos: terrain_sockets.grass.material,
-                y_neg: terrain_sockets.grass.material,
-            },
-            vec![SpawnableAsset::new("yellow_grass").with_tile_type(TileType::YellowGrass)],
-        )
-        .with_weight(5.);
-
-    // Outer corner template
-    let yellow_grass_corner_out = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.void_and_grass,
-        x_neg: terrain_sockets.void,
-        z_pos: terrain_sockets.yellow_grass.layer_up,
-        z_neg: terrain_sockets.yellow_grass.yellow_grass_fill_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.grass.grass_and_void,
-    }
-    .to_template();
-
-    // Inner corner template
-    let yellow_grass_corner_in = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.grass_and_void,
-        x_neg: terrain_sockets.grass.material,
-        z_pos: terrain_sockets.yellow_grass.layer_up,
-        z_neg: terrain_sockets.yellow_grass.yellow_grass_fill_down,
-        y_pos: terrain_sockets.grass.material,
-        y_neg: terrain_sockets.grass.void_and_grass,
-    }
-    .to_template();
-
-    // Side edge template
-    let yellow_grass_side = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.void_and_grass,
-        x_neg: terrain_sockets.grass.grass_and_void,
-        z_pos: terrain_sockets.yellow_grass.layer_up,
-        z_neg: terrain_sockets.yellow_grass.yellow_grass_fill_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.grass.material,
-    }
-    .to_template();
-
-    // Create rotated versions of outer corners
-    terrain_model_builder.create_model(
-        yellow_grass_corner_out.clone(),
-        vec![SpawnableAsset::new("yellow_grass_corner_out_tl").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_out.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_out_bl").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_out.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_out_br").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_out.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_out_tr").with_tile_type(TileType::YellowGrass)],
-    );
-
-    // Create rotated versions of inner corners
-    terrain_model_builder.create_model(
-        yellow_grass_corner_in.clone(),
-        vec![SpawnableAsset::new("yellow_grass_corner_in_tl").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_in.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_in_bl").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_in.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_in_br").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_corner_in.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_corner_in_tr").with_tile_type(TileType::YellowGrass)],
-    );
-
-    // Create rotated versions of side edges
-    terrain_model_builder.create_model(
-        yellow_grass_side.clone(),
-        vec![SpawnableAsset::new("yellow_grass_side_t").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_side.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_side_l").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_side.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_side_b").with_tile_type(TileType::YellowGrass)],
-    );
-    terrain_model_builder.create_model(
-        yellow_grass_side.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("yellow_grass_side_r").with_tile_type(TileType::YellowGrass)],
-    );
-
-    // Add connection rules
-    socket_collection
-        .add_rotated_connection(
-            terrain_sockets.grass.layer_up,
-            vec![terrain_sockets.yellow_grass.layer_down],
-        )
-        .add_rotated_connection(
-            terrain_sockets.yellow_grass.yellow_grass_fill_down,
-            vec![terrain_sockets.grass.grass_fill_up],
-        );
+/// One sprite a model spawns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetDefinition {
+    pub sprite: String,
+    #[serde(default)]
+    pub tile_type: Option<TileType>,
+    #[serde(default)]
+    pub pickable: Option<ItemKind>,
+    /// Offset in cells, for sprites of multi-tile props
+    #[serde(default)]
+    pub grid_offset: (i32, i32, i32),
 }
 
-// src/map/rules.rs - Add this function after build_dirt_layer
-fn build_grass_layer(
-    terrain_model_builder: &mut TerrainModelBuilder,
-    terrain_sockets: &TerrainSockets,
-    socket_collection: &mut SocketCollection,
-) {
-    // Void model - empty space above dirt where no grass exists
-    terrain_model_builder.create_model(
-        SocketsCartesian3D::Simple {
-            x_pos: terrain_sockets.void,
-            x_neg: terrain_sockets.void,
-            z_pos: terrain_sockets.grass.layer_up,
-            z_neg: terrain_sockets.grass.layer_down,
-            y_pos: terrain_sockets.void,
-            y_neg: terrain_sockets.void,
-        },
-        Vec::new(),
-    );
-
-    // Main grass tile
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Multiple {
-                x_pos: vec![terrain_sockets.grass.material],
-                x_neg: vec![terrain_sockets.grass.material],
-                z_pos: vec![
-                    terrain_sockets.grass.layer_up,
-                    terrain_sockets.grass.grass_fill_up,
-                ],
-                z_neg: vec![terrain_sockets.grass.layer_down],
-                y_pos: vec![terrain_sockets.grass.material],
-                y_neg: vec![terrain_sockets.grass.material],
-            },
-            vec![SpawnableAsset::new("green_grass").with_tile_type(TileType::Grass)],
-        )
-        .with_weight(5.);
-
-    // Outer corner template
-    let green_grass_corner_out = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.void_and_grass,
-        x_neg: terrain_sockets.void,
-        z_pos: terrain_sockets.grass.layer_up,
-        z_neg: terrain_sockets.grass.layer_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.grass.grass_and_void,
-    }
-    .to_template();
-
-    // Inner corner template
-    let green_grass_corner_in = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.grass_and_void,
-        x_neg: terrain_sockets.grass.material,
-        z_pos: terrain_sockets.grass.layer_up,
-        z_neg: terrain_sockets.grass.layer_down,
-        y_pos: terrain_sockets.grass.material,
-        y_neg: terrain_sockets.grass.void_and_grass,
-    }
-    .to_template();
-
-    // Side edge template
-    let green_grass_side = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.grass.void_and_grass,
-        x_neg: terrain_sockets.grass.grass_and_void,
-        z_pos: terrain_sockets.grass.layer_up,
-        z_neg: terrain_sockets.grass.layer_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.grass.material,
-    }
-    .to_template();
-
-    // Create rotated versions of outer corners
-    terrain_model_builder.create_model(
-        green_grass_corner_out.clone(),
-        vec![SpawnableAsset::new("green_grass_corner_out_tl").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_out.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_out_bl").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_out.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_out_br").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_out.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_out_tr").with_tile_type(TileType::Grass)],
-    );
-
-    // Create rotated versions of inner corners
-    terrain_model_builder.create_model(
-        green_grass_corner_in.clone(),
-        vec![SpawnableAsset::new("green_grass_corner_in_tl").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_in.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_in_bl").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_in.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_in_br").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_corner_in.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_corner_in_tr").with_tile_type(TileType::Grass)],
-    );
-
-    // Create rotated versions of side edges
-    terrain_model_builder.create_model(
-        green_grass_side.clone(),
-        vec![SpawnableAsset::new("green_grass_side_t").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_side.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_side_l").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_side.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_side_b").with_tile_type(TileType::Grass)],
-    );
-    terrain_model_builder.create_model(
-        green_grass_side.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("green_grass_side_r").with_tile_type(TileType::Grass)],
-    );
-
-    // Add connection rules
-    socket_collection.add_rotated_connection(
-        terrain_sockets.dirt.layer_up,
-        vec![terrain_sockets.grass.layer_down],
-    );
-    socket_collection.add_connections(vec![
-        (terrain_sockets.void, vec![terrain_sockets.void]),
-        (
-            terrain_sockets.grass.material,
-            vec![terrain_sockets.grass.material],
-        ),
-        (
-            terrain_sockets.grass.void_and_grass,
-            vec![terrain_sockets.grass.grass_and_void],
-        ),
-    ]);
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Rotation {
+    Rot90,
+    Rot180,
+    Rot270,
 }
 
-pub fn build_water_layer(
-    terrain_model_builder: &mut TerrainModelBuilder,
-    terrain_sockets: &TerrainSockets,
-    socket_collection: &mut SocketCollection,
-) {
-    // Void model - represents land areas where no water exists
-    terrain_model_builder.create_model(
-        SocketsCartesian3D::Multiple {
-            x_pos: vec![terrain_sockets.void],
-            x_neg: vec![terrain_sockets.void],
-            z_pos: vec![
-                terrain_sockets.water.layer_up,
-                terrain_sockets.water.ground_up,
-            ],
-            z_neg: vec![terrain_sockets.water.layer_down],
-            y_pos: vec![terrain_sockets.void],
-            y_neg: vec![terrain_sockets.void],
-        },
-        Vec::new(),
-    );
-
-    // Main water tile
-    const WATER_WEIGHT: f32 = 0.002;
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.water.material,
-                x_neg: terrain_sockets.water.material,
-                z_pos: terrain_sockets.water.layer_up,
-                z_neg: terrain_sockets.water.layer_down,
-                y_pos: terrain_sockets.water.material,
-                y_neg: terrain_sockets.water.material,
-            },
-            vec![SpawnableAsset::new("water").with_tile_type(TileType::Water)],
-        )
-        .with_weight(10. * WATER_WEIGHT);
-
-    // Outer corner template
-    let water_corner_out = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.water.void_and_water,
-        x_neg: terrain_sockets.void,
-        z_pos: terrain_sockets.water.layer_up,
-        z_neg: terrain_sockets.water.layer_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.water.water_and_void,
-    }
-    .to_template()
-    .with_weight(WATER_WEIGHT);
-
-    // Inner corner template
-    let water_corner_in = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.water.water_and_void,
-        x_neg: terrain_sockets.water.material,
-        z_pos: terrain_sockets.water.layer_up,
-        z_neg: terrain_sockets.water.layer_down,
-        y_pos: terrain_sockets.water.material,
-        y_neg: terrain_sockets.water.void_and_water,
-    }
-    .to_template()
-    .with_weight(WATER_WEIGHT);
-
-    // Side edge template
-    let water_side = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.water.void_and_water,
-        x_neg: terrain_sockets.water.water_and_void,
-        z_pos: terrain_sockets.water.layer_up,
-        z_neg: terrain_sockets.water.layer_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.water.material,
+impl From<Rotation> for ModelRotation {
+    fn from(rotation: Rotation) -> Self {
+        match rotation {
+            Rotation::Rot90 => ModelRotation::Rot90,
+            Rotation::Rot180 => ModelRotation::Rot180,
+            Rotation::Rot270 => ModelRotation::Rot270,
+        }
     }
-    .to_template()
-    .with_weight(WATER_WEIGHT);
-
-    // Create rotated versions of outer corners
-    terrain_model_builder.create_model(
-        water_corner_out.clone(),
-        vec![SpawnableAsset::new("water_corner_out_tl").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_out.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_out_bl").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_out.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_out_br").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_out.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_out_tr").with_tile_type(TileType::Water)],
-    );
-
-    // Create rotated versions of inner corners
-    terrain_model_builder.create_model(
-        water_corner_in.clone(),
-        vec![SpawnableAsset::new("water_corner_in_tl").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_in.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_in_bl").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_in.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_in_br").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_corner_in.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("water_corner_in_tr").with_tile_type(TileType::Water)],
-    );
-
-    // Create rotated versions of side edges
-    terrain_model_builder.create_model(
-        water_side.clone(),
-        vec![SpawnableAsset::new("water_side_t").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_side.rotated(ModelRotation::Rot90, Direction::ZForward),
-        vec![SpawnableAsset::new("water_side_l").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_side.rotated(ModelRotation::Rot180, Direction::ZForward),
-        vec![SpawnableAsset::new("water_side_b").with_tile_type(TileType::Water)],
-    );
-    terrain_model_builder.create_model(
-        water_side.rotated(ModelRotation::Rot270, Direction::ZForward),
-        vec![SpawnableAsset::new("water_side_r").with_tile_type(TileType::Water)],
-    );
+}
 
-    // Add connection rules
-    socket_collection.add_connections(vec![
-        (
-            terrain_sockets.water.material,
-            vec![terrain_sockets.water.material],
-        ),
-        (
-            terrain_sockets.water.water_and_void,
-            vec![terrain_sockets.water.void_and_water],
-        ),
-    ]);
+/// The model turned around the layer axis, with its own sprites.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotatedVariant {
+    pub rotation: Rotation,
+    pub assets: Vec<AssetDefinition>,
+}
 
-    // Connect water layer to yellow grass layer
-    socket_collection.add_rotated_connection(
-        terrain_sockets.yellow_grass.layer_up,
-        vec![terrain_sockets.water.layer_down],
-    );
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDefinition {
+    pub name: String,
+    pub sockets: SocketsDefinition,
+    #[serde(default)]
+    pub weight: Option<f32>,
+    /// Sprites of the unrotated model; empty for void models
+    #[serde(default)]
+    pub assets: Vec<AssetDefinition>,
+    #[serde(default)]
+    pub rotated: Vec<RotatedVariant>,
 }
 
-// src/map/rules.rs - Add this function after build_water_layer
-pub fn build_props_layer(
-    terrain_model_builder: &mut TerrainModelBuilder,
-    terrain_sockets: &TerrainSockets,
-    socket_collection: &mut SocketCollection,
-) {
-    // Void model - represents areas where no props exist
-    terrain_model_builder.create_model(
-        SocketsCartesian3D::Multiple {
-            x_pos: vec![terrain_sockets.void],
-            x_neg: vec![terrain_sockets.void],
-            z_pos: vec![terrain_sockets.props.layer_up],
-            z_neg: vec![terrain_sockets.props.layer_down],
-            y_pos: vec![terrain_sockets.void],
-            y_neg: vec![terrain_sockets.void],
-        },
-        Vec::new(),
-    );
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionDefinition {
+    pub from: String,
+    pub to: Vec<String>,
+    /// Also connects the sockets of rotated models on the layer axis
+    #[serde(default)]
+    pub rotated: bool,
+}
 
-    // Weight constants for different prop types
-    const PROPS_WEIGHT: f32 = 0.025;
-    const ROCKS_WEIGHT: f32 = 0.008;
-    const PLANTS_WEIGHT: f32 = 0.025;
-    const STUMPS_WEIGHT: f32 = 0.012;
+/// World generation rules, loaded from world.rules.ron so level designers
+/// can change the world without recompiling.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct RulesDefinition {
+    pub sockets: Vec<String>,
+    pub models: Vec<ModelDefinition>,
+    pub connections: Vec<ConnectionDefinition>,
+}
 
-    // Base prop template - single tile props
-    let prop = SocketsCartesian3D::Simple {
-        x_pos: terrain_sockets.void,
-        x_neg: terrain_sockets.void,
-        z_pos: terrain_sockets.props.layer_up,
-        z_neg: terrain_sockets.props.props_down,
-        y_pos: terrain_sockets.void,
-        y_neg: terrain_sockets.void,
+/// Handle to the rules asset, loaded at startup with the tilemap.
+#[derive(Resource)]
+pub struct WorldRules(pub Handle<RulesDefinition>);
+
+impl RulesDefinition {
+    /// Builds the models, their sprites and the socket connections. Errors
+    /// name the model or connection using an unknown socket.
+    pub fn compile(
+        &self,
+    ) -> Result<(Vec<Vec<SpawnableAsset>>, ModelCollection<Cartesian3D>, SocketCollection), String> {
+        let mut socket_collection = SocketCollection::new();
+        let mut sockets: HashMap<&str, Socket> = HashMap::new();
+        for name in &self.sockets {
+            if sockets.insert(name, socket_collection.create()).is_some() {
+                return Err(format!("socket '{}' is declared twice", name));
+            }
+        }
+        let lookup = |names: &[String], context: &str| -> Result<Vec<Socket>, String> {
+            names
+                .iter()
+                .map(|name| {
+                    sockets
+                        .get(name.as_str())
+                        .copied()
+                        .ok_or_else(|| format!("{}: unknown socket '{}'", context, name))
+                })
+                .collect()
+        };
+
+        let mut builder = TerrainModelBuilder::new();
+        for (index, model) in self.models.iter().enumerate() {
+            if self.models[..index].iter().any(|other| other.name == model.name) {
+                return Err(format!("model '{}' is defined twice", model.name));
+            }
+            if model.weight.is_some_and(|weight| weight.is_nan() || weight <= 0.0) {
+                return Err(format!("model '{}' needs a positive weight", model.name));
+            }
+            let side = |side: &str, names: &[String]| lookup(names, &format!("model '{}' {}", model.name, side));
+            let mut template = SocketsCartesian3D::Multiple {
+                x_pos: side("x_pos", &model.sockets.x_pos)?,
+                x_neg: side("x_neg", &model.sockets.x_neg)?,
+                z_pos: side("z_pos", &model.sockets.z_pos)?,
+                z_neg: side("z_neg", &model.sockets.z_neg)?,
+                y_pos: side("y_pos", &model.sockets.y_pos)?,
+                y_neg: side("y_neg", &model.sockets.y_neg)?,
+            }
+            .to_template();
+            if let Some(weight) = model.weight {
+                template = template.with_weight(weight);
+            }
+
+            builder.create_model(template.clone(), spawnable_assets(&model.assets));
+            for variant in &model.rotated {
+                builder.create_model(
+                    template.rotated(variant.rotation.into(), Direction::ZForward),
+                    spawnable_assets(&variant.assets),
+                );
+            }
+        }
+
+        for connection in &self.connections {
+            let context = format!("connection from '{}'", connection.from);
+            let from = lookup(std::slice::from_ref(&connection.from), &context)?[0];
+            let to = lookup(&connection.to, &context)?;
+            if connection.rotated {
+                socket_collection.add_rotated_connection(from, to);
+            } else {
+                socket_collection.add_connections(vec![(from, to)]);
+            }
+        }
+
+        let (assets, models) = builder.into_parts();
+        Ok((assets, models, socket_collection))
     }
-    .to_template()
-    .with_weight(PROPS_WEIGHT);
-
-    // Create different prop types with different weights
-    let plant_prop = prop.clone().with_weight(PLANTS_WEIGHT);
-    let stump_prop = prop.clone().with_weight(STUMPS_WEIGHT);
-    let rock_prop = prop.clone().with_weight(ROCKS_WEIGHT);
-
-    // Small tree (2 tiles high)
-    terrain_model_builder.create_model(
-        plant_prop.clone(),
-        vec![
-            SpawnableAsset::new("small_tree_bottom").with_tile_type(TileType::Tree),
-            SpawnableAsset::new("small_tree_top").with_grid_offset(GridDelta::new(0, 1, 0)),
-        ],
-    );
-
-    // Big tree 1 (2x2 tiles)
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.props.big_tree_1_base,
-                x_neg: terrain_sockets.void,
-                z_pos: terrain_sockets.props.layer_up,
-                z_neg: terrain_sockets.props.props_down,
-                y_pos: terrain_sockets.void,
-                y_neg: terrain_sockets.void,
-            },
-            vec![
-                SpawnableAsset::new("big_tree_1_bl").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_1_tl").with_grid_offset(GridDelta::new(0, 1, 0)),
-            ],
-        )
-        .with_weight(PROPS_WEIGHT);
-
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.void,
-                x_neg: terrain_sockets.props.big_tree_1_base,
-                z_pos: terrain_sockets.props.layer_up,
-                z_neg: terrain_sockets.props.props_down,
-                y_pos: terrain_sockets.void,
-                y_neg: terrain_sockets.void,
-            },
-            vec![
-                SpawnableAsset::new("big_tree_1_br").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_1_tr").with_grid_offset(GridDelta::new(0, 1, 0)),
-            ],
-        )
-        .with_weight(PROPS_WEIGHT);
-
-    // Big tree 2 (2x2 tiles)
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.props.big_tree_2_base,
-                x_neg: terrain_sockets.void,
-                z_pos: terrain_sockets.props.layer_up,
-                z_neg: terrain_sockets.props.props_down,
-                y_pos: terrain_sockets.void,
-                y_neg: terrain_sockets.void,
-            },
-            vec![
-                SpawnableAsset::new("big_tree_2_bl").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_2_tl").with_grid_offset(GridDelta::new(0, 1, 0)),
-            ],
-        )
-        .with_weight(PROPS_WEIGHT);
-
-    terrain_model_builder
-        .create_model(
-            SocketsCartesian3D::Simple {
-                x_pos: terrain_sockets.void,
-                x_neg: terrain_sockets.props.big_tree_2_base,
-                z_pos: terrain_sockets.props.layer_up,
-                z_neg: terrain_sockets.props.props_down,
-                y_pos: terrain_sockets.void,
-                y_neg: terrain_sockets.void,
-            },
-            vec![
-                SpawnableAsset::new("big_tree_2_br").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_2_tr").with_grid_offset(GridDelta::new(0, 1, 0)),
-            ],
-        )
-        .with_weight(PROPS_WEIGHT);
-
-    // Tree stumps
-    terrain_model_builder.create_model(
-        stump_prop.clone(),
-        vec![SpawnableAsset::new("tree_stump_1").with_tile_type(TileType::Tree)],
-    );
-    terrain_model_builder.create_model(
-        stump_prop.clone(),
-        vec![SpawnableAsset::new("tree_stump_2").with_tile_type(TileType::Tree)],
-    );
-    terrain_model_builder.create_model(
-        stump_prop.clone(),
-        vec![SpawnableAsset::new("tree_stump_3").with_tile_type(TileType::Tree)],
-    );
-
-    // Rocks
-    terrain_model_builder.create_model(rock_prop.clone(), vec![SpawnableAsset::new("rock_1").with_tile_type(TileType::Rock)]);
-    terrain_model_builder.create_model(rock_prop.clone(), vec![SpawnableAsset::new("rock_2").with_tile_type(TileType::Rock)]);
-    terrain_model_builder.create_model(rock_prop.clone(), vec![SpawnableAsset::new("rock_3").with_tile_type(TileType::Rock)]);
-    terrain_model_builder.create_model(rock_prop.clone(), vec![SpawnableAsset::new("rock_4").with_tile_type(TileType::Rock)]);
-
-    // Plants
-    terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_1").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant1)]);
-    terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_2").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant2)]);
-    terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_3").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant3)]);
-    terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_4").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant4)]);
-
-    // Add connection rules
-    socket_collection.add_connections(vec![
-        (
-            terrain_sockets.props.big_tree_1_base,
-            vec![terrain_sockets.props.big_tree_1_base],
-        ),
-        (
-            terrain_sockets.props.big_tree_2_base,
-            vec![terrain_sockets.props.big_tree_2_base],
-        ),
-    ]);
-
-    // Connect props to water layer
-    socket_collection
-        .add_rotated_connection(
-            terrain_sockets.water.layer_up,
-            vec![terrain_sockets.props.layer_down],
-        )
-        .add_rotated_connection(
-            terrain_sockets.props.props_down,
-            vec![terrain_sockets.water.ground_up],
-        );
 }
 
-pub fn build_world() -> (
-    Vec<Vec<SpawnableAsset>>,
-    ModelCollection<Cartesian3D>,
-    SocketCollection,
-) {
-    let mut socket_collection = SocketCollection::new();
-    let terrain_sockets = create_sockets(&mut socket_collection);
-
-    let mut terrain_model_builder = TerrainModelBuilder::new();
-
-    // Build dirt layer
-    build_dirt_layer(
-        &mut terrain_model_builder,
-        &terrain_sockets,
-        &mut socket_collection,
-    );
-
-    build_grass_layer(
-        &mut terrain_model_builder,
-        &terrain_sockets,
-        &mut socket_collection,
-    );
-
-    // Build yellow grass layer
-    build_yellow_grass_layer(
-        &mut terrain_model_builder,
-        &terrain_sockets,
-        &mut socket_collection,
-    );
-
-    // Build water layer
-    build_water_layer(
-        &mut terrain_model_builder,
-        &terrain_sockets,
-        &mut socket_collection,
-    );
-
-     // Build props layer
-     build_props_layer(
-        &mut terrain_model_builder,
-        &terrain_sockets,
-        &mut socket_collection,
-    );
-
-    let (assets, models) = terrain_model_builder.into_parts();
-
-    (assets, models, socket_collection)
-}
\ No newline at end of file
+fn spawnable_assets(definitions: &[AssetDefinition]) -> Vec<SpawnableAsset> {
+    definitions
+        .iter()
+        .map(|definition| {
+            let (dx, dy, dz) = definition.grid_offset;
+            let mut asset =
+                SpawnableAsset::new(definition.sprite.clone()).with_grid_offset(GridDelta::new(dx, dy, dz));
+            if let Some(tile_type) = definition.tile_type {
+                asset = asset.with_tile_type(tile_type);
+            }
+            if let Some(kind) = definition.pickable {
+                asset = asset.with_pickable(kind);
+            }
+            asset
+        })
+        .collect()
+}
@@ -0,0 +1,54 @@
+// src/map/tile_animation.rs
+use bevy::prelude::*;
+
+use crate::collision::TileMarker;
+use crate::map::assets::TilemapHandles;
+
+/// Tile whose sprite has animation frames in the atlas description.
+/// `animation` indexes `TilemapHandles::animations`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AnimatedTile {
+    pub animation: usize,
+}
+
+/// Tags a tile as it spawns if it shows any frame of an animated sprite,
+/// whichever path spawned it: generation, a level file or a save.
+pub fn tag_animated_tile(
+    add: On<Add, TileMarker>,
+    mut commands: Commands,
+    handles: Option<Res<TilemapHandles>>,
+    sprites: Query<&Sprite>,
+) {
+    let Some(handles) = handles else {
+        return;
+    };
+    let Some(atlas) = sprites.get(add.entity).ok().and_then(|s| s.texture_atlas.as_ref()) else {
+        return;
+    };
+    let animation = handles
+        .animations
+        .iter()
+        .position(|frames| frames.indices.contains(&atlas.index));
+    if let Some(animation) = animation {
+        commands.entity(add.entity).insert(AnimatedTile { animation });
+    }
+}
+
+/// Steps animated tiles off the global clock, so every tile of a sprite
+/// stays in sync.
+pub fn animate_tiles(
+    time: Res<Time>,
+    handles: Res<TilemapHandles>,
+    mut tiles: Query<(&AnimatedTile, &mut Sprite)>,
+) {
+    let elapsed = time.elapsed_secs();
+    for (tile, mut sprite) in &mut tiles {
+        let Some(frames) = handles.animations.get(tile.animation) else {
+            continue;
+        };
+        let frame = (elapsed / frames.frame_secs) as usize % frames.indices.len();
+        if let Some(atlas) = sprite.texture_atlas.as_mut() {
+            atlas.index = frames.indices[frame];
+        }
+    }
+}
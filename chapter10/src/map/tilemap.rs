@@ -1,17 +1,38 @@
 use bevy::math::{URect, UVec2};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+/// Seconds per frame for animated tiles that don't set their own
+const DEFAULT_FRAME_SECS: f32 = 0.25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TilemapSprite {
-    pub name: &'static str,
+    pub name: String,
     pub pixel_x: u32,
     pub pixel_y: u32,
+    /// Top-left corners of further animation frames, after the one above
+    #[serde(default)]
+    pub frames: Vec<(u32, u32)>,
+    #[serde(default)]
+    pub frame_secs: Option<f32>,
 }
 
+/// Layout of tilemap.png, loaded from tilemap.atlas.ron so new sprites
+/// don't need a code change.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
 pub struct TilemapDefinition {
     pub tile_width: u32,
     pub tile_height: u32,
     pub atlas_width: u32,
     pub atlas_height: u32,
-    pub sprites: &'static [TilemapSprite],
+    pub sprites: Vec<TilemapSprite>,
+}
+
+/// Atlas indices an animated tile cycles through.
+#[derive(Debug, Clone)]
+pub struct TileAnimationFrames {
+    pub indices: Vec<usize>,
+    pub frame_secs: f32,
 }
 
 impl TilemapDefinition {
@@ -27,323 +48,77 @@ impl TilemapDefinition {
         self.sprites.iter().position(|sprite| sprite.name == name)
     }
 
-    pub fn sprite_rect(&self, index: usize) -> URect {
-        let sprite = &self.sprites[index];
-        let min = UVec2::new(sprite.pixel_x, sprite.pixel_y);
+    fn rect_at(&self, pixel_x: u32, pixel_y: u32) -> URect {
+        let min = UVec2::new(pixel_x, pixel_y);
         URect::from_corners(min, min + self.tile_size())
     }
-}
 
-pub const TILEMAP: TilemapDefinition = TilemapDefinition {
-    tile_width: 32,
-    tile_height: 32,
-    atlas_width: 256,
-    atlas_height: 320,
-    sprites: &[
-          TilemapSprite {
-            name: "dirt",
-            pixel_x: 128,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "green_grass",
-            pixel_x: 160,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_in_tl",
-            pixel_x: 192,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_in_tr",
-            pixel_x: 224,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_in_bl",
-            pixel_x: 192,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_in_br",
-            pixel_x: 224,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_out_tl",
-            pixel_x: 0,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_out_tr",
-            pixel_x: 32,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_out_bl",
-            pixel_x: 0,
-            pixel_y: 96,
-        },
-        TilemapSprite {
-            name: "green_grass_corner_out_br",
-            pixel_x: 32,
-            pixel_y: 96,
-        },
-        TilemapSprite {
-            name: "green_grass_side_t",
-            pixel_x: 64,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "green_grass_side_r",
-            pixel_x: 96,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "green_grass_side_l",
-            pixel_x: 64,
-            pixel_y: 96,
-        },
-        TilemapSprite {
-            name: "green_grass_side_b",
-            pixel_x: 96,
-            pixel_y: 96,
-        },
-        TilemapSprite {
-            name: "yellow_grass",
-            pixel_x: 0,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_in_tl",
-            pixel_x: 32,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_in_tr",
-            pixel_x: 64,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_in_bl",
-            pixel_x: 32,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_in_br",
-            pixel_x: 64,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_out_tl",
-            pixel_x: 96,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_out_tr",
-            pixel_x: 128,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_out_bl",
-            pixel_x: 96,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "yellow_grass_corner_out_br",
-            pixel_x: 128,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "yellow_grass_side_t",
-            pixel_x: 160,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_side_r",
-            pixel_x: 192,
-            pixel_y: 256,
-        },
-        TilemapSprite {
-            name: "yellow_grass_side_l",
-            pixel_x: 160,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "yellow_grass_side_b",
-            pixel_x: 192,
-            pixel_y: 288,
-        },
-        TilemapSprite {
-            name: "water",
-            pixel_x: 32,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_corner_in_tl",
-            pixel_x: 64,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_corner_in_tr",
-            pixel_x: 96,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_corner_in_bl",
-            pixel_x: 64,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "water_corner_in_br",
-            pixel_x: 96,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "water_corner_out_tl",
-            pixel_x: 128,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_corner_out_tr",
-            pixel_x: 160,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_corner_out_bl",
-            pixel_x: 128,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "water_corner_out_br",
-            pixel_x: 160,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "water_side_t",
-            pixel_x: 192,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_side_r",
-            pixel_x: 224,
-            pixel_y: 192,
-        },
-        TilemapSprite {
-            name: "water_side_l",
-            pixel_x: 192,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "water_side_b",
-            pixel_x: 224,
-            pixel_y: 224,
-        },
-        TilemapSprite {
-            name: "big_tree_1_tl",
-            pixel_x: 0,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "big_tree_1_tr",
-            pixel_x: 32,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "big_tree_1_bl",
-            pixel_x: 0,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "big_tree_1_br",
-            pixel_x: 32,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "big_tree_2_tl",
-            pixel_x: 64,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "big_tree_2_tr",
-            pixel_x: 96,
-            pixel_y: 0,
-        },
-        TilemapSprite {
-            name: "big_tree_2_bl",
-            pixel_x: 64,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "big_tree_2_br",
-            pixel_x: 96,
-            pixel_y: 32,
-        },
-        TilemapSprite {
-            name: "plant_1",
-            pixel_x: 128,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "plant_2",
-            pixel_x: 160,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "plant_3",
-            pixel_x: 192,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "plant_4",
-            pixel_x: 224,
-            pixel_y: 64,
-        },
-        TilemapSprite {
-            name: "rock_1",
-            pixel_x: 0,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "rock_2",
-            pixel_x: 32,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "rock_3",
-            pixel_x: 64,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "rock_4",
-            pixel_x: 96,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "small_tree_top",
-            pixel_x: 128,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "small_tree_bottom",
-            pixel_x: 128,
-            pixel_y: 160,
-        },
-        TilemapSprite {
-            name: "tree_stump_1",
-            pixel_x: 192,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "tree_stump_2",
-            pixel_x: 224,
-            pixel_y: 128,
-        },
-        TilemapSprite {
-            name: "tree_stump_3",
-            pixel_x: 0,
-            pixel_y: 192,
-        },
-    ]
-};
\ No newline at end of file
+    /// The first frame of every sprite, in definition order so sprite
+    /// indices are atlas indices, then the extra animation frames.
+    pub fn atlas_layout(&self) -> TextureAtlasLayout {
+        let mut layout = TextureAtlasLayout::new_empty(self.atlas_size());
+        for sprite in &self.sprites {
+            layout.add_texture(self.rect_at(sprite.pixel_x, sprite.pixel_y));
+        }
+        for sprite in &self.sprites {
+            for &(x, y) in &sprite.frames {
+                layout.add_texture(self.rect_at(x, y));
+            }
+        }
+        layout
+    }
+
+    /// Frame lists of the animated sprites, matching `atlas_layout`.
+    pub fn animations(&self) -> Vec<TileAnimationFrames> {
+        let mut next_frame = self.sprites.len();
+        self.sprites
+            .iter()
+            .enumerate()
+            .filter(|(_, sprite)| !sprite.frames.is_empty())
+            .map(|(index, sprite)| {
+                let extra = next_frame..next_frame + sprite.frames.len();
+                next_frame = extra.end;
+                TileAnimationFrames {
+                    indices: std::iter::once(index).chain(extra).collect(),
+                    frame_secs: sprite.frame_secs.unwrap_or(DEFAULT_FRAME_SECS),
+                }
+            })
+            .collect()
+    }
+
+    /// Checks that every name the world rules use exists, that names are
+    /// unique and that every frame lies inside the atlas. Lists every
+    /// problem at once.
+    pub fn validate<'a>(&self, used: impl IntoIterator<Item = &'a str>) -> Result<(), String> {
+        let mut problems = Vec::new();
+
+        let mut missing: Vec<&str> = used
+            .into_iter()
+            .filter(|name| self.sprite_index(name).is_none())
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        if !missing.is_empty() {
+            problems.push(format!("missing sprites: {}", missing.join(", ")));
+        }
+
+        for (index, sprite) in self.sprites.iter().enumerate() {
+            if self.sprites[..index].iter().any(|other| other.name == sprite.name) {
+                problems.push(format!("sprite '{}' is defined twice", sprite.name));
+            }
+            let corners = std::iter::once((sprite.pixel_x, sprite.pixel_y)).chain(sprite.frames.iter().copied());
+            for (x, y) in corners {
+                let rect = self.rect_at(x, y);
+                if rect.max.x > self.atlas_width || rect.max.y > self.atlas_height {
+                    problems.push(format!("sprite '{}' has a frame at ({}, {}) outside the atlas", sprite.name, x, y));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid tilemap atlas: {}", problems.join("; ")))
+        }
+    }
+}
@@ -0,0 +1,621 @@
+// src/map/tmx.rs
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
+use std::fmt::Write as _;
+
+use bevy::prelude::*;
+
+use crate::characters::spawn::PlayerSpawned;
+use crate::checkpoints::ActiveCheckpoint;
+use crate::collision::{CollisionMap, CollisionMapBuilder, CollisionMapBuilt, Edges, TileMarker, TileType};
+use crate::config::map::{NODE_SIZE_Z, TILE_SIZE};
+use crate::config::player::PLAYER_Z_POSITION;
+use crate::console::ConsoleResult;
+use crate::enemy::{ambush::spawn_ambush, respawn::RespawnDirector, spawn::EnemiesSpawned};
+use crate::inventory::{ItemKind, Pickable};
+use crate::lighting::{spawn_cave, spawn_torch};
+use crate::logging::targets;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::{GeneratedMap, WorldGenSettings, ASSETS_SCALE};
+use crate::map::level::LevelLayout;
+use crate::map::tilemap::TilemapDefinition;
+use crate::props::{spawn_platform, spawn_prop, Platform, PropKind};
+use crate::state::{despawn_all_gameplay, GameState, GameplayEntity};
+
+const DEFAULT_EXPORT_FILE: &str = "map_export.tmx";
+/// Tiled keeps flips and rotations in the top bits of each gid
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+const FLIP_FLAGS: u32 = FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY;
+/// Flags of a tile turned 0, 90, 180 and 270 degrees counter-clockwise
+const QUARTER_TURN_FLAGS: [u32; 4] = [
+    0,
+    FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY,
+    FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY,
+    FLIPPED_HORIZONTALLY | FLIPPED_DIAGONALLY,
+];
+
+/// One tile in map cells, row 0 at the bottom like the collision map.
+struct MapTile {
+    x: u32,
+    y: u32,
+    layer: u32,
+    /// Index into the atlas description's sprites
+    sprite: usize,
+    quarter_turns: usize,
+}
+
+/// What a sprite means for gameplay, stored as Tiled tile properties.
+#[derive(Clone, Copy, PartialEq)]
+struct SpriteTraits {
+    tile_type: TileType,
+    pickable: Option<ItemKind>,
+}
+
+/// A Tiled object: a spawn point, an interactable or an area. Enemies are
+/// named after their character.
+struct MapObject {
+    kind: String,
+    name: String,
+    /// Position in cells from the bottom-left corner of the map; the top-left
+    /// corner of areas
+    cell: Vec2,
+    /// Width and height in cells, zero for points
+    size: Vec2,
+    /// Points of a polyline in cells from the bottom-left corner, empty for
+    /// other shapes
+    path: Vec<Vec2>,
+}
+
+/// A tile map as read from or written to a Tiled TMX file.
+pub struct TmxMap {
+    width: u32,
+    height: u32,
+    seed: Option<u64>,
+    tiles: Vec<MapTile>,
+    traits: HashMap<usize, SpriteTraits>,
+    objects: Vec<MapObject>,
+    /// Cells with one-way edges, from "ledge" objects
+    ledges: Vec<(IVec2, Edges)>,
+}
+
+impl TmxMap {
+    /// Bottom-left corner that keeps the map centered like a generated one,
+    /// with its cells on the world grid.
+    pub fn origin(&self, settings: &WorldGenSettings) -> Vec2 {
+        let total = IVec2::new(settings.total_grid_x() as i32, settings.total_grid_y() as i32);
+        let offset = (total - IVec2::new(self.width as i32, self.height as i32)) / 2;
+        settings.grid_origin() + offset.as_vec2() * TILE_SIZE
+    }
+
+    /// Collision map of the tiles as `spawn_map` places them. Hand-made and
+    /// imported maps keep the tiles they were drawn with, so no hazards are
+    /// scattered over them.
+    pub fn collision_map(&self, origin: Vec2) -> Option<CollisionMap> {
+        let mut builder = CollisionMapBuilder::default();
+        for tile in &self.tiles {
+            let tile_type = self.traits.get(&tile.sprite).map_or(TileType::Empty, |t| t.tile_type);
+            let cell = IVec2::new(tile.x as i32, tile.y as i32);
+            builder.add(cell, z_from_layer(tile.layer, tile.y, self.height), tile_type);
+        }
+        for (cell, edges) in &self.ledges {
+            builder.add_one_way(*cell, *edges);
+        }
+        builder.build(origin, None)
+    }
+}
+
+/// Writes the current world to a Tiled map, for bug reports and as a starting
+/// point for hand-made test levels.
+pub fn map_export_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let path = args.first().copied().unwrap_or(DEFAULT_EXPORT_FILE);
+    let handles = world
+        .get_resource::<TilemapHandles>()
+        .ok_or("The tilemap hasn't loaded")?
+        .clone();
+    let definition = world
+        .resource::<Assets<TilemapDefinition>>()
+        .get(&handles.definition)
+        .ok_or("The tilemap atlas description hasn't loaded")?
+        .clone();
+    let settings = *world.resource::<WorldGenSettings>();
+
+    let mut query = world.query::<(&Transform, &Sprite, &TileMarker, Option<&Pickable>)>();
+    let origin = settings.grid_origin();
+    let mut cells = Vec::new();
+    for (transform, sprite, marker, pickable) in query.iter(world) {
+        let Some(atlas) = &sprite.texture_atlas else {
+            continue;
+        };
+        let Some(sprite_index) = handles
+            .sprite_names
+            .get(atlas.index)
+            .and_then(|name| definition.sprite_index(name))
+        else {
+            continue;
+        };
+        let cell = ((transform.translation.truncate() - origin) / TILE_SIZE).floor().as_ivec2();
+        let angle = transform.rotation.to_euler(EulerRot::ZYX).0;
+        let quarter_turns = ((angle / FRAC_PI_2).round() as i32).rem_euclid(4) as usize;
+        let traits = SpriteTraits {
+            tile_type: marker.tile_type,
+            pickable: pickable.map(|p| p.kind),
+        };
+        cells.push((cell, transform.translation.z, sprite_index, quarter_turns, traits));
+    }
+    if cells.is_empty() {
+        return Err("There is no map to export".into());
+    }
+
+    let min = cells.iter().fold(IVec2::MAX, |min, (cell, ..)| min.min(*cell));
+    let max = cells.iter().fold(IVec2::MIN, |max, (cell, ..)| max.max(*cell));
+    let size = (max - min + IVec2::ONE).as_uvec2();
+    let mut map = TmxMap {
+        width: size.x,
+        height: size.y,
+        seed: Some(settings.seed),
+        tiles: Vec::with_capacity(cells.len()),
+        traits: HashMap::new(),
+        objects: Vec::new(),
+        ledges: Vec::new(),
+    };
+    let mut conflicts = 0;
+    for (cell, z, sprite, quarter_turns, traits) in cells {
+        let local = (cell - min).as_uvec2();
+        map.tiles.push(MapTile {
+            x: local.x,
+            y: local.y,
+            layer: layer_from_z(z, local.y, size.y),
+            sprite,
+            quarter_turns,
+        });
+        if *map.traits.entry(sprite).or_insert(traits) != traits {
+            conflicts += 1;
+        }
+    }
+    if conflicts > 0 {
+        warn!(
+            target: targets::MAP,
+            "{} tiles disagree with their sprite's tile type; the first one seen was exported", conflicts
+        );
+    }
+
+    let xml = write_tmx(&map, &definition)?;
+    std::fs::write(path, xml).map_err(|e| format!("Cannot write {}: {}", path, e))?;
+    info!(target: targets::MAP, "Exported {}x{} map to {}", size.x, size.y, path);
+    Ok(format!("Exported {} tiles to {}", map.tiles.len(), path))
+}
+
+/// Replaces the current world with a Tiled map. The collision map, player,
+/// enemies and props are rebuilt from it as after a fresh generation.
+pub fn map_import_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let Some(path) = args.first() else {
+        return Err("Usage: map_import <file.tmx>".into());
+    };
+    if *world.resource::<State<GameState>>().get() != GameState::Playing {
+        return Err("Maps can only be imported while playing".into());
+    }
+    let handles = world
+        .get_resource::<TilemapHandles>()
+        .ok_or("The tilemap hasn't loaded")?
+        .clone();
+    let definition = world
+        .resource::<Assets<TilemapDefinition>>()
+        .get(&handles.definition)
+        .ok_or("The tilemap atlas description hasn't loaded")?
+        .clone();
+    let xml = std::fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    let map = read_tmx(&xml, &definition).map_err(|e| format!("{}: {}", path, e))?;
+
+    let mut settings = world.resource_mut::<WorldGenSettings>();
+    if let Some(seed) = map.seed {
+        settings.seed = seed;
+    }
+    let origin = map.origin(&settings);
+    let collision_map = map.collision_map(origin).ok_or("The map has no tiles")?;
+
+    despawn_all_gameplay(world);
+    world.resource_mut::<PlayerSpawned>().0 = false;
+    world.resource_mut::<EnemiesSpawned>().0 = false;
+    *world.resource_mut::<RespawnDirector>() = RespawnDirector::default();
+    *world.resource_mut::<ActiveCheckpoint>() = ActiveCheckpoint::default();
+
+    let mut commands = world.commands();
+    let layout = spawn_map(&mut commands, &handles, &map, origin);
+    // Without spawn points the usual placement rules apply
+    if map.objects.is_empty() {
+        commands.remove_resource::<LevelLayout>();
+    } else {
+        commands.insert_resource(layout);
+    }
+    world.flush();
+
+    world.insert_resource(collision_map);
+    world.resource_mut::<CollisionMapBuilt>().0 = true;
+    world.remove_resource::<GeneratedMap>();
+
+    info!(target: targets::MAP, "Imported {}x{} map from {}", map.width, map.height, path);
+    Ok(format!("Imported {} tiles from {}", map.tiles.len(), path))
+}
+
+/// Spawns the tiles, torches, caves, props, platforms and ambushes of a map
+/// with its bottom-left cell at `origin`, and returns where its other objects
+/// go.
+pub fn spawn_map(commands: &mut Commands, handles: &TilemapHandles, map: &TmxMap, origin: Vec2) -> LevelLayout {
+    for tile in &map.tiles {
+        let translation = Vec3::new(
+            origin.x + (tile.x as f32 + 0.5) * TILE_SIZE,
+            origin.y + (tile.y as f32 + 0.5) * TILE_SIZE,
+            z_from_layer(tile.layer, tile.y, map.height),
+        );
+        let transform = Transform {
+            translation,
+            rotation: Quat::from_rotation_z(tile.quarter_turns as f32 * FRAC_PI_2),
+            scale: ASSETS_SCALE,
+        };
+        let traits = map.traits.get(&tile.sprite).copied();
+        let tile_type = traits.map_or(TileType::Empty, |t| t.tile_type);
+        let mut entity =
+            commands.spawn((handles.sprite(tile.sprite), transform, TileMarker::new(tile_type), GameplayEntity));
+        if let Some(kind) = traits.and_then(|t| t.pickable) {
+            entity.insert(Pickable::new(kind));
+        }
+    }
+
+    let mut layout = LevelLayout::default();
+    for object in &map.objects {
+        let position = origin + object.cell * TILE_SIZE;
+        match object.kind.as_str() {
+            "player" => layout.player_spawn = Some(position),
+            "enemy" if !object.name.is_empty() => layout.enemies.push((object.name.clone(), position)),
+            "checkpoint" => layout.checkpoints.push(position),
+            "shrine" => layout.shrines.push(position),
+            "obelisk" => layout.obelisks.push(position),
+            "torch" => {
+                spawn_torch(commands, position.extend(PLAYER_Z_POSITION));
+            }
+            "cave" => {
+                let size = object.size * TILE_SIZE;
+                spawn_cave(commands, position + Vec2::new(size.x, -size.y) / 2.0, size);
+            }
+            "crate" => {
+                spawn_prop(commands, PropKind::Crate, position);
+            }
+            "barrel" => {
+                spawn_prop(commands, PropKind::Barrel, position);
+            }
+            "platform" if object.path.len() < 2 => warn!(
+                target: targets::MAP,
+                "Skipping platform '{}': its route needs a polyline of two points or more", object.name
+            ),
+            "platform" => {
+                let waypoints: Vec<Vec2> = object.path.iter().map(|cell| origin + *cell * TILE_SIZE).collect();
+                let start = waypoints[0];
+                spawn_platform(commands, Platform::new(waypoints), start);
+            }
+            "ambush" => {
+                let size = object.size * TILE_SIZE;
+                let center = position + Vec2::new(size.x, -size.y) / 2.0;
+                spawn_ambush(commands, center, size, &object.name);
+            }
+            _ => warn!(
+                target: targets::MAP,
+                "Skipping map object '{}' of unknown type '{}'", object.name, object.kind
+            ),
+        }
+    }
+    layout
+}
+
+/// Tiles are drawn NODE_SIZE_Z apart per layer, plus up to one more step so
+/// lower rows draw in front; see `spawn_chunk_tiles`.
+fn z_from_layer(layer: u32, row: u32, height: u32) -> f32 {
+    NODE_SIZE_Z * (layer as f32 + 0.5) + NODE_SIZE_Z * (1.0 - row as f32 / height as f32)
+}
+
+fn layer_from_z(z: f32, row: u32, height: u32) -> u32 {
+    (z / NODE_SIZE_Z - 1.5 + row as f32 / height as f32).round().max(0.0) as u32
+}
+
+/// Tiled numbers the tiles of an image left to right, top to bottom, from 1.
+fn sprite_gids(definition: &TilemapDefinition) -> Result<Vec<u32>, String> {
+    let columns = definition.atlas_width / definition.tile_width;
+    definition
+        .sprites
+        .iter()
+        .map(|sprite| {
+            if sprite.pixel_x % definition.tile_width != 0 || sprite.pixel_y % definition.tile_height != 0 {
+                return Err(format!("sprite '{}' is not on the atlas tile grid", sprite.name));
+            }
+            let column = sprite.pixel_x / definition.tile_width;
+            let row = sprite.pixel_y / definition.tile_height;
+            Ok(1 + row * columns + column)
+        })
+        .collect()
+}
+
+fn write_tmx(map: &TmxMap, definition: &TilemapDefinition) -> Result<String, String> {
+    let gids = sprite_gids(definition)?;
+    let layer_count = map.tiles.iter().map(|tile| tile.layer + 1).max().unwrap_or(0);
+    let cells = (map.width * map.height) as usize;
+    let mut layers = vec![vec![0u32; cells]; layer_count as usize];
+    let mut overlaps = 0;
+    for tile in &map.tiles {
+        // Tiled rows run top to bottom
+        let index = ((map.height - 1 - tile.y) * map.width + tile.x) as usize;
+        let cell = &mut layers[tile.layer as usize][index];
+        if *cell != 0 {
+            overlaps += 1;
+        }
+        *cell = gids[tile.sprite] | QUARTER_TURN_FLAGS[tile.quarter_turns];
+    }
+    if overlaps > 0 {
+        warn!(target: targets::MAP, "{} tiles shared a cell and layer and were dropped from the export", overlaps);
+    }
+
+    let (tw, th) = (definition.tile_width, definition.tile_height);
+    let columns = definition.atlas_width / tw;
+    let tile_count = columns * (definition.atlas_height / th);
+    let mut xml = String::new();
+    let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        xml,
+        r#"<map version="1.10" orientation="orthogonal" renderorder="right-down" width="{}" height="{}" tilewidth="{}" tileheight="{}" infinite="0" nextlayerid="{}" nextobjectid="1">"#,
+        map.width, map.height, tw, th, layer_count + 1
+    );
+    if let Some(seed) = map.seed {
+        let _ = writeln!(xml, r#" <properties>"#);
+        let _ = writeln!(xml, r#"  <property name="seed" value="{}"/>"#, seed);
+        let _ = writeln!(xml, r#" </properties>"#);
+    }
+    let _ = writeln!(
+        xml,
+        r#" <tileset firstgid="1" name="tilemap" tilewidth="{}" tileheight="{}" tilecount="{}" columns="{}">"#,
+        tw, th, tile_count, columns
+    );
+    let _ = writeln!(
+        xml,
+        r#"  <image source="tilemap.png" width="{}" height="{}"/>"#,
+        definition.atlas_width, definition.atlas_height
+    );
+    let mut traits: Vec<_> = map.traits.iter().collect();
+    traits.sort_by_key(|(sprite, _)| gids[**sprite]);
+    for (sprite, traits) in traits {
+        let _ = writeln!(xml, r#"  <tile id="{}">"#, gids[*sprite] - 1);
+        let _ = writeln!(xml, r#"   <properties>"#);
+        let _ = writeln!(xml, r#"    <property name="tile_type" value="{:?}"/>"#, traits.tile_type);
+        if let Some(kind) = traits.pickable {
+            let _ = writeln!(xml, r#"    <property name="pickable" value="{:?}"/>"#, kind);
+        }
+        let _ = writeln!(xml, r#"   </properties>"#);
+        let _ = writeln!(xml, r#"  </tile>"#);
+    }
+    let _ = writeln!(xml, r#" </tileset>"#);
+    for (index, layer) in layers.iter().enumerate() {
+        let _ = writeln!(
+            xml,
+            r#" <layer id="{}" name="layer {}" width="{}" height="{}">"#,
+            index + 1, index, map.width, map.height
+        );
+        let _ = writeln!(xml, r#"  <data encoding="csv">"#);
+        let rows: Vec<String> = layer
+            .chunks(map.width as usize)
+            .map(|row| row.iter().map(u32::to_string).collect::<Vec<_>>().join(","))
+            .collect();
+        let _ = writeln!(xml, "{}", rows.join(",\n"));
+        let _ = writeln!(xml, r#"  </data>"#);
+        let _ = writeln!(xml, r#" </layer>"#);
+    }
+    let _ = writeln!(xml, "</map>");
+    Ok(xml)
+}
+
+/// Reads the subset of TMX the exporter writes: one tileset over tilemap.png
+/// and CSV tile layers, bottom layer first. Objects from object layers are
+/// kept as spawn points and areas, except "ledge" rectangles: the sides listed in
+/// their name ("south", "south,west") become one-way edges of every cell
+/// they cover, which can be dropped off but not climbed.
+pub fn read_tmx(xml: &str, definition: &TilemapDefinition) -> Result<TmxMap, String> {
+    let map_tag = tags(xml, "map").next().ok_or("no <map> element")?;
+    let width: u32 = parse_attr(map_tag, "width")?;
+    let height: u32 = parse_attr(map_tag, "height")?;
+    if width == 0 || height == 0 {
+        return Err("the map is empty".into());
+    }
+    let seed = tags(xml, "property")
+        .find(|tag| attr(tag, "name") == Some("seed"))
+        .map(|tag| parse_attr(tag, "value"))
+        .transpose()?;
+
+    let tileset_tag = tags(xml, "tileset").next().ok_or("no <tileset> element")?;
+    let first_gid: u32 = parse_attr(tileset_tag, "firstgid")?;
+    let columns: u32 = parse_attr(tileset_tag, "columns")?;
+    let tile_width: u32 = parse_attr(tileset_tag, "tilewidth")?;
+    if tile_width != definition.tile_width || columns != definition.atlas_width / definition.tile_width {
+        return Err("the tileset doesn't match tilemap.png".into());
+    }
+    let sprite_by_gid: HashMap<u32, usize> = sprite_gids(definition)?
+        .into_iter()
+        .enumerate()
+        .map(|(sprite, gid)| (gid - 1 + first_gid, sprite))
+        .collect();
+
+    let mut traits = HashMap::new();
+    for (tile_tag, body) in elements(xml, "tile") {
+        let gid = parse_attr::<u32>(tile_tag, "id")? + first_gid;
+        let Some(&sprite) = sprite_by_gid.get(&gid) else {
+            continue;
+        };
+        let property = |name: &str| {
+            tags(body, "property")
+                .find(|tag| attr(tag, "name") == Some(name))
+                .and_then(|tag| attr(tag, "value"))
+        };
+        let tile_type = match property("tile_type") {
+            Some(value) => parse_tile_type(value).ok_or_else(|| format!("unknown tile type '{}'", value))?,
+            None => TileType::Empty,
+        };
+        let pickable = match property("pickable") {
+            Some(value) => Some(parse_item_kind(value).ok_or_else(|| format!("unknown item '{}'", value))?),
+            None => None,
+        };
+        traits.insert(sprite, SpriteTraits { tile_type, pickable });
+    }
+
+    let mut tiles = Vec::new();
+    for (layer, (_, body)) in elements(xml, "layer").enumerate() {
+        let data_tag = tags(body, "data").next().ok_or_else(|| format!("layer {} has no data", layer))?;
+        if attr(data_tag, "encoding") != Some("csv") {
+            return Err(format!("layer {} is not CSV encoded", layer));
+        }
+        let (_, data) = elements(body, "data").next().ok_or_else(|| format!("layer {} has no data", layer))?;
+        let gids: Vec<u32> = data
+            .split(',')
+            .map(|gid| gid.trim().parse().map_err(|_| format!("layer {} has a bad tile '{}'", layer, gid.trim())))
+            .collect::<Result<_, _>>()?;
+        if gids.len() != (width * height) as usize {
+            return Err(format!("layer {} has {} tiles, expected {}", layer, gids.len(), width * height));
+        }
+        for (index, raw) in gids.into_iter().enumerate() {
+            if raw == 0 {
+                continue;
+            }
+            let gid = raw & !FLIP_FLAGS;
+            let sprite = *sprite_by_gid
+                .get(&gid)
+                .ok_or_else(|| format!("layer {} uses tile {}, which is not an atlas sprite", layer, gid))?;
+            // Plain mirroring has no counterpart in the game and is dropped
+            let quarter_turns = QUARTER_TURN_FLAGS
+                .iter()
+                .position(|flags| *flags == raw & FLIP_FLAGS)
+                .unwrap_or(0);
+            let index = index as u32;
+            tiles.push(MapTile {
+                x: index % width,
+                y: height - 1 - index / width,
+                layer: layer as u32,
+                sprite,
+                quarter_turns,
+            });
+        }
+    }
+
+    // Tiled measures objects in pixels from the top-left corner
+    let tile_height: u32 = parse_attr(tileset_tag, "tileheight")?;
+    let mut objects = Vec::new();
+    let mut ledges = Vec::new();
+    for tag in tags(xml, "object") {
+        let x: f32 = parse_attr(tag, "x")?;
+        let y: f32 = parse_attr(tag, "y")?;
+        let kind = attr(tag, "type").or_else(|| attr(tag, "class")).unwrap_or_default();
+        let name = attr(tag, "name").unwrap_or_default();
+        let w: f32 = attr(tag, "width").map_or(Ok(0.0), |_| parse_attr(tag, "width"))?;
+        let h: f32 = attr(tag, "height").map_or(Ok(0.0), |_| parse_attr(tag, "height"))?;
+        if kind == "ledge" {
+            let edges = Edges::parse(name).ok_or_else(|| format!("ledge '{}' does not name its sides", name))?;
+            // Columns and rows (from the top) the rectangle covers
+            let first = Vec2::new(x / tile_width as f32, y / tile_height as f32).floor().as_ivec2();
+            let last = Vec2::new((x + w) / tile_width as f32, (y + h) / tile_height as f32)
+                .ceil()
+                .as_ivec2()
+                - IVec2::ONE;
+            for row in first.y..=last.y.max(first.y) {
+                for column in first.x..=last.x.max(first.x) {
+                    ledges.push((IVec2::new(column, height as i32 - 1 - row), edges));
+                }
+            }
+            continue;
+        }
+        // Polyline points are offsets in pixels from the object's position
+        let mut path = Vec::new();
+        if let Some(polyline) = body(xml, tag, "object").and_then(|body| tags(body, "polyline").next()) {
+            let points = attr(polyline, "points").unwrap_or_default();
+            for point in points.split_whitespace() {
+                let (px, py) = point
+                    .split_once(',')
+                    .and_then(|(px, py)| Some((px.parse::<f32>().ok()?, py.parse::<f32>().ok()?)))
+                    .ok_or_else(|| format!("object '{}' has a bad polyline point '{}'", name, point))?;
+                path.push(Vec2::new((x + px) / tile_width as f32, height as f32 - (y + py) / tile_height as f32));
+            }
+        }
+        objects.push(MapObject {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            cell: Vec2::new(x / tile_width as f32, height as f32 - y / tile_height as f32),
+            size: Vec2::new(w / tile_width as f32, h / tile_height as f32),
+            path,
+        });
+    }
+
+    Ok(TmxMap { width, height, seed, tiles, traits, objects, ledges })
+}
+
+/// Opening tags named `name`, without the angle brackets.
+fn tags<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = &'a str> {
+    xml.match_indices('<').filter_map(move |(start, _)| {
+        let rest = &xml[start + 1..];
+        let after = rest.strip_prefix(name)?;
+        if !after.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+            return None;
+        }
+        rest.find('>').map(|end| &rest[..end])
+    })
+}
+
+/// Elements named `name` with a body, as (opening tag, body) pairs.
+fn elements<'a>(xml: &'a str, name: &'a str) -> impl Iterator<Item = (&'a str, &'a str)> {
+    tags(xml, name).filter_map(move |tag| Some((tag, body(xml, tag, name)?)))
+}
+
+/// Text between an opening tag from `tags(xml, name)` and its closing tag;
+/// `None` for self-closing tags.
+fn body<'a>(xml: &'a str, tag: &'a str, name: &str) -> Option<&'a str> {
+    if tag.ends_with('/') {
+        return None;
+    }
+    // Tags are slices of `xml`, so their offset finds the body
+    let body_start = tag.as_ptr() as usize - xml.as_ptr() as usize + tag.len() + 1;
+    let body_len = xml[body_start..].find(format!("</{}>", name).as_str())?;
+    Some(&xml[body_start..body_start + body_len])
+}
+
+fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!(" {}=\"", name);
+    let start = tag.find(key.as_str())? + key.len();
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+fn parse_attr<T: std::str::FromStr>(tag: &str, name: &str) -> Result<T, String> {
+    let value = attr(tag, name).ok_or_else(|| format!("missing '{}' attribute", name))?;
+    value.parse().map_err(|_| format!("bad '{}' attribute '{}'", name, value))
+}
+
+fn parse_tile_type(name: &str) -> Option<TileType> {
+    Some(match name {
+        "Empty" => TileType::Empty,
+        "Dirt" => TileType::Dirt,
+        "Grass" => TileType::Grass,
+        "YellowGrass" => TileType::YellowGrass,
+        "Shore" => TileType::Shore,
+        "Bridge" => TileType::Bridge,
+        "Lava" => TileType::Lava,
+        "Spikes" => TileType::Spikes,
+        "ToxicPool" => TileType::ToxicPool,
+        "Water" => TileType::Water,
+        "Tree" => TileType::Tree,
+        "Rock" => TileType::Rock,
+        _ => return None,
+    })
+}
+
+fn parse_item_kind(name: &str) -> Option<ItemKind> {
+    Some(match name {
+        "Plant1" => ItemKind::Plant1,
+        "Plant2" => ItemKind::Plant2,
+        "Plant3" => ItemKind::Plant3,
+        "Plant4" => ItemKind::Plant4,
+        _ => return None,
+    })
+}
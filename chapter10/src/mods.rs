@@ -0,0 +1,241 @@
+// src/mods.rs
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::asset::{LoadState, LoadedFolder};
+use bevy::prelude::*;
+
+use crate::characters::config::CharactersList;
+use crate::characters::spawn::CharactersListResource;
+use crate::combat::{PowerId, PowerList, PowerRegistry};
+use crate::logging::targets;
+use crate::map::rules::{RulesDefinition, WorldRules};
+use crate::prefabs::{PrefabList, PrefabRegistry};
+use crate::save::data::data_directory;
+
+/// Asset source that reads from the mods folder, as `mods://pack/file.ron`.
+pub const MODS_SOURCE: &str = "mods";
+
+/// Source for the mods folder next to the executable. Registered before
+/// `DefaultPlugins`, since the asset plugin builds its sources on startup.
+pub fn mods_source() -> AssetSourceBuilder {
+    let path = data_directory().join("mods");
+    AssetSourceBuilder::platform_default(&path.to_string_lossy(), None)
+}
+
+/// Content packs from the mods folder. Each subfolder is a pack holding any of
+/// characters.ron, prefabs.ron (enemy archetypes and other spawnables),
+/// powers.ron and world.rules.ron, in the same format as the game's own.
+/// Packs merge over the game's data in alphabetical order, so a later pack
+/// wins a name clash; every clash is logged.
+///
+/// Merging happens once at startup. Hot reloading one of the game's own
+/// files afterwards drops the mod entries from that registry until restart.
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_mod_packs).add_systems(
+            Update,
+            merge_mod_packs.run_if(resource_exists::<PendingMods>),
+        );
+    }
+}
+
+/// The mods folder while it loads, removed once merged.
+#[derive(Resource)]
+struct PendingMods(Handle<LoadedFolder>);
+
+fn load_mod_packs(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let folder = asset_server.load_folder(format!("{MODS_SOURCE}://"));
+    commands.insert_resource(PendingMods(folder));
+}
+
+/// Which pack last defined each name, for clash reports.
+#[derive(Default)]
+struct Origins(HashMap<String, String>);
+
+impl Origins {
+    /// Records `pack` as the owner of `name`, warning if something already
+    /// defined it.
+    fn claim(&mut self, kind: &str, name: &str, pack: &str, existed: bool) {
+        let previous = self.0.insert(name.to_string(), pack.to_string());
+        if !existed {
+            return;
+        }
+        let owner = match &previous {
+            Some(other) => format!("pack '{}'", other),
+            None => "the base game".to_string(),
+        };
+        warn!(
+            target: targets::MODS,
+            "Pack '{}' overrides {} '{}' from {}",
+            pack,
+            kind,
+            name,
+            owner
+        );
+    }
+}
+
+/// Waits for the mods folder and the game's own data, then merges each pack
+/// into the registries.
+fn merge_mod_packs(world: &mut World) {
+    let folder = world.resource::<PendingMods>().0.clone();
+    match world.resource::<AssetServer>().load_state(&folder) {
+        LoadState::Loaded => {}
+        LoadState::Failed(_) => {
+            info!(target: targets::MODS, "No mods folder, playing without mods");
+            world.remove_resource::<PendingMods>();
+            return;
+        }
+        _ => return,
+    }
+    if !base_data_loaded(world) {
+        return;
+    }
+    world.remove_resource::<PendingMods>();
+
+    // Pack name -> handles of its files
+    let mut packs: BTreeMap<String, Vec<UntypedHandle>> = BTreeMap::new();
+    let Some(loaded) = world.resource::<Assets<LoadedFolder>>().get(&folder) else {
+        return;
+    };
+    for handle in &loaded.handles {
+        let Some(path) = handle.path() else {
+            continue;
+        };
+        // Loose files at the top of the folder don't belong to a pack
+        let mut components = path.path().iter();
+        let (Some(pack), Some(_)) = (components.next(), components.next()) else {
+            warn!(target: targets::MODS, "Ignoring {}: mod files go in a pack folder", path);
+            continue;
+        };
+        packs
+            .entry(pack.to_string_lossy().into_owned())
+            .or_default()
+            .push(handle.clone());
+    }
+
+    let mut prefab_origins = Origins::default();
+    let mut power_origins = Origins::default();
+    let mut character_origins = Origins::default();
+    let mut model_origins = Origins::default();
+    for (pack, handles) in &packs {
+        for handle in handles {
+            merge_prefabs(world, pack, handle, &mut prefab_origins);
+            merge_powers(world, pack, handle, &mut power_origins);
+            merge_characters(world, pack, handle, &mut character_origins);
+            merge_rules(world, pack, handle, &mut model_origins);
+        }
+    }
+    info!(
+        target: targets::MODS,
+        "Merged {} mod pack(s): {}",
+        packs.len(),
+        packs.keys().cloned().collect::<Vec<_>>().join(", ")
+    );
+}
+
+fn base_data_loaded(world: &World) -> bool {
+    let characters_loaded = world
+        .get_resource::<CharactersListResource>()
+        .is_some_and(|res| world.resource::<Assets<CharactersList>>().contains(&res.handle));
+    let rules_loaded = world
+        .get_resource::<WorldRules>()
+        .is_some_and(|rules| world.resource::<Assets<RulesDefinition>>().contains(&rules.0));
+    characters_loaded
+        && rules_loaded
+        && world.resource::<PrefabRegistry>().is_loaded()
+        && world.resource::<PowerRegistry>().is_loaded()
+}
+
+fn merge_prefabs(world: &mut World, pack: &str, handle: &UntypedHandle, origins: &mut Origins) {
+    let Ok(handle) = handle.clone().try_typed::<PrefabList>() else {
+        return;
+    };
+    let Some(list) = world.resource::<Assets<PrefabList>>().get(&handle).cloned() else {
+        return;
+    };
+    let mut registry = world.resource_mut::<PrefabRegistry>();
+    for prefab in list.prefabs {
+        origins.claim("prefab", &prefab.name, pack, registry.get(&prefab.name).is_some());
+        registry.register(prefab);
+    }
+}
+
+fn merge_powers(world: &mut World, pack: &str, handle: &UntypedHandle, origins: &mut Origins) {
+    let Ok(handle) = handle.clone().try_typed::<PowerList>() else {
+        return;
+    };
+    let Some(list) = world.resource::<Assets<PowerList>>().get(&handle).cloned() else {
+        return;
+    };
+    let mut registry = world.resource_mut::<PowerRegistry>();
+    for power in list.powers {
+        let existed = registry.get(&PowerId::from(power.id.as_str())).is_some();
+        origins.claim("power", &power.id, pack, existed);
+        registry.register(power);
+    }
+}
+
+fn merge_characters(world: &mut World, pack: &str, handle: &UntypedHandle, origins: &mut Origins) {
+    let Ok(handle) = handle.clone().try_typed::<CharactersList>() else {
+        return;
+    };
+    let Some(list) = world.resource::<Assets<CharactersList>>().get(&handle).cloned() else {
+        return;
+    };
+    let base = world.resource::<CharactersListResource>().handle.clone();
+    let mut lists = world.resource_mut::<Assets<CharactersList>>();
+    let Some(base) = lists.get_mut(&base) else {
+        return;
+    };
+    for character in list.characters {
+        match base.characters.iter_mut().find(|c| c.name == character.name) {
+            Some(existing) => {
+                origins.claim("character", &character.name, pack, true);
+                *existing = character;
+            }
+            None => {
+                origins.claim("character", &character.name, pack, false);
+                base.characters.push(character);
+            }
+        }
+    }
+}
+
+/// Adds a pack's sockets, models and connections to the world rules. The
+/// rules get recompiled on the change, so a pack that breaks them shows up
+/// in the log straight away.
+fn merge_rules(world: &mut World, pack: &str, handle: &UntypedHandle, origins: &mut Origins) {
+    let Ok(handle) = handle.clone().try_typed::<RulesDefinition>() else {
+        return;
+    };
+    let Some(rules) = world.resource::<Assets<RulesDefinition>>().get(&handle).cloned() else {
+        return;
+    };
+    let base = world.resource::<WorldRules>().0.clone();
+    let mut definitions = world.resource_mut::<Assets<RulesDefinition>>();
+    let Some(base) = definitions.get_mut(&base) else {
+        return;
+    };
+    for socket in rules.sockets {
+        if !base.sockets.contains(&socket) {
+            base.sockets.push(socket);
+        }
+    }
+    for model in rules.models {
+        match base.models.iter_mut().find(|m| m.name == model.name) {
+            Some(existing) => {
+                origins.claim("world model", &model.name, pack, true);
+                *existing = model;
+            }
+            None => {
+                origins.claim("world model", &model.name, pack, false);
+                base.models.push(model);
+            }
+        }
+    }
+    base.connections.extend(rules.connections);
+}
@@ -0,0 +1,100 @@
+// src/music/layers.rs
+use bevy::audio::{AudioSinkPlayback, Volume};
+use bevy::prelude::*;
+
+use crate::characters::input::Player;
+use crate::config::music::{CROSSFADE_SECS, MUSIC_VOLUME, STINGER_VOLUME};
+use crate::enemy::{AIBehavior, Boss, Enemy, SpawningIn};
+use crate::optional_assets;
+use crate::telemetry::GameplayEvent;
+
+use super::state::{AudioState, MusicHint, MusicLayer, Stinger};
+
+/// A looping layer of the score.
+#[derive(Component, Debug)]
+pub struct MusicTrack(pub MusicLayer);
+
+/// Starts every installed layer at once, silent, so they play in time and
+/// only their volumes change. The music isn't shipped, so layers without a
+/// file are left out rather than failing to load.
+pub fn spawn_music_layers(mut commands: Commands, asset_server: Res<AssetServer>) {
+    for layer in MusicLayer::ALL {
+        if !optional_assets::installed(layer.track()) {
+            continue;
+        }
+        commands.spawn((
+            MusicTrack(layer),
+            AudioPlayer::new(asset_server.load(layer.track())),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        ));
+    }
+}
+
+/// Counts the enemies hunting the player, the same range test the AI uses
+/// to give chase.
+pub fn update_audio_state(
+    time: Res<Time>,
+    mut audio: ResMut<AudioState>,
+    player: Query<&Transform, With<Player>>,
+    enemies: Query<(&Transform, &AIBehavior, Has<Boss>), (With<Enemy>, Without<SpawningIn>)>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    let (mut aggroed, mut boss) = (0, false);
+    for (transform, ai, is_boss) in &enemies {
+        if transform.translation.truncate().distance(player_pos) <= ai.detection_range {
+            aggroed += 1;
+            boss |= is_boss;
+        }
+    }
+    audio.update(aggroed, boss, time.delta());
+}
+
+/// Eases each layer towards the volume the state asks for. Runs on real
+/// time so fades finish while the game is paused.
+pub fn crossfade_layers(
+    time: Res<Time<Real>>,
+    audio: Res<AudioState>,
+    mut layers: Query<(&MusicTrack, &mut AudioSink)>,
+) {
+    let step = MUSIC_VOLUME * time.delta_secs() / CROSSFADE_SECS;
+    for (track, mut sink) in &mut layers {
+        let target = audio.layer_volume(track.0) * MUSIC_VOLUME;
+        let current = sink.volume().to_linear();
+        sink.set_volume(Volume::Linear(current + (target - current).clamp(-step, step)));
+    }
+}
+
+pub fn stinger_on_player_death(
+    mut events: MessageReader<GameplayEvent>,
+    mut audio: ResMut<AudioState>,
+) {
+    for event in events.read() {
+        if let GameplayEvent::EntityDied { was_player: true, .. } = event {
+            audio.push_hint(MusicHint::Stinger(Stinger::PlayerDied));
+        }
+    }
+}
+
+pub fn play_stingers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut audio: ResMut<AudioState>,
+) {
+    for stinger in audio.take_stingers() {
+        if !optional_assets::installed(stinger.track()) {
+            continue;
+        }
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(stinger.track())),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(STINGER_VOLUME)),
+        ));
+    }
+}
+
+pub fn reset_audio_state(mut audio: ResMut<AudioState>) {
+    *audio = AudioState::default();
+}
@@ -0,0 +1,39 @@
+// src/music/mod.rs
+mod layers;
+mod state;
+
+use bevy::prelude::*;
+
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+pub use state::{AudioState, MusicHint, Stinger};
+
+/// Adaptive music: exploration, combat and boss layers crossfaded by how
+/// many enemies are hunting the player, with stingers on top. Other systems
+/// push hints into `AudioState` for fights and cues it can't see itself.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioState>()
+            .add_systems(Startup, layers::spawn_music_layers)
+            // Runs and the menu start out exploring
+            .add_systems(OnEnter(GameState::Loading), layers::reset_audio_state)
+            .add_systems(OnEnter(GameState::MainMenu), layers::reset_audio_state)
+            .add_systems(
+                Update,
+                layers::update_audio_state.in_set(GameSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                (
+                    layers::stinger_on_player_death,
+                    layers::play_stingers,
+                    layers::crossfade_layers,
+                )
+                    .chain()
+                    .after(GameSet::PostPhysics),
+            );
+    }
+}
@@ -0,0 +1,137 @@
+// src/music/state.rs
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::config::music::*;
+
+/// The looping layers the score is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicLayer {
+    Exploration,
+    Combat,
+    Boss,
+}
+
+impl MusicLayer {
+    pub const ALL: [MusicLayer; 3] = [Self::Exploration, Self::Combat, Self::Boss];
+
+    pub fn track(self) -> &'static str {
+        match self {
+            Self::Exploration => EXPLORATION_TRACK,
+            Self::Combat => COMBAT_TRACK,
+            Self::Boss => BOSS_TRACK,
+        }
+    }
+}
+
+/// One-off cues played over the music.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stinger {
+    WaveCleared,
+    PlayerDied,
+}
+
+impl Stinger {
+    pub fn track(self) -> &'static str {
+        match self {
+            Self::WaveCleared => WAVE_CLEAR_STINGER,
+            Self::PlayerDied => DEATH_STINGER,
+        }
+    }
+}
+
+/// What other systems can tell the music about things it can't see by
+/// counting enemies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MusicHint {
+    /// Hold the combat layer at `level` (0 to 1) or above for `secs`
+    Intensity { level: f32, secs: f32 },
+    Stinger(Stinger),
+}
+
+/// The music state machine. Moves between exploration, combat and boss
+/// music from the aggroed enemies around the player, plus any hints pushed
+/// in with `push_hint`. The layer volumes fade towards `layer_volume`.
+#[derive(Resource, Debug)]
+pub struct AudioState {
+    pub layer: MusicLayer,
+    /// How hard the fight is, from 0 (nothing nearby) to 1
+    pub intensity: f32,
+    hinted: Option<(f32, Timer)>,
+    cooldown: Timer,
+    stingers: Vec<Stinger>,
+}
+
+impl Default for AudioState {
+    fn default() -> Self {
+        Self {
+            layer: MusicLayer::Exploration,
+            intensity: 0.0,
+            hinted: None,
+            cooldown: Timer::from_seconds(COMBAT_COOLDOWN_SECS, TimerMode::Once),
+            stingers: Vec::new(),
+        }
+    }
+}
+
+impl AudioState {
+    pub fn push_hint(&mut self, hint: MusicHint) {
+        match hint {
+            MusicHint::Intensity { level, secs } => {
+                let level = level.clamp(0.0, 1.0);
+                // A weaker hint doesn't cut a stronger one short
+                let stronger = match &self.hinted {
+                    Some((held, timer)) => level >= *held || timer.is_finished(),
+                    None => true,
+                };
+                if stronger {
+                    self.hinted = Some((level, Timer::from_seconds(secs, TimerMode::Once)));
+                }
+            }
+            MusicHint::Stinger(stinger) => self.stingers.push(stinger),
+        }
+    }
+
+    /// Stingers pushed since the last call, to be played.
+    pub fn take_stingers(&mut self) -> Vec<Stinger> {
+        std::mem::take(&mut self.stingers)
+    }
+
+    /// Steps the state machine with this frame's view of the fight.
+    pub fn update(&mut self, aggroed: usize, boss: bool, delta: Duration) {
+        let hinted = match &mut self.hinted {
+            Some((level, timer)) => {
+                timer.tick(delta);
+                if timer.is_finished() { 0.0 } else { *level }
+            }
+            None => 0.0,
+        };
+        self.intensity = (aggroed as f32 / FULL_COMBAT_ENEMIES as f32).min(1.0).max(hinted);
+
+        let layer = self.layer;
+        self.layer = match layer {
+            _ if boss => MusicLayer::Boss,
+            _ if self.intensity > 0.0 => {
+                self.cooldown.reset();
+                MusicLayer::Combat
+            }
+            MusicLayer::Exploration => MusicLayer::Exploration,
+            _ if self.cooldown.tick(delta).is_finished() => MusicLayer::Exploration,
+            _ => MusicLayer::Combat,
+        };
+    }
+
+    /// Volume `layer` fades towards, from 0 to 1.
+    pub fn layer_volume(&self, layer: MusicLayer) -> f32 {
+        let combat = self.intensity.max(MIN_COMBAT_INTENSITY);
+        match (self.layer, layer) {
+            (MusicLayer::Boss, MusicLayer::Boss) => 1.0,
+            (MusicLayer::Boss, _) | (_, MusicLayer::Boss) => 0.0,
+            (MusicLayer::Exploration, MusicLayer::Exploration) => 1.0,
+            (MusicLayer::Exploration, MusicLayer::Combat) => 0.0,
+            (MusicLayer::Combat, MusicLayer::Exploration) => 1.0 - combat,
+            (MusicLayer::Combat, MusicLayer::Combat) => combat,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// src/optional_assets.rs
+use bevy::asset::io::file::FileAssetReader;
+
+use crate::config::assets::ASSET_DIR;
+
+/// Whether an asset the game doesn't ship, like audio or an extra font, has
+/// been dropped into the assets folder. Resolved the way the asset server's
+/// file reader resolves `ASSET_DIR`, so a missing file can be skipped before
+/// a load would fail and log an error.
+pub fn installed(path: &str) -> bool {
+    FileAssetReader::get_base_path()
+        .join(ASSET_DIR)
+        .join(path)
+        .exists()
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 
 /// A single particle in the particle system
-#[derive(Component, Clone)]
+#[derive(Component, Reflect, Clone)]
+#[reflect(Component)]
 pub struct Particle {
     pub velocity: Vec3,           // Movement speed and direction (units/sec)
     pub lifetime: f32,             // Remaining time before death (seconds)
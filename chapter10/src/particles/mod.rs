@@ -2,8 +2,9 @@ pub mod components;
 pub mod material;
 pub mod systems;
 
-use crate::state::GameState;
+use crate::schedule::GameSet;
 use bevy::{prelude::*, sprite_render::Material2dPlugin};
+use crate::logging::targets;
 
 pub use material::*;
 pub use systems::*;
@@ -12,14 +13,15 @@ pub struct ParticlesPlugin;
 
 impl Plugin for ParticlesPlugin {
     fn build(&self, app: &mut App) {
-        info!("Initializing ParticlesPlugin");
+        debug!(target: targets::PARTICLES, "Initializing ParticlesPlugin");
         app.add_plugins(Material2dPlugin::<ParticleMaterial>::default())
+            .register_type::<components::Particle>()
             .add_systems(
                 Update,
                 (update_emitters, update_particles, cleanup_finished_emitters)
                     .chain()
-                    .run_if(in_state(GameState::Playing)),
+                    .in_set(GameSet::Render),
             );
-        info!("ParticlesPlugin initialized");
+        debug!(target: targets::PARTICLES, "ParticlesPlugin initialized");
     }
 }
\ No newline at end of file
@@ -1,6 +1,10 @@
 use super::components::*;
 use super::material::ParticleMaterial;
 use bevy::prelude::*;
+use crate::accessibility::AccessibilitySettings;
+use crate::map::generate::WorldGenSettings;
+use crate::rendering::{y_sort, EFFECT_Z_BAND, FEET_OFFSET};
+use crate::state::GameplayEntity;
 use rand::Rng;
 
 /// System to update particle emitters and spawn new particles
@@ -10,6 +14,8 @@ pub fn update_emitters(
     mut emitters: Query<(Entity, &mut ParticleEmitter, &GlobalTransform)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ParticleMaterial>>,
+    settings: Res<WorldGenSettings>,
+    accessibility: Res<AccessibilitySettings>,
 ) {
     let mut rng = rand::thread_rng();
 
@@ -29,8 +35,19 @@ pub fn update_emitters(
         if emitter.spawn_timer.just_finished() {
             emitter.has_spawned = true;
 
-            // Spawn particles
-            for i in 0..emitter.particles_per_spawn {
+            // Particles keep the depth of where they were emitted; emitters
+            // ride at body height, so sort from the feet below
+            let feet_y = global_transform.translation().y - FEET_OFFSET;
+            let depth = y_sort(EFFECT_Z_BAND, feet_y, &settings);
+
+            // Spawn particles, thinned by the density setting. The fraction left
+            // over is rolled for, so sparse emitters still put something out
+            let scaled = emitter.particles_per_spawn as f32 * accessibility.particle_density;
+            let mut count = scaled as u32;
+            if rng.gen_bool(scaled.fract() as f64) {
+                count += 1;
+            }
+            for _ in 0..count {
                 spawn_particle(
                     &mut commands,
                     &emitter.particle_config,
@@ -39,7 +56,7 @@ pub fn update_emitters(
                     &mut meshes,
                     &mut materials,
                     Some(entity),
-                    i,
+                    depth,
                 );
             }
 
@@ -58,7 +75,7 @@ pub fn spawn_particle(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ParticleMaterial>>,
     owner: Option<Entity>,
-    _particle_index: u32,
+    depth: f32,
 ) {
     // Calculate randomized values
     let lifetime =
@@ -97,8 +114,7 @@ pub fn spawn_particle(
     let emitter_position = global_transform.translation();
     let mut position = emitter_position + emission_offset;
 
-    // Ensure particles are at a visible Z layer (above player)
-    position.z = 25.0;
+    position.z = depth;
 
     // Create particle with color curves
     let start_color = config.color;
@@ -135,6 +151,7 @@ pub fn spawn_particle(
         Mesh2d(mesh),
         MeshMaterial2d(material),
         Transform::from_translation(position),
+        GameplayEntity,
     ));
 }
 
@@ -201,7 +218,8 @@ pub fn cleanup_finished_emitters(
 ) {
     for (entity, emitter) in emitters.iter() {
         if emitter.one_shot && !emitter.active {
-            commands.entity(entity).despawn();
+            // May already be gone if its parent projectile was despawned this frame
+            commands.entity(entity).try_despawn();
         }
     }
 }
\ No newline at end of file
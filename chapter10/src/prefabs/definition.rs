@@ -0,0 +1,133 @@
+// src/prefabs/definition.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::behavior::Behavior;
+use crate::characters::collider::Collider;
+use crate::characters::input::Player;
+use crate::collision::{ColliderShape, TileType};
+use crate::combat::{ContactDamage, HazardImmunity, PlayerCombat, PowerId};
+use crate::enemy::components::{EnemyPath, Kiting};
+use crate::enemy::{AIBehavior, Boss, Enemy, EnemyCombat, Flying, SpawningIn};
+use crate::lighting::LightSource;
+use crate::rendering::YSorted;
+use crate::scripting::EnemyScript;
+
+/// A single component a prefab contributes, in RON-friendly form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PrefabComponent {
+    Player,
+    Enemy,
+    PlayerCombat(PowerId),
+    EnemyCombat { power_type: PowerId, cooldown: f32 },
+    AIBehavior { attack_range: f32, detection_range: f32 },
+    Collider {
+        shape: ColliderShape,
+        offset: (f32, f32),
+    },
+    HazardImmunity(Vec<TileType>),
+    LightSource { radius: f32 },
+    ContactDamage { damage: f32, interval: f32 },
+    Flying { altitude: f32 },
+    Kiting { min_distance: f32 },
+    /// Seconds the enemy spends materializing before it acts
+    SpawnIn(f32),
+    /// Rhai script that drives the enemy instead of the built-in AI
+    Script(String),
+    /// Behavior tree (.bt.ron) that drives the enemy instead of the built-in AI
+    Behavior(String),
+    /// Plays the boss music while it hunts the player
+    Boss,
+}
+
+impl PrefabComponent {
+    /// Inserts the component unless the spawner already provided one.
+    pub fn insert_into(&self, entity: &mut EntityWorldMut) {
+        match self {
+            PrefabComponent::Player => {
+                entity.insert_if_new(Player);
+            }
+            PrefabComponent::Enemy => {
+                entity.insert_if_new((Enemy, EnemyPath::default()));
+            }
+            PrefabComponent::PlayerCombat(power_type) => {
+                entity.insert_if_new(PlayerCombat::new(power_type.clone()));
+            }
+            PrefabComponent::EnemyCombat { power_type, cooldown } => {
+                entity.insert_if_new(EnemyCombat::new(power_type.clone(), *cooldown));
+            }
+            PrefabComponent::AIBehavior { attack_range, detection_range } => {
+                entity.insert_if_new(AIBehavior::new(*attack_range, *detection_range));
+            }
+            PrefabComponent::Collider { shape, offset } => {
+                entity.insert_if_new(Collider {
+                    shape: *shape,
+                    offset: Vec2::new(offset.0, offset.1),
+                });
+            }
+            PrefabComponent::HazardImmunity(tiles) => {
+                entity.insert_if_new(HazardImmunity(tiles.clone()));
+            }
+            PrefabComponent::LightSource { radius } => {
+                entity.insert_if_new(LightSource::new(*radius).with_flicker(0.1));
+            }
+            PrefabComponent::ContactDamage { damage, interval } => {
+                entity.insert_if_new(ContactDamage::new(*damage, *interval));
+            }
+            PrefabComponent::Flying { altitude } => {
+                // Flyers sort above walkers, whatever band they were given
+                entity.insert_if_new(Flying { altitude: *altitude }).insert(YSorted::FLYING);
+            }
+            PrefabComponent::Kiting { min_distance } => {
+                entity.insert_if_new(Kiting::new(*min_distance));
+            }
+            PrefabComponent::SpawnIn(secs) => {
+                entity.insert_if_new(SpawningIn::from_seconds(*secs));
+            }
+            PrefabComponent::Script(path) => {
+                entity.insert_if_new(EnemyScript { path: path.clone() });
+            }
+            PrefabComponent::Behavior(path) => {
+                entity.insert_if_new(Behavior { path: path.clone() });
+            }
+            PrefabComponent::Boss => {
+                entity.insert_if_new(Boss);
+            }
+        }
+    }
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefabDefinition {
+    pub name: String,
+    /// Character from characters.ron that provides sprite, animations and health.
+    /// Left empty for prefabs whose character is chosen at spawn time (the player).
+    #[serde(default)]
+    pub character: Option<String>,
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    #[serde(default)]
+    pub components: Vec<PrefabComponent>,
+}
+
+impl PrefabDefinition {
+    /// The collider this prefab overrides its character's with, if any.
+    pub fn collider(&self) -> Option<Collider> {
+        self.components.iter().find_map(|component| match component {
+            PrefabComponent::Collider { shape, offset } => Some(Collider {
+                shape: *shape,
+                offset: Vec2::new(offset.0, offset.1),
+            }),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct PrefabList {
+    pub prefabs: Vec<PrefabDefinition>,
+}
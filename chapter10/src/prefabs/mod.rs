@@ -0,0 +1,165 @@
+// src/prefabs/mod.rs
+mod definition;
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use crate::characters::config::{CharacterEntry, CharactersList};
+use crate::characters::spawn::{spawn_character, CharactersListResource};
+use crate::logging::targets;
+use crate::state::GameplayEntity;
+
+pub use definition::{PrefabDefinition, PrefabList};
+
+/// Prefab definitions from prefabs.ron, indexed by name.
+/// Rebuilt whenever the asset (re)loads.
+#[derive(Resource, Default)]
+pub struct PrefabRegistry {
+    handle: Handle<PrefabList>,
+    prefabs: HashMap<String, PrefabDefinition>,
+}
+
+impl PrefabRegistry {
+    pub fn is_loaded(&self) -> bool {
+        !self.prefabs.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PrefabDefinition> {
+        self.prefabs.get(name)
+    }
+
+    /// Adds or replaces a prefab.
+    pub fn register(&mut self, prefab: PrefabDefinition) {
+        self.prefabs.insert(prefab.name.clone(), prefab);
+    }
+}
+
+pub struct PrefabsPlugin;
+
+impl Plugin for PrefabsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<PrefabList>::new(&["prefabs.ron"]))
+            .init_resource::<PrefabRegistry>()
+            .add_systems(Startup, load_prefabs)
+            .add_systems(Update, sync_prefab_registry);
+    }
+}
+
+fn load_prefabs(asset_server: Res<AssetServer>, mut registry: ResMut<PrefabRegistry>) {
+    registry.handle = asset_server.load("prefabs/prefabs.ron");
+}
+
+fn sync_prefab_registry(
+    mut events: MessageReader<AssetEvent<PrefabList>>,
+    lists: Res<Assets<PrefabList>>,
+    mut registry: ResMut<PrefabRegistry>,
+) {
+    for event in events.read() {
+        if !event.is_loaded_with_dependencies(&registry.handle)
+            && !event.is_modified(&registry.handle)
+        {
+            continue;
+        }
+        let Some(list) = lists.get(&registry.handle) else {
+            continue;
+        };
+
+        registry.prefabs = list
+            .prefabs
+            .iter()
+            .map(|prefab| (prefab.name.clone(), prefab.clone()))
+            .collect();
+        info!(target: targets::PREFABS, "Loaded {} prefabs", registry.prefabs.len());
+    }
+}
+
+/// Spawn prefabs through `Commands`.
+pub trait PrefabCommandsExt {
+    /// Spawns `bundle` and fills in everything else from the named prefab.
+    /// Components in `bundle` take precedence over the prefab's, so callers
+    /// pass the transform plus any overrides (restored health, facing, ...).
+    fn spawn_prefab(&mut self, name: &str, bundle: impl Bundle) -> EntityCommands<'_>;
+}
+
+impl PrefabCommandsExt for Commands<'_, '_> {
+    fn spawn_prefab(&mut self, name: &str, bundle: impl Bundle) -> EntityCommands<'_> {
+        let name = name.to_string();
+        let mut entity = self.spawn(bundle);
+        entity.queue(move |mut entity: EntityWorldMut| {
+            let id = entity.id();
+            entity.world_scope(|world| {
+                if let Err(e) = build_prefab(world, id, &name) {
+                    error!(target: targets::PREFABS, "{}", e);
+                    world.despawn(id);
+                }
+            });
+        });
+        entity
+    }
+}
+
+/// World counterpart of [`PrefabCommandsExt::spawn_prefab`], for exclusive systems.
+pub fn spawn_prefab(world: &mut World, name: &str, bundle: impl Bundle) -> Result<Entity, String> {
+    let id = world.spawn(bundle).id();
+    if let Err(e) = build_prefab(world, id, name) {
+        world.despawn(id);
+        return Err(e);
+    }
+    Ok(id)
+}
+
+/// Inserts the prefab's components on `entity`, skipping any it already has.
+///
+/// The character comes from a `CharacterEntry` already on the entity, or
+/// failing that from the prefab's `character` field.
+fn build_prefab(world: &mut World, entity: Entity, name: &str) -> Result<(), String> {
+    let prefab = world
+        .resource::<PrefabRegistry>()
+        .get(name)
+        .cloned()
+        .ok_or_else(|| format!("Unknown prefab '{}'", name))?;
+
+    let character = match world.get::<CharacterEntry>(entity) {
+        Some(entry) => Some(entry.clone()),
+        None => match &prefab.character {
+            Some(character_name) => Some(find_character(world, character_name)?),
+            None => None,
+        },
+    };
+
+    let mut entity_mut = world.entity_mut(entity);
+    for component in &prefab.components {
+        component.insert_into(&mut entity_mut);
+    }
+    entity_mut.insert_if_new(GameplayEntity);
+    if let Some(mut transform) = entity_mut.get_mut::<Transform>() {
+        transform.scale *= prefab.scale;
+    }
+
+    if let Some(entry) = character {
+        spawn_character(&mut entity_mut, entry);
+    }
+
+    debug!(target: targets::PREFABS, "Spawned prefab '{}' as {:?}", name, entity);
+    Ok(())
+}
+
+fn find_character(world: &World, name: &str) -> Result<CharacterEntry, String> {
+    let handle = world
+        .get_resource::<CharactersListResource>()
+        .map(|res| res.handle.clone())
+        .ok_or("CharactersListResource not available")?;
+    let list = world
+        .resource::<Assets<CharactersList>>()
+        .get(&handle)
+        .ok_or("Characters list not loaded")?;
+
+    list.characters
+        .iter()
+        .find(|c| c.name == name)
+        .cloned()
+        .ok_or_else(|| format!("Unknown character '{}'", name))
+}
+
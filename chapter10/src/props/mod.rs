@@ -0,0 +1,31 @@
+// src/props/mod.rs
+mod platform;
+mod pushable;
+
+use bevy::prelude::*;
+
+use crate::characters::collider::validate_movement;
+use crate::schedule::GameSet;
+
+pub use platform::{spawn_platform, Platform, Riding};
+pub use pushable::{spawn_prop, PropKind, Pushable};
+
+/// Crates and barrels the player can shove around, and rafts that ferry
+/// whoever stands on them across water. Props slide until the map or another
+/// body stops them, and stay where they were left across saves.
+pub struct PropsPlugin;
+
+impl Plugin for PropsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Pushable>()
+            .register_type::<PropKind>()
+            .register_type::<Platform>()
+            .add_systems(
+                Update,
+                (pushable::push_props, platform::steer_platforms)
+                    .before(validate_movement)
+                    .in_set(GameSet::Physics),
+            )
+            .add_systems(Update, platform::update_riders.in_set(GameSet::PostPhysics));
+    }
+}
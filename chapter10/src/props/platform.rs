@@ -0,0 +1,134 @@
+// src/props/platform.rs
+use bevy::prelude::*;
+
+use crate::characters::collider::Collider;
+use crate::characters::physics::Velocity;
+use crate::config::props::{PLATFORM_DOCK_SECS, PLATFORM_HALF_SIZE, PLATFORM_SPEED};
+use crate::enemy::Flying;
+use crate::state::GameplayEntity;
+
+/// Just under pushable props, which can ride along
+const PLATFORM_Z: f32 = 2.9;
+const PLATFORM_COLOR: Color = Color::srgb(0.5, 0.35, 0.2);
+
+/// A raft that shuttles back and forth along its waypoints, waiting a moment
+/// at each one. Whoever stands on its deck is carried along.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+#[require(Velocity)]
+pub struct Platform {
+    /// Route in world units, at least two points
+    pub waypoints: Vec<Vec2>,
+    /// Waypoint the platform is heading for
+    pub next: usize,
+    /// Whether it's working up the list or back down it
+    pub forward: bool,
+    /// Seconds left before it sets off again
+    docked: f32,
+}
+
+impl Platform {
+    pub fn new(waypoints: Vec<Vec2>) -> Self {
+        Self {
+            waypoints,
+            next: 1,
+            forward: true,
+            docked: PLATFORM_DOCK_SECS,
+        }
+    }
+
+    /// Resumes the route partway, heading for waypoint `next`.
+    pub fn with_heading(mut self, next: usize, forward: bool) -> Self {
+        self.next = next;
+        self.forward = forward;
+        self
+    }
+
+    /// Whether `point` is on the deck of the platform at `transform`.
+    pub fn carries(transform: &Transform, point: Vec2) -> bool {
+        let offset = point - transform.translation.truncate();
+        offset.abs().cmple(PLATFORM_HALF_SIZE).all()
+    }
+
+    /// Nearest point to `point` on the deck of the platform at `transform`.
+    pub fn clamp_to_deck(transform: &Transform, point: Vec2) -> Vec2 {
+        let center = transform.translation.truncate();
+        center + (point - center).clamp(-PLATFORM_HALF_SIZE, PLATFORM_HALF_SIZE)
+    }
+
+    /// Turns around at either end of the route.
+    fn advance(&mut self) {
+        let last = self.waypoints.len() - 1;
+        if self.next == last {
+            self.forward = false;
+        } else if self.next == 0 {
+            self.forward = true;
+        }
+        self.next = if self.forward { self.next + 1 } else { self.next - 1 };
+    }
+}
+
+/// The platform an entity stands on. Its velocity is added to the rider's
+/// in `apply_velocity`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Riding(pub Entity);
+
+/// Spawns a platform at `position`. Routes need two waypoints or more.
+pub fn spawn_platform(commands: &mut Commands, platform: Platform, position: Vec2) -> Entity {
+    commands
+        .spawn((
+            platform,
+            Sprite::from_color(PLATFORM_COLOR, PLATFORM_HALF_SIZE * 2.0),
+            Transform::from_translation(position.extend(PLATFORM_Z)),
+            GameplayEntity,
+        ))
+        .id()
+}
+
+/// Heads each platform for its next waypoint, landing on it exactly.
+pub fn steer_platforms(time: Res<Time>, mut platforms: Query<(&Transform, &mut Platform, &mut Velocity)>) {
+    let dt = time.delta_secs();
+
+    for (transform, mut platform, mut velocity) in &mut platforms {
+        if platform.docked > 0.0 {
+            platform.docked -= dt;
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+
+        let to_target = platform.waypoints[platform.next] - transform.translation.truncate();
+        if to_target.length() > PLATFORM_SPEED * dt {
+            velocity.0 = to_target.normalize() * PLATFORM_SPEED;
+        } else {
+            velocity.0 = if dt > 0.0 { to_target / dt } else { Vec2::ZERO };
+            platform.advance();
+            platform.docked = PLATFORM_DOCK_SECS;
+        }
+    }
+}
+
+/// Marks walkers whose feet are on a deck as riding it, and frees them once
+/// they step off.
+pub fn update_riders(
+    mut commands: Commands,
+    platforms: Query<(Entity, &Transform), With<Platform>>,
+    bodies: Query<(Entity, &Transform, &Collider, Option<&Riding>), (With<Velocity>, Without<Flying>)>,
+) {
+    for (entity, transform, collider, riding) in &bodies {
+        let feet = collider.world_position(transform);
+        let under = platforms
+            .iter()
+            .find(|(_, platform_transform)| Platform::carries(platform_transform, feet))
+            .map(|(platform, _)| platform);
+
+        match (under, riding) {
+            (Some(platform), riding) if riding.map(|r| r.0) != Some(platform) => {
+                commands.entity(entity).insert(Riding(platform));
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<Riding>();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// src/props/pushable.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::characters::collider::Collider;
+use crate::characters::input::Player;
+use crate::characters::physics::Velocity;
+use crate::collision::{ColliderShape, Layer};
+use crate::config::props::{BARREL_MASS, CRATE_MASS, PROP_FRICTION, PUSHER_MASS, PUSH_CONTACT_MARGIN};
+use crate::rendering::{YSorted, ITEM_Z_BAND};
+use crate::state::GameplayEntity;
+
+/// What a prop is, kept in saves to rebuild it.
+#[derive(Component, Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum PropKind {
+    Crate,
+    Barrel,
+}
+
+impl PropKind {
+    fn mass(self) -> f32 {
+        match self {
+            PropKind::Crate => CRATE_MASS,
+            PropKind::Barrel => BARREL_MASS,
+        }
+    }
+
+    fn shape(self) -> ColliderShape {
+        match self {
+            PropKind::Crate => ColliderShape::Aabb { half_size: Vec2::splat(16.0) },
+            PropKind::Barrel => ColliderShape::Circle { radius: 14.0 },
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            PropKind::Crate => Color::srgb(0.55, 0.38, 0.2),
+            PropKind::Barrel => Color::srgb(0.4, 0.26, 0.16),
+        }
+    }
+}
+
+/// A prop the player can shove. Heavier props take less of the push.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+#[require(Velocity)]
+pub struct Pushable {
+    pub mass: f32,
+}
+
+pub fn spawn_prop(commands: &mut Commands, kind: PropKind, position: Vec2) -> Entity {
+    let shape = kind.shape();
+    commands
+        .spawn((
+            kind,
+            Pushable { mass: kind.mass() },
+            Collider { shape, offset: Vec2::ZERO },
+            Sprite::from_color(kind.color(), shape.half_extents() * 2.0),
+            YSorted { base_z: ITEM_Z_BAND, feet_offset: shape.half_extents().y },
+            Transform::from_translation(position.extend(ITEM_Z_BAND)),
+            GameplayEntity,
+        ))
+        .id()
+}
+
+/// Slows sliding props down, then hands each one the push of any player
+/// walking into it. The push is an inelastic collision: player and prop end
+/// up sharing the player's speed along the contact, split by mass.
+/// `validate_movement` then keeps the prop out of walls, and
+/// `resolve_entity_collisions` lets the player follow it.
+pub fn push_props(
+    time: Res<Time>,
+    players: Query<(&Transform, &Collider, &Velocity, &Layer), (With<Player>, Without<Pushable>)>,
+    mut props: Query<(&Transform, &Collider, &mut Velocity, &Layer, &Pushable)>,
+) {
+    let friction = (1.0 - PROP_FRICTION * time.delta_secs()).max(0.0);
+
+    for (transform, collider, mut velocity, layer, pushable) in &mut props {
+        velocity.0 *= friction;
+        if velocity.0.length_squared() < 1.0 {
+            velocity.0 = Vec2::ZERO;
+        }
+
+        for (player_transform, player_collider, player_velocity, player_layer) in &players {
+            if player_layer != layer {
+                continue;
+            }
+            let (direction, gap) = player_collider.separation(player_transform, collider, transform);
+            if gap >= player_collider.contact_gap(collider, PUSH_CONTACT_MARGIN) {
+                continue;
+            }
+
+            let toward = player_velocity.0.dot(direction);
+            if toward <= 0.0 {
+                continue;
+            }
+            let shared = toward * PUSHER_MASS / (PUSHER_MASS + pushable.mass);
+            let along = velocity.0.dot(direction);
+            if along < shared {
+                velocity.0 += direction * (shared - along);
+            }
+        }
+    }
+}
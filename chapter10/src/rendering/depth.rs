@@ -0,0 +1,69 @@
+// src/rendering/depth.rs
+use bevy::prelude::*;
+
+use crate::config::map::{NODE_SIZE_Z, TILE_SIZE};
+use crate::config::player::PLAYER_SCALE;
+use crate::map::generate::WorldGenSettings;
+
+/// Level with the tilemap's props layer, so props and items on the ground
+/// sort like the rocks and stumps around them
+pub const ITEM_Z_BAND: f32 = 4.0;
+/// Half a layer above props, so characters stand in front of a tree whose
+/// base is behind them and behind one whose base is in front
+const CHARACTER_Z_BAND: f32 = 4.5;
+/// A hair above characters, so a spell draws over its caster
+pub const EFFECT_Z_BAND: f32 = 4.6;
+/// Flyers sort among themselves one full layer above walkers and props
+const FLYING_Z_BAND: f32 = 6.5;
+
+/// From a character's centre down to its feet. Things sort by where they
+/// touch the ground, so effects launched at body height drop by this too.
+pub const FEET_OFFSET: f32 = 32.0 * PLAYER_SCALE;
+
+/// Z for something whose ground point is at `y`, within `z_band`. Matches the
+/// tilemap's `with_z_offset_from_y`: lower on screen draws in front.
+pub fn y_sort(z_band: f32, y: f32, settings: &WorldGenSettings) -> f32 {
+    let map_height = TILE_SIZE * settings.total_grid_y() as f32;
+    let t = ((y - settings.grid_origin().y) / map_height).clamp(0.0, 1.0);
+    z_band + NODE_SIZE_Z * (1.0 - t)
+}
+
+/// Gives a sprite its depth from where it touches the ground, kept up to
+/// date as it moves. Any sprite that stands among the map's props wants one.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default)]
+pub struct YSorted {
+    /// Band the sprite sorts within, see `y_sort`
+    pub base_z: f32,
+    /// Distance from the transform down to the ground point
+    pub feet_offset: f32,
+}
+
+impl YSorted {
+    pub const CHARACTER: Self = Self { base_z: CHARACTER_Z_BAND, feet_offset: FEET_OFFSET };
+    pub const FLYING: Self = Self { base_z: FLYING_Z_BAND, feet_offset: FEET_OFFSET };
+    /// Projectiles fly at body height, so they sort by the ground below
+    pub const EFFECT: Self = Self { base_z: EFFECT_Z_BAND, feet_offset: FEET_OFFSET };
+}
+
+impl Default for YSorted {
+    fn default() -> Self {
+        Self::CHARACTER
+    }
+}
+
+/// Sorts every y-sorted sprite that moved or changed band.
+pub fn update_y_sorted_depth(
+    settings: Res<WorldGenSettings>,
+    mut query: Query<(&mut Transform, &YSorted), Or<(Changed<Transform>, Changed<YSorted>)>>,
+) {
+    for (mut transform, sorted) in &mut query {
+        let feet_y = transform.translation.y - sorted.feet_offset;
+        let z = y_sort(sorted.base_z, feet_y, &settings);
+        // Writing through Mut flags Changed even for the same value, which
+        // would re-sort everything here again next frame
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
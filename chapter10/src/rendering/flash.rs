@@ -0,0 +1,146 @@
+// src/rendering/flash.rs
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+
+/// Just in front of the sprite it covers
+const OVERLAY_Z_OFFSET: f32 = 0.01;
+
+/// Flashes a sprite white, fading out over the timer. Insert it again to
+/// restart the flash. Works on any entity with a `Sprite`: characters,
+/// enemies and tiles alike.
+#[derive(Component, Debug, Clone)]
+pub struct FlashEffect(pub Timer);
+
+impl FlashEffect {
+    pub fn from_seconds(secs: f32) -> Self {
+        Self(Timer::from_seconds(secs, TimerMode::Once))
+    }
+}
+
+/// The overlay that draws an entity's flash. Kept hidden between flashes.
+#[derive(Component, Debug)]
+pub struct FlashOverlay(Entity);
+
+#[derive(ShaderType, Debug, Clone, Default)]
+pub struct HitFlash {
+    /// Atlas frame in UV space: min corner in xy, size in zw
+    pub frame: Vec4,
+    /// 0 = invisible, 1 = solid white
+    pub white: f32,
+}
+
+/// Draws the current frame of a sprite's texture as a white silhouette.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct FlashMaterial {
+    #[uniform(0)]
+    pub flash: HitFlash,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl Material2d for FlashMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/hit_flash.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+/// Gives an entity its overlay the first time it flashes.
+pub fn attach_flash_overlays(
+    mut commands: Commands,
+    owners: Query<(Entity, &Sprite), (With<FlashEffect>, Without<FlashOverlay>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<FlashMaterial>>,
+) {
+    for (owner, sprite) in &owners {
+        let overlay = commands
+            .spawn((
+                Mesh2d(meshes.add(Rectangle::new(1.0, 1.0))),
+                MeshMaterial2d(materials.add(FlashMaterial {
+                    flash: HitFlash::default(),
+                    texture: sprite.image.clone(),
+                })),
+                Transform::from_xyz(0.0, 0.0, OVERLAY_Z_OFFSET),
+                Visibility::Hidden,
+                ChildOf(owner),
+            ))
+            .id();
+        commands.entity(owner).insert(FlashOverlay(overlay));
+    }
+}
+
+/// Fades flashes out, following the sprite's current frame so the flash
+/// animates along with it.
+pub fn update_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut owners: Query<(Entity, &mut FlashEffect, &FlashOverlay, &Sprite)>,
+    mut overlays: Query<(&mut Transform, &mut Visibility, &MeshMaterial2d<FlashMaterial>)>,
+    mut materials: ResMut<Assets<FlashMaterial>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    images: Res<Assets<Image>>,
+) {
+    for (owner, mut effect, overlay, sprite) in &mut owners {
+        let Ok((mut transform, mut visibility, material)) = overlays.get_mut(overlay.0) else {
+            continue;
+        };
+
+        effect.0.tick(time.delta());
+        if effect.0.is_finished() {
+            *visibility = Visibility::Hidden;
+            commands.entity(owner).remove::<FlashEffect>();
+            continue;
+        }
+
+        let Some((frame, size)) = sprite_frame(sprite, &layouts, &images) else {
+            continue;
+        };
+        let flip = Vec2::new(
+            if sprite.flip_x { -1.0 } else { 1.0 },
+            if sprite.flip_y { -1.0 } else { 1.0 },
+        );
+        transform.scale = (size * flip).extend(1.0);
+        *visibility = Visibility::Inherited;
+
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.flash = HitFlash { frame, white: effect.0.fraction_remaining() };
+            if material.texture != sprite.image {
+                material.texture = sprite.image.clone();
+            }
+        }
+    }
+}
+
+/// The sprite's current frame as a UV rectangle (min corner, size), and its
+/// size in world units before the transform's scale.
+fn sprite_frame(
+    sprite: &Sprite,
+    layouts: &Assets<TextureAtlasLayout>,
+    images: &Assets<Image>,
+) -> Option<(Vec4, Vec2)> {
+    let (rect, texture_size) = match &sprite.texture_atlas {
+        Some(atlas) => {
+            let layout = layouts.get(&atlas.layout)?;
+            (layout.textures.get(atlas.index)?.as_rect(), layout.size.as_vec2())
+        }
+        None => {
+            let size = images.get(&sprite.image)?.size_f32();
+            (Rect::from_corners(Vec2::ZERO, size), size)
+        }
+    };
+    let frame = Vec4::new(
+        rect.min.x / texture_size.x,
+        rect.min.y / texture_size.y,
+        rect.width() / texture_size.x,
+        rect.height() / texture_size.y,
+    );
+    Some((frame, sprite.custom_size.unwrap_or(rect.size())))
+}
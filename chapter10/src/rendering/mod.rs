@@ -0,0 +1,57 @@
+// src/rendering/mod.rs
+mod depth;
+mod flash;
+mod outline;
+mod palette;
+mod screen_effects;
+mod shadow;
+
+use bevy::{prelude::*, sprite_render::Material2dPlugin};
+
+use crate::schedule::GameSet;
+use crate::state::GameState;
+
+pub use depth::{y_sort, YSorted, EFFECT_Z_BAND, FEET_OFFSET, ITEM_Z_BAND};
+pub use flash::FlashEffect;
+pub use palette::ColorMode;
+pub use screen_effects::{ScreenEffectSettings, ScreenFlash};
+
+/// Draw order for sprites that stand among the map's props: whatever touches
+/// the ground lower on screen draws in front. Characters and items also get
+/// a shadow at their feet, and anything hit flashes white. Over it all sit
+/// full-view effects: a low-health vignette, flashes and pause desaturation.
+/// `ColorMode` swaps the game's key colours for colourblind players.
+pub struct RenderingPlugin;
+
+impl Plugin for RenderingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<flash::FlashMaterial>::default())
+            .add_plugins(Material2dPlugin::<screen_effects::ScreenEffectsMaterial>::default())
+            .register_type::<YSorted>()
+            .register_type::<ScreenEffectSettings>()
+            .register_type::<ColorMode>()
+            .init_resource::<ColorMode>()
+            .add_observer(screen_effects::on_screen_flash)
+            .add_systems(PreStartup, screen_effects::load_screen_effect_settings)
+            .add_systems(Startup, screen_effects::spawn_screen_effects)
+            .add_systems(OnEnter(GameState::GameOver), screen_effects::clear_screen_effects)
+            .add_systems(OnEnter(GameState::MainMenu), screen_effects::clear_screen_effects)
+            .add_systems(OnEnter(GameState::Loading), screen_effects::clear_screen_effects)
+            .add_systems(
+                Update,
+                screen_effects::update_screen_effects
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+            )
+            .add_systems(Update, depth::update_y_sorted_depth.in_set(GameSet::PostPhysics))
+            .add_systems(
+                Update,
+                (
+                    shadow::attach_shadows,
+                    shadow::fade_jump_shadows,
+                    (flash::attach_flash_overlays, flash::update_flashes).chain(),
+                    outline::draw_high_contrast_outlines,
+                )
+                    .in_set(GameSet::Render),
+            );
+    }
+}
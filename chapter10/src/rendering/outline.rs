@@ -0,0 +1,43 @@
+// src/rendering/outline.rs
+use bevy::prelude::*;
+
+use super::palette::ColorMode;
+use crate::characters::config::CharacterEntry;
+use crate::enemy::Enemy;
+use crate::inventory::Pickable;
+
+const ENEMY_OUTLINE_COLOR: Color = Color::srgb(1.0, 0.2, 0.6);
+const ITEM_OUTLINE_COLOR: Color = Color::srgb(1.0, 1.0, 0.0);
+/// Share of the sprite frame the character actually fills
+const FRAME_FILL: f32 = 0.6;
+/// Size of items with no sized sprite
+const DEFAULT_ITEM_SIZE: f32 = 24.0;
+
+/// Boxes enemies and items in bright, fixed colours in high contrast mode,
+/// so they stand out from the terrain whatever the lighting.
+pub fn draw_high_contrast_outlines(
+    mode: Res<ColorMode>,
+    enemies: Query<(&Transform, Option<&CharacterEntry>), With<Enemy>>,
+    items: Query<(&Transform, Option<&Sprite>), With<Pickable>>,
+    mut gizmos: Gizmos,
+) {
+    if !mode.outlines() {
+        return;
+    }
+
+    for (transform, character) in &enemies {
+        let Some(character) = character else {
+            continue;
+        };
+        let size = character.sprite_size(transform.scale) * FRAME_FILL;
+        gizmos.rect_2d(transform.translation.truncate(), size, ENEMY_OUTLINE_COLOR);
+    }
+
+    for (transform, sprite) in &items {
+        let size = sprite
+            .and_then(|s| s.custom_size)
+            .unwrap_or(Vec2::splat(DEFAULT_ITEM_SIZE))
+            * transform.scale.truncate();
+        gizmos.rect_2d(transform.translation.truncate(), size, ITEM_OUTLINE_COLOR);
+    }
+}
@@ -0,0 +1,156 @@
+// src/rendering/palette.rs
+use bevy::prelude::*;
+
+use crate::combat::PowerType;
+
+/// Colour scheme for health bars, power effects and debug overlays. Cycled
+/// from the pause menu. The colourblind modes pick hues that stay apart
+/// without red/green (or blue/yellow); high contrast also outlines enemies
+/// and items.
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource, Default)]
+pub enum ColorMode {
+    #[default]
+    Standard,
+    /// Protanopia and deuteranopia
+    RedGreen,
+    /// Tritanopia
+    BlueYellow,
+    HighContrast,
+}
+
+/// Particle colours for one power: the trail and its brighter core.
+#[derive(Debug, Clone, Copy)]
+pub struct PowerColors {
+    pub primary: Color,
+    pub core: Color,
+}
+
+/// Tile tints for the collision (F3) and reachability (F4) overlays.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugPalette {
+    pub walkable: Color,
+    pub blocked: Color,
+    pub bridge: Color,
+    pub unreachable: Color,
+}
+
+impl ColorMode {
+    pub fn next(self) -> Self {
+        match self {
+            ColorMode::Standard => ColorMode::RedGreen,
+            ColorMode::RedGreen => ColorMode::BlueYellow,
+            ColorMode::BlueYellow => ColorMode::HighContrast,
+            ColorMode::HighContrast => ColorMode::Standard,
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ColorMode::Standard => "Standard",
+            ColorMode::RedGreen => "Red-Green Safe",
+            ColorMode::BlueYellow => "Blue-Yellow Safe",
+            ColorMode::HighContrast => "High Contrast",
+        }
+    }
+
+    /// Whether enemies and items get an outline.
+    pub fn outlines(self) -> bool {
+        self == ColorMode::HighContrast
+    }
+
+    /// Health bar fill from full through half to empty, continuous at 0.5.
+    pub fn health_color(self, ratio: f32) -> Color {
+        let (full, half, empty) = match self {
+            ColorMode::Standard => (
+                Vec3::new(0.2, 0.8, 0.2),
+                Vec3::new(1.0, 0.8, 0.2),
+                Vec3::new(1.0, 0.0, 0.2),
+            ),
+            ColorMode::RedGreen => (
+                Vec3::new(0.2, 0.55, 1.0),
+                Vec3::new(0.95, 0.9, 0.25),
+                Vec3::new(0.9, 0.37, 0.0),
+            ),
+            ColorMode::BlueYellow => (
+                Vec3::new(0.0, 0.62, 0.45),
+                Vec3::new(0.8, 0.47, 0.65),
+                Vec3::new(0.85, 0.1, 0.1),
+            ),
+            ColorMode::HighContrast => (
+                Vec3::new(0.3, 0.75, 1.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(1.0, 0.2, 0.6),
+            ),
+        };
+        let rgb = if ratio >= 0.5 {
+            half.lerp(full, (ratio - 0.5) * 2.0)
+        } else {
+            empty.lerp(half, ratio * 2.0)
+        };
+        Color::srgb_from_array(rgb.to_array())
+    }
+
+    /// Overheal segment past the end of a health bar.
+    pub fn bonus_color(self) -> Color {
+        match self {
+            ColorMode::Standard => Color::srgb(0.3, 0.55, 1.0),
+            _ => Color::srgb(0.95, 0.95, 0.95),
+        }
+    }
+
+    /// Replacement colours for a built-in power, `None` to keep the preset's own.
+    /// Values above 1 feed the bloom like the presets do.
+    pub fn power_colors(self, power: PowerType) -> Option<PowerColors> {
+        let (primary, core) = match (self, power) {
+            (ColorMode::Standard, _) => return None,
+            (ColorMode::RedGreen | ColorMode::HighContrast, PowerType::Fire) => {
+                ((3.0, 1.2, 0.0), (4.0, 2.2, 0.4))
+            }
+            (ColorMode::RedGreen | ColorMode::HighContrast, PowerType::Arcane) => {
+                ((0.3, 0.8, 3.0), (0.9, 1.4, 3.5))
+            }
+            (ColorMode::RedGreen | ColorMode::HighContrast, PowerType::Poison) => {
+                ((2.5, 2.5, 0.2), (3.5, 3.5, 1.0))
+            }
+            (ColorMode::BlueYellow, PowerType::Fire) => ((3.0, 0.2, 0.2), (4.0, 0.8, 0.8)),
+            (ColorMode::BlueYellow, PowerType::Arcane) => ((0.2, 2.2, 2.2), (1.0, 3.0, 3.0)),
+            (ColorMode::BlueYellow, PowerType::Poison) => ((2.5, 0.4, 1.8), (3.5, 1.2, 2.6)),
+            // Purple shadow reads as blue to most colourblind players; grey doesn't
+            (_, PowerType::Shadow) => ((0.6, 0.6, 0.7), (1.6, 1.6, 1.8)),
+        };
+        Some(PowerColors {
+            primary: Color::srgb(primary.0, primary.1, primary.2),
+            core: Color::srgb(core.0, core.1, core.2),
+        })
+    }
+
+    pub fn debug_palette(self) -> DebugPalette {
+        match self {
+            ColorMode::Standard => DebugPalette {
+                walkable: Color::srgba(0.0, 1.0, 0.0, 0.25),
+                blocked: Color::srgba(1.0, 0.0, 0.0, 0.4),
+                bridge: Color::srgba(0.0, 0.5, 1.0, 0.35),
+                unreachable: Color::srgba(1.0, 0.0, 1.0, 0.6),
+            },
+            ColorMode::RedGreen => DebugPalette {
+                walkable: Color::srgba(0.0, 0.45, 1.0, 0.3),
+                blocked: Color::srgba(1.0, 0.6, 0.0, 0.45),
+                bridge: Color::srgba(1.0, 1.0, 1.0, 0.4),
+                unreachable: Color::srgba(0.0, 0.0, 0.0, 0.7),
+            },
+            ColorMode::BlueYellow => DebugPalette {
+                walkable: Color::srgba(0.0, 0.8, 0.5, 0.3),
+                blocked: Color::srgba(1.0, 0.0, 0.0, 0.45),
+                bridge: Color::srgba(1.0, 1.0, 1.0, 0.4),
+                unreachable: Color::srgba(0.0, 0.0, 0.0, 0.7),
+            },
+            ColorMode::HighContrast => DebugPalette {
+                walkable: Color::srgba(1.0, 1.0, 1.0, 0.35),
+                blocked: Color::srgba(0.0, 0.0, 0.0, 0.6),
+                bridge: Color::srgba(0.0, 1.0, 1.0, 0.5),
+                unreachable: Color::srgba(1.0, 0.0, 1.0, 0.8),
+            },
+        }
+    }
+}
@@ -0,0 +1,245 @@
+// src/rendering/screen_effects.rs
+use std::f32::consts::TAU;
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::camera::MainCamera;
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileType};
+use crate::combat::Health;
+use crate::config::screen_effects::{
+    DESATURATE_SECS, GRADE_BLEND_SECS, LOW_HEALTH_RATIO, SCREEN_EFFECTS_Z, SCREEN_FLASH_SECS, VIGNETTE_PULSE_HZ,
+};
+use crate::logging::targets;
+use crate::save::data::saves_directory;
+use crate::state::GameState;
+
+/// Whether the low-health vignette, flashes and pause desaturation show.
+/// Toggled from the pause menu and persisted next to the save slots as JSON.
+#[derive(Resource, Serialize, Deserialize, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Default)]
+#[serde(default)]
+pub struct ScreenEffectSettings {
+    pub enabled: bool,
+}
+
+impl Default for ScreenEffectSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+pub fn screen_effect_settings_file_path() -> PathBuf {
+    saves_directory().join("screen_effects.json")
+}
+
+impl ScreenEffectSettings {
+    /// Load the settings from disk, falling back to defaults if missing or unreadable.
+    pub fn load() -> Self {
+        let Ok(bytes) = fs::read(screen_effect_settings_file_path()) else {
+            return Self::default();
+        };
+        serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            warn!(
+                target: targets::SAVE,
+                "Unreadable screen effect settings, using defaults: {}",
+                e
+            );
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let bytes =
+            serde_json::to_vec_pretty(self).map_err(|e| format!("Serialize error: {}", e))?;
+        fs::create_dir_all(saves_directory()).map_err(|e| format!("Create dir error: {}", e))?;
+        fs::write(screen_effect_settings_file_path(), bytes)
+            .map_err(|e| format!("Write error: {}", e))
+    }
+}
+
+pub fn load_screen_effect_settings(mut commands: Commands) {
+    commands.insert_resource(ScreenEffectSettings::load());
+}
+
+/// Flashes the whole view white for a moment.
+#[derive(Event)]
+pub struct ScreenFlash;
+
+#[derive(ShaderType, Debug, Clone, Default)]
+pub struct ScreenEffects {
+    /// Terrain tint in rgb, its strength in w
+    pub grade: Vec4,
+    /// 0 = no vignette, 1 = thick red edges
+    pub vignette: f32,
+    /// 0 = none, 1 = solid white
+    pub flash: f32,
+    /// 0 = full colour, 1 = washed out to grey
+    pub desaturation: f32,
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone, Default)]
+pub struct ScreenEffectsMaterial {
+    #[uniform(0)]
+    pub effects: ScreenEffects,
+}
+
+impl Material2d for ScreenEffectsMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/screen_effects.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+/// The quad the effects draw on, sized to the view and riding along with
+/// the camera. Holds the effects that fade over time.
+#[derive(Component, Debug, Default)]
+pub struct ScreenEffectsOverlay {
+    flash: f32,
+    desaturation: f32,
+    grade: Vec4,
+}
+
+/// Tint laid over the view while the player stands on each kind of ground,
+/// so meadows, dry plains, dirt tracks and coasts each read differently.
+/// Bridges, hazards and unknown tiles keep whatever grade was last chosen.
+fn terrain_grade(tile: TileType) -> Option<Vec4> {
+    match tile {
+        TileType::Grass => Some(Vec4::new(0.3, 0.6, 0.3, 0.06)),
+        TileType::YellowGrass => Some(Vec4::new(0.9, 0.7, 0.3, 0.08)),
+        TileType::Dirt => Some(Vec4::new(0.55, 0.4, 0.3, 0.07)),
+        TileType::Shore => Some(Vec4::new(0.3, 0.5, 0.8, 0.08)),
+        _ => None,
+    }
+}
+
+pub fn spawn_screen_effects(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ScreenEffectsMaterial>>,
+) {
+    commands.spawn((
+        ScreenEffectsOverlay::default(),
+        Mesh2d(meshes.add(Rectangle::new(1.0, 1.0))),
+        MeshMaterial2d(materials.add(ScreenEffectsMaterial::default())),
+        Transform::from_xyz(0.0, 0.0, SCREEN_EFFECTS_Z),
+    ));
+}
+
+pub fn on_screen_flash(
+    _flash: On<ScreenFlash>,
+    settings: Res<ScreenEffectSettings>,
+    mut overlay: Query<&mut ScreenEffectsOverlay>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    for mut overlay in &mut overlay {
+        overlay.flash = 1.0;
+    }
+}
+
+/// Clears the overlay when play stops, since `update_screen_effects` only
+/// runs while playing or paused and would leave it frozen over the game over
+/// screen and the menu.
+pub fn clear_screen_effects(
+    mut overlay: Query<(&mut ScreenEffectsOverlay, &MeshMaterial2d<ScreenEffectsMaterial>)>,
+    mut materials: ResMut<Assets<ScreenEffectsMaterial>>,
+) {
+    for (mut overlay, material) in &mut overlay {
+        *overlay = ScreenEffectsOverlay::default();
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.effects = ScreenEffects::default();
+        }
+    }
+}
+
+/// Covers the view with the overlay and works out this frame's effects.
+/// Runs on real time, so the pause desaturation eases in while the game is
+/// frozen.
+#[allow(clippy::too_many_arguments)]
+pub fn update_screen_effects(
+    time: Res<Time<Real>>,
+    state: Res<State<GameState>>,
+    settings: Res<ScreenEffectSettings>,
+    collision_map: Option<Res<CollisionMap>>,
+    players: Query<(&Health, &Transform), With<Player>>,
+    camera: Query<(&Transform, &Projection), (With<MainCamera>, Without<ScreenEffectsOverlay>)>,
+    mut overlay: Query<
+        (
+            &mut ScreenEffectsOverlay,
+            &mut Transform,
+            &MeshMaterial2d<ScreenEffectsMaterial>,
+        ),
+        Without<Player>,
+    >,
+    mut materials: ResMut<Assets<ScreenEffectsMaterial>>,
+) {
+    let (Ok((camera, projection)), Ok((mut overlay, mut transform, material))) =
+        (camera.single(), overlay.single_mut())
+    else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection else {
+        return;
+    };
+    transform.translation = camera.translation.truncate().extend(SCREEN_EFFECTS_Z);
+    transform.scale = (ortho.area.size() * camera.scale.truncate()).extend(1.0);
+
+    let dt = time.delta_secs();
+    overlay.flash = (overlay.flash - dt / SCREEN_FLASH_SECS).max(0.0);
+
+    let paused = *state.get() == GameState::Paused;
+    let target = if paused && settings.enabled { 1.0 } else { 0.0 };
+    let step = dt / DESATURATE_SECS;
+    overlay.desaturation = if overlay.desaturation < target {
+        (overlay.desaturation + step).min(target)
+    } else {
+        (overlay.desaturation - step).max(target)
+    };
+
+    let player = players.single().ok();
+
+    // Ease toward the tint of the ground underfoot, so crossing into new
+    // terrain shifts the mood gradually rather than on a single tile
+    let ground = player.zip(collision_map.as_deref()).and_then(|((_, transform), map)| {
+        let cell = map.world_to_grid(transform.translation.truncate());
+        map.get_tile(cell.x, cell.y)
+    });
+    if let Some(grade) = ground.and_then(terrain_grade) {
+        let target = if settings.enabled { grade } else { grade.with_w(0.0) };
+        // About 95% of the way there after GRADE_BLEND_SECS
+        let blend = 1.0 - (-3.0 * dt / GRADE_BLEND_SECS).exp();
+        overlay.grade = overlay.grade.lerp(target, blend);
+    }
+
+    // Thicker the closer to death, pulsing like a heartbeat
+    let vignette = match player {
+        Some((health, _)) if settings.enabled && health.ratio() < LOW_HEALTH_RATIO => {
+            let danger = 1.0 - health.ratio() / LOW_HEALTH_RATIO;
+            let pulse = 0.5 + 0.5 * (time.elapsed_secs() * TAU * VIGNETTE_PULSE_HZ).sin();
+            (0.5 + 0.5 * danger) * (0.7 + 0.3 * pulse)
+        }
+        _ => 0.0,
+    };
+
+    if let Some(material) = materials.get_mut(&material.0) {
+        material.effects = ScreenEffects {
+            grade: overlay.grade,
+            vignette,
+            flash: overlay.flash,
+            desaturation: overlay.desaturation,
+        };
+    }
+}
@@ -0,0 +1,104 @@
+// src/rendering/shadow.rs
+use bevy::prelude::*;
+
+use super::depth::YSorted;
+use crate::characters::collider::Collider;
+use crate::characters::state::CharacterState;
+use crate::enemy::Flying;
+
+/// Shadow ellipse size relative to the owner's half width
+const SHADOW_WIDTH_FACTOR: f32 = 1.4;
+const SHADOW_HEIGHT_FACTOR: f32 = 0.5;
+const SHADOW_ALPHA: f32 = 0.35;
+/// Half width of owners with neither a collider nor a sized sprite
+const DEFAULT_HALF_WIDTH: f32 = 16.0;
+/// Just under the props and items on the ground, above the terrain
+const SHADOW_Z_BAND: f32 = 3.95;
+/// How much of the shadow is left at the top of a jump
+const JUMP_SHADOW_FADE: f32 = 0.4;
+/// Fade per second toward the target
+const SHADOW_FADE_SPEED: f32 = 6.0;
+
+/// Oval on the ground under a character, a child so it follows and despawns
+/// with its owner.
+#[derive(Component, Debug)]
+pub struct Shadow {
+    /// 1 when the owner stands on the ground, lower while it's in the air
+    fade: f32,
+    /// Scale that undoes the owner's
+    base_scale: Vec3,
+}
+
+/// The shadow an entity casts.
+#[derive(Component, Debug)]
+pub struct CastsShadow(Entity);
+
+/// Gives new characters a soft shadow at their feet, as wide as their
+/// collider or sprite. Flyers cast theirs straight down.
+pub fn attach_shadows(
+    mut commands: Commands,
+    owners: Query<
+        (Entity, &Transform, &YSorted, Option<&Collider>, Option<&Sprite>, Option<&Flying>),
+        (Added<YSorted>, With<CharacterState>),
+    >,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (owner, transform, sorted, collider, sprite, flying) in &owners {
+        let half_width = collider
+            .map(|c| c.shape.half_extents().x)
+            .or_else(|| sprite.and_then(|s| s.custom_size).map(|size| size.x / 2.0))
+            .unwrap_or(DEFAULT_HALF_WIDTH);
+        let drop = flying.map_or(sorted.feet_offset, |f| f.altitude);
+        // Undo the owner's scale, like the healthbars do
+        let inverse_scale = transform.scale.recip();
+
+        let shadow = commands
+            .spawn((
+                Shadow { fade: 1.0, base_scale: inverse_scale },
+                Mesh2d(meshes.add(Ellipse::new(
+                    half_width * SHADOW_WIDTH_FACTOR / 2.0,
+                    half_width * SHADOW_HEIGHT_FACTOR / 2.0,
+                ))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(Color::srgba(0.0, 0.0, 0.0, SHADOW_ALPHA)))),
+                Transform::from_translation(
+                    Vec3::new(0.0, -drop, SHADOW_Z_BAND - sorted.base_z) * inverse_scale,
+                )
+                .with_scale(inverse_scale),
+                ChildOf(owner),
+            ))
+            .id();
+        commands.entity(owner).insert(CastsShadow(shadow));
+    }
+}
+
+/// Fades and shrinks the shadows of jumping characters, and brings them back
+/// on landing.
+pub fn fade_jump_shadows(
+    time: Res<Time>,
+    owners: Query<(&CastsShadow, &CharacterState)>,
+    mut shadows: Query<(&mut Shadow, &mut Transform, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (casts, state) in &owners {
+        let Ok((mut shadow, mut transform, material)) = shadows.get_mut(casts.0) else {
+            continue;
+        };
+        let target = if *state == CharacterState::Jumping { JUMP_SHADOW_FADE } else { 1.0 };
+        if shadow.fade == target {
+            continue;
+        }
+
+        let step = SHADOW_FADE_SPEED * time.delta_secs();
+        shadow.fade = if shadow.fade < target {
+            (shadow.fade + step).min(target)
+        } else {
+            (shadow.fade - step).max(target)
+        };
+        // A smaller shadow reads as further off the ground
+        transform.scale = shadow.base_scale * (0.5 + 0.5 * shadow.fade);
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.color = Color::srgba(0.0, 0.0, 0.0, SHADOW_ALPHA * shadow.fade);
+        }
+    }
+}
@@ -1,29 +1,85 @@
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 
+use crate::buffs::BuffKind;
 use crate::characters::facing::Facing;
 use crate::collision::TileType;
-use crate::combat::PowerType;
+use crate::combat::PowerId;
 use crate::inventory::ItemKind;
+use crate::props::PropKind;
+use crate::world_events::EventPhase;
 
-pub const SAVE_VERSION: u32 = 1;
-pub const MAX_SLOTS: usize = 5;
+use super::play_time::format_play_time;
 
+pub const SAVE_VERSION: u32 = 12;
+/// Oldest save that still loads: the first whose payload is JSON. Saves
+/// from before that were bincode, which can't skip or default a field.
+pub const OLDEST_SAVE_VERSION: u32 = 9;
+/// Slots are only listed once used, so this just bounds the slot numbers
+pub const MAX_SLOTS: usize = 99;
+/// Earlier good saves kept per slot, to restore if the slot gets damaged
+pub const SAVE_BACKUPS: usize = 3;
+/// Format of `SaveMetadata::timestamp`, shown in the slot list
+pub const TIMESTAMP_FORMAT: &str = "%d %b %Y, %I:%M %p";
+
+/// On-disk envelope. Only the payload is JSON; the envelope itself never changes.
 #[derive(Serialize, Deserialize)]
 pub struct SaveFile {
     pub checksum: u64,
     pub data: Vec<u8>,
 }
 
+/// Everything in a save. Stored as JSON, so fields are matched by name:
+/// a field added later takes `#[serde(default)]` so saves from before it
+/// still load, and a renamed one keeps its old name as an `alias`. Only a
+/// change old saves can't be read through needs `OLDEST_SAVE_VERSION` raised.
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
     pub version: u32,
+    #[serde(default)]
     pub timestamp: String,
+    #[serde(default)]
     pub slot_name: String,
     pub player: PlayerSave,
+    #[serde(default)]
     pub enemies: Vec<EnemySave>,
+    #[serde(default)]
     pub inventory: HashMap<ItemKind, u32>,
+    /// The settings the map was built with. A generated map is generated
+    /// again on load and `tiles` left empty
+    #[serde(default)]
+    pub map: Option<MapSave>,
+    #[serde(default)]
     pub tiles: Vec<TileSave>,
+    #[serde(default)]
+    pub props: Vec<PropSave>,
+    #[serde(default)]
+    pub platforms: Vec<PlatformSave>,
+    #[serde(default)]
+    pub obelisks: Vec<ObeliskSave>,
+    #[serde(default)]
+    pub respawn: RespawnSave,
+    #[serde(default)]
+    pub delta: WorldDeltaSave,
+    /// Seconds played (see `save::play_time`)
+    #[serde(default)]
+    pub play_time: f64,
+    #[serde(default)]
+    pub world_events: WorldEventsSave,
+    /// Hour of the day (see `lighting::DayNightCycle`). Saves from before
+    /// it was kept start in the morning
+    #[serde(default)]
+    pub hour: Option<f32>,
+    #[serde(default)]
+    pub torches: Vec<[f32; 3]>,
+    #[serde(default)]
+    pub caves: Vec<CaveSave>,
+}
+
+/// Just enough of a payload to tell whether the rest will load.
+#[derive(Deserialize)]
+pub struct SaveHeader {
+    pub version: u32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -31,10 +87,29 @@ pub struct PlayerSave {
     pub position: [f32; 3],
     pub health_current: f32,
     pub health_max: f32,
-    pub power_type: PowerType,
+    /// Temporary bonus max health, kept apart so it keeps decaying after a load
+    #[serde(default)]
+    pub health_bonus: f32,
+    pub power_type: PowerId,
     pub character_name: String,
     pub character_index: usize,
+    #[serde(default)]
     pub facing: Facing,
+    /// Buffs and debuffs still running
+    #[serde(default)]
+    pub buffs: Vec<BuffSave>,
+}
+
+/// A running buff. Buffs time themselves against the game clock, which
+/// starts over on load, so this keeps what was left of it instead.
+#[derive(Serialize, Deserialize)]
+pub struct BuffSave {
+    pub kind: BuffKind,
+    pub magnitude: f32,
+    pub stacks: u32,
+    /// Length of the current run, and how much of it is left
+    pub duration: f32,
+    pub remaining: f32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -42,9 +117,91 @@ pub struct EnemySave {
     pub position: [f32; 3],
     pub health_current: f32,
     pub health_max: f32,
+    #[serde(default)]
+    pub health_bonus: f32,
+    /// Distance-based level; health above is already scaled for it
+    pub level: u32,
     pub character_name: String,
-    pub power_type: PowerType,
+    pub power_type: PowerId,
+    #[serde(default)]
     pub facing: Facing,
+    pub home_zone: Option<[i32; 2]>,
+}
+
+/// Where the world event schedule is (see `world_events::EventDirector`).
+#[derive(Serialize, Deserialize, Default)]
+pub struct WorldEventsSave {
+    pub night: u32,
+    pub phase: EventPhase,
+}
+
+/// Zone repopulation state (see `enemy::respawn`).
+#[derive(Serialize, Deserialize, Default)]
+pub struct RespawnSave {
+    pub clock: f64,
+    pub zones: Vec<ZoneSave>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ZoneSave {
+    pub zone: [i32; 2],
+    pub population: Vec<String>,
+    pub cleared_at: Option<f64>,
+    pub last_visited: f64,
+}
+
+/// The world settings a map was built with. A generated map (see
+/// `map::generate::GeneratedMap`) is generated again from them; saved tiles
+/// are laid back on the same grid and get the same hazards.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct MapSave {
+    pub seed: u64,
+    pub chunks_x: u32,
+    pub chunks_y: u32,
+    /// Generated again on load rather than read from `tiles`. Saves from
+    /// before this was kept only had a `MapSave` for generated maps
+    #[serde(default = "default_regenerate")]
+    pub regenerate: bool,
+    /// Bottom-left corner of the saved tiles' grid
+    #[serde(default)]
+    pub origin: [f32; 2],
+    /// The saved tiles had hazards scattered over them with `seed`
+    #[serde(default)]
+    pub hazards: bool,
+}
+
+fn default_regenerate() -> bool {
+    true
+}
+
+/// What the player changed in the world, replayed onto a regenerated map.
+#[derive(Serialize, Deserialize, Default)]
+pub struct WorldDeltaSave {
+    /// Map cells whose pickable was collected
+    #[serde(default)]
+    pub consumed_pickables: Vec<[i32; 2]>,
+    /// The lit checkpoint
+    #[serde(default)]
+    pub active_checkpoint: Option<[f32; 2]>,
+    #[serde(default)]
+    pub waypoints: Vec<WaypointSave>,
+    /// Ambushes the player hasn't sprung yet
+    #[serde(default)]
+    pub ambushes: Vec<AmbushSave>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WaypointSave {
+    pub label: String,
+    pub position: [f32; 2],
+}
+
+/// An ambush area (see `enemy::ambush::Ambush`).
+#[derive(Serialize, Deserialize)]
+pub struct AmbushSave {
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+    pub enemies: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -57,22 +214,81 @@ pub struct TileSave {
     pub pickable: Option<ItemKind>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+/// A pushable prop where the player left it.
+#[derive(Serialize, Deserialize)]
+pub struct PropSave {
+    pub kind: PropKind,
+    pub position: [f32; 2],
+}
+
+/// A moving platform partway along its route, so riders aren't left in the water.
+#[derive(Serialize, Deserialize)]
+pub struct PlatformSave {
+    pub position: [f32; 2],
+    pub waypoints: Vec<[f32; 2]>,
+    pub next: usize,
+    pub forward: bool,
+}
+
+/// A fast travel obelisk, kept as placed since a loaded map gets a new seed.
+#[derive(Serialize, Deserialize)]
+pub struct ObeliskSave {
+    pub name: String,
+    pub position: [f32; 2],
+    #[serde(default)]
+    pub attuned: bool,
+}
+
+/// An area lit as a cave (see `lighting::CaveArea`).
+#[derive(Serialize, Deserialize)]
+pub struct CaveSave {
+    pub position: [f32; 2],
+    pub size: [f32; 2],
+}
+
+/// Slot list label, kept apart from the save so listing slots stays cheap.
+/// JSON too, with every field defaulted, so an old label still reads.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 pub struct SaveMetadata {
     pub timestamp: String,
     pub character_name: String,
     pub player_health: f32,
     pub player_max_health: f32,
+    /// Seconds played; `None` for saves from before it was tracked
+    pub play_time: Option<f64>,
 }
 
-pub fn saves_directory() -> std::path::PathBuf {
-    let mut path = std::env::current_exe()
+impl SaveMetadata {
+    /// When the save was made and, if known, how long it's been played.
+    pub fn summary(&self) -> String {
+        match self.play_time {
+            Some(secs) => format!("{} · {}", self.timestamp, format_play_time(secs)),
+            None => self.timestamp.clone(),
+        }
+    }
+}
+
+/// Directory next to the executable where saves and logs live.
+pub fn data_directory() -> std::path::PathBuf {
+    std::env::current_exe()
         .unwrap_or_default()
         .parent()
         .unwrap_or(std::path::Path::new("."))
-        .to_path_buf();
-    path.push("saves");
-    path
+        .to_path_buf()
+}
+
+pub fn saves_directory() -> std::path::PathBuf {
+    data_directory().join("saves")
+}
+
+/// Where the crash handler writes its emergency save; moved into a free slot on next launch.
+pub fn emergency_save_path() -> std::path::PathBuf {
+    saves_directory().join("emergency.sav")
+}
+
+pub fn emergency_meta_path() -> std::path::PathBuf {
+    saves_directory().join("emergency.meta")
 }
 
 pub fn save_file_path(slot: usize) -> std::path::PathBuf {
@@ -83,6 +299,15 @@ pub fn meta_file_path(slot: usize) -> std::path::PathBuf {
     saves_directory().join(format!("slot_{}.meta", slot))
 }
 
+/// The `n`th newest backup of a slot, counting from 0.
+pub fn backup_file_path(slot: usize, n: usize) -> std::path::PathBuf {
+    saves_directory().join(format!("slot_{}.sav.{}.bak", slot, n))
+}
+
+pub fn backup_meta_path(slot: usize, n: usize) -> std::path::PathBuf {
+    saves_directory().join(format!("slot_{}.meta.{}.bak", slot, n))
+}
+
 pub fn compute_checksum(data: &[u8]) -> u64 {
     // Simple FNV-1a hash
     let mut hash: u64 = 0xcbf29ce484222325;
@@ -91,4 +316,70 @@ pub fn compute_checksum(data: &[u8]) -> u64 {
         hash = hash.wrapping_mul(0x100000001b3);
     }
     hash
-}
\ No newline at end of file
+}
+
+/// A save serialized to the on-disk format, ready to be written.
+#[derive(Clone)]
+pub struct EncodedSave {
+    pub file_bytes: Vec<u8>,
+    pub meta_bytes: Vec<u8>,
+}
+
+impl EncodedSave {
+    pub fn write_to(&self, save_path: &std::path::Path, meta_path: &std::path::Path) -> Result<(), String> {
+        std::fs::create_dir_all(saves_directory()).map_err(|e| format!("Create dir error: {}", e))?;
+        std::fs::write(save_path, &self.file_bytes).map_err(|e| format!("Write error: {}", e))?;
+        std::fs::write(meta_path, &self.meta_bytes)
+            .map_err(|e| format!("Meta write error: {}", e))?;
+        Ok(())
+    }
+}
+
+impl SaveData {
+    /// The map's settings, if it's generated again rather than read from `tiles`.
+    pub fn regenerated_map(&self) -> Option<MapSave> {
+        self.map.filter(|map| map.regenerate)
+    }
+}
+
+/// Whether a payload of this version can be read into the current `SaveData`.
+pub fn check_save_version(version: u32) -> Result<(), String> {
+    match version {
+        v if v > SAVE_VERSION => Err(format!(
+            "Save is from a newer version of the game: {} (expected {})",
+            v, SAVE_VERSION
+        )),
+        v if v < OLDEST_SAVE_VERSION => Err(format!(
+            "Incompatible save version: {} (oldest supported is {})",
+            v, OLDEST_SAVE_VERSION
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub fn encode_save(save_data: &SaveData) -> Result<EncodedSave, String> {
+    let data_bytes =
+        serde_json::to_vec(save_data).map_err(|e| format!("Serialize error: {}", e))?;
+    let checksum = compute_checksum(&data_bytes);
+    let save_file = SaveFile {
+        checksum,
+        data: data_bytes,
+    };
+    let file_bytes =
+        bincode::serialize(&save_file).map_err(|e| format!("Serialize error: {}", e))?;
+
+    let metadata = SaveMetadata {
+        timestamp: save_data.timestamp.clone(),
+        character_name: save_data.player.character_name.clone(),
+        player_health: save_data.player.health_current,
+        player_max_health: save_data.player.health_max,
+        play_time: Some(save_data.play_time),
+    };
+    let meta_bytes =
+        serde_json::to_vec(&metadata).map_err(|e| format!("Meta serialize error: {}", e))?;
+
+    Ok(EncodedSave {
+        file_bytes,
+        meta_bytes,
+    })
+}
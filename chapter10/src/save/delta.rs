@@ -0,0 +1,82 @@
+// src/save/delta.rs
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::checkpoints::ActiveCheckpoint;
+use crate::collision::TileMarker;
+use crate::config::map::TILE_SIZE;
+use crate::inventory::Pickable;
+use crate::map::generate::WorldGenSettings;
+use crate::telemetry::GameplayEvent;
+
+use super::data::WorldDeltaSave;
+
+/// What the player has changed on the current map that generating it again
+/// from its seed wouldn't bring back. Reset along with the game world.
+#[derive(Resource, Debug, Default)]
+pub struct WorldDelta {
+    /// Map cells whose pickable has been collected
+    pub consumed_pickables: HashSet<IVec2>,
+}
+
+impl WorldDelta {
+    pub fn from_save(save: &WorldDeltaSave) -> Self {
+        Self {
+            consumed_pickables: save
+                .consumed_pickables
+                .iter()
+                .map(|cell| IVec2::from_array(*cell))
+                .collect(),
+        }
+    }
+}
+
+/// The map cell under a world position.
+fn map_cell(position: Vec2, settings: &WorldGenSettings) -> IVec2 {
+    ((position - settings.grid_origin()) / TILE_SIZE).floor().as_ivec2()
+}
+
+pub fn record_consumed_pickables(
+    mut events: MessageReader<GameplayEvent>,
+    settings: Res<WorldGenSettings>,
+    mut delta: ResMut<WorldDelta>,
+) {
+    for event in events.read() {
+        if let GameplayEvent::ItemPicked { position, .. } = event {
+            delta.consumed_pickables.insert(map_cell(*position, &settings));
+        }
+    }
+}
+
+/// Puts a save's delta back before its map is rebuilt. The lit checkpoint
+/// is matched to its altar once `place_checkpoints` has put the altars back.
+pub fn restore_world_delta(world: &mut World, save: &WorldDeltaSave) {
+    world.insert_resource(WorldDelta::from_save(save));
+    *world.resource_mut::<ActiveCheckpoint>() = ActiveCheckpoint {
+        checkpoint: None,
+        position: save.active_checkpoint.map(Vec2::from_array),
+    };
+}
+
+/// Takes the pickables the player already collected off a freshly
+/// generated map.
+pub fn remove_consumed_pickables(world: &mut World) {
+    let settings = *world.resource::<WorldGenSettings>();
+    let consumed = std::mem::take(&mut world.resource_mut::<WorldDelta>().consumed_pickables);
+
+    let mut pickables =
+        world.query_filtered::<(Entity, &Transform), (With<Pickable>, With<TileMarker>)>();
+    let collected: Vec<Entity> = pickables
+        .iter(world)
+        .filter(|(_, transform)| {
+            consumed.contains(&map_cell(transform.translation.truncate(), &settings))
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in collected {
+        world.despawn(entity);
+    }
+
+    world.resource_mut::<WorldDelta>().consumed_pickables = consumed;
+}
@@ -0,0 +1,95 @@
+// src/save/export.rs
+use std::path::Path;
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+
+use crate::console::ConsoleResult;
+use crate::logging::targets;
+
+use super::data::*;
+use super::systems;
+use super::ui::SaveLoadUIState;
+
+/// Text formats a save can be dumped to, picked by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Ron,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ron") => Ok(Self::Ron),
+            Some("json") => Ok(Self::Json),
+            _ => Err(format!("{} should end in .ron or .json", path.display())),
+        }
+    }
+}
+
+/// Writes a slot's save as pretty-printed text, for inspecting and
+/// hand-editing when chasing serialization bugs.
+pub fn export(slot: usize, path: &Path) -> Result<(), String> {
+    let format = ExportFormat::from_path(path)?;
+    let save_data = systems::load_save_data(slot)?;
+    let text = match format {
+        ExportFormat::Ron => ron::ser::to_string_pretty(&save_data, PrettyConfig::default())
+            .map_err(|e| format!("RON error: {}", e))?,
+        ExportFormat::Json => serde_json::to_string_pretty(&save_data)
+            .map_err(|e| format!("JSON error: {}", e))?,
+    };
+    std::fs::write(path, text).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Reads an exported save back into a slot. The slot's old save goes into
+/// its backups like any other overwrite.
+pub fn import(path: &Path, slot: usize) -> Result<(), String> {
+    let format = ExportFormat::from_path(path)?;
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    let mut save_data: SaveData = match format {
+        ExportFormat::Ron => ron::from_str(&text).map_err(|e| format!("RON error: {}", e))?,
+        ExportFormat::Json => {
+            serde_json::from_str(&text).map_err(|e| format!("JSON error: {}", e))?
+        }
+    };
+    check_save_version(save_data.version)?;
+    save_data.version = SAVE_VERSION;
+    systems::write_slot(slot, &encode_save(&save_data)?)
+}
+
+/// A 1-based slot number as typed in the console.
+fn parse_slot(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(n) if (1..=MAX_SLOTS).contains(&n) => Ok(n - 1),
+        _ => Err(format!("Bad save slot: {} (1-{})", arg, MAX_SLOTS)),
+    }
+}
+
+pub fn save_export_command(_world: &mut World, args: &[&str]) -> ConsoleResult {
+    let Some(slot) = args.first() else {
+        return Err("Usage: save_export <slot> [file.ron|file.json]".into());
+    };
+    let slot = parse_slot(slot)?;
+    let default_file = format!("slot_{}.ron", slot + 1);
+    let path = Path::new(args.get(1).copied().unwrap_or(&default_file));
+
+    export(slot, path)?;
+    info!(target: targets::SAVE, "Exported slot {} to {}", slot + 1, path.display());
+    Ok(format!("Exported slot {} to {}", slot + 1, path.display()))
+}
+
+pub fn save_import_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let [path, slot] = args else {
+        return Err("Usage: save_import <file.ron|file.json> <slot>".into());
+    };
+    let slot = parse_slot(slot)?;
+    let path = Path::new(path);
+
+    import(path, slot)?;
+    // Rebuilds the slot list if it's open
+    world.resource_mut::<SaveLoadUIState>().set_changed();
+    info!(target: targets::SAVE, "Imported {} into slot {}", path.display(), slot + 1);
+    Ok(format!("Imported {} into slot {}", path.display(), slot + 1))
+}
@@ -0,0 +1,640 @@
+// src/save/io.rs
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future::poll_once, AsyncComputeTaskPool, Task};
+use bevy::ui::FocusPolicy;
+
+use crate::buffs::{Buff, Buffs};
+use crate::characters::config::CharactersList;
+use crate::characters::spawn::{CharactersListResource, CurrentCharacterIndex, PlayerSpawned};
+use crate::collision::{CollisionMapBuilder, CollisionMapBuilt, TileMarker};
+use crate::combat::{Health, PlayerCombat};
+use crate::config::map::TILE_SIZE;
+use crate::enemy::ambush::spawn_ambush;
+use crate::enemy::respawn::{HomeZone, RespawnDirector, ZoneState};
+use crate::enemy::spawn::EnemiesSpawned;
+use crate::enemy::EnemyLevel;
+use crate::fast_travel::obelisk_bundle;
+use crate::inventory::{Inventory, Pickable};
+use crate::lighting::{spawn_cave, spawn_torch, DayNightCycle, LightingEnvironment};
+use crate::logging::targets;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::{
+    poll_map_generation, start_generation, GeneratedMap, MapGenTask, MapReady, WorldGenSettings,
+};
+use crate::map::level::LevelLayout;
+use crate::map::rules::{RulesDefinition, WorldRules};
+use crate::map::tilemap::TilemapDefinition;
+use crate::prefabs::spawn_prefab;
+use crate::props::{spawn_platform, spawn_prop, Platform};
+use crate::state::{despawn_all_gameplay, ConfirmDialog, GameMode, GameState, GameplayEntity};
+use crate::ui::{TextRole, UiTheme};
+use crate::waypoints::spawn_waypoint;
+use crate::world_events::EventDirector;
+
+use super::data::*;
+use super::delta;
+use super::play_time::PlayTime;
+use super::systems::{self, SaveBackup};
+use super::ui::{save_timestamp, PendingSaveLoadAction, SaveLoadMode, WorldSnapshot};
+
+/// Tiles respawned per frame while a load is restored
+const TILES_PER_FRAME: usize = 4000;
+
+/// A save being encoded and written on the task pool. The world was
+/// captured when it started, so play can carry on meanwhile.
+#[derive(Resource)]
+pub struct SaveTask {
+    slot: usize,
+    task: Task<Result<(), String>>,
+}
+
+/// A save being read, checked and decoded on the task pool.
+#[derive(Resource)]
+pub struct LoadTask {
+    slot: usize,
+    task: Task<Result<SaveData, LoadFailure>>,
+}
+
+/// Why a slot didn't load, and the backup that could stand in for it.
+struct LoadFailure {
+    error: String,
+    backup: Option<SaveBackup>,
+}
+
+impl LoadTask {
+    /// Reads a slot, first copying one of its backups over it if `restore`
+    /// is set.
+    fn spawn(slot: usize, restore: Option<usize>) -> Self {
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            restore
+                .map_or(Ok(()), |index| systems::restore_backup(slot, index))
+                .and_then(|()| systems::load_save_data(slot))
+                .map_err(|error| LoadFailure {
+                    error,
+                    backup: systems::find_backup(slot),
+                })
+        });
+        Self { slot, task }
+    }
+}
+
+enum LoadStage {
+    Clear,
+    /// Index of the next tile to spawn
+    Tiles(usize),
+    /// Generating the save's map again from its seed
+    Generate(MapSave),
+    Generating,
+    Props,
+    Actors,
+}
+
+/// A decoded save being put back into the world, one stage per frame and
+/// the tiles in batches, so a big map doesn't stall a single frame. A save
+/// of a generated map has no tiles; its map is generated again on the task
+/// pool after the props are back.
+#[derive(Resource)]
+pub struct StagedLoad {
+    slot: usize,
+    data: SaveData,
+    stage: LoadStage,
+    collision: CollisionMapBuilder,
+}
+
+/// True from when a save starts being read until the world is restored.
+pub fn load_in_progress(loading: Option<Res<LoadTask>>, staged: Option<Res<StagedLoad>>) -> bool {
+    loading.is_some() || staged.is_some()
+}
+
+/// Captures the world right away and leaves encoding and writing to a task.
+/// A save asked for while another is still writing waits for it.
+pub fn execute_save(
+    mut commands: Commands,
+    mut pending: ResMut<PendingSaveLoadAction>,
+    writing: Option<Res<SaveTask>>,
+    snapshot: WorldSnapshot,
+) {
+    let Some((SaveLoadMode::Save, slot)) = pending.0 else {
+        return;
+    };
+    if writing.is_some() {
+        return;
+    }
+    pending.0 = None;
+
+    let save_data = match snapshot.capture(format!("Slot {}", slot + 1), save_timestamp()) {
+        Ok(data) => data,
+        Err(e) => {
+            error!(target: targets::SAVE, "{}", e);
+            return;
+        }
+    };
+
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        systems::write_slot(slot, &encode_save(&save_data)?)
+    });
+    commands.insert_resource(SaveTask { slot, task });
+}
+
+pub fn poll_save_task(mut commands: Commands, mut save: ResMut<SaveTask>) {
+    let Some(result) = block_on(poll_once(&mut save.task)) else {
+        return;
+    };
+    commands.remove_resource::<SaveTask>();
+    match result {
+        Ok(()) => info!(target: targets::SAVE, "Saved to slot {}", save.slot + 1),
+        Err(e) => error!(target: targets::SAVE, "Failed to save: {}", e),
+    }
+}
+
+pub fn start_load(
+    mut commands: Commands,
+    mut pending: ResMut<PendingSaveLoadAction>,
+    loading: Option<Res<LoadTask>>,
+    staged: Option<Res<StagedLoad>>,
+) {
+    let Some((SaveLoadMode::Load, slot)) = pending.0 else {
+        return;
+    };
+    pending.0 = None;
+    if load_in_progress(loading, staged) {
+        warn!(target: targets::SAVE, "Already loading; ignoring slot {}", slot + 1);
+        return;
+    }
+
+    commands.insert_resource(LoadTask::spawn(slot, None));
+}
+
+/// Starts restoring a load, or offers the slot's newest good backup if the
+/// save couldn't be read.
+pub fn poll_load_task(mut commands: Commands, mut load: ResMut<LoadTask>) {
+    let Some(result) = block_on(poll_once(&mut load.task)) else {
+        return;
+    };
+    commands.remove_resource::<LoadTask>();
+    match result {
+        Ok(data) => commands.insert_resource(StagedLoad {
+            slot: load.slot,
+            data,
+            stage: LoadStage::Clear,
+            collision: CollisionMapBuilder::default(),
+        }),
+        Err(LoadFailure { error, backup }) => {
+            error!(target: targets::SAVE, "Failed to load: {}", error);
+            let Some(backup) = backup else {
+                return;
+            };
+            let slot = load.slot;
+            commands.spawn(ConfirmDialog::open(
+                format!(
+                    "Slot {} is damaged. Restore its backup from {}?",
+                    slot + 1,
+                    backup.timestamp
+                ),
+                move |world| {
+                    world.insert_resource(LoadTask::spawn(slot, Some(backup.index)));
+                },
+            ));
+        }
+    }
+}
+
+/// Runs the next stage of a staged load. A load that can't go on is
+/// dropped; before the world is cleared, that leaves the game as it was.
+pub fn advance_staged_load(world: &mut World) {
+    let Some(mut staged) = world.remove_resource::<StagedLoad>() else {
+        return;
+    };
+
+    let next = match staged.stage {
+        LoadStage::Clear => clear_world(world, &staged.data),
+        LoadStage::Tiles(start) => spawn_tiles(world, &mut staged, start),
+        LoadStage::Generate(map) => start_regeneration(world, map),
+        LoadStage::Generating => await_regeneration(world),
+        LoadStage::Props => {
+            spawn_props(world, &staged.data);
+            Ok(match staged.data.regenerated_map() {
+                Some(map) => LoadStage::Generate(map),
+                None => LoadStage::Actors,
+            })
+        }
+        LoadStage::Actors => {
+            if let Err(e) = restore_actors(world, staged) {
+                error!(target: targets::SAVE, "Failed to load: {}", e);
+            }
+            return;
+        }
+    };
+
+    match next {
+        Ok(stage) => {
+            staged.stage = stage;
+            world.insert_resource(staged);
+        }
+        Err(e) => error!(target: targets::SAVE, "Failed to load: {}", e),
+    }
+}
+
+fn characters_list(world: &World) -> Result<CharactersList, String> {
+    let handle = world
+        .get_resource::<CharactersListResource>()
+        .ok_or("CharactersListResource not available")?
+        .handle
+        .clone();
+    world
+        .resource::<Assets<CharactersList>>()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| "Characters list not loaded".to_string())
+}
+
+fn generation_inputs(world: &World) -> Result<(TilemapDefinition, RulesDefinition), String> {
+    let handles = world
+        .get_resource::<TilemapHandles>()
+        .ok_or("TilemapHandles not available for loading")?;
+    let definition = world
+        .resource::<Assets<TilemapDefinition>>()
+        .get(&handles.definition)
+        .ok_or("The tilemap atlas description hasn't loaded")?;
+    let rules = world.get_resource::<WorldRules>().ok_or("The world rules haven't loaded")?;
+    let rules_definition = world
+        .resource::<Assets<RulesDefinition>>()
+        .get(&rules.0)
+        .ok_or("The world rules haven't loaded")?;
+    Ok((definition.clone(), rules_definition.clone()))
+}
+
+fn clear_world(world: &mut World, save_data: &SaveData) -> Result<LoadStage, String> {
+    // Everything the later stages need is checked before anything is removed
+    if !world.contains_resource::<TilemapHandles>() {
+        return Err("TilemapHandles not available for loading".into());
+    }
+    characters_list(world)?;
+    if save_data.regenerated_map().is_some() {
+        generation_inputs(world)?;
+    }
+
+    despawn_all_gameplay(world);
+    // Set before the map is back, for what gets placed on it
+    world.insert_resource(GameMode::Story);
+    // A hand-made level's spawn points no longer apply
+    world.remove_resource::<LevelLayout>();
+    delta::restore_world_delta(world, &save_data.delta);
+
+    // A regenerated map places obelisks unless the saved ones are already there
+    Ok(match save_data.regenerated_map() {
+        Some(_) => LoadStage::Props,
+        None => LoadStage::Tiles(0),
+    })
+}
+
+fn map_settings(map: &MapSave) -> WorldGenSettings {
+    WorldGenSettings {
+        chunks_x: map.chunks_x,
+        chunks_y: map.chunks_y,
+        seed: map.seed,
+    }
+}
+
+fn start_regeneration(world: &mut World, map: MapSave) -> Result<LoadStage, String> {
+    let settings = map_settings(&map);
+    let (definition, rules_definition) = generation_inputs(world)?;
+    let handles = world.resource::<TilemapHandles>().clone();
+
+    let mut commands = world.commands();
+    start_generation(&mut commands, &handles, &definition, &rules_definition, settings)?;
+    world.flush();
+    world.insert_resource(settings);
+    Ok(LoadStage::Generating)
+}
+
+/// Spawns the map once it has generated, then takes back off it what the
+/// player already collected.
+fn await_regeneration(world: &mut World) -> Result<LoadStage, String> {
+    world
+        .run_system_cached(poll_map_generation)
+        .map_err(|e| format!("Map generation error: {}", e))?;
+    if world.contains_resource::<MapGenTask>() {
+        return Ok(LoadStage::Generating);
+    }
+    delta::remove_consumed_pickables(world);
+    Ok(LoadStage::Actors)
+}
+
+fn spawn_tiles(
+    world: &mut World,
+    staged: &mut StagedLoad,
+    start: usize,
+) -> Result<LoadStage, String> {
+    let tilemap_handles = world
+        .get_resource::<TilemapHandles>()
+        .ok_or("TilemapHandles not available for loading")?
+        .clone();
+    // Back on the grid they were saved on. Saves from before the grid was
+    // kept were all of the full-size story map
+    let origin = match staged.data.map {
+        Some(map) => Vec2::from_array(map.origin),
+        None => WorldGenSettings::default().grid_origin(),
+    };
+    let end = (start + TILES_PER_FRAME).min(staged.data.tiles.len());
+
+    for tile in &staged.data.tiles[start..end] {
+        let cell = ((Vec2::new(tile.position[0], tile.position[1]) - origin) / TILE_SIZE).floor();
+        staged.collision.add(cell.as_ivec2(), tile.position[2], tile.tile_type);
+
+        let sprite = tilemap_handles.sprite(tile.atlas_index);
+        let transform = Transform {
+            translation: Vec3::new(tile.position[0], tile.position[1], tile.position[2]),
+            rotation: Quat::from_xyzw(
+                tile.rotation[0],
+                tile.rotation[1],
+                tile.rotation[2],
+                tile.rotation[3],
+            ),
+            scale: Vec3::new(tile.scale[0], tile.scale[1], tile.scale[2]),
+        };
+
+        let mut entity =
+            world.spawn((sprite, transform, TileMarker::new(tile.tile_type), GameplayEntity));
+        if let Some(item_kind) = tile.pickable {
+            entity.insert(Pickable::new(item_kind));
+        }
+    }
+
+    if end < staged.data.tiles.len() {
+        Ok(LoadStage::Tiles(end))
+    } else {
+        Ok(LoadStage::Props)
+    }
+}
+
+fn spawn_props(world: &mut World, save_data: &SaveData) {
+    let mut commands = world.commands();
+    for prop in &save_data.props {
+        spawn_prop(&mut commands, prop.kind, Vec2::from_array(prop.position));
+    }
+    for saved in &save_data.platforms {
+        let waypoints: Vec<Vec2> = saved.waypoints.iter().map(|w| Vec2::from_array(*w)).collect();
+        if waypoints.len() < 2 || saved.next >= waypoints.len() {
+            warn!(target: targets::SAVE, "Skipping saved platform with a broken route");
+            continue;
+        }
+        let platform = Platform::new(waypoints).with_heading(saved.next, saved.forward);
+        spawn_platform(&mut commands, platform, Vec2::from_array(saved.position));
+    }
+    for saved in &save_data.obelisks {
+        let position = Vec2::from_array(saved.position);
+        commands.spawn(obelisk_bundle(saved.name.clone(), position, saved.attuned));
+    }
+    for saved in &save_data.delta.waypoints {
+        spawn_waypoint(&mut commands, Vec2::from_array(saved.position), saved.label.clone());
+    }
+    for saved in &save_data.delta.ambushes {
+        let (center, size) = (Vec2::from_array(saved.position), Vec2::from_array(saved.size));
+        spawn_ambush(&mut commands, center, size, &saved.enemies.join(","));
+    }
+    for position in &save_data.torches {
+        spawn_torch(&mut commands, Vec3::from_array(*position));
+    }
+    for saved in &save_data.caves {
+        spawn_cave(&mut commands, Vec2::from_array(saved.position), Vec2::from_array(saved.size));
+    }
+    world.flush();
+}
+
+/// The last stage: player, enemies, resources, then into the game.
+fn restore_actors(world: &mut World, staged: StagedLoad) -> Result<(), String> {
+    let StagedLoad {
+        slot,
+        data: save_data,
+        collision,
+        ..
+    } = staged;
+    let characters_list = characters_list(world)?;
+
+    // Spawn player
+    let player_data = &save_data.player;
+    let char_idx = player_data.character_index.min(characters_list.characters.len() - 1);
+    let character_entry = characters_list.characters[char_idx].clone();
+    let now = world.resource::<Time>().elapsed_secs();
+    let buffs: Buffs = player_data
+        .buffs
+        .iter()
+        .map(|saved| Buff {
+            kind: saved.kind,
+            magnitude: saved.magnitude,
+            duration: saved.duration,
+            applied_at: now - (saved.duration - saved.remaining),
+            expires_at: now + saved.remaining,
+            stacks: saved.stacks,
+        })
+        .collect();
+
+    if let Err(e) = spawn_prefab(
+        world,
+        "player",
+        (
+            Transform::from_translation(Vec3::from_array(player_data.position)),
+            player_data.facing,
+            PlayerCombat::new(player_data.power_type.clone()),
+            Health {
+                current: player_data.health_current,
+                max: player_data.health_max,
+                bonus: player_data.health_bonus,
+            },
+            character_entry,
+            buffs,
+        ),
+    ) {
+        error!(target: targets::SAVE, "Failed to restore player: {}", e);
+    }
+
+    // Spawn enemies
+    for enemy_data in &save_data.enemies {
+        let enemy = spawn_prefab(
+            world,
+            &enemy_data.character_name,
+            (
+                Transform::from_translation(Vec3::from_array(enemy_data.position)),
+                enemy_data.facing,
+                Health {
+                    current: enemy_data.health_current,
+                    max: enemy_data.health_max,
+                    bonus: enemy_data.health_bonus,
+                },
+                EnemyLevel(enemy_data.level),
+            ),
+        );
+
+        match enemy {
+            Ok(entity) => {
+                if let Some([x, y]) = enemy_data.home_zone {
+                    world.entity_mut(entity).insert(HomeZone(IVec2::new(x, y)));
+                }
+            }
+            Err(e) => warn!(target: targets::SAVE, "Skipping saved enemy: {}", e),
+        }
+    }
+
+    {
+        let mut director = world.resource_mut::<RespawnDirector>();
+        *director = RespawnDirector::default();
+        director.clock = save_data.respawn.clock;
+        for zone in &save_data.respawn.zones {
+            director.zones.insert(
+                IVec2::new(zone.zone[0], zone.zone[1]),
+                ZoneState {
+                    population: zone.population.clone(),
+                    cleared_at: zone.cleared_at,
+                    last_visited: zone.last_visited,
+                },
+            );
+        }
+    }
+
+    world
+        .resource_mut::<Inventory>()
+        .set_items(save_data.inventory);
+
+    world.resource_mut::<PlayerSpawned>().0 = true;
+    world.resource_mut::<EnemiesSpawned>().0 = true;
+    world.resource_mut::<CurrentCharacterIndex>().index = save_data.player.character_index;
+    world.insert_resource(PlayTime(save_data.play_time));
+    world.insert_resource(EventDirector::restored(
+        save_data.world_events.night,
+        save_data.world_events.phase,
+    ));
+    if let Some(hour) = save_data.hour {
+        world.resource_mut::<DayNightCycle>().hour = hour;
+    }
+    // A cave the player stands in is entered again once the zones update
+    world.insert_resource(LightingEnvironment::Outdoors);
+    world.insert_resource(MapReady);
+    // A regenerated map built its collision map with it. Saved tiles get
+    // back the settings and hazards they were saved with
+    if save_data.regenerated_map().is_none() {
+        let (settings, origin, hazard_seed) = match save_data.map {
+            Some(map) => (
+                map_settings(&map),
+                Vec2::from_array(map.origin),
+                map.hazards.then_some(map.seed),
+            ),
+            // Older saves didn't keep the seed, so their hazards are rolled again
+            None => {
+                let settings = WorldGenSettings::default();
+                (settings, settings.grid_origin(), Some(settings.seed))
+            }
+        };
+        match collision.build(origin, hazard_seed) {
+            Some(map) => {
+                world.insert_resource(map);
+                world.resource_mut::<CollisionMapBuilt>().0 = true;
+            }
+            None => warn!(target: targets::SAVE, "Save has no tiles to build a collision map from"),
+        }
+        world.insert_resource(settings);
+        world.remove_resource::<GeneratedMap>();
+    }
+
+    world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+
+    info!(target: targets::SAVE, "Game loaded from slot {}", slot + 1);
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Busy {
+    Saving,
+    Loading,
+}
+
+/// "Saving..." in a corner while a save is written, or a full-screen
+/// "Loading..." that holds off the menus while a save is read and restored.
+#[derive(Component)]
+pub struct SaveIndicator(Busy);
+
+#[derive(Component)]
+pub struct SaveIndicatorText;
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_save_indicator(
+    mut commands: Commands,
+    saving: Option<Res<SaveTask>>,
+    loading: Option<Res<LoadTask>>,
+    staged: Option<Res<StagedLoad>>,
+    indicators: Query<(Entity, &SaveIndicator)>,
+    mut texts: Query<&mut Text, With<SaveIndicatorText>>,
+    time: Res<Time<Real>>,
+    theme: Res<UiTheme>,
+) {
+    let busy = match (load_in_progress(loading, staged), saving.is_some()) {
+        (true, _) => Some(Busy::Loading),
+        (false, true) => Some(Busy::Saving),
+        (false, false) => None,
+    };
+
+    let mut shown = false;
+    for (entity, indicator) in &indicators {
+        if Some(indicator.0) == busy {
+            shown = true;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let Some(busy) = busy else {
+        return;
+    };
+    let word = match busy {
+        Busy::Saving => "Saving",
+        Busy::Loading => "Loading",
+    };
+    let dots = (time.elapsed_secs() * 2.0) as usize % 4;
+    let label = format!("{}{}", word, ".".repeat(dots));
+
+    if shown {
+        for mut text in &mut texts {
+            **text = label.clone();
+        }
+        return;
+    }
+
+    let text = (SaveIndicatorText, Text::new(label), TextColor(Color::WHITE));
+    match busy {
+        Busy::Saving => {
+            commands
+                .spawn((
+                    SaveIndicator(busy),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        right: Val::Px(24.0),
+                        bottom: Val::Px(24.0),
+                        ..default()
+                    },
+                    GlobalZIndex(200),
+                ))
+                .with_child((text, theme.text(TextRole::Body)));
+        }
+        Busy::Loading => {
+            commands
+                .spawn((
+                    SaveIndicator(busy),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                    // Above every menu and dialog; clicks stop here
+                    GlobalZIndex(200),
+                    FocusPolicy::Block,
+                ))
+                .with_child((text, theme.text(TextRole::Heading)));
+        }
+    }
+}
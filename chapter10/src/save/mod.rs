@@ -1,44 +1,108 @@
 pub mod data;
+pub mod delta;
+pub mod export;
+pub mod io;
+pub mod play_time;
+pub mod slot_list;
 pub mod systems;
 pub mod ui;
 
 use bevy::prelude::*;
+use crate::console::ConsoleAppExt;
 use crate::state::GameState;
 
+pub use delta::WorldDelta;
+pub use io::load_in_progress;
+pub use play_time::PlayTime;
 pub use ui::{SaveLoadUIState, SaveLoadMode};
 
 pub struct SavePlugin;
 
+/// States with a save/load panel: pause, main menu and game over.
+fn save_load_ui_open(state: Res<State<GameState>>) -> bool {
+    matches!(
+        state.get(),
+        GameState::Paused | GameState::MainMenu | GameState::GameOver
+    )
+}
+
+fn save_requested(pending: Res<ui::PendingSaveLoadAction>) -> bool {
+    matches!(pending.0, Some((SaveLoadMode::Save, _)))
+}
+
+fn load_requested(pending: Res<ui::PendingSaveLoadAction>) -> bool {
+    matches!(pending.0, Some((SaveLoadMode::Load, _)))
+}
+
 impl Plugin for SavePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SaveLoadUIState>()
             .init_resource::<ui::PendingSaveLoadAction>()
+            .init_resource::<slot_list::SlotListing>()
+            .init_resource::<WorldDelta>()
+            .init_resource::<PlayTime>()
+            .add_systems(Startup, systems::recover_emergency_save)
             .add_systems(
                 Update,
                 ui::handle_save_load_ui
-                    .run_if(in_state(GameState::Paused).or(in_state(GameState::MainMenu))),
+                    .run_if(save_load_ui_open),
+            )
+            .add_systems(
+                Update,
+                (
+                    slot_list::populate_slot_list,
+                    slot_list::update_visible_slot_rows,
+                    slot_list::scroll_slot_list,
+                )
+                    .chain()
+                    .after(ui::handle_save_load_ui)
+                    .run_if(|ui_state: Res<SaveLoadUIState>| ui_state.active)
+                    .run_if(save_load_ui_open),
             )
             .add_systems(
                 Update,
                 ui::handle_slot_buttons
                     .run_if(|ui_state: Res<SaveLoadUIState>| ui_state.active)
-                    .run_if(in_state(GameState::Paused).or(in_state(GameState::MainMenu))),
+                    .run_if(save_load_ui_open),
+            )
+            .add_systems(
+                Update,
+                ui::handle_delete_buttons
+                    .run_if(|ui_state: Res<SaveLoadUIState>| ui_state.active)
+                    .run_if(save_load_ui_open),
             )
             .add_systems(
                 Update,
                 ui::handle_back_button
                     .run_if(|ui_state: Res<SaveLoadUIState>| ui_state.active)
-                    .run_if(in_state(GameState::Paused).or(in_state(GameState::MainMenu))),
+                    .run_if(save_load_ui_open),
             )
             .add_systems(
                 Update,
-                ui::execute_save
-                    .run_if(|p: Res<ui::PendingSaveLoadAction>| matches!(p.0, Some((SaveLoadMode::Save, _)))),
+                (
+                    io::execute_save.run_if(save_requested),
+                    io::poll_save_task.run_if(resource_exists::<io::SaveTask>),
+                    io::start_load.run_if(load_requested),
+                    io::poll_load_task.run_if(resource_exists::<io::LoadTask>),
+                    io::advance_staged_load.run_if(resource_exists::<io::StagedLoad>),
+                    io::update_save_indicator,
+                )
+                    .chain(),
             )
+            .add_systems(Update, delta::record_consumed_pickables)
             .add_systems(
                 Update,
-                ui::execute_load
-                    .run_if(|p: Res<ui::PendingSaveLoadAction>| matches!(p.0, Some((SaveLoadMode::Load, _)))),
+                play_time::tick_play_time.run_if(in_state(GameState::Playing)),
+            )
+            .add_console_command(
+                "save_export",
+                "Dump a save as RON or JSON: save_export <slot> [file.ron|file.json]",
+                export::save_export_command,
+            )
+            .add_console_command(
+                "save_import",
+                "Write a RON or JSON save into a slot: save_import <file> <slot>",
+                export::save_import_command,
             );
     }
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+// src/save/play_time.rs
+use bevy::prelude::*;
+
+/// Seconds spent playing this game, carried through its saves. Stops with
+/// the virtual clock behind menus, but counts at full speed however fast
+/// gameplay runs.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct PlayTime(pub f64);
+
+pub fn tick_play_time(
+    real_time: Res<Time<Real>>,
+    virtual_time: Res<Time<Virtual>>,
+    mut play_time: ResMut<PlayTime>,
+) {
+    if !virtual_time.is_paused() {
+        play_time.0 += real_time.delta_secs_f64();
+    }
+}
+
+/// "3 h 12 m", or just "12 m" under an hour.
+pub fn format_play_time(secs: f64) -> String {
+    let minutes = (secs / 60.0) as u64;
+    match minutes / 60 {
+        0 => format!("{} m", minutes),
+        hours => format!("{} h {} m", hours, minutes % 60),
+    }
+}
@@ -0,0 +1,270 @@
+// src/save/slot_list.rs
+use std::collections::HashMap;
+
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future::poll_once, IoTaskPool, Task};
+
+use super::data::{SaveMetadata, MAX_SLOTS};
+use super::systems;
+use super::ui::{DeleteSlotButton, SaveLoadMode, SaveLoadUIState, SlotButton};
+use crate::ui::{ButtonColors, GameButton, TextRole, UiTheme};
+
+const SLOT_WIDTH: f32 = 500.0;
+const ROW_HEIGHT: f32 = 50.0;
+const ROW_GAP: f32 = 10.0;
+const ROW_STRIDE: f32 = ROW_HEIGHT + ROW_GAP;
+const VISIBLE_ROWS: usize = 6;
+const LIST_HEIGHT: f32 = VISIBLE_ROWS as f32 * ROW_STRIDE - ROW_GAP;
+/// Rows kept built past each edge of the viewport, so scrolling doesn't
+/// show them popping in
+const ROW_MARGIN: usize = 2;
+/// Arrow key, d-pad and stick scrolling, in pixels per second
+const SCROLL_SPEED: f32 = 600.0;
+
+/// Scrolling viewport with a row per saved slot.
+#[derive(Component)]
+pub struct SlotList;
+
+/// Stand-in for a saved slot's row. Its buttons are only built while it's
+/// near the viewport and its metadata has loaded.
+#[derive(Component)]
+pub struct SlotRow {
+    /// Position in the list, counting the new save entry
+    index: usize,
+    slot: usize,
+}
+
+/// A row whose buttons are currently spawned.
+#[derive(Component)]
+pub struct RowBuilt;
+
+enum SlotMeta {
+    Loading(Task<Option<SaveMetadata>>),
+    Ready(Option<SaveMetadata>),
+}
+
+/// What the open slot list knows about the saves on disk. All of it is read
+/// on the IO pool, so opening the panel doesn't wait on the disk.
+#[derive(Resource, Default)]
+pub struct SlotListing {
+    scan: Option<Task<Vec<usize>>>,
+    metadata: HashMap<usize, SlotMeta>,
+}
+
+impl SlotListing {
+    /// Forgets what was known and starts reading the saves folder again.
+    pub fn rescan(&mut self) {
+        self.metadata.clear();
+        self.scan = Some(IoTaskPool::get().spawn(async { systems::occupied_slots() }));
+    }
+
+    /// A slot's metadata once it has loaded, starting the load on first ask.
+    /// `Some(None)` is a save without a readable metadata file.
+    fn metadata(&mut self, slot: usize) -> Option<Option<SaveMetadata>> {
+        let meta = self.metadata.entry(slot).or_insert_with(|| {
+            SlotMeta::Loading(
+                IoTaskPool::get().spawn(async move { systems::load_slot_metadata(slot) }),
+            )
+        });
+        if let SlotMeta::Loading(task) = meta {
+            *meta = SlotMeta::Ready(block_on(poll_once(task))?);
+        }
+        match meta {
+            SlotMeta::Ready(loaded) => Some(loaded.clone()),
+            SlotMeta::Loading(_) => None,
+        }
+    }
+}
+
+fn row_node() -> Node {
+    Node {
+        height: Val::Px(ROW_HEIGHT),
+        flex_shrink: 0.0,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(ROW_GAP),
+        ..default()
+    }
+}
+
+fn slot_button_node(width: f32) -> Node {
+    Node {
+        width: Val::Px(width),
+        height: Val::Px(ROW_HEIGHT),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+fn max_scroll(rows: usize) -> f32 {
+    (rows as f32 * ROW_STRIDE - ROW_GAP - LIST_HEIGHT).max(0.0)
+}
+
+/// The empty viewport, filled by `populate_slot_list`.
+pub fn slot_list() -> impl Bundle {
+    (
+        SlotList,
+        Node {
+            width: Val::Px(SLOT_WIDTH + ROW_GAP + ROW_HEIGHT),
+            height: Val::Px(LIST_HEIGHT),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(ROW_GAP),
+            overflow: Overflow::scroll_y(),
+            ..default()
+        },
+        ScrollPosition::default(),
+    )
+}
+
+/// Once the saves folder has been read, adds a row per saved slot, headed
+/// by an entry that saves into the first free slot.
+pub fn populate_slot_list(
+    mut commands: Commands,
+    mut listing: ResMut<SlotListing>,
+    ui_state: Res<SaveLoadUIState>,
+    lists: Query<Entity, With<SlotList>>,
+    theme: Res<UiTheme>,
+) {
+    let (Some(task), Ok(list)) = (listing.scan.as_mut(), lists.single()) else {
+        return;
+    };
+    let Some(slots) = block_on(poll_once(task)) else {
+        return;
+    };
+    listing.scan = None;
+
+    let saving = ui_state.mode == SaveLoadMode::Save;
+    commands.entity(list).with_children(|list| {
+        if saving {
+            let free = (0..MAX_SLOTS).find(|slot| !slots.contains(slot));
+            let button = GameButton::new(ButtonColors::MENU).disabled(free.is_none());
+            let label = if free.is_some() { "+ New Save" } else { "All slots are full" };
+            list.spawn(row_node()).with_children(|row| {
+                let mut new_save =
+                    row.spawn(button.bundle(slot_button_node(SLOT_WIDTH + ROW_GAP + ROW_HEIGHT)));
+                if let Some(slot) = free {
+                    new_save.insert(SlotButton(slot));
+                }
+                new_save.with_child(button.label(label, theme.text(TextRole::Body)));
+            });
+        } else if slots.is_empty() {
+            list.spawn((
+                Text::new("No saves yet"),
+                theme.text(TextRole::Body),
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+            ));
+        }
+
+        for (i, slot) in slots.into_iter().enumerate() {
+            list.spawn((
+                SlotRow {
+                    index: i + usize::from(saving),
+                    slot,
+                },
+                row_node(),
+            ));
+        }
+    });
+}
+
+/// Builds the rows near the viewport once their metadata has loaded, and
+/// tears down rows that have scrolled well out of it.
+pub fn update_visible_slot_rows(
+    mut commands: Commands,
+    mut listing: ResMut<SlotListing>,
+    lists: Query<&ScrollPosition, With<SlotList>>,
+    rows: Query<(Entity, &SlotRow, Has<RowBuilt>)>,
+    theme: Res<UiTheme>,
+) {
+    let Ok(scroll) = lists.single() else {
+        return;
+    };
+    let first = (scroll.0.y / ROW_STRIDE) as usize;
+    let near = first.saturating_sub(ROW_MARGIN)..first + VISIBLE_ROWS + ROW_MARGIN;
+
+    for (entity, row, built) in &rows {
+        match (near.contains(&row.index), built) {
+            (true, false) => {
+                if let Some(meta) = listing.metadata(row.slot) {
+                    build_row(&mut commands.entity(entity), row.slot, meta, &theme);
+                }
+            }
+            (false, true) => {
+                commands.entity(entity).despawn_children().remove::<RowBuilt>();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn build_row(row: &mut EntityCommands, slot: usize, meta: Option<SaveMetadata>, theme: &UiTheme) {
+    let label = match meta {
+        Some(meta) => format!("Slot {} — {}", slot + 1, meta.summary()),
+        None => format!("Slot {}", slot + 1),
+    };
+    let button = GameButton::new(ButtonColors::MENU);
+    let delete = GameButton::new(ButtonColors::DANGER);
+
+    row.insert(RowBuilt).with_children(|row| {
+        row.spawn((SlotButton(slot), button.bundle(slot_button_node(SLOT_WIDTH))))
+            .with_child(button.label(label, theme.text(TextRole::Body)));
+        row.spawn((DeleteSlotButton(slot), delete.bundle(slot_button_node(ROW_HEIGHT))))
+            .with_child(delete.label("X", theme.text(TextRole::Body)));
+    });
+}
+
+/// Scroll wheel, arrow keys and the gamepad d-pad or left stick move the list.
+pub fn scroll_slot_list(
+    mut wheel: MessageReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time<Real>>,
+    mut lists: Query<(&mut ScrollPosition, &Children), With<SlotList>>,
+) {
+    let Ok((mut scroll, rows)) = lists.single_mut() else {
+        wheel.clear();
+        return;
+    };
+
+    let mut delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => -event.y * ROW_STRIDE,
+            MouseScrollUnit::Pixel => -event.y,
+        })
+        .sum();
+
+    let mut axis = 0.0;
+    if keys.pressed(KeyCode::ArrowDown) {
+        axis += 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowUp) {
+        axis -= 1.0;
+    }
+    for gamepad in &gamepads {
+        if gamepad.pressed(GamepadButton::DPadDown) {
+            axis += 1.0;
+        }
+        if gamepad.pressed(GamepadButton::DPadUp) {
+            axis -= 1.0;
+        }
+        axis -= gamepad.left_stick().y;
+    }
+    delta += axis.clamp(-1.0, 1.0) * SCROLL_SPEED * time.delta_secs();
+
+    if delta.abs() > f32::EPSILON {
+        scroll.0.y = (scroll.0.y + delta).clamp(0.0, max_scroll(rows.len()));
+    }
+}
+
+/// Dragging anywhere on the list scrolls it, for touch screens.
+pub fn drag_slot_list(
+    drag: On<Pointer<Drag>>,
+    mut lists: Query<(&mut ScrollPosition, &Children), With<SlotList>>,
+) {
+    let Ok((mut scroll, rows)) = lists.single_mut() else {
+        return;
+    };
+    scroll.0.y = (scroll.0.y - drag.delta.y).clamp(0.0, max_scroll(rows.len()));
+}
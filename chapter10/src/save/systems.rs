@@ -1,35 +1,181 @@
 use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
 
 use super::data::*;
+use crate::logging::targets;
 
 pub fn load_save_data(slot: usize) -> Result<SaveData, String> {
-    let path = save_file_path(slot);
-    let file_bytes = fs::read(&path).map_err(|e| format!("Read error: {}", e))?;
+    read_save(&save_file_path(slot))
+}
+
+fn read_save(path: &Path) -> Result<SaveData, String> {
+    let file_bytes = fs::read(path).map_err(|e| format!("Read error: {}", e))?;
+    let save_file = verify_save_file(&file_bytes)?;
+
+    // The version decides whether the rest is worth reading
+    let header: SaveHeader = serde_json::from_slice(&save_file.data)
+        .map_err(|e| format!("Data deserialize error: {}", e))?;
+    check_save_version(header.version)?;
+
+    let mut save_data: SaveData = serde_json::from_slice(&save_file.data)
+        .map_err(|e| format!("Data deserialize error: {}", e))?;
+    // Defaulted fields are filled in now, so a resave is current
+    save_data.version = SAVE_VERSION;
+    Ok(save_data)
+}
 
+fn verify_save_file(file_bytes: &[u8]) -> Result<SaveFile, String> {
     let save_file: SaveFile =
-        bincode::deserialize(&file_bytes).map_err(|e| format!("Deserialize error: {}", e))?;
+        bincode::deserialize(file_bytes).map_err(|e| format!("Deserialize error: {}", e))?;
 
     // Verify checksum
     let computed = compute_checksum(&save_file.data);
     if computed != save_file.checksum {
         return Err("Save file corrupted or tampered with".into());
     }
+    Ok(save_file)
+}
 
-    let save_data: SaveData = bincode::deserialize(&save_file.data)
-        .map_err(|e| format!("Data deserialize error: {}", e))?;
+/// Writes a save into a slot, first moving the intact save it replaces into
+/// the slot's backups.
+pub fn write_slot(slot: usize, save: &EncodedSave) -> Result<(), String> {
+    if let Err(e) = rotate_backups(slot) {
+        // Losing a backup shouldn't cost the save itself
+        warn!(target: targets::SAVE, "Failed to back up slot {}: {}", slot + 1, e);
+    }
+    save.write_to(&save_file_path(slot), &meta_file_path(slot))
+}
 
-    if save_data.version != SAVE_VERSION {
-        return Err(format!(
-            "Incompatible save version: {} (expected {})",
-            save_data.version, SAVE_VERSION
-        ));
+fn rotate_backups(slot: usize) -> Result<(), String> {
+    let Ok(file_bytes) = fs::read(save_file_path(slot)) else {
+        return Ok(());
+    };
+    // A damaged save would only push out a good backup
+    if let Err(e) = verify_save_file(&file_bytes) {
+        warn!(target: targets::SAVE, "Not backing up slot {}: {}", slot + 1, e);
+        return Ok(());
     }
 
-    Ok(save_data)
+    for n in (1..SAVE_BACKUPS).rev() {
+        let older = backup_file_path(slot, n - 1);
+        if !older.exists() {
+            continue;
+        }
+        fs::rename(&older, backup_file_path(slot, n))
+            .map_err(|e| format!("Rotate error: {}", e))?;
+        let _ = fs::remove_file(backup_meta_path(slot, n));
+        let _ = fs::rename(backup_meta_path(slot, n - 1), backup_meta_path(slot, n));
+    }
+
+    fs::write(backup_file_path(slot, 0), &file_bytes)
+        .map_err(|e| format!("Backup error: {}", e))?;
+    // A missing metadata file only costs the backup's label
+    let _ = fs::copy(meta_file_path(slot), backup_meta_path(slot, 0));
+    Ok(())
+}
+
+/// A backup of a slot that passes every check a load makes.
+#[derive(Clone)]
+pub struct SaveBackup {
+    /// 0 is the newest
+    pub index: usize,
+    pub timestamp: String,
+}
+
+/// The newest of a slot's backups that would load. Decodes each one in
+/// full, so it runs on the task pool.
+pub fn find_backup(slot: usize) -> Option<SaveBackup> {
+    (0..SAVE_BACKUPS).find_map(|index| {
+        let data = read_save(&backup_file_path(slot, index)).ok()?;
+        Some(SaveBackup {
+            index,
+            timestamp: data.timestamp,
+        })
+    })
+}
+
+/// Copies a backup over its slot. The backup itself is kept.
+pub fn restore_backup(slot: usize, index: usize) -> Result<(), String> {
+    fs::copy(backup_file_path(slot, index), save_file_path(slot))
+        .map_err(|e| format!("Restore error: {}", e))?;
+    // The damaged save's metadata would label the slot wrongly
+    let _ = fs::remove_file(meta_file_path(slot));
+    let _ = fs::copy(backup_meta_path(slot, index), meta_file_path(slot));
+    info!(target: targets::SAVE, "Restored slot {} from backup {}", slot + 1, index + 1);
+    Ok(())
 }
 
 pub fn load_slot_metadata(slot: usize) -> Option<SaveMetadata> {
     let path = meta_file_path(slot);
     let bytes = fs::read(&path).ok()?;
-    bincode::deserialize(&bytes).ok()
-}
\ No newline at end of file
+    serde_json::from_slice(&bytes).ok()
+}
+
+pub fn delete_slot(slot: usize) -> Result<(), String> {
+    fs::remove_file(save_file_path(slot)).map_err(|e| format!("Delete error: {}", e))?;
+    // A missing metadata file only costs the slot label
+    let _ = fs::remove_file(meta_file_path(slot));
+    for n in 0..SAVE_BACKUPS {
+        let _ = fs::remove_file(backup_file_path(slot, n));
+        let _ = fs::remove_file(backup_meta_path(slot, n));
+    }
+    info!(target: targets::SAVE, "Deleted slot {}", slot + 1);
+    Ok(())
+}
+
+/// Slots with a save file, in slot order. Reads the whole saves folder, so
+/// the slot list runs it on the IO pool.
+pub fn occupied_slots() -> Vec<usize> {
+    let Ok(entries) = fs::read_dir(saves_directory()) else {
+        return Vec::new();
+    };
+    let mut slots: Vec<usize> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let slot = name.strip_prefix("slot_")?.strip_suffix(".sav")?.parse().ok()?;
+            (slot < MAX_SLOTS).then_some(slot)
+        })
+        .collect();
+    slots.sort_unstable();
+    slots
+}
+
+/// Slot holding the most recent save, going by when the save files were
+/// last written. Reads the saves folder, so the main menu runs it on the IO
+/// pool.
+pub fn newest_slot() -> Option<usize> {
+    occupied_slots()
+        .into_iter()
+        .filter_map(|slot| {
+            let written = fs::metadata(save_file_path(slot)).and_then(|m| m.modified()).ok()?;
+            Some((written, slot))
+        })
+        .max()
+        .map(|(_, slot)| slot)
+}
+
+/// Moves an emergency save left by the crash handler into the first free slot.
+pub fn recover_emergency_save() {
+    let save_path = emergency_save_path();
+    if !save_path.exists() {
+        return;
+    }
+
+    let Some(slot) = (0..MAX_SLOTS).find(|&slot| !save_file_path(slot).exists()) else {
+        warn!(
+            target: targets::SAVE,
+            "Emergency save found at {:?} but all slots are full", save_path
+        );
+        return;
+    };
+
+    let moved = fs::rename(&save_path, save_file_path(slot))
+        .and_then(|_| fs::rename(emergency_meta_path(), meta_file_path(slot)));
+    match moved {
+        Ok(()) => info!(target: targets::SAVE, "Recovered emergency save into slot {}", slot + 1),
+        Err(e) => error!(target: targets::SAVE, "Failed to recover emergency save: {}", e),
+    }
+}
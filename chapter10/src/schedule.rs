@@ -0,0 +1,95 @@
+// src/schedule.rs
+use bevy::prelude::*;
+
+use crate::characters::spawn::PlayerSpawned;
+use crate::collision::CollisionMapBuilt;
+use crate::console::console_closed;
+use crate::map::generate::MapReady;
+use crate::state::{GameState, PlayState};
+
+/// Frame phases for gameplay systems, run in this order while playing.
+/// Plugins register into a set instead of relying on ordering across plugins.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    /// Keys read into `PlayerActions`
+    Actions,
+    /// Player input turned into intent (velocity, character state, actions)
+    Input,
+    /// Enemy decision making
+    AI,
+    /// Attacks, projectiles and hits
+    Combat,
+    /// Collision-checked movement
+    Physics,
+    /// Systems that need final positions (depth sorting, camera, pickups)
+    PostPhysics,
+    /// Visual-only updates (animation, healthbars, particles, debug drawing)
+    Render,
+}
+
+/// Run condition: the game is playing and the world is fully set up
+/// (map spawned, collision map built, player spawned).
+///
+/// Every `GameSet` already runs under it; use `.run_if(world_ready)` for
+/// gameplay systems outside the sets.
+pub fn world_ready(
+    state: Option<Res<State<GameState>>>,
+    map_ready: Option<Res<MapReady>>,
+    collision_map_built: Option<Res<CollisionMapBuilt>>,
+    player_spawned: Option<Res<PlayerSpawned>>,
+) -> bool {
+    state.is_some_and(|state| *state.get() == GameState::Playing)
+        && map_ready.is_some()
+        && collision_map_built.is_some_and(|built| built.0)
+        && player_spawned.is_some_and(|spawned| spawned.0)
+}
+
+/// Run condition: true once on the first ready frame after a map is built,
+/// whether for a new run, a restart or a loaded save. For systems that dress
+/// the map (hazard overlays, shrines, ...): what they spawn is despawned with
+/// the rest of the gameplay entities before the next map.
+pub fn world_rebuilt(
+    state: Option<Res<State<GameState>>>,
+    map_ready: Option<Res<MapReady>>,
+    collision_map_built: Option<Res<CollisionMapBuilt>>,
+    player_spawned: Option<Res<PlayerSpawned>>,
+    mut dressed: Local<bool>,
+) -> bool {
+    if collision_map_built.as_ref().is_some_and(|built| built.is_changed()) {
+        *dressed = false;
+    }
+    if *dressed || !world_ready(state, map_ready, collision_map_built, player_spawned) {
+        return false;
+    }
+    *dressed = true;
+    true
+}
+
+pub struct SchedulePlugin;
+
+impl Plugin for SchedulePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            (
+                GameSet::Actions,
+                GameSet::Input,
+                GameSet::AI,
+                GameSet::Combat,
+                GameSet::Physics,
+                GameSet::PostPhysics,
+                GameSet::Render,
+            )
+                .chain()
+                .run_if(world_ready),
+        )
+        // Typing in the dev console shouldn't move the player
+        .configure_sets(Update, (GameSet::Actions, GameSet::Input).run_if(console_closed))
+        // Cutscenes, dialogue and UI screens freeze the player and enemies
+        .configure_sets(
+            Update,
+            (GameSet::Actions, GameSet::Input, GameSet::AI)
+                .run_if(in_state(PlayState::Exploring)),
+        );
+    }
+}
@@ -0,0 +1,52 @@
+// src/scripting/mod.rs
+#[cfg(feature = "scripting")]
+mod runtime;
+
+use bevy::prelude::*;
+
+#[cfg(not(feature = "scripting"))]
+use crate::logging::targets;
+
+/// Hands an enemy's thinking to a Rhai script in place of the built-in chase
+/// and attack logic. Prefabs set it with `Script("scripts/name.rhai")`.
+///
+/// Scripts need the `scripting` feature; without it the enemy falls back to
+/// the built-in AI.
+///
+/// Only enemies are scriptable: the game has no quests yet, so there is no
+/// quest data for scripts to hang off.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct EnemyScript {
+    /// Asset path of the script
+    pub path: String,
+}
+
+pub struct ScriptingPlugin;
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EnemyScript>();
+
+        #[cfg(feature = "scripting")]
+        runtime::build(app);
+
+        #[cfg(not(feature = "scripting"))]
+        app.add_systems(Update, drop_enemy_scripts);
+    }
+}
+
+/// Without a script runtime, scripted enemies get the built-in AI.
+#[cfg(not(feature = "scripting"))]
+fn drop_enemy_scripts(
+    mut commands: Commands,
+    scripted: Query<(Entity, &EnemyScript), Added<EnemyScript>>,
+) {
+    for (entity, script) in &scripted {
+        warn!(
+            target: targets::ENEMY,
+            "{} needs the scripting feature, using the built-in AI", script.path
+        );
+        commands.entity(entity).remove::<EnemyScript>();
+    }
+}
@@ -0,0 +1,249 @@
+// src/scripting/runtime.rs
+use std::collections::HashMap;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use rhai::{CallFnOptions, Dynamic, Engine, Scope, AST, FLOAT};
+
+use super::EnemyScript;
+use crate::characters::input::Player;
+use crate::combat::Health;
+use crate::enemy::orders::follow_enemy_orders;
+use crate::enemy::{AIBehavior, EnemyCombat, EnemyOrders, MoveGoal, SimulationLod, SpawningIn};
+use crate::logging::targets;
+use crate::schedule::GameSet;
+
+/// Operations a script may run per call before it's cut off, so a runaway
+/// loop costs a frame instead of hanging the game.
+const MAX_OPERATIONS: u64 = 50_000;
+const MAX_CALL_LEVELS: usize = 16;
+/// The function each enemy script defines, called with the enemy as `this`.
+const THINK_FN: &str = "think";
+
+pub fn build(app: &mut App) {
+    app.init_asset::<ScriptFile>()
+        .init_asset_loader::<ScriptLoader>()
+        .init_resource::<ScriptEngine>()
+        .init_resource::<ScriptErrors>()
+        .add_systems(
+            Update,
+            (load_enemy_scripts, forget_reloaded_script_errors, run_enemy_scripts)
+                .chain()
+                .before(follow_enemy_orders)
+                .in_set(GameSet::AI),
+        );
+}
+
+/// A compiled .rhai file.
+#[derive(Asset, TypePath)]
+pub struct ScriptFile(AST);
+
+#[derive(Default, TypePath)]
+pub struct ScriptLoader;
+
+impl AssetLoader for ScriptLoader {
+    type Asset = ScriptFile;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        // Parsing doesn't depend on what the engine exposes, so any engine will do
+        Engine::new()
+            .compile(&source)
+            .map(ScriptFile)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rhai"]
+    }
+}
+
+/// The engine scripts run in. It only knows the enemy API below: scripts
+/// can't touch files, the world, or other entities.
+#[derive(Resource)]
+pub struct ScriptEngine(Engine);
+
+impl Default for ScriptEngine {
+    fn default() -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(MAX_CALL_LEVELS);
+        register_enemy_api(&mut engine);
+        Self(engine)
+    }
+}
+
+#[derive(Component)]
+pub struct ScriptHandle(Handle<ScriptFile>);
+
+/// Last error each script path failed with, so it's logged once rather than
+/// every frame for every enemy running it.
+#[derive(Resource, Default)]
+pub struct ScriptErrors(HashMap<String, String>);
+
+/// The enemy as scripts see it: a snapshot to read, plus the orders they give.
+#[derive(Debug, Clone)]
+struct EnemyContext {
+    position: Vec2,
+    player: Option<Vec2>,
+    health_ratio: f32,
+    attack_ready: bool,
+    attack_range: f32,
+    detection_range: f32,
+    orders: EnemyOrders,
+}
+
+impl EnemyContext {
+    fn distance_to_player(&self) -> f32 {
+        self.player.map_or(f32::INFINITY, |player| self.position.distance(player))
+    }
+}
+
+/// Properties and orders available on `this` inside `think()`.
+fn register_enemy_api(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<EnemyContext>("Enemy")
+        .register_get("x", |me: &mut EnemyContext| me.position.x as FLOAT)
+        .register_get("y", |me: &mut EnemyContext| me.position.y as FLOAT)
+        .register_get("sees_player", |me: &mut EnemyContext| {
+            me.distance_to_player() <= me.detection_range
+        })
+        .register_get("player_x", |me: &mut EnemyContext| {
+            me.player.unwrap_or(me.position).x as FLOAT
+        })
+        .register_get("player_y", |me: &mut EnemyContext| {
+            me.player.unwrap_or(me.position).y as FLOAT
+        })
+        .register_get("distance_to_player", |me: &mut EnemyContext| {
+            me.distance_to_player() as FLOAT
+        })
+        .register_get("health", |me: &mut EnemyContext| me.health_ratio as FLOAT)
+        .register_get("attack_ready", |me: &mut EnemyContext| me.attack_ready)
+        .register_get("attack_range", |me: &mut EnemyContext| me.attack_range as FLOAT)
+        .register_get("detection_range", |me: &mut EnemyContext| me.detection_range as FLOAT)
+        .register_fn("stop", |me: &mut EnemyContext| me.orders.movement = MoveGoal::Stop)
+        .register_fn("move_toward", |me: &mut EnemyContext, x: FLOAT, y: FLOAT| {
+            me.orders.movement = MoveGoal::Toward(Vec2::new(x as f32, y as f32));
+        })
+        .register_fn("path_to", |me: &mut EnemyContext, x: FLOAT, y: FLOAT| {
+            me.orders.movement = MoveGoal::PathTo(Vec2::new(x as f32, y as f32));
+        })
+        .register_fn("chase", |me: &mut EnemyContext| {
+            if let Some(player) = me.player {
+                me.orders.movement = MoveGoal::PathTo(player);
+            }
+        })
+        .register_fn("flee", |me: &mut EnemyContext| {
+            if let Some(player) = me.player {
+                me.orders.movement = MoveGoal::Toward(me.position * 2.0 - player);
+            }
+        })
+        .register_fn("attack", |me: &mut EnemyContext| me.orders.attack = true);
+}
+
+fn load_enemy_scripts(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    scripted: Query<(Entity, &EnemyScript), Added<EnemyScript>>,
+) {
+    for (entity, script) in &scripted {
+        commands.entity(entity).insert((
+            ScriptHandle(asset_server.load(script.path.clone())),
+            EnemyOrders::default(),
+        ));
+    }
+}
+
+/// An edited script gets a fresh start: its next error is logged even if
+/// it's the same as before.
+fn forget_reloaded_script_errors(
+    asset_server: Res<AssetServer>,
+    mut events: MessageReader<AssetEvent<ScriptFile>>,
+    mut errors: ResMut<ScriptErrors>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        if let Some(path) = asset_server.get_path(*id) {
+            errors.0.remove(&path.to_string());
+        }
+    }
+}
+
+/// Calls each scripted enemy's `think()` to refresh its orders. A script
+/// that fails to run leaves its enemy standing still rather than stopping
+/// the game. Its error is logged once, and again only if it changes.
+fn run_enemy_scripts(
+    time: Res<Time>,
+    engine: Res<ScriptEngine>,
+    scripts: Res<Assets<ScriptFile>>,
+    mut enemies: Query<
+        (
+            &Transform,
+            &EnemyScript,
+            &ScriptHandle,
+            &AIBehavior,
+            Option<&EnemyCombat>,
+            Option<&Health>,
+            &mut SimulationLod,
+            &mut EnemyOrders,
+        ),
+        Without<SpawningIn>,
+    >,
+    player: Query<&Transform, With<Player>>,
+    mut last_errors: ResMut<ScriptErrors>,
+) {
+    let player = player.single().ok().map(|t| t.translation.truncate());
+    let frame_delta = time.delta_secs();
+
+    for (transform, script, handle, ai, combat, health, mut lod, mut orders) in &mut enemies {
+        if lod.tick(frame_delta).is_none() {
+            continue;
+        }
+        let Some(ScriptFile(ast)) = scripts.get(&handle.0) else {
+            continue;
+        };
+
+        let context = EnemyContext {
+            position: transform.translation.truncate(),
+            player,
+            health_ratio: health.map_or(1.0, Health::ratio),
+            attack_ready: combat.is_some_and(|c| c.cooldown.is_finished()),
+            attack_range: ai.attack_range,
+            detection_range: ai.detection_range,
+            orders: EnemyOrders::default(),
+        };
+        let mut this = Dynamic::from(context);
+        let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut this);
+        let result = engine.0.call_fn_with_options::<Dynamic>(
+            options,
+            &mut Scope::new(),
+            ast,
+            THINK_FN,
+            (),
+        );
+
+        *orders = match result {
+            Ok(_) => this.try_cast::<EnemyContext>().map(|c| c.orders).unwrap_or_default(),
+            Err(e) => {
+                let error = e.to_string();
+                if last_errors.0.get(&script.path) != Some(&error) {
+                    warn!(target: targets::ENEMY, "{} failed: {}", script.path, error);
+                    last_errors.0.insert(script.path.clone(), error);
+                }
+                EnemyOrders::default()
+            }
+        };
+    }
+}
@@ -0,0 +1,24 @@
+// src/state/cleanup.rs
+use bevy::prelude::*;
+
+/// Marker for everything that belongs to a running game (tiles, characters,
+/// projectiles, particles). Inserted by every gameplay spawner so teardown
+/// doesn't need to know each feature's component types.
+///
+/// Children (healthbars, projectile effects) don't need it: they are despawned
+/// with their parent.
+#[derive(Component, Default)]
+pub struct GameplayEntity;
+
+/// Despawns every `GameplayEntity`. Used by both the return-to-menu cleanup and save loading.
+pub fn despawn_all_gameplay(world: &mut World) {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, With<GameplayEntity>>()
+        .iter(world)
+        .collect();
+
+    for entity in entities {
+        // Tagged children may already be gone with their parent
+        let _ = world.try_despawn(entity);
+    }
+}
@@ -0,0 +1,181 @@
+// src/state/confirm.rs
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use crate::logging::targets;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+
+type ConfirmAction = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Modal yes/no question. Spawn one with
+/// `commands.spawn(ConfirmDialog::open("Quit the game?", |world| ...))`;
+/// `on_yes` runs with full world access if the player confirms.
+///
+/// While a dialog is open it swallows every key press and blocks clicks on
+/// the UI behind it. Escape cancels, Enter confirms.
+#[derive(Component)]
+pub struct ConfirmDialog {
+    title: String,
+    on_yes: Option<ConfirmAction>,
+}
+
+impl ConfirmDialog {
+    pub fn open(title: impl Into<String>, on_yes: impl FnOnce(&mut World) + Send + Sync + 'static) -> Self {
+        Self {
+            title: title.into(),
+            on_yes: Some(Box::new(on_yes)),
+        }
+    }
+}
+
+/// Shared by the main and pause menus' Quit buttons.
+pub fn quit_dialog() -> ConfirmDialog {
+    ConfirmDialog::open("Quit the game?", |world| {
+        world.write_message(AppExit::Success);
+    })
+}
+
+/// Open dialogs, oldest first. Only the last one answers keys and it's drawn
+/// over the rest; the others wait their turn.
+#[derive(Resource, Default)]
+pub struct ConfirmStack(Vec<Entity>);
+
+#[derive(Component)]
+pub struct ConfirmChoice {
+    dialog: Entity,
+    confirm: bool,
+}
+
+/// Builds the dialog's UI when one is spawned.
+pub fn build_confirm_dialog(
+    add: On<Add, ConfirmDialog>,
+    mut commands: Commands,
+    dialogs: Query<&ConfirmDialog>,
+    mut stack: ResMut<ConfirmStack>,
+    theme: Res<UiTheme>,
+) {
+    let dialog = add.entity;
+    let Ok(title) = dialogs.get(dialog).map(|d| d.title.clone()) else {
+        return;
+    };
+    stack.0.push(dialog);
+    let z_index = 150 + stack.0.len() as i32;
+
+    commands
+        .entity(dialog)
+        .insert((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            // Above menus, the save/load panel and older dialogs; clicks stop here
+            GlobalZIndex(z_index),
+            FocusPolicy::Block,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(24.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    row_gap: Val::Px(20.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.08, 0.08, 0.15, 0.98)),
+                BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new(title),
+                    theme.text(TextRole::Subheading),
+                    TextColor(Color::WHITE),
+                ));
+                panel
+                    .spawn(Node {
+                        column_gap: Val::Px(16.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for (confirm, label, colors) in [
+                            (true, "Yes", ButtonColors::MENU),
+                            (false, "No", ButtonColors::DANGER),
+                        ] {
+                            let button = GameButton::new(colors);
+                            row.spawn((
+                                ConfirmChoice { dialog, confirm },
+                                button.bundle(Node {
+                                    width: Val::Px(140.0),
+                                    height: Val::Px(45.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                }),
+                            ))
+                            .with_child(button.label(label, theme.text(TextRole::Body)));
+                        }
+                    });
+            });
+        });
+}
+
+/// However a dialog goes, answered or despawned with its screen, the one
+/// under it is next.
+pub fn forget_confirm_dialog(remove: On<Remove, ConfirmDialog>, mut stack: ResMut<ConfirmStack>) {
+    stack.0.retain(|&dialog| dialog != remove.entity);
+}
+
+fn resolve(commands: &mut Commands, entity: Entity, dialog: &mut ConfirmDialog, confirm: bool) {
+    debug!(target: targets::STATE, "'{}' {}", dialog.title, if confirm { "confirmed" } else { "cancelled" });
+    if let (true, Some(on_yes)) = (confirm, dialog.on_yes.take()) {
+        commands.queue(move |world: &mut World| on_yes(world));
+    }
+    commands.entity(entity).despawn();
+}
+
+pub fn handle_confirm_buttons(
+    mut commands: Commands,
+    mut clicks: MessageReader<ButtonClicked>,
+    choices: Query<&ConfirmChoice>,
+    mut dialogs: Query<&mut ConfirmDialog>,
+) {
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(choice) = choices.get(*entity) else {
+            continue;
+        };
+        if let Ok(mut dialog) = dialogs.get_mut(choice.dialog) {
+            resolve(&mut commands, choice.dialog, &mut dialog, choice.confirm);
+        }
+    }
+}
+
+/// Runs right after input is collected: Escape and Enter answer the dialog,
+/// and every key press is cleared so menus and gameplay behind it never see one.
+pub fn trap_confirm_keys(
+    mut commands: Commands,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    stack: Res<ConfirmStack>,
+    mut dialogs: Query<&mut ConfirmDialog>,
+) {
+    let Some(&entity) = stack.0.last() else {
+        return;
+    };
+    let Ok(mut dialog) = dialogs.get_mut(entity) else {
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        resolve(&mut commands, entity, &mut dialog, false);
+    } else if keys.just_pressed(KeyCode::Enter) {
+        resolve(&mut commands, entity, &mut dialog, true);
+    }
+
+    let pressed: Vec<KeyCode> = keys.get_just_pressed().copied().collect();
+    for key in pressed {
+        keys.clear_just_pressed(key);
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Which ruleset the current run uses. Selected from the main menu.
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub enum GameMode {
+    /// The regular procedurally generated world with hand-placed enemies.
+    #[default]
+    Story,
+    /// Endless enemy waves on a smaller map, scored on a local leaderboard.
+    Survival,
+}
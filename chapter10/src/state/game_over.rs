@@ -1,28 +1,44 @@
 // src/state/game_over.rs
 use bevy::prelude::*;
 
-use crate::characters::input::Player;
 use crate::characters::spawn::PlayerSpawned;
-use crate::combat::healthbar::HealthBarOwner;
-use crate::combat::systems::{Projectile, ProjectileEffect};
-use crate::enemy::{spawn::EnemiesSpawned, Enemy};
-use crate::particles::components::{Particle, ParticleEmitter};
-use crate::collision::{TileMarker, CollisionMapBuilt};
+use crate::checkpoints::{ActiveCheckpoint, RespawnAtCheckpoint};
+use crate::enemy::{respawn::RespawnDirector, spawn::EnemiesSpawned};
+use crate::collision::CollisionMapBuilt;
 use crate::inventory::Inventory;
 use crate::map::generate::MapReady;
+use crate::map::level::LevelLayout;
+use crate::save::ui::SaveLoadMode;
+use crate::save::{PlayTime, SaveLoadUIState, WorldDelta};
 
-use super::GameState;
+use super::{despawn_all_gameplay, GameMode, GameState};
+use crate::logging::targets;
+use crate::transitions::TransitionRequest;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
 
-#[derive(Component)]
-pub struct GameOverScreen;
+#[derive(Component, Clone, Copy)]
+pub enum GameOverButton {
+    Respawn,
+    LoadSave,
+    MainMenu,
+}
+
+pub fn spawn_game_over_screen(
+    mut commands: Commands,
+    game_mode: Res<GameMode>,
+    checkpoint: Res<ActiveCheckpoint>,
+    theme: Res<UiTheme>,
+) {
+    // Survival runs end for good; story players can come back at a lit checkpoint
+    let can_respawn = *game_mode == GameMode::Story && checkpoint.position.is_some();
 
-pub fn spawn_game_over_screen(mut commands: Commands) {
     commands
         .spawn((
-            GameOverScreen,
+            DespawnOnExit(GameState::GameOver),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 ..default()
@@ -32,82 +48,112 @@ pub fn spawn_game_over_screen(mut commands: Commands) {
         .with_children(|parent| {
             parent.spawn((
                 Text::new("GAME OVER\n\nPress R to restart"),
-                TextFont {
-                    font_size: 48.0,
-                    ..default()
-                },
+                theme.text(TextRole::Title),
                 TextColor(Color::WHITE),
                 TextLayout::new_with_justify(Justify::Center),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
             ));
+
+            let buttons = [
+                (GameOverButton::Respawn, "Respawn at checkpoint"),
+                (GameOverButton::LoadSave, "Load save"),
+                (GameOverButton::MainMenu, "Main menu"),
+            ];
+
+            for (btn_type, label) in buttons {
+                if matches!(btn_type, GameOverButton::Respawn) && !can_respawn {
+                    continue;
+                }
+
+                let button = GameButton::new(ButtonColors::MENU);
+
+                parent
+                    .spawn((
+                        btn_type,
+                        button.bundle(Node {
+                            width: Val::Px(300.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::vertical(Val::Px(5.0)),
+                            ..default()
+                        }),
+                    ))
+                    .with_child(button.label(label, theme.text(TextRole::Button)));
+            }
         });
 
-    info!("Game over screen spawned");
+    debug!(target: targets::STATE, "Game over screen spawned");
 }
 
-pub fn despawn_game_over_screen(mut commands: Commands, query: Query<Entity, With<GameOverScreen>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn();
+pub fn handle_game_over_buttons(
+    mut commands: Commands,
+    mut ui_state: ResMut<SaveLoadUIState>,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&GameOverButton>,
+    mut transitions: MessageWriter<TransitionRequest>,
+) {
+    if ui_state.active {
+        clicks.clear();
+        return;
+    }
+
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
+            continue;
+        };
+
+        match button {
+            GameOverButton::Respawn => {
+                commands.trigger(RespawnAtCheckpoint);
+                transitions.write(TransitionRequest { to: GameState::Playing });
+            }
+            GameOverButton::LoadSave => {
+                ui_state.active = true;
+                ui_state.mode = SaveLoadMode::Load;
+            }
+            GameOverButton::MainMenu => {
+                transitions.write(TransitionRequest { to: GameState::MainMenu });
+            }
+        }
     }
-    info!("Game over screen despawned");
 }
 
 pub fn handle_restart_input(
     input: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
+    mut transitions: MessageWriter<TransitionRequest>,
 ) {
     if input.just_pressed(KeyCode::KeyR) {
-        info!("Restarting game...");
-        next_state.set(GameState::Loading);
+        info!(target: targets::STATE, "Restarting game...");
+        transitions.write(TransitionRequest { to: GameState::Loading });
     }
 }
 
 /// Despawns all gameplay entities and resets spawn flags so they re-trigger.
 pub fn cleanup_game_world(
     mut commands: Commands,
-    tiles: Query<Entity, With<TileMarker>>,
-    players: Query<Entity, With<Player>>,
-    enemies: Query<Entity, With<Enemy>>,
-    projectiles: Query<Entity, With<Projectile>>,
-    projectile_effects: Query<Entity, With<ProjectileEffect>>,
-    emitters: Query<Entity, With<ParticleEmitter>>,
-    particles: Query<Entity, With<Particle>>,
-    healthbars: Query<Entity, With<HealthBarOwner>>,
     mut player_spawned: ResMut<PlayerSpawned>,
     mut enemies_spawned: ResMut<EnemiesSpawned>,
     mut collision_map_built: ResMut<CollisionMapBuilt>,
-    mut inventory: ResMut<Inventory>
+    mut inventory: ResMut<Inventory>,
+    mut respawn_director: ResMut<RespawnDirector>,
+    mut checkpoint: ResMut<ActiveCheckpoint>,
 ) {
-
-    for entity in tiles.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in players.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in enemies.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in projectiles.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in projectile_effects.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in emitters.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in particles.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in healthbars.iter() {
-        commands.entity(entity).despawn();
-    }
+    commands.queue(despawn_all_gameplay);
 
     player_spawned.0 = false;
     enemies_spawned.0 = false;
 
     collision_map_built.0 = false;
     inventory.set_items(Default::default());
+    *respawn_director = RespawnDirector::default();
+    *checkpoint = ActiveCheckpoint::default();
     commands.remove_resource::<MapReady>();
+    commands.remove_resource::<LevelLayout>();
+    commands.insert_resource(WorldDelta::default());
+    commands.insert_resource(PlayTime::default());
 
 }
\ No newline at end of file
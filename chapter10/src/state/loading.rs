@@ -3,16 +3,22 @@ use std::sync::atomic::Ordering;
 use bevy::prelude::*;
 
 use crate::map::generate::{MapGenProgress, MapReady};
-
-#[derive(Component)]
-pub struct LoadingScreen;
+use super::GameState;
+use crate::logging::targets;
+use crate::transitions::TransitionRequest;
+use crate::ui::{TextRole, UiTheme};
 
 #[derive(Component)]
 pub struct LoadingText;
 
-pub fn spawn_loading_screen(mut commands: Commands) {
+/// Why the world couldn't be built. The loading screen shows it instead of
+/// waiting forever, and Escape goes back to the main menu.
+#[derive(Resource, Debug)]
+pub struct LoadingFailed(pub String);
+
+pub fn spawn_loading_screen(mut commands: Commands, theme: Res<UiTheme>) {
     commands.spawn((
-        LoadingScreen,
+        DespawnOnExit(GameState::Loading),
         Node {
             width: Val::Percent(100.0),
             height: Val::Percent(100.0),
@@ -25,25 +31,25 @@ pub fn spawn_loading_screen(mut commands: Commands) {
         parent.spawn((
             LoadingText,
             Text::new("Loading..."),
-            TextFont {
-                font_size: 48.0,
-                ..default()
-            },
+            theme.text(TextRole::Title),
             TextColor(Color::WHITE),
         ));
     });
     
-    info!("Loading screen spawned");
+    debug!(target: targets::STATE, "Loading screen spawned");
 }
 
 pub fn animate_loading(
-    time: Res<Time>,
+    time: Res<Time<Real>>, // UI runs on real time, unaffected by GameSpeed
     mut query: Query<&mut Text, With<LoadingText>>,
     progress: Option<Res<MapGenProgress>>,
     map_ready: Option<Res<MapReady>>,
+    failed: Option<Res<LoadingFailed>>,
 ) {
     for mut text in query.iter_mut() {
-        if map_ready.is_some() {
+        if let Some(failed) = &failed {
+            **text = format!("{}\nPress Escape to return to the menu", failed.0);
+        } else if map_ready.is_some() {
             **text = "Starting...".to_string();
         } else if let Some(ref progress) = progress {
             // Read atomic progress safely
@@ -56,13 +62,15 @@ pub fn animate_loading(
     }
 }
 
-pub fn despawn_loading_screen(
-    mut commands: Commands,
-    query: Query<Entity, With<LoadingScreen>>,
+pub fn leave_failed_loading(
+    input: Res<ButtonInput<KeyCode>>,
+    mut transitions: MessageWriter<TransitionRequest>,
 ) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn();
+    if input.just_pressed(KeyCode::Escape) {
+        transitions.write(TransitionRequest { to: GameState::MainMenu });
     }
-    
-    info!("Loading screen despawned");
-}
\ No newline at end of file
+}
+
+pub fn clear_loading_failure(mut commands: Commands) {
+    commands.remove_resource::<LoadingFailed>();
+}
@@ -1,24 +1,57 @@
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future::poll_once, IoTaskPool, Task};
 
+use crate::accessibility::AccessibilityUIState;
+use crate::map::generate::WorldGenSettings;
 use crate::save::SaveLoadUIState;
-use crate::save::ui::SaveLoadMode;
+use crate::save::systems::{load_slot_metadata, newest_slot};
+use crate::save::ui::{PendingSaveLoadAction, SaveLoadMode};
+use crate::transitions::TransitionRequest;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+use crate::video::VideoUIState;
 
-use super::GameState;
-
-#[derive(Component)]
-pub struct MainMenuScreen;
+use super::confirm::quit_dialog;
+use super::{GameMode, GameState};
 
 #[derive(Component)]
 pub enum MainMenuButton {
+    Continue,
     NewGame,
+    Survival,
     LoadGame,
+    Video,
+    Accessibility,
     Quit,
 }
 
-pub fn spawn_main_menu(mut commands: Commands) {
+/// The save Continue loads. Looked up on the IO pool each time the menu
+/// opens; the button stays greyed out until one is found.
+#[derive(Resource, Default)]
+pub struct ContinueSlot {
+    scan: Option<Task<Option<(usize, Option<String>)>>>,
+    slot: Option<usize>,
+}
+
+/// Caption under Continue with what it would load.
+#[derive(Component)]
+pub struct ContinueSummary;
+
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    mut continue_slot: ResMut<ContinueSlot>,
+    theme: Res<UiTheme>,
+) {
+    *continue_slot = ContinueSlot {
+        scan: Some(IoTaskPool::get().spawn(async {
+            let slot = newest_slot()?;
+            Some((slot, load_slot_metadata(slot).map(|meta| meta.summary())))
+        })),
+        slot: None,
+    };
+
     commands
         .spawn((
-            MainMenuScreen,
+            DespawnOnExit(GameState::MainMenu),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -32,10 +65,7 @@ pub fn spawn_main_menu(mut commands: Commands) {
         .with_children(|parent| {
             parent.spawn((
                 Text::new("Main Menu"),
-                TextFont {
-                    font_size: 64.0,
-                    ..default()
-                },
+                theme.text(TextRole::Title),
                 TextColor(Color::srgb(0.8, 0.7, 1.0)),
                 Node {
                     margin: UiRect::bottom(Val::Px(60.0)),
@@ -44,83 +74,127 @@ pub fn spawn_main_menu(mut commands: Commands) {
             ));
 
             let buttons = [
+                (MainMenuButton::Continue, "Continue"),
                 (MainMenuButton::NewGame, "New Game"),
+                (MainMenuButton::Survival, "Survival"),
                 (MainMenuButton::LoadGame, "Load Game"),
+                (MainMenuButton::Video, "Video"),
+                (MainMenuButton::Accessibility, "Accessibility"),
                 (MainMenuButton::Quit, "Quit"),
             ];
 
             for (btn_type, label) in buttons {
+                let is_continue = matches!(btn_type, MainMenuButton::Continue);
+                let button = GameButton::new(ButtonColors::MENU).disabled(is_continue);
+
                 parent
                     .spawn((
                         btn_type,
-                        Button,
-                        Node {
+                        button.bundle(Node {
                             width: Val::Px(300.0),
                             height: Val::Px(55.0),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
                             margin: UiRect::vertical(Val::Px(8.0)),
                             ..default()
-                        },
-                        BackgroundColor(Color::srgba(0.15, 0.15, 0.3, 0.9)),
+                        }),
                     ))
-                    .with_children(|btn_parent| {
-                        btn_parent.spawn((
-                            Text::new(label),
-                            TextFont {
-                                font_size: 28.0,
-                                ..default()
-                            },
-                            TextColor(Color::WHITE),
-                        ));
-                    });
+                    .with_child(button.label(label, theme.text(TextRole::Subheading)));
+
+                // What Continue would load, filled in by `update_continue_button`
+                if is_continue {
+                    parent.spawn((
+                        ContinueSummary,
+                        Text::new(""),
+                        theme.text(TextRole::Caption),
+                        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                        Node {
+                            margin: UiRect::bottom(Val::Px(8.0)),
+                            ..default()
+                        },
+                    ));
+                }
             }
         });
 }
 
-pub fn despawn_main_menu(mut commands: Commands, query: Query<Entity, With<MainMenuScreen>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn();
+/// Enables Continue and captions it once the newest save has been found.
+pub fn update_continue_button(
+    mut continue_slot: ResMut<ContinueSlot>,
+    mut buttons: Query<(&MainMenuButton, &mut GameButton)>,
+    mut summaries: Query<&mut Text, With<ContinueSummary>>,
+) {
+    let Some(task) = continue_slot.scan.as_mut() else {
+        return;
+    };
+    let Some(newest) = block_on(poll_once(task)) else {
+        return;
+    };
+    continue_slot.scan = None;
+    let Some((slot, summary)) = newest else {
+        return;
+    };
+    continue_slot.slot = Some(slot);
+
+    for (button_type, mut button) in &mut buttons {
+        if matches!(button_type, MainMenuButton::Continue) {
+            button.disabled = false;
+        }
+    }
+    if let (Ok(mut text), Some(summary)) = (summaries.single_mut(), summary) {
+        text.0 = summary;
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_main_menu_buttons(
-    mut next_state: ResMut<NextState<GameState>>,
+    mut transitions: MessageWriter<TransitionRequest>,
     mut ui_state: ResMut<SaveLoadUIState>,
-    interaction_query: Query<(&Interaction, &MainMenuButton), Changed<Interaction>>,
-    mut exit: MessageWriter<AppExit>,
+    mut video_ui: ResMut<VideoUIState>,
+    mut accessibility_ui: ResMut<AccessibilityUIState>,
+    mut pending: ResMut<PendingSaveLoadAction>,
+    continue_slot: Res<ContinueSlot>,
+    mut game_mode: ResMut<GameMode>,
+    mut world_gen: ResMut<WorldGenSettings>,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&MainMenuButton>,
+    mut commands: Commands,
 ) {
-    for (interaction, button) in interaction_query.iter() {
-        if *interaction != Interaction::Pressed {
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
             continue;
-        }
+        };
 
         match button {
+            MainMenuButton::Continue => {
+                // Straight to the load path, skipping the slot list
+                if let Some(slot) = continue_slot.slot {
+                    pending.0 = Some((SaveLoadMode::Load, slot));
+                }
+            }
             MainMenuButton::NewGame => {
-                next_state.set(GameState::Loading);
+                *game_mode = GameMode::Story;
+                *world_gen = WorldGenSettings::default();
+                transitions.write(TransitionRequest { to: GameState::Loading });
+            }
+            MainMenuButton::Survival => {
+                *game_mode = GameMode::Survival;
+                *world_gen = WorldGenSettings::survival();
+                transitions.write(TransitionRequest { to: GameState::Loading });
             }
             MainMenuButton::LoadGame => {
                 ui_state.active = true;
                 ui_state.mode = SaveLoadMode::Load;
             }
+            MainMenuButton::Video => {
+                video_ui.active = true;
+            }
+            MainMenuButton::Accessibility => {
+                accessibility_ui.active = true;
+            }
             MainMenuButton::Quit => {
-                exit.write(AppExit::Success);
+                commands.spawn(quit_dialog());
             }
         }
     }
 }
-
-pub fn handle_main_menu_hover(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<MainMenuButton>),
-    >,
-) {
-    for (interaction, mut bg) in interaction_query.iter_mut() {
-        *bg = match interaction {
-            Interaction::Hovered => BackgroundColor(Color::srgba(0.25, 0.25, 0.5, 0.9)),
-            Interaction::Pressed => BackgroundColor(Color::srgba(0.35, 0.35, 0.6, 0.9)),
-            Interaction::None => BackgroundColor(Color::srgba(0.15, 0.15, 0.3, 0.9)),
-        };
-    }
-}
\ No newline at end of file
@@ -1,16 +1,30 @@
+mod cleanup;
+mod confirm;
 mod game_over;
+mod game_mode;
 mod game_state;
 mod loading;
 mod pause;
+mod play_state;
 pub mod main_menu;
 
-use bevy::prelude::*;
+use bevy::{input::InputSystems, prelude::*, window::WindowFocused};
 use crate::characters::spawn::CharactersListResource;
 use crate::characters::config::CharactersList;
 use crate::map::generate::MapReady;
-use crate::save::SaveLoadUIState;
+use crate::combat::PowerRegistry;
+use crate::prefabs::PrefabRegistry;
+use crate::save::{load_in_progress, SaveLoadUIState};
+use crate::accessibility::AccessibilityUIState;
+use crate::video::{VideoSettings, VideoUIState};
+use crate::logging::targets;
 
+pub use cleanup::{despawn_all_gameplay, GameplayEntity};
+pub use confirm::ConfirmDialog;
+pub use game_mode::GameMode;
 pub use game_state::GameState;
+pub use loading::LoadingFailed;
+pub use play_state::PlayState;
 
 pub struct StatePlugin;
 
@@ -18,19 +32,22 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app
             .init_state::<GameState>()
+            .add_sub_state::<PlayState>()
+            .register_type::<GameMode>()
+            .init_resource::<GameMode>()
+            .init_resource::<confirm::ConfirmStack>()
+            .init_resource::<main_menu::ContinueSlot>()
+            .add_observer(confirm::build_confirm_dialog)
+            .add_observer(confirm::forget_confirm_dialog)
+            .add_systems(PreUpdate, confirm::trap_confirm_keys.after(InputSystems))
+            .add_systems(Update, confirm::handle_confirm_buttons)
             .add_systems(
                 OnEnter(GameState::MainMenu),
                 (game_over::cleanup_game_world, main_menu::spawn_main_menu).chain(),
             )
-            .add_systems(OnExit(GameState::MainMenu), main_menu::despawn_main_menu)
             .add_systems(
                 Update,
-                main_menu::handle_main_menu_buttons
-                    .run_if(in_state(GameState::MainMenu)),
-            )
-            .add_systems(
-                Update,
-                main_menu::handle_main_menu_hover
+                (main_menu::update_continue_button, main_menu::handle_main_menu_buttons)
                     .run_if(in_state(GameState::MainMenu)),
             )
             // Loading state systems
@@ -38,35 +55,40 @@ impl Plugin for StatePlugin {
             .add_systems(Update, (
                 check_assets_loaded,
                 loading::animate_loading,
+                loading::leave_failed_loading.run_if(resource_exists::<LoadingFailed>),
             ).run_if(in_state(GameState::Loading)))
-            .add_systems(OnExit(GameState::Loading), (
-                loading::despawn_loading_screen
-            ))
+            .add_systems(OnExit(GameState::Loading), loading::clear_loading_failure)
                 // Pause state systems
             .add_systems(OnEnter(GameState::Paused), pause::spawn_pause_menu)
-            .add_systems(OnExit(GameState::Paused), (pause::despawn_pause_menu, close_save_load_ui))
+            .add_systems(OnExit(GameState::Paused), close_save_load_ui)
             .add_systems(
                 Update,
                 pause::handle_pause_buttons
                     .run_if(in_state(GameState::Paused)),
             )
-            .add_systems(
-                Update,
-                pause::handle_pause_hover
-                    .run_if(in_state(GameState::Paused)),
-            )
             // Pause toggle (works in Playing or Paused states)
             .add_systems(Update, 
-                toggle_pause.run_if(in_state(GameState::Playing).or(in_state(GameState::Paused)))
+                toggle_pause
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Paused)))
+                    .run_if(not(load_in_progress))
             )
+            .add_systems(Update, pause_on_focus_loss.run_if(in_state(GameState::Playing)))
             .add_systems(OnEnter(GameState::GameOver), game_over::spawn_game_over_screen)
+            .add_systems(OnExit(GameState::GameOver), close_save_load_ui)
+            // Respawning at a checkpoint or loading a save goes straight back to
+            // Playing, and must keep the world (`State` already holds the new state)
             .add_systems(
                 OnExit(GameState::GameOver),
-                (game_over::despawn_game_over_screen, game_over::cleanup_game_world),
+                game_over::cleanup_game_world.run_if(not(in_state(GameState::Playing))),
             )
             .add_systems(
                 Update,
-                game_over::handle_restart_input.run_if(in_state(GameState::GameOver)),
+                (
+                    game_over::handle_restart_input,
+                    game_over::handle_game_over_buttons,
+                )
+                    .run_if(in_state(GameState::GameOver))
+                    .run_if(not(load_in_progress)),
             );
     }
 }
@@ -75,14 +97,16 @@ fn check_assets_loaded(
     characters_list_res: Option<Res<CharactersListResource>>,
     characters_lists: Res<Assets<CharactersList>>,
     map_ready: Option<Res<MapReady>>,
+    prefabs: Res<PrefabRegistry>,
+    powers: Res<PowerRegistry>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     let Some(res) = characters_list_res else {
         return;
     };
     
-    if characters_lists.get(&res.handle).is_some() && map_ready.is_some()  {
-        info!("Assets loaded, transitioning to Playing!");
+    if characters_lists.get(&res.handle).is_some() && map_ready.is_some() && prefabs.is_loaded() && powers.is_loaded() {
+        info!(target: targets::STATE, "Assets loaded, transitioning to Playing!");
         next_state.set(GameState::Playing);
     }
 }
@@ -90,27 +114,51 @@ fn check_assets_loaded(
 fn toggle_pause(
     input: Res<ButtonInput<KeyCode>>,
     current_state: Res<State<GameState>>,
+    play_state: Option<Res<State<PlayState>>>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
     ui_state: Res<SaveLoadUIState>, 
+    video_ui: Res<VideoUIState>,
+    accessibility_ui: Res<AccessibilityUIState>,
 ) {
-    if input.just_pressed(KeyCode::Escape) {
+    if !input.just_pressed(KeyCode::Escape) {
+        return;
+    }
 
-        if ui_state.active {
-            return;
+    match current_state.get() {
+        GameState::Playing => match play_state.map(|s| *s.get()) {
+            // Escape backs out of an open screen instead of pausing
+            Some(PlayState::UsingUI) => next_play_state.set(PlayState::Exploring),
+            // Cutscenes and dialogue handle their own skip input
+            Some(PlayState::InCutscene | PlayState::InDialogue) => {}
+            _ => next_state.set(GameState::Paused),
+        },
+        // The save/load, video and accessibility panels close themselves on Escape
+        GameState::Paused if !ui_state.active && !video_ui.active && !accessibility_ui.active => {
+            next_state.set(GameState::Playing);
         }
+        _ => {}
+    }
+}
 
-        match current_state.get() {
-            GameState::Playing => {
-                info!("Game paused");
-                next_state.set(GameState::Paused);
-            }
-            GameState::Paused => {
-                info!("Game resumed");
-                next_state.set(GameState::Playing);
-            }
-            _ => {}
-        }
+/// Pauses when the window loses focus, so alt-tabbing away doesn't leave the
+/// player to enemies that keep simulating. Cutscenes, dialogue and UI screens
+/// already hold the enemies off and are left running.
+fn pause_on_focus_loss(
+    mut focus_events: MessageReader<WindowFocused>,
+    settings: Res<VideoSettings>,
+    play_state: Option<Res<State<PlayState>>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let lost_focus = focus_events.read().any(|event| !event.focused);
+    if !lost_focus || !settings.pause_on_focus_loss {
+        return;
+    }
+    if play_state.is_some_and(|s| *s.get() != PlayState::Exploring) {
+        return;
     }
+    info!(target: targets::STATE, "Window lost focus, pausing");
+    next_state.set(GameState::Paused);
 }
 
 fn close_save_load_ui(mut ui_state: ResMut<SaveLoadUIState>) {
@@ -1,111 +1,197 @@
 use bevy::prelude::*;
 
+use crate::accessibility::AccessibilityUIState;
+use crate::enemy::NameplateSettings;
+use crate::feedback::RumbleSettings;
+use crate::rendering::ScreenEffectSettings;
 use crate::save::SaveLoadUIState;
 use crate::save::ui::SaveLoadMode;
+use crate::transitions::TransitionRequest;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+use crate::video::VideoUIState;
 
-use super::GameState;
-
-#[derive(Component)]
-pub struct PauseMenu;
+use super::confirm::{quit_dialog, ConfirmDialog};
+use super::{GameMode, GameState};
+use crate::logging::targets;
 
 #[derive(Component)]
 pub enum PauseButton {
     Resume,
     SaveGame,
     LoadGame,
+    Nameplates,
+    ScreenEffects,
+    Rumble,
+    Video,
+    Accessibility,
     MainMenu,
     Quit,
 }
 
-pub fn spawn_pause_menu(mut commands: Commands) {
+/// Text of the nameplate toggle, rewritten when it's pressed
+#[derive(Component)]
+pub struct NameplatesLabel;
+
+fn nameplates_label(settings: &NameplateSettings) -> &'static str {
+    if settings.visible {
+        "Nameplates: On"
+    } else {
+        "Nameplates: Off"
+    }
+}
+
+/// Text of the screen effects toggle, rewritten when it's pressed
+#[derive(Component)]
+pub struct ScreenEffectsLabel;
+
+fn screen_effects_label(settings: &ScreenEffectSettings) -> &'static str {
+    if settings.enabled {
+        "Screen Effects: On"
+    } else {
+        "Screen Effects: Off"
+    }
+}
+
+/// Text of the rumble toggle, rewritten when it's pressed
+#[derive(Component)]
+pub struct RumbleLabel;
+
+fn rumble_label(settings: &RumbleSettings) -> &'static str {
+    if settings.enabled {
+        "Rumble: On"
+    } else {
+        "Rumble: Off"
+    }
+}
+
+pub fn spawn_pause_menu(
+    mut commands: Commands,
+    game_mode: Res<GameMode>,
+    nameplates: Res<NameplateSettings>,
+    screen_effects: Res<ScreenEffectSettings>,
+    rumble: Res<RumbleSettings>,
+    theme: Res<UiTheme>,
+) {
+    // Survival runs are score attacks on a throwaway arena, so they can't be saved
+    let can_save = *game_mode == GameMode::Story;
+
     commands
         .spawn((
-            PauseMenu,
+            DespawnOnExit(GameState::Paused),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                flex_direction: FlexDirection::Column,
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            // Light enough that the frozen world stays visible behind the menu
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.05, 0.45)),
         ))
-        .with_children(|parent| {
-            parent.spawn((
-                Text::new("PAUSED"),
-                TextFont {
-                    font_size: 42.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
+        .with_children(|root| {
+            root.spawn((
                 Node {
-                    margin: UiRect::bottom(Val::Px(30.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(30.0)),
                     ..default()
                 },
-            ));
-
-            let buttons = [
-                (PauseButton::Resume, "Resume"),
-                (PauseButton::SaveGame, "Save Game"),
-                (PauseButton::LoadGame, "Load Game"),
-                (PauseButton::MainMenu, "Main Menu"),
-                (PauseButton::Quit, "Quit"),
-            ];
-
-            for (btn_type, label) in buttons {
-                parent
-                    .spawn((
-                        btn_type,
-                        Button,
-                        Node {
-                            width: Val::Px(250.0),
-                            height: Val::Px(50.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            margin: UiRect::vertical(Val::Px(5.0)),
-                            ..default()
-                        },
-                        BackgroundColor(Color::srgba(0.15, 0.15, 0.3, 0.9)),
-                    ))
-                    .with_children(|btn_parent| {
-                        btn_parent.spawn((
-                            Text::new(label),
-                            TextFont {
-                                font_size: 24.0,
-                                ..default()
-                            },
-                            TextColor(Color::WHITE),
-                        ));
-                    });
-            }
-        });
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.85)),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new("PAUSED"),
+                    theme.text(TextRole::Heading),
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(30.0)),
+                        ..default()
+                    },
+                ));
 
-    info!("Pause menu spawned");
-}
+                let buttons = [
+                    (PauseButton::Resume, "Resume"),
+                    (PauseButton::SaveGame, "Save Game"),
+                    (PauseButton::LoadGame, "Load Game"),
+                    (PauseButton::Nameplates, nameplates_label(&nameplates)),
+                    (PauseButton::ScreenEffects, screen_effects_label(&screen_effects)),
+                    (PauseButton::Rumble, rumble_label(&rumble)),
+                    (PauseButton::Video, "Video"),
+                    (PauseButton::Accessibility, "Accessibility"),
+                    (PauseButton::MainMenu, "Main Menu"),
+                    (PauseButton::Quit, "Quit"),
+                ];
 
-pub fn despawn_pause_menu(mut commands: Commands, query: Query<Entity, With<PauseMenu>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn();
-    }
+                for (btn_type, label) in buttons {
+                    if matches!(btn_type, PauseButton::SaveGame) && !can_save {
+                        continue;
+                    }
+                    let is_nameplates = matches!(btn_type, PauseButton::Nameplates);
+                    let is_screen_effects = matches!(btn_type, PauseButton::ScreenEffects);
+                    let is_rumble = matches!(btn_type, PauseButton::Rumble);
+
+                    let button = GameButton::new(ButtonColors::MENU);
 
-    info!("Pause menu despawned");
+                    parent
+                        .spawn((
+                            btn_type,
+                            button.bundle(Node {
+                                width: Val::Px(250.0),
+                                height: Val::Px(44.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::vertical(Val::Px(5.0)),
+                                ..default()
+                            }),
+                        ))
+                        .with_children(|btn_parent| {
+                            let mut text =
+                                btn_parent.spawn(button.label(label, theme.text(TextRole::Button)));
+                            if is_nameplates {
+                                text.insert(NameplatesLabel);
+                            }
+                            if is_screen_effects {
+                                text.insert(ScreenEffectsLabel);
+                            }
+                            if is_rumble {
+                                text.insert(RumbleLabel);
+                            }
+                        });
+                }
+            });
+        });
+
+    debug!(target: targets::STATE, "Pause menu spawned");
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_pause_buttons(
     mut next_state: ResMut<NextState<GameState>>,
     mut ui_state: ResMut<SaveLoadUIState>,
-    interaction_query: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
-    mut exit: MessageWriter<AppExit>,
+    mut video_ui: ResMut<VideoUIState>,
+    mut accessibility_ui: ResMut<AccessibilityUIState>,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&PauseButton>,
+    mut nameplates: ResMut<NameplateSettings>,
+    mut nameplate_labels: Query<
+        &mut Text,
+        (With<NameplatesLabel>, Without<ScreenEffectsLabel>, Without<RumbleLabel>),
+    >,
+    mut screen_effects: ResMut<ScreenEffectSettings>,
+    mut screen_effects_labels: Query<&mut Text, (With<ScreenEffectsLabel>, Without<RumbleLabel>)>,
+    mut rumble: ResMut<RumbleSettings>,
+    mut rumble_labels: Query<&mut Text, With<RumbleLabel>>,
+    mut commands: Commands,
 ) {
-    if ui_state.active {
+    if ui_state.active || video_ui.active || accessibility_ui.active {
+        clicks.clear();
         return;
     }
 
-    for (interaction, button) in interaction_query.iter() {
-        if *interaction != Interaction::Pressed {
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
             continue;
-        }
+        };
 
         match button {
             PauseButton::Resume => {
@@ -119,27 +205,51 @@ pub fn handle_pause_buttons(
                 ui_state.active = true;
                 ui_state.mode = SaveLoadMode::Load;
             }
+            PauseButton::Nameplates => {
+                nameplates.visible = !nameplates.visible;
+                for mut text in &mut nameplate_labels {
+                    text.0 = nameplates_label(&nameplates).to_string();
+                }
+                debug!(target: targets::STATE, "Nameplates visible: {}", nameplates.visible);
+            }
+            PauseButton::ScreenEffects => {
+                screen_effects.enabled = !screen_effects.enabled;
+                for mut text in &mut screen_effects_labels {
+                    text.0 = screen_effects_label(&screen_effects).to_string();
+                }
+                debug!(
+                    target: targets::STATE,
+                    "Screen effects enabled: {}",
+                    screen_effects.enabled
+                );
+                if let Err(e) = screen_effects.save() {
+                    error!(target: targets::SAVE, "Failed to save screen effect settings: {}", e);
+                }
+            }
+            PauseButton::Rumble => {
+                rumble.enabled = !rumble.enabled;
+                for mut text in &mut rumble_labels {
+                    text.0 = rumble_label(&rumble).to_string();
+                }
+                debug!(target: targets::STATE, "Rumble enabled: {}", rumble.enabled);
+            }
+            PauseButton::Video => {
+                video_ui.active = true;
+            }
+            PauseButton::Accessibility => {
+                accessibility_ui.active = true;
+            }
             PauseButton::MainMenu => {
-                next_state.set(GameState::MainMenu);
+                commands.spawn(ConfirmDialog::open(
+                    "Return to the main menu? Unsaved progress will be lost.",
+                    |world| {
+                        world.write_message(TransitionRequest { to: GameState::MainMenu });
+                    },
+                ));
             }
             PauseButton::Quit => {
-                exit.write(AppExit::Success);
+                commands.spawn(quit_dialog());
             }
         }
     }
 }
-
-pub fn handle_pause_hover(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<PauseButton>),
-    >,
-) {
-    for (interaction, mut bg) in interaction_query.iter_mut() {
-        *bg = match interaction {
-            Interaction::Hovered => BackgroundColor(Color::srgba(0.25, 0.25, 0.5, 0.9)),
-            Interaction::Pressed => BackgroundColor(Color::srgba(0.35, 0.35, 0.6, 0.9)),
-            Interaction::None => BackgroundColor(Color::srgba(0.15, 0.15, 0.3, 0.9)),
-        };
-    }
-}
\ No newline at end of file
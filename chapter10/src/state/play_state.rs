@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use super::GameState;
+
+/// What the player is doing while in the game world. Cutscenes, dialogue and
+/// full-screen UI suppress player input and enemy AI without leaving Playing.
+///
+/// Also exists while Paused, so pausing mid-dialogue resumes the dialogue.
+#[derive(SubStates, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[source(GameState = GameState::Playing | GameState::Paused)]
+pub enum PlayState {
+    #[default]
+    Exploring,
+    InCutscene,
+    InDialogue,
+    UsingUI,
+}
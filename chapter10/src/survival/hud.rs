@@ -0,0 +1,149 @@
+// src/survival/hud.rs
+use bevy::prelude::*;
+
+use super::leaderboard::{Leaderboard, LeaderboardEntry};
+use super::score::{format_time, Score};
+use super::waves::WaveDirector;
+use crate::logging::targets;
+use crate::state::GameState;
+use crate::ui::{TextRole, UiTheme};
+
+/// Number of leaderboard rows shown on the game over screen.
+const RESULTS_ROWS: usize = 5;
+
+#[derive(Component)]
+pub struct SurvivalHud;
+
+#[derive(Component)]
+pub struct SurvivalHudText;
+
+/// Rank of the last finished run on the leaderboard, if it placed.
+#[derive(Resource, Default)]
+pub struct LastRunRank(pub Option<usize>);
+
+pub fn spawn_survival_hud(mut commands: Commands, theme: Res<UiTheme>) {
+    commands
+        .spawn((
+            SurvivalHud,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.0),
+                left: Val::Px(16.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SurvivalHudText,
+                Text::new(""),
+                theme.text(TextRole::Button),
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+pub fn update_survival_hud(
+    score: Res<Score>,
+    director: Res<WaveDirector>,
+    mut query: Query<&mut Text, With<SurvivalHudText>>,
+) {
+    for mut text in query.iter_mut() {
+        let combo = if score.combo > 1 {
+            format!("   x{}", score.multiplier())
+        } else {
+            String::new()
+        };
+        **text = format!(
+            "Wave {}   Score {}   {}{}",
+            director.wave,
+            score.total(),
+            format_time(score.survival_time),
+            combo
+        );
+    }
+}
+
+pub fn despawn_survival_hud(mut commands: Commands, query: Query<Entity, With<SurvivalHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Submits the finished run to the leaderboard and writes it to disk.
+pub fn record_final_score(
+    score: Res<Score>,
+    director: Res<WaveDirector>,
+    mut leaderboard: ResMut<Leaderboard>,
+    mut last_rank: ResMut<LastRunRank>,
+) {
+    let entry = LeaderboardEntry {
+        score: score.total(),
+        kills: score.kills,
+        wave: director.wave,
+        survival_secs: score.survival_time,
+        timestamp: chrono::Local::now().format("%d %b %Y, %I:%M %p").to_string(),
+    };
+
+    last_rank.0 = leaderboard.submit(entry);
+
+    if let Err(e) = leaderboard.save() {
+        error!(target: targets::SURVIVAL, "Failed to save leaderboard: {}", e);
+    }
+}
+
+pub fn spawn_survival_results(
+    mut commands: Commands,
+    score: Res<Score>,
+    director: Res<WaveDirector>,
+    leaderboard: Res<Leaderboard>,
+    last_rank: Res<LastRunRank>,
+    theme: Res<UiTheme>,
+) {
+    let mut lines = vec![
+        format!(
+            "Score {}  —  {} kills, wave {}, survived {}",
+            score.total(),
+            score.kills,
+            director.wave,
+            format_time(score.survival_time)
+        ),
+        String::new(),
+        "Best runs".to_string(),
+    ];
+
+    for (rank, entry) in leaderboard.entries.iter().take(RESULTS_ROWS).enumerate() {
+        let marker = if last_rank.0 == Some(rank) { "  <" } else { "" };
+        lines.push(format!(
+            "{}. {}  (wave {}, {})  {}{}",
+            rank + 1,
+            entry.score,
+            entry.wave,
+            format_time(entry.survival_secs),
+            entry.timestamp,
+            marker
+        ));
+    }
+
+    commands
+        .spawn((
+            DespawnOnExit(GameState::GameOver),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Percent(8.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(lines.join("\n")),
+                theme.text(TextRole::Body),
+                TextColor(Color::srgb(0.9, 0.85, 0.6)),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+        });
+}
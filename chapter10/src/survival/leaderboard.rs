@@ -0,0 +1,68 @@
+// src/survival/leaderboard.rs
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::save::data::saves_directory;
+
+/// Number of runs kept on the leaderboard.
+pub const MAX_ENTRIES: usize = 10;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LeaderboardEntry {
+    pub score: u64,
+    pub kills: u32,
+    pub wave: u32,
+    pub survival_secs: f32,
+    pub timestamp: String,
+}
+
+/// Best survival runs, highest score first. Persisted next to the save slots.
+#[derive(Resource, Serialize, Deserialize, Default, Debug)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+pub fn leaderboard_file_path() -> PathBuf {
+    saves_directory().join("leaderboard.dat")
+}
+
+impl Leaderboard {
+    /// Load the leaderboard from disk, starting empty if missing or unreadable.
+    pub fn load() -> Self {
+        fs::read(leaderboard_file_path())
+            .ok()
+            .and_then(|bytes| bincode::deserialize(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let bytes = bincode::serialize(self).map_err(|e| format!("Serialize error: {}", e))?;
+        fs::create_dir_all(saves_directory()).map_err(|e| format!("Create dir error: {}", e))?;
+        fs::write(leaderboard_file_path(), bytes).map_err(|e| format!("Write error: {}", e))
+    }
+
+    /// Insert a run, keeping the board sorted and trimmed.
+    /// Returns the 0-based rank if the run made it onto the board.
+    pub fn submit(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|e| entry.score > e.score)
+            .unwrap_or(self.entries.len());
+
+        if rank >= MAX_ENTRIES {
+            return None;
+        }
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(MAX_ENTRIES);
+        Some(rank)
+    }
+}
+
+pub fn load_leaderboard(mut commands: Commands) {
+    commands.insert_resource(Leaderboard::load());
+}
@@ -0,0 +1,59 @@
+// src/survival/mod.rs
+mod hud;
+mod leaderboard;
+mod score;
+mod waves;
+
+use bevy::prelude::*;
+
+use crate::schedule::world_ready;
+use crate::state::{GameMode, GameState};
+
+use score::Score;
+use waves::WaveDirector;
+
+/// Endless survival mode: escalating enemy waves, scoring and a local leaderboard.
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .init_resource::<WaveDirector>()
+            .init_resource::<hud::LastRunRank>()
+            .add_observer(score::on_enemy_killed)
+            .add_systems(Startup, leaderboard::load_leaderboard)
+            // Every run starts from a clean slate
+            .add_systems(OnEnter(GameState::Loading), reset_run)
+            .add_systems(
+                OnExit(GameState::Loading),
+                hud::spawn_survival_hud.run_if(resource_equals(GameMode::Survival)),
+            )
+            .add_systems(
+                Update,
+                (
+                    score::tick_score,
+                    waves::advance_waves,
+                    waves::announce_wave_clear,
+                    hud::update_survival_hud,
+                )
+                    .chain()
+                    .run_if(resource_equals(GameMode::Survival))
+                    .run_if(world_ready),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    hud::despawn_survival_hud,
+                    (hud::record_final_score, hud::spawn_survival_results)
+                        .chain()
+                        .run_if(resource_equals(GameMode::Survival)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), hud::despawn_survival_hud);
+    }
+}
+
+fn reset_run(mut score: ResMut<Score>, mut director: ResMut<WaveDirector>) {
+    *score = Score::default();
+    *director = WaveDirector::default();
+}
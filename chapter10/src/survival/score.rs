@@ -0,0 +1,85 @@
+// src/survival/score.rs
+use bevy::prelude::*;
+
+use crate::combat::EntityDeath;
+use crate::enemy::Enemy;
+use crate::state::GameMode;
+
+/// Base points awarded per kill (before the combo multiplier).
+const KILL_POINTS: u64 = 100;
+/// Seconds after a kill during which the next kill extends the combo.
+const COMBO_WINDOW: f32 = 3.0;
+/// Highest combo multiplier that can be reached.
+const MAX_COMBO: u32 = 8;
+/// Points awarded for every second survived.
+const POINTS_PER_SECOND: f32 = 10.0;
+
+/// Running score of the current survival run.
+#[derive(Resource, Default, Debug)]
+pub struct Score {
+    pub kills: u32,
+    /// Points earned from kills (survival time is added in `total`)
+    pub kill_points: u64,
+    /// Current combo count, 0 when no combo is running
+    pub combo: u32,
+    /// Time left before the combo resets
+    pub combo_timer: f32,
+    /// Seconds survived in this run
+    pub survival_time: f32,
+}
+
+impl Score {
+    /// Current kill multiplier (always at least 1).
+    pub fn multiplier(&self) -> u32 {
+        self.combo.max(1)
+    }
+
+    pub fn register_kill(&mut self) {
+        self.combo = if self.combo_timer > 0.0 {
+            (self.combo + 1).min(MAX_COMBO)
+        } else {
+            1
+        };
+        self.combo_timer = COMBO_WINDOW;
+        self.kills += 1;
+        self.kill_points += KILL_POINTS * self.multiplier() as u64;
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.survival_time += delta_secs;
+        self.combo_timer = (self.combo_timer - delta_secs).max(0.0);
+        if self.combo_timer <= 0.0 {
+            self.combo = 0;
+        }
+    }
+
+    /// Final score: kill points plus a bonus for time survived.
+    pub fn total(&self) -> u64 {
+        self.kill_points + (self.survival_time * POINTS_PER_SECOND) as u64
+    }
+}
+
+/// Advances survival time and the combo window.
+pub fn tick_score(time: Res<Time>, mut score: ResMut<Score>) {
+    score.tick(time.delta_secs());
+}
+
+/// Observer that counts enemy deaths towards the score.
+pub fn on_enemy_killed(
+    death: On<EntityDeath>,
+    enemies: Query<(), With<Enemy>>,
+    game_mode: Res<GameMode>,
+    mut score: ResMut<Score>,
+) {
+    if *game_mode != GameMode::Survival || enemies.get(death.entity).is_err() {
+        return;
+    }
+
+    score.register_kill();
+}
+
+/// Formats seconds as `mm:ss`.
+pub fn format_time(seconds: f32) -> String {
+    let total = seconds as u32;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
@@ -0,0 +1,174 @@
+// src/survival/waves.rs
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::characters::config::CharactersList;
+use crate::characters::input::Player;
+use crate::characters::spawn::CharactersListResource;
+use crate::collision::CollisionMap;
+use crate::combat::{Health, PowerType};
+use crate::config::enemy::ENEMY_Z_POSITION;
+use crate::config::player::COLLIDER_RADIUS;
+use crate::enemy::{spawn_enemy, AIBehavior, Enemy, EnemyCombat};
+use crate::game_rng::GameRng;
+use crate::logging::targets;
+use crate::music::{AudioState, MusicHint, Stinger};
+
+/// Enemy types that take part in survival waves, with the power each one uses.
+const ROSTER: [(&str, PowerType); 4] = [
+    ("graveyard_reaper", PowerType::Shadow),
+    ("crimson_count", PowerType::Fire),
+    ("lantern_warden", PowerType::Arcane),
+    ("starlit_oracle", PowerType::Poison),
+];
+
+/// Breather between a cleared wave and the next one (seconds).
+const WAVE_BREAK: f32 = 4.0;
+/// A wave that isn't cleared within this time is reinforced by the next one.
+const WAVE_TIMEOUT: f32 = 45.0;
+/// Enemies spawn outside this distance from the player so they appear off-screen.
+const SPAWN_DISTANCE_MIN: f32 = 700.0;
+const SPAWN_DISTANCE_MAX: f32 = 1000.0;
+/// Attempts to find a clear spawn point for a single enemy.
+const SPAWN_ATTEMPTS: usize = 16;
+/// Survival enemies always know where the player is.
+const SURVIVAL_DETECTION_RANGE: f32 = 3000.0;
+const MAX_ENEMIES_PER_WAVE: u32 = 14;
+
+/// Tracks wave progression for the endless survival mode.
+#[derive(Resource)]
+pub struct WaveDirector {
+    /// Current wave number, 0 before the first wave spawns
+    pub wave: u32,
+    break_timer: Timer,
+    wave_timer: Timer,
+}
+
+impl Default for WaveDirector {
+    fn default() -> Self {
+        Self {
+            wave: 0,
+            break_timer: Timer::from_seconds(WAVE_BREAK, TimerMode::Once),
+            wave_timer: Timer::from_seconds(WAVE_TIMEOUT, TimerMode::Once),
+        }
+    }
+}
+
+impl WaveDirector {
+    fn enemy_count(wave: u32) -> u32 {
+        (2 + wave).min(MAX_ENEMIES_PER_WAVE)
+    }
+
+    fn health_multiplier(wave: u32) -> f32 {
+        1.0 + 0.2 * wave.saturating_sub(1) as f32
+    }
+
+    fn attack_cooldown(wave: u32) -> f32 {
+        (2.0 - 0.1 * wave.saturating_sub(1) as f32).max(0.8)
+    }
+}
+
+/// Spawns the next wave once the arena is cleared (after a short break),
+/// or when the current wave has dragged on too long.
+#[allow(clippy::too_many_arguments)]
+pub fn advance_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<WaveDirector>,
+    enemies: Query<(), With<Enemy>>,
+    player_query: Query<&Transform, With<Player>>,
+    collision_map: Option<Res<CollisionMap>>,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    mut rng: ResMut<GameRng>,
+) {
+    let Some(collision_map) = collision_map else {
+        return;
+    };
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let Some(characters_list) = characters_list_res
+        .as_ref()
+        .and_then(|res| characters_lists.get(&res.handle))
+    else {
+        return;
+    };
+
+    let arena_cleared = enemies.is_empty();
+    let ready = if arena_cleared {
+        director.break_timer.tick(time.delta()).is_finished()
+    } else {
+        director.wave_timer.tick(time.delta()).is_finished()
+    };
+
+    if !ready {
+        return;
+    }
+
+    director.wave += 1;
+    director.break_timer.reset();
+    director.wave_timer.reset();
+
+    let wave = director.wave;
+    let player_pos = player_transform.translation.truncate();
+    let rng = &mut rng.0;
+    let mut spawned = 0;
+
+    for i in 0..WaveDirector::enemy_count(wave) {
+        let Some(position) = find_offscreen_position(&collision_map, player_pos, rng) else {
+            continue;
+        };
+
+        let (name, power_type) = ROSTER[(i + wave) as usize % ROSTER.len()];
+        let Some(entry) = characters_list.characters.iter().find(|c| c.name == name) else {
+            warn!(target: targets::SURVIVAL, "Survival roster character '{}' not found", name);
+            continue;
+        };
+        let max_health = entry.max_health * WaveDirector::health_multiplier(wave);
+
+        spawn_enemy(
+            &mut commands,
+            position.extend(ENEMY_Z_POSITION),
+            name,
+            (
+                Health::new(max_health),
+                EnemyCombat::new(power_type, WaveDirector::attack_cooldown(wave)),
+                AIBehavior::new(150.0, SURVIVAL_DETECTION_RANGE),
+            ),
+        );
+        spawned += 1;
+    }
+
+    info!(target: targets::SURVIVAL, "Survival wave {} started with {} enemies", wave, spawned);
+}
+
+/// Picks a random walkable point in a ring around the player.
+fn find_offscreen_position(
+    collision_map: &CollisionMap,
+    player_pos: Vec2,
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    (0..SPAWN_ATTEMPTS).find_map(|_| {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = rng.gen_range(SPAWN_DISTANCE_MIN..SPAWN_DISTANCE_MAX);
+        let candidate = player_pos + Vec2::from_angle(angle) * distance;
+        collision_map
+            .is_circle_clear(candidate, COLLIDER_RADIUS)
+            .then_some(candidate)
+    })
+}
+
+/// Plays the wave-clear stinger when the last enemy of a wave goes down.
+pub fn announce_wave_clear(
+    director: Res<WaveDirector>,
+    enemies: Query<(), With<Enemy>>,
+    mut was_fighting: Local<bool>,
+    mut audio: ResMut<AudioState>,
+) {
+    let fighting = !enemies.is_empty();
+    if *was_fighting && !fighting && director.wave > 0 {
+        audio.push_hint(MusicHint::Stinger(Stinger::WaveCleared));
+    }
+    *was_fighting = fighting;
+}
@@ -0,0 +1,49 @@
+// src/telemetry/events.rs
+use bevy::prelude::*;
+
+use crate::combat::PowerId;
+use crate::combat::ProjectileOwner;
+use crate::inventory::ItemKind;
+use crate::state::GameState;
+
+/// Typed stream of notable gameplay happenings.
+///
+/// Gameplay code writes these instead of logging directly; stats, achievements,
+/// audio or tutorials read them with a `MessageReader<GameplayEvent>`.
+#[derive(Message, Debug, Clone)]
+pub enum GameplayEvent {
+    ProjectileFired {
+        power_type: PowerId,
+        owner: ProjectileOwner,
+    },
+    DamageDealt {
+        target: Entity,
+        amount: f32,
+        power_type: PowerId,
+        remaining: f32,
+        max: f32,
+    },
+    Healed {
+        target: Entity,
+        amount: f32,
+        remaining: f32,
+        max: f32,
+    },
+    EntityDied {
+        entity: Entity,
+        was_player: bool,
+    },
+    /// Pickable map tiles are the only tiles the game ever removes, so this
+    /// doubles as the tile-destroyed event; terrain reactions leave the tile
+    /// in place and lay a hazard over it.
+    ItemPicked {
+        kind: ItemKind,
+        total: u32,
+        /// Where the item lay
+        position: Vec2,
+    },
+    StateChanged {
+        from: Option<GameState>,
+        to: Option<GameState>,
+    },
+}
@@ -0,0 +1,77 @@
+// src/telemetry/mod.rs
+mod events;
+
+use bevy::prelude::*;
+
+use crate::inventory::Inventory;
+use crate::logging::targets;
+use crate::state::GameState;
+
+pub use events::GameplayEvent;
+
+/// Collects gameplay events into a single stream that cross-cutting
+/// features (stats, achievements, audio, tutorials) can subscribe to.
+pub struct TelemetryPlugin;
+
+impl Plugin for TelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<GameplayEvent>()
+            .add_systems(Update, forward_state_transitions)
+            .add_systems(Last, log_gameplay_events);
+    }
+}
+
+/// Re-publishes `GameState` transitions as telemetry.
+fn forward_state_transitions(
+    mut transitions: MessageReader<StateTransitionEvent<GameState>>,
+    mut telemetry: MessageWriter<GameplayEvent>,
+) {
+    for transition in transitions.read() {
+        telemetry.write(GameplayEvent::StateChanged {
+            from: transition.exited,
+            to: transition.entered,
+        });
+    }
+}
+
+/// Default subscriber: writes every event to the log.
+fn log_gameplay_events(mut events: MessageReader<GameplayEvent>, inventory: Res<Inventory>) {
+    for event in events.read() {
+        match event {
+            GameplayEvent::ProjectileFired { power_type, owner } => {
+                debug!(target: targets::COMBAT, "{} projectile fired by {:?}", power_type, owner);
+            }
+            GameplayEvent::DamageDealt { target, amount, power_type, remaining, max } => {
+                info!(
+                    target: targets::COMBAT,
+                    "{} hit {:?} for {} damage! HP: {:.0}/{:.0}",
+                    power_type, target, amount, remaining, max
+                );
+            }
+            GameplayEvent::Healed { target, amount, remaining, max } => {
+                debug!(
+                    target: targets::COMBAT,
+                    "{:?} healed {:.0}. HP: {:.0}/{:.0}",
+                    target, amount, remaining, max
+                );
+            }
+            GameplayEvent::EntityDied { entity, was_player } => {
+                if *was_player {
+                    info!(target: targets::COMBAT, "Player defeated! Game Over.");
+                } else {
+                    info!(target: targets::COMBAT, "Entity {:?} defeated!", entity);
+                }
+            }
+            GameplayEvent::ItemPicked { kind, total, .. } => {
+                info!(
+                    target: targets::INVENTORY,
+                    "Picked up {} (total: {}) — inventory: {}",
+                    kind, total, inventory.summary()
+                );
+            }
+            GameplayEvent::StateChanged { from, to } => {
+                info!(target: targets::STATE, "Game state: {:?} -> {:?}", from, to);
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// src/time_scale.rs
+use bevy::prelude::*;
+
+use crate::combat::HitPause;
+use crate::console::{ConsoleAppExt, ConsoleResult};
+use crate::logging::targets;
+use crate::save::SaveLoadUIState;
+use crate::state::{GameState, PlayState};
+
+/// Speeds cycled through by the debug keys.
+#[cfg(debug_assertions)]
+const SPEED_PRESETS: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+/// Slowest and fastest speed the console accepts.
+const MIN_SPEED: f32 = 0.05;
+const MAX_SPEED: f32 = 8.0;
+
+/// Multiplier for the virtual clock that gameplay runs on (`Res<Time>`).
+/// UI reads `Time<Real>` and keeps full speed.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameSpeed(pub f32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Combines game speed with any active hit-pause into `Time<Virtual>`.
+fn apply_time_scale(
+    game_speed: Res<GameSpeed>,
+    hit_pause: Res<HitPause>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let speed = game_speed.0 * hit_pause.scale();
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
+
+/// Holds the virtual clock while gameplay is frozen behind a menu: the pause
+/// menu, the save/load panel and full-screen UI screens. Run conditions stop
+/// the systems; this stops cooldowns, buff timers and particle lifetimes that
+/// read `Res<Time>` from jumping ahead when the game resumes.
+fn sync_virtual_pause(
+    state: Res<State<GameState>>,
+    play_state: Option<Res<State<PlayState>>>,
+    save_load_ui: Res<SaveLoadUIState>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let frozen = *state.get() == GameState::Paused
+        || save_load_ui.active
+        || play_state.is_some_and(|s| *s.get() == PlayState::UsingUI);
+    if frozen == virtual_time.is_paused() {
+        return;
+    }
+
+    if frozen {
+        virtual_time.pause();
+    } else {
+        virtual_time.unpause();
+    }
+    debug!(target: targets::STATE, "Gameplay clock {}", if frozen { "paused" } else { "resumed" });
+}
+
+/// F5 / F6 step the game speed down / up, F7 resets it.
+#[cfg(debug_assertions)]
+fn debug_speed_keys(keyboard: Res<ButtonInput<KeyCode>>, mut game_speed: ResMut<GameSpeed>) {
+    let current = SPEED_PRESETS
+        .iter()
+        .position(|&s| s >= game_speed.0)
+        .unwrap_or(SPEED_PRESETS.len() - 1);
+
+    let next = if keyboard.just_pressed(KeyCode::F5) {
+        SPEED_PRESETS[current.saturating_sub(1)]
+    } else if keyboard.just_pressed(KeyCode::F6) {
+        SPEED_PRESETS[(current + 1).min(SPEED_PRESETS.len() - 1)]
+    } else if keyboard.just_pressed(KeyCode::F7) {
+        1.0
+    } else {
+        return;
+    };
+
+    game_speed.0 = next;
+    info!(target: targets::STATE, "Game speed set to {}x", next);
+}
+
+/// Console: `speed <multiplier>`, e.g. `speed 0.25`; no argument shows it.
+fn speed_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    let Some(value) = args.first() else {
+        return Ok(format!("game speed: {}x", world.resource::<GameSpeed>().0));
+    };
+    let speed: f32 = value
+        .trim_end_matches('x')
+        .parse()
+        .map_err(|_| format!("Not a number: '{}'", value))?;
+    if !(MIN_SPEED..=MAX_SPEED).contains(&speed) {
+        return Err(format!("Speed must be between {} and {}", MIN_SPEED, MAX_SPEED));
+    }
+
+    world.resource_mut::<GameSpeed>().0 = speed;
+    Ok(format!("game speed: {}x", speed))
+}
+
+pub struct TimeScalePlugin;
+
+impl Plugin for TimeScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameSpeed>()
+            .add_systems(Update, (sync_virtual_pause, apply_time_scale))
+            .add_console_command("speed", "Set gameplay speed: speed [multiplier]", speed_command);
+
+        // Debug keys - only in debug builds
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            Update,
+            debug_speed_keys.run_if(crate::console::console_closed),
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// src/transitions/fade.rs
+use bevy::prelude::*;
+
+use crate::logging::targets;
+use crate::state::GameState;
+
+const FADE_OUT_SECS: f32 = 0.35;
+const FADE_IN_SECS: f32 = 0.5;
+
+/// Asks for a fade to black before switching to `to`. Write this instead of
+/// setting `NextState<GameState>` directly when the switch should be eased.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct TransitionRequest {
+    pub to: GameState,
+}
+
+/// Asks for a fade to black and back without changing state. `ScreenCovered`
+/// fires at the bottom, for moving things around while nobody can see.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct FadeThrough;
+
+/// Event triggered when a `FadeThrough` has turned the screen fully black.
+#[derive(Event)]
+pub struct ScreenCovered;
+
+/// Black layer above all other UI, including cutscene overlays.
+#[derive(Component)]
+pub struct ScreenFade;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum FadePhase {
+    #[default]
+    Idle,
+    /// Darkening; once fully black, switches state or reports the cover
+    Out { to: Option<GameState> },
+    /// Clearing after a state change
+    In,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct ScreenTransition {
+    phase: FadePhase,
+    /// 0 = clear, 1 = black
+    alpha: f32,
+}
+
+pub fn spawn_screen_fade(mut commands: Commands) {
+    commands.spawn((
+        ScreenFade,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(100),
+        // Never swallows clicks meant for the menus underneath
+        Pickable::IGNORE,
+    ));
+}
+
+/// Starts fading out for the first request; later ones during the same fade
+/// are dropped (a double-clicked menu button).
+pub fn read_transition_requests(
+    mut requests: MessageReader<TransitionRequest>,
+    mut fade_throughs: MessageReader<FadeThrough>,
+    mut transition: ResMut<ScreenTransition>,
+) {
+    for request in requests.read() {
+        if matches!(transition.phase, FadePhase::Out { .. }) {
+            continue;
+        }
+        debug!(target: targets::STATE, "Fading out to {:?}", request.to);
+        transition.phase = FadePhase::Out { to: Some(request.to) };
+    }
+    for _ in fade_throughs.read() {
+        if matches!(transition.phase, FadePhase::Out { .. }) {
+            continue;
+        }
+        debug!(target: targets::STATE, "Fading through black");
+        transition.phase = FadePhase::Out { to: None };
+    }
+}
+
+/// Runs on `OnExit`: state changes that didn't ask for a fade snap to black,
+/// so the next `OnEnter` always fades in from a covered screen.
+pub fn cover_screen(mut transition: ResMut<ScreenTransition>) {
+    transition.alpha = 1.0;
+}
+
+/// Runs on `OnEnter`: clears whatever `cover_screen` or a fade-out left behind.
+pub fn reveal_screen(mut transition: ResMut<ScreenTransition>) {
+    if transition.alpha > 0.0 {
+        transition.phase = FadePhase::In;
+    }
+}
+
+/// Advances the fade on real time, so pauses and slow motion don't stretch it,
+/// and switches state (or reports the cover) at the bottom of a fade-out.
+pub fn update_screen_fade(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut transition: ResMut<ScreenTransition>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut fades: Query<&mut BackgroundColor, With<ScreenFade>>,
+) {
+    let dt = time.delta_secs();
+    match transition.phase {
+        FadePhase::Idle => {}
+        FadePhase::Out { to } => {
+            transition.alpha = (transition.alpha + dt / FADE_OUT_SECS).min(1.0);
+            if transition.alpha >= 1.0 {
+                match to {
+                    Some(to) => {
+                        next_state.set(to);
+                        transition.phase = FadePhase::Idle;
+                    }
+                    None => {
+                        commands.trigger(ScreenCovered);
+                        transition.phase = FadePhase::In;
+                    }
+                }
+            }
+        }
+        FadePhase::In => {
+            transition.alpha = (transition.alpha - dt / FADE_IN_SECS).max(0.0);
+            if transition.alpha <= 0.0 {
+                transition.phase = FadePhase::Idle;
+            }
+        }
+    }
+
+    if let Ok(mut background) = fades.single_mut() {
+        let color = Color::BLACK.with_alpha(transition.alpha);
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
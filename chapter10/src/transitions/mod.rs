@@ -0,0 +1,38 @@
+// src/transitions/mod.rs
+mod fade;
+
+use bevy::prelude::*;
+
+use crate::state::GameState;
+
+pub use fade::{FadeThrough, ScreenCovered, TransitionRequest};
+
+/// Fades between the main menu, loading, playing and game over screens, and
+/// through black for jumps within the game like fast travel. Pausing and
+/// resuming stay instant.
+pub struct TransitionsPlugin;
+
+impl Plugin for TransitionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<TransitionRequest>()
+            .add_message::<FadeThrough>()
+            .init_resource::<fade::ScreenTransition>()
+            .add_systems(Startup, fade::spawn_screen_fade)
+            .add_systems(
+                Update,
+                (fade::read_transition_requests, fade::update_screen_fade).chain(),
+            )
+            .add_systems(OnExit(GameState::MainMenu), fade::cover_screen)
+            .add_systems(OnExit(GameState::Loading), fade::cover_screen)
+            // `State` already holds the new state here; pausing isn't a screen change
+            .add_systems(
+                OnExit(GameState::Playing),
+                fade::cover_screen.run_if(not(in_state(GameState::Paused))),
+            )
+            .add_systems(OnExit(GameState::GameOver), fade::cover_screen)
+            .add_systems(OnEnter(GameState::MainMenu), fade::reveal_screen)
+            .add_systems(OnEnter(GameState::Loading), fade::reveal_screen)
+            .add_systems(OnEnter(GameState::Playing), fade::reveal_screen)
+            .add_systems(OnEnter(GameState::GameOver), fade::reveal_screen);
+    }
+}
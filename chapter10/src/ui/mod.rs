@@ -0,0 +1,40 @@
+// src/ui/mod.rs
+pub mod theme;
+pub mod widgets;
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+
+pub use theme::{TextRole, UiTheme};
+pub use widgets::{ButtonClicked, ButtonColors, GameButton};
+
+/// Shared building blocks for the game's screens: the text theme that the
+/// accessibility text size and font options feed into, and the menu button
+/// every screen spawns.
+pub struct UiPlugin;
+
+impl Plugin for UiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTheme>()
+            .init_resource::<widgets::ButtonFocus>()
+            .add_message::<ButtonClicked>()
+            .add_systems(
+                PreUpdate,
+                (
+                    widgets::update_game_buttons,
+                    widgets::highlight_focused_button
+                        .run_if(resource_changed::<widgets::ButtonFocus>),
+                )
+                    .chain()
+                    .after(UiSystems::Focus),
+            )
+            .add_systems(
+                Update,
+                (
+                    theme::sync_ui_theme,
+                    theme::restyle_themed_text.run_if(resource_changed::<UiTheme>),
+                )
+                    .chain(),
+            );
+    }
+}
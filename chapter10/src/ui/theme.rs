@@ -0,0 +1,118 @@
+// src/ui/theme.rs
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+use crate::accessibility::AccessibilitySettings;
+use crate::config::accessibility::DYSLEXIC_FONT_PATH;
+use crate::logging::targets;
+
+/// What a piece of UI text is for. Screens pick a role instead of a size,
+/// so the text size option scales them all alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextRole {
+    /// Badges and map labels
+    Small,
+    /// Hints and secondary HUD lines
+    Caption,
+    /// HUD banners, dialogue and option buttons
+    Body,
+    /// Menu buttons
+    Button,
+    /// Questions and larger menu buttons
+    Subheading,
+    /// Page titles
+    Heading,
+    /// Full-screen titles
+    Title,
+}
+
+impl TextRole {
+    pub fn size(self) -> f32 {
+        match self {
+            TextRole::Small => 14.0,
+            TextRole::Caption => 18.0,
+            TextRole::Body => 22.0,
+            TextRole::Button => 24.0,
+            TextRole::Subheading => 28.0,
+            TextRole::Heading => 42.0,
+            TextRole::Title => 56.0,
+        }
+    }
+}
+
+/// Restyled whenever the theme changes.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThemedText(pub TextRole);
+
+/// Font and text scale shared by every menu, HUD and dialogue box, built
+/// from the accessibility settings.
+#[derive(Resource, Debug, Clone)]
+pub struct UiTheme {
+    scale: f32,
+    font: Handle<Font>,
+}
+
+impl Default for UiTheme {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            font: Handle::default(),
+        }
+    }
+}
+
+impl UiTheme {
+    pub fn font(&self, role: TextRole) -> TextFont {
+        TextFont {
+            font: self.font.clone(),
+            font_size: role.size() * self.scale,
+            ..default()
+        }
+    }
+
+    /// Font for a new piece of text, tagged so it follows later theme changes.
+    pub fn text(&self, role: TextRole) -> (TextFont, ThemedText) {
+        (self.font(role), ThemedText(role))
+    }
+}
+
+/// Rebuilds the theme from the settings. The dyslexia-friendly font is only
+/// switched to once it has loaded; if it isn't installed, text stays in the
+/// default font rather than disappearing.
+pub fn sync_ui_theme(
+    settings: Res<AccessibilitySettings>,
+    asset_server: Res<AssetServer>,
+    mut theme: ResMut<UiTheme>,
+    mut dyslexic_font: Local<Option<Handle<Font>>>,
+    mut warned: Local<bool>,
+) {
+    let font = if settings.dyslexic_font {
+        let handle = dyslexic_font
+            .get_or_insert_with(|| asset_server.load(DYSLEXIC_FONT_PATH))
+            .clone();
+        match asset_server.load_state(&handle) {
+            LoadState::Loaded => handle,
+            LoadState::Failed(err) => {
+                if !*warned {
+                    warn!(target: targets::STATE, "Dyslexia-friendly font unavailable: {}", err);
+                    *warned = true;
+                }
+                Handle::default()
+            }
+            _ => theme.font.clone(),
+        }
+    } else {
+        Handle::default()
+    };
+
+    if theme.scale != settings.text_scale || theme.font != font {
+        theme.scale = settings.text_scale;
+        theme.font = font;
+    }
+}
+
+pub fn restyle_themed_text(theme: Res<UiTheme>, mut texts: Query<(&ThemedText, &mut TextFont)>) {
+    for (themed, mut font) in &mut texts {
+        *font = theme.font(themed.0);
+    }
+}
@@ -0,0 +1,147 @@
+// src/ui/widgets.rs
+use bevy::prelude::*;
+
+use super::theme::ThemedText;
+
+const DISABLED_COLOR: Color = Color::srgba(0.2, 0.2, 0.2, 0.5);
+const DISABLED_TEXT_COLOR: Color = Color::srgba(0.5, 0.5, 0.5, 1.0);
+const FOCUS_COLOR: Color = Color::srgba(1.0, 0.85, 0.3, 0.9);
+const FOCUS_WIDTH: f32 = 2.0;
+
+/// Background colours a button moves through under the pointer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonColors {
+    pub normal: Color,
+    pub hovered: Color,
+    pub pressed: Color,
+}
+
+impl ButtonColors {
+    /// Most menu buttons
+    pub const MENU: Self = Self {
+        normal: Color::srgba(0.15, 0.15, 0.3, 0.9),
+        hovered: Color::srgba(0.25, 0.25, 0.5, 0.9),
+        pressed: Color::srgba(0.35, 0.35, 0.6, 0.9),
+    };
+    /// Back, No and delete
+    pub const DANGER: Self = Self {
+        normal: Color::srgba(0.4, 0.1, 0.1, 0.9),
+        hovered: Color::srgba(0.55, 0.15, 0.15, 0.9),
+        pressed: Color::srgba(0.7, 0.2, 0.2, 0.9),
+    };
+}
+
+/// A menu button. Spawn it with `GameButton::new(colors).bundle(node)` next
+/// to whatever marks what it does, give it a `label`, and read
+/// `ButtonClicked` instead of watching `Interaction`.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Button)]
+pub struct GameButton {
+    pub colors: ButtonColors,
+    /// Greyed out, never focused and never clicked
+    pub disabled: bool,
+}
+
+impl GameButton {
+    pub fn new(colors: ButtonColors) -> Self {
+        Self {
+            colors,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn background(&self, interaction: Interaction) -> Color {
+        match interaction {
+            _ if self.disabled => DISABLED_COLOR,
+            Interaction::Pressed => self.colors.pressed,
+            Interaction::Hovered => self.colors.hovered,
+            Interaction::None => self.colors.normal,
+        }
+    }
+
+    fn text_color(&self) -> Color {
+        if self.disabled {
+            DISABLED_TEXT_COLOR
+        } else {
+            Color::WHITE
+        }
+    }
+
+    pub fn bundle(self, node: Node) -> impl Bundle {
+        (
+            BackgroundColor(self.background(Interaction::None)),
+            Outline::new(Val::Px(FOCUS_WIDTH), Val::ZERO, Color::NONE),
+            self,
+            node,
+        )
+    }
+
+    /// The button's text, greyed out along with it.
+    pub fn label(&self, text: impl Into<String>, font: (TextFont, ThemedText)) -> impl Bundle {
+        (Text::new(text), font, TextColor(self.text_color()))
+    }
+}
+
+/// An enabled `GameButton` was pressed.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ButtonClicked(pub Entity);
+
+/// The button last pointed at or pressed. It stays outlined after the
+/// pointer leaves, so the player can tell where they were.
+#[derive(Resource, Debug, Default, PartialEq)]
+pub struct ButtonFocus(pub Option<Entity>);
+
+/// Runs right after `Interaction` is updated: recolours buttons, moves the
+/// focus and reports clicks for every screen.
+pub fn update_game_buttons(
+    mut buttons: Query<
+        (Entity, Ref<GameButton>, Ref<Interaction>, &mut BackgroundColor, Option<&Children>),
+        Or<(Changed<Interaction>, Changed<GameButton>)>,
+    >,
+    mut labels: Query<&mut TextColor>,
+    mut focus: ResMut<ButtonFocus>,
+    mut clicks: MessageWriter<ButtonClicked>,
+) {
+    for (entity, button, interaction, mut background, children) in &mut buttons {
+        background.0 = button.background(*interaction);
+
+        if button.is_changed() {
+            for &child in children.into_iter().flatten() {
+                if let Ok(mut color) = labels.get_mut(child) {
+                    color.0 = button.text_color();
+                }
+            }
+        }
+
+        match *interaction {
+            _ if button.disabled => {
+                if focus.0 == Some(entity) {
+                    focus.0 = None;
+                }
+            }
+            _ if !interaction.is_changed() => {}
+            Interaction::Pressed => {
+                focus.set_if_neq(ButtonFocus(Some(entity)));
+                clicks.write(ButtonClicked(entity));
+            }
+            Interaction::Hovered => {
+                focus.set_if_neq(ButtonFocus(Some(entity)));
+            }
+            Interaction::None => {}
+        }
+    }
+}
+
+pub fn highlight_focused_button(
+    focus: Res<ButtonFocus>,
+    mut buttons: Query<(Entity, &mut Outline), With<GameButton>>,
+) {
+    for (entity, mut outline) in &mut buttons {
+        outline.color = if focus.0 == Some(entity) { FOCUS_COLOR } else { Color::NONE };
+    }
+}
@@ -0,0 +1,40 @@
+// src/video/mod.rs
+pub mod settings;
+pub mod ui;
+
+use bevy::prelude::*;
+
+use crate::state::GameState;
+
+pub use settings::{DisplayMode, VideoSettings};
+pub use ui::VideoUIState;
+
+/// Window mode, monitor, windowed resolution and vsync, changed live from a
+/// video page reachable from the main and pause menus.
+pub struct VideoPlugin;
+
+/// States with a video page: the main menu and pause.
+fn video_ui_open(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::MainMenu | GameState::Paused)
+}
+
+impl Plugin for VideoPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<VideoSettings>()
+            .init_resource::<VideoUIState>()
+            .add_systems(PreStartup, settings::load_video_settings)
+            .add_systems(
+                Update,
+                settings::apply_video_settings.run_if(resource_changed::<VideoSettings>),
+            )
+            .add_systems(Update, ui::handle_video_ui.run_if(video_ui_open))
+            .add_systems(
+                Update,
+                ui::handle_video_buttons
+                    .run_if(|ui_state: Res<VideoUIState>| ui_state.active)
+                    .run_if(video_ui_open),
+            )
+            .add_systems(OnExit(GameState::MainMenu), ui::close_video_ui)
+            .add_systems(OnExit(GameState::Paused), ui::close_video_ui);
+    }
+}
@@ -0,0 +1,158 @@
+// src/video/settings.rs
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::window::{
+    MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode, WindowPosition,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::config::video::{FPS_CAPS, WINDOWED_RESOLUTIONS};
+use crate::logging::targets;
+use crate::save::data::saves_directory;
+
+#[derive(Serialize, Deserialize, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DisplayMode {
+    Windowed,
+    #[default]
+    Borderless,
+    /// Takes over the monitor at its current video mode
+    Exclusive,
+}
+
+impl DisplayMode {
+    pub fn next(self) -> Self {
+        match self {
+            DisplayMode::Windowed => DisplayMode::Borderless,
+            DisplayMode::Borderless => DisplayMode::Exclusive,
+            DisplayMode::Exclusive => DisplayMode::Windowed,
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "Windowed",
+            DisplayMode::Borderless => "Borderless",
+            DisplayMode::Exclusive => "Fullscreen",
+        }
+    }
+}
+
+/// How the game window is shown. Applied to the primary window whenever it
+/// changes and persisted next to the save slots as JSON; a field missing
+/// from the file, like one added since it was written, takes its default.
+#[derive(Resource, Serialize, Deserialize, Reflect, Clone, Debug)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct VideoSettings {
+    pub mode: DisplayMode,
+    /// Index into the connected monitors, in the order the OS reports them
+    pub monitor: usize,
+    /// Window size in windowed mode; fullscreen modes use the monitor's own
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    /// Most frames per second to draw; `None` leaves it to vsync
+    pub fps_cap: Option<u32>,
+    /// Update at a trickle while the window doesn't have focus
+    pub background_throttle: bool,
+    /// Pause the game when the window loses focus
+    pub pause_on_focus_loss: bool,
+}
+
+impl Default for VideoSettings {
+    fn default() -> Self {
+        Self {
+            mode: DisplayMode::default(),
+            monitor: 0,
+            resolution: WINDOWED_RESOLUTIONS[0],
+            vsync: true,
+            fps_cap: None,
+            background_throttle: true,
+            pause_on_focus_loss: true,
+        }
+    }
+}
+
+pub fn video_settings_file_path() -> PathBuf {
+    saves_directory().join("video.json")
+}
+
+impl VideoSettings {
+    /// Load the settings from disk, falling back to defaults if missing or unreadable.
+    pub fn load() -> Self {
+        let Ok(bytes) = fs::read(video_settings_file_path()) else {
+            return Self::default();
+        };
+        serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            warn!(target: targets::SAVE, "Unreadable video settings, using defaults: {}", e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let bytes =
+            serde_json::to_vec_pretty(self).map_err(|e| format!("Serialize error: {}", e))?;
+        fs::create_dir_all(saves_directory()).map_err(|e| format!("Create dir error: {}", e))?;
+        fs::write(video_settings_file_path(), bytes).map_err(|e| format!("Write error: {}", e))
+    }
+
+    /// The windowed resolution after the current one, wrapping around.
+    pub fn next_resolution(&self) -> (u32, u32) {
+        let next = WINDOWED_RESOLUTIONS
+            .iter()
+            .position(|&r| r == self.resolution)
+            .map_or(0, |i| (i + 1) % WINDOWED_RESOLUTIONS.len());
+        WINDOWED_RESOLUTIONS[next]
+    }
+
+    /// The frame rate cap after the current one, going through "Off" after the highest.
+    pub fn next_fps_cap(&self) -> Option<u32> {
+        match self.fps_cap {
+            None => Some(FPS_CAPS[0]),
+            Some(cap) => FPS_CAPS.iter().copied().find(|&c| c > cap),
+        }
+    }
+}
+
+pub fn load_video_settings(mut commands: Commands) {
+    commands.insert_resource(VideoSettings::load());
+}
+
+/// Pushes the settings onto the primary window; winit picks up the change
+/// on its next update.
+pub fn apply_video_settings(
+    settings: Res<VideoSettings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let monitor = MonitorSelection::Index(settings.monitor);
+
+    window.mode = match settings.mode {
+        DisplayMode::Windowed => WindowMode::Windowed,
+        DisplayMode::Borderless => WindowMode::BorderlessFullscreen(monitor),
+        DisplayMode::Exclusive => WindowMode::Fullscreen(monitor, VideoModeSelection::Current),
+    };
+    if settings.mode == DisplayMode::Windowed {
+        let (width, height) = settings.resolution;
+        window.resolution.set(width as f32, height as f32);
+        window.position = WindowPosition::Centered(monitor);
+    }
+    window.present_mode = if settings.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+
+    info!(
+        target: targets::STATE,
+        "Video: {} on monitor {}, {}x{}, vsync {}",
+        settings.mode.display_name(),
+        settings.monitor + 1,
+        settings.resolution.0,
+        settings.resolution.1,
+        if settings.vsync { "on" } else { "off" }
+    );
+}
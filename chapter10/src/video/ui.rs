@@ -0,0 +1,202 @@
+// src/video/ui.rs
+use bevy::prelude::*;
+use bevy::window::Monitor;
+
+use super::settings::{DisplayMode, VideoSettings};
+use crate::logging::targets;
+use crate::ui::{ButtonClicked, ButtonColors, GameButton, TextRole, UiTheme};
+
+#[derive(Resource, Default)]
+pub struct VideoUIState {
+    pub active: bool,
+}
+
+#[derive(Component)]
+pub struct VideoUI;
+
+#[derive(Component, Clone, Copy)]
+pub enum VideoButton {
+    Mode,
+    Monitor,
+    Resolution,
+    VSync,
+    FpsCap,
+    BackgroundThrottle,
+    PauseOnFocusLoss,
+    Back,
+}
+
+/// Rebuilds the page whenever it opens, closes or a setting changes, so the
+/// labels always show what's applied.
+pub fn handle_video_ui(
+    mut commands: Commands,
+    ui_state: Res<VideoUIState>,
+    settings: Res<VideoSettings>,
+    existing_ui: Query<Entity, With<VideoUI>>,
+    theme: Res<UiTheme>,
+) {
+    if !ui_state.is_changed() && !settings.is_changed() {
+        return;
+    }
+
+    for entity in existing_ui.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if !ui_state.active {
+        return;
+    }
+
+    let windowed = settings.mode == DisplayMode::Windowed;
+    let (width, height) = settings.resolution;
+    let buttons = [
+        (VideoButton::Mode, format!("Mode: {}", settings.mode.display_name()), true),
+        (VideoButton::Monitor, format!("Monitor: {}", settings.monitor + 1), true),
+        // Fullscreen modes take the monitor's own resolution
+        (VideoButton::Resolution, format!("Resolution: {}x{}", width, height), windowed),
+        (
+            VideoButton::VSync,
+            format!("VSync: {}", if settings.vsync { "On" } else { "Off" }),
+            true,
+        ),
+        (
+            VideoButton::FpsCap,
+            match settings.fps_cap {
+                Some(cap) => format!("FPS Cap: {}", cap),
+                None => "FPS Cap: Off".to_string(),
+            },
+            true,
+        ),
+        (
+            VideoButton::BackgroundThrottle,
+            format!(
+                "Background Throttle: {}",
+                if settings.background_throttle { "On" } else { "Off" }
+            ),
+            true,
+        ),
+        (
+            VideoButton::PauseOnFocusLoss,
+            format!(
+                "Pause When Unfocused: {}",
+                if settings.pause_on_focus_loss { "On" } else { "Off" }
+            ),
+            true,
+        ),
+    ];
+
+    commands
+        .spawn((
+            VideoUI,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 1.0)),
+            GlobalZIndex(100),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("VIDEO"),
+                theme.text(TextRole::Heading),
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            for (button, label, enabled) in buttons {
+                let game_button = GameButton::new(ButtonColors::MENU).disabled(!enabled);
+                parent
+                    .spawn((
+                        button,
+                        game_button.bundle(Node {
+                            width: Val::Px(350.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::vertical(Val::Px(5.0)),
+                            ..default()
+                        }),
+                    ))
+                    .with_child(game_button.label(label, theme.text(TextRole::Body)));
+            }
+
+            let back = GameButton::new(ButtonColors::DANGER);
+            parent
+                .spawn((
+                    VideoButton::Back,
+                    back.bundle(Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(45.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    }),
+                ))
+                .with_child(back.label("Back", theme.text(TextRole::Button)));
+        });
+}
+
+pub fn handle_video_buttons(
+    mut ui_state: ResMut<VideoUIState>,
+    mut settings: ResMut<VideoSettings>,
+    monitors: Query<(), With<Monitor>>,
+    mut clicks: MessageReader<ButtonClicked>,
+    buttons: Query<&VideoButton>,
+    input: Res<ButtonInput<KeyCode>>,
+) {
+    // Close on ESC
+    if input.just_pressed(KeyCode::Escape) {
+        ui_state.active = false;
+        return;
+    }
+
+    for ButtonClicked(entity) in clicks.read() {
+        let Ok(button) = buttons.get(*entity) else {
+            continue;
+        };
+
+        match button {
+            VideoButton::Mode => settings.mode = settings.mode.next(),
+            VideoButton::Monitor => {
+                settings.monitor = (settings.monitor + 1) % monitors.iter().count().max(1);
+            }
+            VideoButton::Resolution => settings.resolution = settings.next_resolution(),
+            VideoButton::VSync => settings.vsync = !settings.vsync,
+            VideoButton::FpsCap => settings.fps_cap = settings.next_fps_cap(),
+            VideoButton::BackgroundThrottle => {
+                settings.background_throttle = !settings.background_throttle;
+            }
+            VideoButton::PauseOnFocusLoss => {
+                settings.pause_on_focus_loss = !settings.pause_on_focus_loss;
+            }
+            VideoButton::Back => {
+                ui_state.active = false;
+                continue;
+            }
+        }
+
+        if let Err(e) = settings.save() {
+            error!(target: targets::SAVE, "Failed to save video settings: {}", e);
+        }
+    }
+}
+
+/// The page only rebuilds in menu states, so leaving one tears it down here.
+pub fn close_video_ui(
+    mut commands: Commands,
+    mut ui_state: ResMut<VideoUIState>,
+    existing_ui: Query<Entity, With<VideoUI>>,
+) {
+    ui_state.active = false;
+    for entity in existing_ui.iter() {
+        commands.entity(entity).despawn();
+    }
+}
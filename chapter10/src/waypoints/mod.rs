@@ -0,0 +1,35 @@
+// src/waypoints/mod.rs
+mod waypoint;
+
+use bevy::prelude::*;
+
+use crate::console::ConsoleAppExt;
+use crate::schedule::GameSet;
+
+pub use waypoint::{spawn_waypoint, Waypoint};
+
+/// World-space markers for quests and the console: a floating icon with the
+/// distance to it, an arrow on the screen edge while it's out of view, and
+/// automatic clearing when the player gets there.
+pub struct WaypointsPlugin;
+
+impl Plugin for WaypointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(waypoint::announce_waypoint_reached)
+            .add_systems(
+                Update,
+                waypoint::clear_reached_waypoints.in_set(GameSet::PostPhysics),
+            )
+            .add_systems(
+                Update,
+                (waypoint::attach_waypoint_visuals, waypoint::update_waypoint_markers)
+                    .chain()
+                    .in_set(GameSet::Render),
+            )
+            .add_console_command(
+                "waypoint",
+                "Place a waypoint: waypoint <x> <y> [label] | waypoint clear",
+                waypoint::waypoint_command,
+            );
+    }
+}
@@ -0,0 +1,199 @@
+// src/waypoints/waypoint.rs
+use bevy::prelude::*;
+
+use crate::camera::{MainCamera, ViewBounds};
+use crate::characters::input::Player;
+use crate::config::enemy::{INDICATOR_EDGE_MARGIN, INDICATOR_Z};
+use crate::config::map::TILE_SIZE;
+use crate::config::waypoints::{ARRIVE_RADIUS, WAYPOINT_Z};
+use crate::console::ConsoleResult;
+use crate::logging::targets;
+use crate::state::GameplayEntity;
+
+const ICON_SIZE: f32 = 14.0;
+const LABEL_OFFSET: f32 = 20.0;
+const BOB_HEIGHT: f32 = 4.0;
+const WAYPOINT_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// A spot the player is headed for. Quests (or the console) spawn one with
+/// [`spawn_waypoint`]; it clears itself when the player arrives.
+#[derive(Component, Debug, Clone)]
+pub struct Waypoint {
+    pub label: String,
+}
+
+/// Event triggered when the player reaches a waypoint, just before it despawns.
+#[derive(Event)]
+pub struct WaypointReached {
+    pub waypoint: Entity,
+    pub label: String,
+}
+
+#[derive(Component)]
+pub struct WaypointIcon;
+
+#[derive(Component)]
+pub struct WaypointLabel;
+
+/// Edge-of-screen arrow, a child of its waypoint so it goes away with it.
+#[derive(Component)]
+pub struct WaypointArrow;
+
+pub fn spawn_waypoint(commands: &mut Commands, position: Vec2, label: impl Into<String>) -> Entity {
+    let label = label.into();
+    info!(target: targets::PLAYER, "Waypoint '{}' at {:?}", label, position);
+    commands
+        .spawn((
+            Waypoint { label },
+            Transform::from_translation(position.extend(WAYPOINT_Z)),
+            Visibility::default(),
+            GameplayEntity,
+        ))
+        .id()
+}
+
+/// Gives new waypoints their floating diamond, distance label and edge arrow.
+pub fn attach_waypoint_visuals(
+    mut commands: Commands,
+    waypoints: Query<Entity, Added<Waypoint>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for waypoint in &waypoints {
+        commands.entity(waypoint).with_children(|parent| {
+            parent.spawn((
+                WaypointIcon,
+                Sprite::from_color(WAYPOINT_COLOR, Vec2::splat(ICON_SIZE)),
+                Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            ));
+            parent.spawn((
+                WaypointLabel,
+                Text2d::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(WAYPOINT_COLOR),
+                Transform::from_xyz(0.0, LABEL_OFFSET, 0.0),
+            ));
+            parent.spawn((
+                WaypointArrow,
+                Mesh2d(meshes.add(Triangle2d::new(
+                    Vec2::new(9.0, 0.0),
+                    Vec2::new(-9.0, 7.0),
+                    Vec2::new(-9.0, -7.0),
+                ))),
+                MeshMaterial2d(materials.add(ColorMaterial::from(WAYPOINT_COLOR))),
+                Transform::default(),
+                Visibility::Hidden,
+            ));
+        });
+    }
+}
+
+/// Keeps the child transform queries apart from the ones read for positioning.
+type ChildOnly = (Without<Waypoint>, Without<Player>, Without<MainCamera>);
+
+/// Bobs the icons, keeps the distance labels current and pins an arrow to
+/// the screen edge for waypoints out of view.
+pub fn update_waypoint_markers(
+    time: Res<Time>,
+    camera: Query<(&Transform, &Projection), With<MainCamera>>,
+    players: Query<&Transform, With<Player>>,
+    waypoints: Query<(&Waypoint, &Transform, &Children), Without<WaypointArrow>>,
+    mut icons: Query<&mut Transform, (With<WaypointIcon>, Without<WaypointArrow>, ChildOnly)>,
+    mut labels: Query<&mut Text2d, With<WaypointLabel>>,
+    mut arrows: Query<(&mut Transform, &mut Visibility), (With<WaypointArrow>, ChildOnly)>,
+) {
+    let Ok(player) = players.single() else {
+        return;
+    };
+    let view = camera
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| ViewBounds::from_camera(transform, projection));
+    let bob = BOB_HEIGHT * (time.elapsed_secs() * 3.0).sin();
+
+    for (waypoint, transform, children) in &waypoints {
+        let position = transform.translation.truncate();
+        let tiles = (position.distance(player.translation.truncate()) / TILE_SIZE).round();
+        let offscreen = view.filter(|view| !view.contains(position));
+
+        for child in children.iter() {
+            if let Ok(mut icon) = icons.get_mut(child) {
+                icon.translation.y = bob;
+            }
+            if let Ok(mut text) = labels.get_mut(child) {
+                let label = format!("{}  {}m", waypoint.label, tiles);
+                if text.0 != label {
+                    text.0 = label;
+                }
+            }
+            if let Ok((mut arrow, mut visibility)) = arrows.get_mut(child) {
+                let Some(view) = offscreen else {
+                    visibility.set_if_neq(Visibility::Hidden);
+                    continue;
+                };
+                // The arrow is a child, so place it relative to the waypoint
+                let edge = view.edge_point(position, INDICATOR_EDGE_MARGIN);
+                arrow.translation = (edge - position).extend(INDICATOR_Z - WAYPOINT_Z);
+                arrow.rotation = Quat::from_rotation_z((position - edge).to_angle());
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+        }
+    }
+}
+
+/// Clears waypoints the player has reached.
+pub fn clear_reached_waypoints(
+    mut commands: Commands,
+    players: Query<&Transform, With<Player>>,
+    waypoints: Query<(Entity, &Waypoint, &Transform)>,
+) {
+    let Ok(player) = players.single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    for (entity, waypoint, transform) in &waypoints {
+        if transform.translation.truncate().distance(player_pos) > ARRIVE_RADIUS {
+            continue;
+        }
+        commands.trigger(WaypointReached {
+            waypoint: entity,
+            label: waypoint.label.clone(),
+        });
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn announce_waypoint_reached(reached: On<WaypointReached>) {
+    info!(target: targets::PLAYER, "Reached waypoint '{}' ({:?})", reached.label, reached.waypoint);
+}
+
+/// Console: `waypoint <x> <y> [label]` places a waypoint, `waypoint clear` removes them all.
+pub fn waypoint_command(world: &mut World, args: &[&str]) -> ConsoleResult {
+    if args.first() == Some(&"clear") {
+        let mut query = world.query_filtered::<Entity, With<Waypoint>>();
+        let waypoints: Vec<Entity> = query.iter(world).collect();
+        for waypoint in &waypoints {
+            world.despawn(*waypoint);
+        }
+        return Ok(format!("Cleared {} waypoints", waypoints.len()));
+    }
+
+    let [x, y, label @ ..] = args else {
+        return Err("Usage: waypoint <x> <y> [label] | waypoint clear".into());
+    };
+    let x: f32 = x.parse().map_err(|_| format!("Bad x '{}'", x))?;
+    let y: f32 = y.parse().map_err(|_| format!("Bad y '{}'", y))?;
+    let label = if label.is_empty() {
+        "Waypoint".to_string()
+    } else {
+        label.join(" ")
+    };
+
+    spawn_waypoint(&mut world.commands(), Vec2::new(x, y), label.clone());
+    world.flush();
+    Ok(format!("Placed '{}' at ({:.0}, {:.0})", label, x, y))
+}
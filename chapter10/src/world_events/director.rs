@@ -0,0 +1,161 @@
+// src/world_events/director.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::world_events::{
+    ANNOUNCE_HOUR, BLOOD_MOON_EVERY, DAWN_HOUR, HORDE_NIGHT_EVERY, NIGHTFALL_HOUR, RECAP_SECS,
+};
+use crate::inventory::ItemKind;
+use crate::lighting::DayNightCycle;
+use crate::logging::targets;
+
+/// A special night the director can schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorldEvent {
+    /// Enemies keep spawning around the player until dawn
+    HordeNight,
+    /// Every enemy hits harder and moves faster until dawn
+    BloodMoon,
+}
+
+impl WorldEvent {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            WorldEvent::HordeNight => "Horde Night",
+            WorldEvent::BloodMoon => "Blood Moon",
+        }
+    }
+
+    /// Items handed out for seeing the night through.
+    pub fn rewards(self) -> &'static [ItemKind] {
+        match self {
+            WorldEvent::HordeNight => &[ItemKind::Plant1, ItemKind::Plant3, ItemKind::Plant4],
+            WorldEvent::BloodMoon => &[ItemKind::Plant1, ItemKind::Plant2],
+        }
+    }
+
+    /// What night `night` (counting from 1) brings, if anything.
+    fn for_night(night: u32) -> Option<Self> {
+        if night % HORDE_NIGHT_EVERY == 0 {
+            Some(WorldEvent::HordeNight)
+        } else if night % BLOOD_MOON_EVERY == 0 {
+            Some(WorldEvent::BloodMoon)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum EventPhase {
+    #[default]
+    Idle,
+    /// Coming at nightfall
+    Announced(WorldEvent),
+    /// Running until dawn
+    Active(WorldEvent),
+    /// Seen through; the banner says so for a few seconds
+    Survived { event: WorldEvent, secs_left: f32 },
+}
+
+/// Event triggered when the player makes it to dawn through a world event.
+#[derive(Event)]
+pub struct WorldEventSurvived {
+    pub event: WorldEvent,
+}
+
+/// Schedules world events off the day/night clock: one may be announced
+/// each dusk, runs from nightfall and ends at dawn.
+#[derive(Resource, Debug, Default)]
+pub struct EventDirector {
+    /// Nights that have fallen (or been announced) this run
+    pub night: u32,
+    pub phase: EventPhase,
+    /// Clock hour last frame, to catch it passing the scheduled hours
+    last_hour: Option<f32>,
+}
+
+impl EventDirector {
+    /// Picks the schedule back up from a save, counting from the next hour
+    /// the clock passes.
+    pub fn restored(night: u32, phase: EventPhase) -> Self {
+        Self {
+            night,
+            phase,
+            last_hour: None,
+        }
+    }
+
+    /// The event currently running, if any.
+    pub fn active(&self) -> Option<WorldEvent> {
+        match self.phase {
+            EventPhase::Active(event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the clock went past `mark` going from `from` to `to`, wrapping
+/// at midnight. Jumps from the `time` console command count too.
+fn passed(from: f32, to: f32, mark: f32) -> bool {
+    if to >= from {
+        from < mark && mark <= to
+    } else {
+        mark > from || mark <= to
+    }
+}
+
+/// Real seconds until the clock reads `hour`, at normal game speed.
+pub fn secs_until(cycle: &DayNightCycle, hour: f32) -> f32 {
+    (hour - cycle.hour).rem_euclid(24.0) * cycle.day_length_secs / 24.0
+}
+
+pub fn advance_world_events(
+    mut commands: Commands,
+    time: Res<Time>,
+    cycle: Res<DayNightCycle>,
+    mut director: ResMut<EventDirector>,
+) {
+    if let EventPhase::Survived { secs_left, .. } = &mut director.phase {
+        *secs_left -= time.delta_secs();
+        if *secs_left <= 0.0 {
+            director.phase = EventPhase::Idle;
+        }
+    }
+
+    let hour = cycle.hour;
+    let Some(last_hour) = director.last_hour.replace(hour) else {
+        return;
+    };
+
+    if passed(last_hour, hour, ANNOUNCE_HOUR) {
+        director.night += 1;
+        if let Some(event) = WorldEvent::for_night(director.night) {
+            director.phase = EventPhase::Announced(event);
+            info!(
+                target: targets::EVENTS,
+                "{} falls tonight (night {})", event.display_name(), director.night
+            );
+        }
+    }
+
+    match director.phase {
+        EventPhase::Announced(event) if passed(last_hour, hour, NIGHTFALL_HOUR) => {
+            director.phase = EventPhase::Active(event);
+            info!(target: targets::EVENTS, "{} has begun", event.display_name());
+        }
+        EventPhase::Active(event) if passed(last_hour, hour, DAWN_HOUR) => {
+            director.phase = EventPhase::Survived {
+                event,
+                secs_left: RECAP_SECS,
+            };
+            info!(target: targets::EVENTS, "{} survived", event.display_name());
+            commands.trigger(WorldEventSurvived { event });
+        }
+        _ => {}
+    }
+}
+
+pub fn reset_world_events(mut director: ResMut<EventDirector>) {
+    *director = EventDirector::default();
+}
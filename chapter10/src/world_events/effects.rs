@@ -0,0 +1,174 @@
+// src/world_events/effects.rs
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use super::director::{EventDirector, WorldEvent, WorldEventSurvived};
+use crate::buffs::{BuffKind, BuffSpec, Buffs};
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::combat::{Heal, Health};
+use crate::config::enemy::ENEMY_Z_POSITION;
+use crate::config::world_events::{
+    BLOOD_MOON_BUFF_SECS, BLOOD_MOON_DAMAGE, BLOOD_MOON_SPEED, HORDE_BATCH,
+    HORDE_DETECTION_RANGE, HORDE_MAX_ALIVE, HORDE_SPAWN_DISTANCE_MAX, HORDE_SPAWN_DISTANCE_MIN,
+    HORDE_SPAWN_INTERVAL,
+};
+use crate::enemy::difficulty::ScaleWithDistance;
+use crate::enemy::spawn::spawn_clearance;
+use crate::enemy::{spawn_enemy, AIBehavior, Enemy};
+use crate::game_rng::GameRng;
+use crate::inventory::Inventory;
+use crate::logging::targets;
+use crate::prefabs::PrefabRegistry;
+
+/// Enemy types that join a horde.
+const HORDE_ROSTER: [&str; 4] =
+    ["graveyard_reaper", "crimson_count", "lantern_warden", "starlit_oracle"];
+/// Attempts to find a clear spawn point for a single horde enemy.
+const SPAWN_ATTEMPTS: usize = 16;
+
+/// Spawned by a horde night. Horde enemies don't belong to a zone, so they
+/// never repopulate one.
+#[derive(Component)]
+pub struct HordeEnemy;
+
+/// Paces horde batches.
+#[derive(Resource)]
+pub struct HordeTimer(pub Timer);
+
+impl Default for HordeTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(HORDE_SPAWN_INTERVAL, TimerMode::Repeating))
+    }
+}
+
+/// Sends a batch of enemies at the player from off-screen every few seconds
+/// of a horde night, as long as the horde isn't already at full strength.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_horde(
+    mut commands: Commands,
+    time: Res<Time>,
+    director: Res<EventDirector>,
+    mut timer: ResMut<HordeTimer>,
+    prefabs: Res<PrefabRegistry>,
+    collision_map: Option<Res<CollisionMap>>,
+    players: Query<&Transform, With<Player>>,
+    horde: Query<(), With<HordeEnemy>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if director.active() != Some(WorldEvent::HordeNight) {
+        return;
+    }
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    let (Some(map), Ok(player)) = (collision_map, players.single()) else {
+        return;
+    };
+
+    let room = HORDE_MAX_ALIVE.saturating_sub(horde.iter().count());
+    let player_pos = player.translation.truncate();
+    let rng = &mut rng.0;
+    let mut spawned = 0;
+    for _ in 0..HORDE_BATCH.min(room) {
+        let Some(&name) = HORDE_ROSTER.choose(rng) else {
+            continue;
+        };
+        let clearance = spawn_clearance(&prefabs, name);
+        let Some(position) = find_horde_position(&map, player_pos, clearance, rng) else {
+            continue;
+        };
+        spawn_enemy(
+            &mut commands,
+            position.extend(ENEMY_Z_POSITION),
+            name,
+            (HordeEnemy, ScaleWithDistance),
+        );
+        spawned += 1;
+    }
+
+    if spawned > 0 {
+        debug!(target: targets::EVENTS, "Horde sent {} more enemies", spawned);
+    }
+}
+
+/// Random clear point in a ring around the player, outside the view.
+fn find_horde_position(
+    map: &CollisionMap,
+    player_pos: Vec2,
+    clearance: f32,
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    (0..SPAWN_ATTEMPTS).find_map(|_| {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = rng.gen_range(HORDE_SPAWN_DISTANCE_MIN..HORDE_SPAWN_DISTANCE_MAX);
+        let candidate = player_pos + Vec2::from_angle(angle) * distance;
+        map.is_circle_clear(candidate, clearance).then_some(candidate)
+    })
+}
+
+/// Horde enemies come straight for the player, however far away they start.
+pub fn rouse_horde(mut enemies: Query<&mut AIBehavior, Added<HordeEnemy>>) {
+    for mut ai in &mut enemies {
+        ai.detection_range = ai.detection_range.max(HORDE_DETECTION_RANGE);
+    }
+}
+
+/// Keeps the blood moon's buffs on every enemy while it's up, including
+/// ones that spawn during the night. They wear off shortly after dawn.
+pub fn empower_under_blood_moon(
+    time: Res<Time>,
+    director: Res<EventDirector>,
+    mut enemies: Query<&mut Buffs, With<Enemy>>,
+) {
+    if director.active() != Some(WorldEvent::BloodMoon) {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let specs = [
+        BuffSpec {
+            kind: BuffKind::Damage,
+            magnitude: BLOOD_MOON_DAMAGE,
+            duration: BLOOD_MOON_BUFF_SECS,
+        },
+        BuffSpec {
+            kind: BuffKind::Speed,
+            magnitude: BLOOD_MOON_SPEED,
+            duration: BLOOD_MOON_BUFF_SECS,
+        },
+    ];
+    for mut buffs in &mut enemies {
+        // Damage stacks, so it's only topped up once it has run out
+        for spec in specs {
+            if buffs.get(spec.kind).is_none() {
+                buffs.apply(spec.into(), now);
+            }
+        }
+    }
+}
+
+/// Seeing a night through heals the player fully and hands out the event's items.
+pub fn reward_survivors(
+    survived: On<WorldEventSurvived>,
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    players: Query<(Entity, &Health), With<Player>>,
+) {
+    let Ok((player, health)) = players.single() else {
+        return;
+    };
+    commands.trigger(Heal {
+        target: player,
+        amount: health.max,
+    });
+    for &item in survived.event.rewards() {
+        inventory.add(item);
+    }
+    info!(
+        target: targets::EVENTS,
+        "Rewarded surviving the {} with {} items",
+        survived.event.display_name(),
+        survived.event.rewards().len()
+    );
+}
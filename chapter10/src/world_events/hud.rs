@@ -0,0 +1,124 @@
+// src/world_events/hud.rs
+use bevy::prelude::*;
+
+use super::director::{secs_until, EventDirector, EventPhase, WorldEvent};
+use crate::config::world_events::{DAWN_HOUR, NIGHTFALL_HOUR};
+use crate::lighting::DayNightCycle;
+use crate::ui::{TextRole, UiTheme};
+
+const HORDE_COLOR: Color = Color::srgb(1.0, 0.6, 0.25);
+const BLOOD_MOON_COLOR: Color = Color::srgb(0.95, 0.25, 0.25);
+const SURVIVED_COLOR: Color = Color::srgb(0.9, 0.85, 0.6);
+
+#[derive(Component)]
+pub struct EventBanner;
+
+#[derive(Component)]
+pub struct EventBannerText;
+
+/// Top-centre banner, hidden while no event is coming or running.
+/// Re-entering Playing (from pause or a load) keeps the existing one.
+pub fn spawn_event_banner(
+    mut commands: Commands,
+    theme: Res<UiTheme>,
+    existing: Query<(), With<EventBanner>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            EventBanner,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
+                ))
+                .with_child((
+                    EventBannerText,
+                    Text::new(""),
+                    theme.text(TextRole::Body),
+                    TextColor(Color::WHITE),
+                ));
+        });
+}
+
+/// Counts down to nightfall for an announced event and to dawn for a running one.
+pub fn update_event_banner(
+    director: Res<EventDirector>,
+    cycle: Res<DayNightCycle>,
+    mut banners: Query<&mut Visibility, With<EventBanner>>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<EventBannerText>>,
+) {
+    let (Ok(mut visibility), Ok((mut text, mut color))) = (banners.single_mut(), texts.single_mut())
+    else {
+        return;
+    };
+
+    let (label, tint) = match director.phase {
+        EventPhase::Idle => {
+            visibility.set_if_neq(Visibility::Hidden);
+            return;
+        }
+        EventPhase::Announced(event) => (
+            format!(
+                "{} falls in {}",
+                event.display_name(),
+                format_countdown(secs_until(&cycle, NIGHTFALL_HOUR))
+            ),
+            event_color(event),
+        ),
+        EventPhase::Active(event) => (
+            format!(
+                "{} - survive until dawn {}",
+                event.display_name().to_uppercase(),
+                format_countdown(secs_until(&cycle, DAWN_HOUR))
+            ),
+            event_color(event),
+        ),
+        EventPhase::Survived { event, .. } => (
+            format!("Survived the {}! Rewards added to your inventory", event.display_name()),
+            SURVIVED_COLOR,
+        ),
+    };
+
+    visibility.set_if_neq(Visibility::Inherited);
+    if text.0 != label {
+        text.0 = label;
+    }
+    if color.0 != tint {
+        color.0 = tint;
+    }
+}
+
+fn event_color(event: WorldEvent) -> Color {
+    match event {
+        WorldEvent::HordeNight => HORDE_COLOR,
+        WorldEvent::BloodMoon => BLOOD_MOON_COLOR,
+    }
+}
+
+fn format_countdown(seconds: f32) -> String {
+    let total = seconds.ceil() as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+pub fn despawn_event_banner(mut commands: Commands, query: Query<Entity, With<EventBanner>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,44 @@
+// src/world_events/mod.rs
+mod director;
+mod effects;
+mod hud;
+
+use bevy::prelude::*;
+
+use crate::schedule::{world_ready, GameSet};
+use crate::state::{GameMode, GameState};
+
+pub use director::{EventDirector, EventPhase};
+
+/// Special nights scheduled off the day/night clock in story mode: a horde
+/// night that keeps sending enemies, or a blood moon that empowers them.
+/// Each is announced at dusk with a countdown, and making it to dawn pays out.
+pub struct WorldEventsPlugin;
+
+impl Plugin for WorldEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<director::EventDirector>()
+            .init_resource::<effects::HordeTimer>()
+            .add_observer(effects::reward_survivors)
+            .add_systems(OnEnter(GameState::Loading), director::reset_world_events)
+            .add_systems(
+                Update,
+                (
+                    director::advance_world_events,
+                    effects::spawn_horde,
+                    effects::empower_under_blood_moon,
+                )
+                    .chain()
+                    .run_if(resource_equals(GameMode::Story))
+                    .run_if(world_ready),
+            )
+            .add_systems(Update, effects::rouse_horde.in_set(GameSet::AI))
+            .add_systems(
+                OnEnter(GameState::Playing),
+                hud::spawn_event_banner.run_if(resource_equals(GameMode::Story)),
+            )
+            .add_systems(Update, hud::update_event_banner.in_set(GameSet::Render))
+            .add_systems(OnEnter(GameState::GameOver), hud::despawn_event_banner)
+            .add_systems(OnEnter(GameState::MainMenu), hud::despawn_event_banner);
+    }
+}